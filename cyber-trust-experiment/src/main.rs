@@ -1,12 +1,17 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::net::UdpSocket;
+use tokio::sync::{Notify, RwLock};
 use tokio::time::{interval, Duration};
 use tracing::info;
 use uuid::Uuid;
 use warp::Filter;
 use dashmap::DashMap;
+use rand::seq::SliceRandom;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityMetrics {
@@ -66,6 +71,29 @@ pub enum ResponseStatus {
     Failed,
 }
 
+impl ResponseType {
+    fn as_label(&self) -> &'static str {
+        match self {
+            ResponseType::Isolation => "isolation",
+            ResponseType::Quarantine => "quarantine",
+            ResponseType::Alert => "alert",
+            ResponseType::Mitigation => "mitigation",
+            ResponseType::Recovery => "recovery",
+        }
+    }
+}
+
+impl ResponseStatus {
+    fn as_label(&self) -> &'static str {
+        match self {
+            ResponseStatus::Pending => "pending",
+            ResponseStatus::InProgress => "in_progress",
+            ResponseStatus::Completed => "completed",
+            ResponseStatus::Failed => "failed",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemTopology {
     pub nodes: Vec<NodeInfo>,
@@ -119,6 +147,492 @@ pub type SecurityStore = Arc<DashMap<String, SecurityMetrics>>;
 pub type IncidentStore = Arc<DashMap<String, IncidentResponse>>;
 pub type TopologyStore = Arc<RwLock<SystemTopology>>;
 
+/// A causal-token change feed shared by the security and incident stores:
+/// a monotonic version counter bumped whenever the monitoring or
+/// prediction loop mutates either store, plus a `Notify` so `/api/security/watch`
+/// requests can park instead of repolling.
+pub struct ChangeFeed {
+    version: AtomicU64,
+    notify: Notify,
+}
+
+impl ChangeFeed {
+    pub fn new() -> Self {
+        Self { version: AtomicU64::new(1), notify: Notify::new() }
+    }
+
+    /// Bumps the version and wakes any parked watchers.
+    pub fn bump(&self) -> u64 {
+        let version = self.version.fetch_add(1, Ordering::SeqCst) + 1;
+        self.notify.notify_waiters();
+        version
+    }
+
+    pub fn current(&self) -> u64 {
+        self.version.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for ChangeFeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counters for Prometheus `/metrics`, updated from the monitoring loops
+/// as attack indicators and incidents are created. Gauges aren't tracked
+/// here since they're read straight off `SecurityStore` at scrape time.
+#[derive(Default)]
+pub struct MetricsRegistry {
+    indicator_totals: DashMap<String, AtomicU64>,
+    incident_totals: DashMap<(String, String), AtomicU64>,
+}
+
+impl MetricsRegistry {
+    fn record_indicator(&self, indicator_type: &str) {
+        self.indicator_totals
+            .entry(indicator_type.to_string())
+            .or_insert_with(|| AtomicU64::new(0))
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_incident(&self, response_type: &str, status: &str) {
+        self.incident_totals
+            .entry((response_type.to_string(), status.to_string()))
+            .or_insert_with(|| AtomicU64::new(0))
+            .fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// A parsed `SecurityRule.condition`: a field/operator/value comparison
+/// such as `trust_score < 0.7`, the `anomaly_detected` predicate, or two
+/// of these joined by `and`/`or`, compiled once at load instead of
+/// re-parsed every tick.
+#[derive(Debug, Clone)]
+pub enum ConditionAst {
+    Compare { field: String, op: ComparisonOp, value: f64 },
+    AnomalyDetected,
+    And(Box<ConditionAst>, Box<ConditionAst>),
+    Or(Box<ConditionAst>, Box<ConditionAst>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ComparisonOp {
+    Lt,
+    Gt,
+    Eq,
+}
+
+impl ConditionAst {
+    /// Parses the tiny condition grammar: `<field> <op> <number>`, the
+    /// literal predicate `anomaly_detected`, and `and`/`or` joining two
+    /// sub-conditions.
+    pub fn parse(condition: &str) -> Option<ConditionAst> {
+        if let Some((lhs, rhs)) = condition.split_once(" and ") {
+            return Some(ConditionAst::And(
+                Box::new(ConditionAst::parse(lhs.trim())?),
+                Box::new(ConditionAst::parse(rhs.trim())?),
+            ));
+        }
+        if let Some((lhs, rhs)) = condition.split_once(" or ") {
+            return Some(ConditionAst::Or(
+                Box::new(ConditionAst::parse(lhs.trim())?),
+                Box::new(ConditionAst::parse(rhs.trim())?),
+            ));
+        }
+
+        if condition.trim() == "anomaly_detected" {
+            return Some(ConditionAst::AnomalyDetected);
+        }
+
+        for (token, op) in [("<", ComparisonOp::Lt), (">", ComparisonOp::Gt), ("==", ComparisonOp::Eq)] {
+            if let Some((field, value)) = condition.split_once(token) {
+                let value: f64 = value.trim().parse().ok()?;
+                return Some(ConditionAst::Compare { field: field.trim().to_string(), op, value });
+            }
+        }
+
+        None
+    }
+
+    fn eval(&self, metrics: &SecurityMetrics) -> bool {
+        match self {
+            ConditionAst::Compare { field, op, value } => {
+                let Some(field_value) = metrics.field_value(field) else {
+                    return false;
+                };
+                match op {
+                    ComparisonOp::Lt => field_value < *value,
+                    ComparisonOp::Gt => field_value > *value,
+                    ComparisonOp::Eq => (field_value - *value).abs() < f64::EPSILON,
+                }
+            }
+            ConditionAst::AnomalyDetected => !metrics.attack_indicators.is_empty(),
+            ConditionAst::And(a, b) => a.eval(metrics) && b.eval(metrics),
+            ConditionAst::Or(a, b) => a.eval(metrics) || b.eval(metrics),
+        }
+    }
+}
+
+impl SecurityMetrics {
+    fn field_value(&self, field: &str) -> Option<f64> {
+        match field {
+            "trust_score" => Some(self.trust_score),
+            "threat_level" => Some(self.threat_level),
+            "behavioral_score" => Some(self.behavioral_score),
+            "network_score" => Some(self.network_score),
+            "system_score" => Some(self.system_score),
+            "overall_security" => Some(self.overall_security),
+            _ => None,
+        }
+    }
+}
+
+/// A single step of a `Directive`: matches indicators by type plus an
+/// optional node filter, and requires `occurrence` matches within
+/// `timeout` before advancing. `capability_filter` is carried with the
+/// stage but is not evaluated when matching.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stage {
+    pub indicator_type: String,
+    pub node_filter: Option<String>,
+    pub capability_filter: Option<String>,
+    pub occurrence: u32,
+    pub timeout_secs: i64,
+    pub reliability: f64,
+    /// An additional metric-based gate in the same `<field> <op> <value>`
+    /// grammar as `SecurityRule.condition` (e.g. `"trust_score < 0.7"`),
+    /// compiled once by `DirectiveEngine` rather than re-parsed per tick.
+    pub condition: Option<String>,
+}
+
+/// An ordered list of `Stage`s modeled on SIEM correlation directives:
+/// once indicators have satisfied every stage in turn, a risk score is
+/// computed from the stages' mean reliability, and an `IncidentResponse`
+/// is raised if it reaches the threshold. No risk is computed for a
+/// partially matched directive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Directive {
+    pub directive_id: String,
+    pub name: String,
+    pub priority: i32,
+    pub risk_threshold: f64,
+    pub stages: Vec<Stage>,
+}
+
+#[derive(Debug, Clone)]
+struct DirectiveBacklogEntry {
+    stage_index: usize,
+    occurrences_in_window: u32,
+    window_started: chrono::DateTime<chrono::Utc>,
+    matched_indicators: Vec<AttackIndicator>,
+}
+
+pub type DirectiveStore = Arc<RwLock<Vec<Directive>>>;
+
+/// Consumes the `AttackIndicator` stream across nodes and advances
+/// per-directive-per-node backlogs, emitting an `IncidentResponse` once a
+/// directive's accumulated risk crosses its threshold.
+pub struct DirectiveEngine {
+    directives: DirectiveStore,
+    backlog: DashMap<(String, String), DirectiveBacklogEntry>,
+    // Compiled once per distinct condition string so the grammar isn't
+    // re-parsed on every tick.
+    compiled_conditions: DashMap<String, ConditionAst>,
+}
+
+impl DirectiveEngine {
+    pub fn new(directives: DirectiveStore) -> Self {
+        Self { directives, backlog: DashMap::new(), compiled_conditions: DashMap::new() }
+    }
+
+    fn condition_holds(&self, condition: &Option<String>, metrics: &SecurityMetrics) -> bool {
+        let Some(condition) = condition else {
+            return true;
+        };
+        if !self.compiled_conditions.contains_key(condition) {
+            if let Some(ast) = ConditionAst::parse(condition) {
+                self.compiled_conditions.insert(condition.clone(), ast);
+            }
+        }
+        match self.compiled_conditions.get(condition) {
+            Some(ast) => ast.eval(metrics),
+            None => true,
+        }
+    }
+
+    /// Evaluates every directive's current stage against `node_id`'s newly
+    /// fired indicators, recomputing risk and emitting an incident when a
+    /// directive completes.
+    pub async fn process_indicators(
+        &self,
+        node_id: &str,
+        indicators: &[AttackIndicator],
+        metrics: &SecurityMetrics,
+        asset_value: f64,
+    ) -> Vec<(Directive, Vec<AttackIndicator>, f64)> {
+        let directives = self.directives.read().await;
+        let mut completed = Vec::new();
+        let now = chrono::Utc::now();
+
+        for directive in directives.iter() {
+            let key = (directive.directive_id.clone(), node_id.to_string());
+            let mut entry = self
+                .backlog
+                .entry(key.clone())
+                .or_insert(DirectiveBacklogEntry {
+                    stage_index: 0,
+                    occurrences_in_window: 0,
+                    window_started: now,
+                    matched_indicators: Vec::new(),
+                });
+
+            let Some(stage) = directive.stages.get(entry.stage_index) else {
+                continue;
+            };
+
+            if (now - entry.window_started).num_seconds() > stage.timeout_secs {
+                entry.stage_index = 0;
+                entry.occurrences_in_window = 0;
+                entry.window_started = now;
+                entry.matched_indicators.clear();
+            }
+
+            for indicator in indicators {
+                if indicator.indicator_type != stage.indicator_type {
+                    continue;
+                }
+                if let Some(filter) = &stage.node_filter {
+                    if filter != node_id {
+                        continue;
+                    }
+                }
+                if !self.condition_holds(&stage.condition, metrics) {
+                    continue;
+                }
+
+                entry.occurrences_in_window += 1;
+                entry.matched_indicators.push(indicator.clone());
+
+                if entry.occurrences_in_window >= stage.occurrence {
+                    entry.stage_index += 1;
+                    entry.occurrences_in_window = 0;
+                    entry.window_started = now;
+
+                    if entry.stage_index >= directive.stages.len() {
+                        let reliability: f64 = directive.stages.iter().map(|s| s.reliability).sum::<f64>()
+                            / directive.stages.len() as f64;
+                        let risk = reliability * directive.priority as f64 * asset_value / 25.0;
+
+                        if risk >= directive.risk_threshold {
+                            completed.push((directive.clone(), entry.matched_indicators.clone(), risk));
+                        }
+
+                        entry.stage_index = 0;
+                        entry.matched_indicators.clear();
+                    }
+                    break;
+                }
+            }
+        }
+
+        completed
+    }
+}
+
+/// A compact, fixed-size Bloom filter over `(node_id, version)` pairs, used
+/// so a gossip pull request can tell a peer what it already holds without
+/// shipping the full key set.
+struct BloomFilter {
+    bits: Vec<u64>,
+    num_hashes: u32,
+}
+
+impl BloomFilter {
+    fn new(num_bits: usize, num_hashes: u32) -> Self {
+        Self { bits: vec![0u64; num_bits.div_ceil(64)], num_hashes }
+    }
+
+    fn hashes(&self, key: &str) -> impl Iterator<Item = usize> + '_ {
+        use std::hash::{Hash, Hasher};
+        let total_bits = self.bits.len() * 64;
+        (0..self.num_hashes).map(move |i| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            (key, i).hash(&mut hasher);
+            (hasher.finish() as usize) % total_bits
+        })
+    }
+
+    fn insert(&mut self, key: &str) {
+        for bit in self.hashes(key).collect::<Vec<_>>() {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        self.hashes(key).all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+}
+
+/// One node's advertised view of a `SecurityMetrics` entry, tagged with a
+/// per-node monotonic logical counter so concurrent updates resolve by
+/// last-version-wins rather than relying on wall-clock skew.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct VersionedMetrics {
+    version: u64,
+    metrics: SecurityMetrics,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum GossipMessage {
+    /// Unsolicited push of this node's locally-updated entries.
+    Push(Vec<(String, VersionedMetrics)>),
+    /// A pull request carrying a Bloom filter of `(node_id, version)` pairs
+    /// the requester already holds, so the reply contains only new data.
+    Pull { bloom_bits: Vec<u64>, num_hashes: u32 },
+    /// Reply to a `Pull`, containing only entries missing from the filter.
+    PullResponse(Vec<(String, VersionedMetrics)>),
+}
+
+/// CRDT-style cluster membership: a gossip subsystem so several `lanc`
+/// instances converge on a shared view of `SecurityMetrics` without a
+/// central database. Conflicts resolve by highest logical version.
+pub struct SecurityGossip {
+    local_store: SecurityStore,
+    cluster: DashMap<String, VersionedMetrics>,
+    local_version: AtomicU64,
+    socket: Arc<UdpSocket>,
+    peers: RwLock<Vec<SocketAddr>>,
+}
+
+const GOSSIP_ENTRY_TIMEOUT_SECS: i64 = 300;
+
+impl SecurityGossip {
+    pub async fn bind(bind_addr: &str, local_store: SecurityStore, peers: Vec<SocketAddr>) -> std::io::Result<Arc<Self>> {
+        let socket = Arc::new(UdpSocket::bind(bind_addr).await?);
+        let gossip = Arc::new(Self {
+            local_store,
+            cluster: DashMap::new(),
+            local_version: AtomicU64::new(0),
+            socket,
+            peers: RwLock::new(peers),
+        });
+        tokio::spawn(gossip.clone().recv_loop());
+        Ok(gossip)
+    }
+
+    /// Bumps this node's logical version and merges its own latest metrics
+    /// into the cluster map, then returns the merged view keyed by
+    /// `node_id` for callers like `get_security_metrics`.
+    pub fn merged_view(&self) -> HashMap<String, SecurityMetrics> {
+        let version = self.local_version.fetch_add(1, Ordering::SeqCst) + 1;
+        for entry in self.local_store.iter() {
+            self.merge_entry(entry.key().clone(), VersionedMetrics { version, metrics: entry.value().clone() });
+        }
+
+        self.cluster
+            .iter()
+            .map(|e| (e.key().clone(), e.value().metrics.clone()))
+            .collect()
+    }
+
+    fn merge_entry(&self, node_id: String, incoming: VersionedMetrics) {
+        let should_apply = match self.cluster.get(&node_id) {
+            Some(existing) => incoming.version > existing.version,
+            None => true,
+        };
+        if should_apply {
+            self.cluster.insert(node_id, incoming);
+        }
+    }
+
+    /// One round of anti-entropy: push a random subset of our entries to a
+    /// random peer, or pull whatever that peer has that we're missing.
+    pub async fn gossip_round(&self) {
+        let peers = self.peers.read().await;
+        let Some(&peer) = peers.choose(&mut rand::thread_rng()) else {
+            return;
+        };
+        drop(peers);
+
+        self.prune_stale_entries();
+
+        if rand::random::<bool>() {
+            let sample: Vec<(String, VersionedMetrics)> = self
+                .cluster
+                .iter()
+                .collect::<Vec<_>>()
+                .choose_multiple(&mut rand::thread_rng(), 8.min(self.cluster.len().max(1)))
+                .map(|e| (e.key().clone(), e.value().clone()))
+                .collect();
+            if let Ok(bytes) = serde_json::to_vec(&GossipMessage::Push(sample)) {
+                let _ = self.socket.send_to(&bytes, peer).await;
+            }
+        } else {
+            let mut filter = BloomFilter::new(2048, 4);
+            for entry in self.cluster.iter() {
+                filter.insert(&format!("{}:{}", entry.key(), entry.value().version));
+            }
+            let message = GossipMessage::Pull { bloom_bits: filter.bits.clone(), num_hashes: filter.num_hashes };
+            if let Ok(bytes) = serde_json::to_vec(&message) {
+                let _ = self.socket.send_to(&bytes, peer).await;
+            }
+        }
+    }
+
+    fn prune_stale_entries(&self) {
+        let now = chrono::Utc::now();
+        self.cluster
+            .retain(|_, v| (now - v.metrics.timestamp).num_seconds() < GOSSIP_ENTRY_TIMEOUT_SECS);
+    }
+
+    async fn recv_loop(self: Arc<Self>) {
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            let (len, src) = match self.socket.recv_from(&mut buf).await {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+
+            let Ok(message) = serde_json::from_slice::<GossipMessage>(&buf[..len]) else {
+                continue;
+            };
+
+            {
+                let mut peers = self.peers.write().await;
+                if !peers.contains(&src) {
+                    peers.push(src);
+                }
+            }
+
+            match message {
+                GossipMessage::Push(entries) => {
+                    for (node_id, versioned) in entries {
+                        self.merge_entry(node_id, versioned);
+                    }
+                }
+                GossipMessage::Pull { bloom_bits, num_hashes } => {
+                    let filter = BloomFilter { bits: bloom_bits, num_hashes };
+                    let missing: Vec<(String, VersionedMetrics)> = self
+                        .cluster
+                        .iter()
+                        .filter(|e| !filter.contains(&format!("{}:{}", e.key(), e.value().version)))
+                        .map(|e| (e.key().clone(), e.value().clone()))
+                        .collect();
+                    if let Ok(bytes) = serde_json::to_vec(&GossipMessage::PullResponse(missing)) {
+                        let _ = self.socket.send_to(&bytes, src).await;
+                    }
+                }
+                GossipMessage::PullResponse(entries) => {
+                    for (node_id, versioned) in entries {
+                        self.merge_entry(node_id, versioned);
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
@@ -130,33 +644,169 @@ async fn main() -> Result<()> {
         connections: vec![],
         security_policies: vec![],
     }));
-    
+    let directive_store: DirectiveStore = Arc::new(RwLock::new(default_directives()));
+    let directive_engine = Arc::new(DirectiveEngine::new(directive_store.clone()));
+
     // Initialize demo data
     initialize_demo_data(&security_store, &topology_store).await;
-    
+
+    // Join the gossip cluster: a static seed list plus any peers learned at
+    // runtime, so several lanc instances converge on one cluster-wide view.
+    let gossip_bind_addr = std::env::var("LANC_GOSSIP_BIND").unwrap_or_else(|_| "0.0.0.0:7946".to_string());
+    let seed_peers: Vec<SocketAddr> = std::env::var("LANC_GOSSIP_PEERS")
+        .unwrap_or_default()
+        .split(',')
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| s.parse().ok())
+        .collect();
+    let gossip = match SecurityGossip::bind(&gossip_bind_addr, security_store.clone(), seed_peers).await {
+        Ok(gossip) => Some(gossip),
+        Err(e) => {
+            info!("gossip disabled, failed to bind {}: {}", gossip_bind_addr, e);
+            None
+        }
+    };
+
+    if let Some(gossip) = gossip.clone() {
+        tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_secs(5));
+            loop {
+                ticker.tick().await;
+                gossip.gossip_round().await;
+            }
+        });
+    }
+
+    let change_feed = Arc::new(ChangeFeed::new());
+    let metrics_registry = Arc::new(MetricsRegistry::default());
+    let health = Arc::new(HealthTracker::new());
+
     // Start security monitoring loop
     let security_store_clone = security_store.clone();
     let incident_store_clone = incident_store.clone();
     let topology_store_clone = topology_store.clone();
+    let directive_engine_clone = directive_engine.clone();
+    let change_feed_clone = change_feed.clone();
+    let metrics_registry_clone = metrics_registry.clone();
+    let health_clone = health.clone();
     tokio::spawn(async move {
-        security_monitoring_loop(security_store_clone, incident_store_clone, topology_store_clone).await;
+        security_monitoring_loop(
+            security_store_clone,
+            incident_store_clone,
+            topology_store_clone,
+            directive_engine_clone,
+            change_feed_clone,
+            metrics_registry_clone,
+            health_clone,
+        )
+        .await;
     });
-    
+
     // Start threat prediction loop
     let security_store_clone = security_store.clone();
+    let change_feed_clone = change_feed.clone();
+    let metrics_registry_clone = metrics_registry.clone();
     tokio::spawn(async move {
-        threat_prediction_loop(security_store_clone).await;
+        threat_prediction_loop(security_store_clone, change_feed_clone, metrics_registry_clone).await;
     });
-    
+
+    // sd-notify readiness/watchdog integration, a no-op off-systemd so
+    // `cargo run` locally is unaffected.
+    let watchdog_security_store = security_store.clone();
+    let watchdog_incident_store = incident_store.clone();
+    tokio::spawn(async move {
+        watchdog_task(health, watchdog_security_store, watchdog_incident_store).await;
+    });
+
     // Setup web server
-    let routes = create_routes(security_store, incident_store, topology_store);
-    
-    info!("Starting Cyber-Trust Experiment server on http://localhost:8080");
-    warp::serve(routes).run(([0, 0, 0, 0], 8080)).await;
-    
+    let routes = create_routes(
+        security_store,
+        incident_store,
+        topology_store,
+        directive_store,
+        gossip,
+        change_feed,
+        metrics_registry,
+    );
+
+    let (bound_addr, server) = warp::serve(routes).bind_ephemeral(([0, 0, 0, 0], 8080));
+    let _ = sd_notify("READY=1");
+    info!("Starting Cyber-Trust Experiment server on http://{}", bound_addr);
+    server.await;
+
+    Ok(())
+}
+
+/// Tracks when the monitoring loop last made progress, so the watchdog
+/// task can withhold its keepalive ping (and let systemd restart the
+/// service) if the loop has stalled rather than blindly pinging on a timer.
+struct HealthTracker {
+    last_tick_unix_secs: AtomicU64,
+}
+
+impl HealthTracker {
+    fn new() -> Self {
+        Self { last_tick_unix_secs: AtomicU64::new(0) }
+    }
+
+    fn mark(&self) {
+        self.last_tick_unix_secs.store(chrono::Utc::now().timestamp().max(0) as u64, Ordering::Relaxed);
+    }
+
+    fn seconds_since_last_tick(&self) -> u64 {
+        let last = self.last_tick_unix_secs.load(Ordering::Relaxed);
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        now.saturating_sub(last)
+    }
+}
+
+/// Sends an sd-notify datagram to `$NOTIFY_SOCKET` (supporting the Linux
+/// abstract-namespace `@` convention). A no-op, not an error, when the
+/// process wasn't launched under a notify-capable supervisor.
+fn sd_notify(state: &str) -> std::io::Result<()> {
+    let Ok(mut socket_path) = std::env::var("NOTIFY_SOCKET") else {
+        return Ok(());
+    };
+    if let Some(rest) = socket_path.strip_prefix('@') {
+        socket_path = format!("\0{}", rest);
+    }
+    let socket = std::os::unix::net::UnixDatagram::unbound()?;
+    socket.connect(&socket_path)?;
+    socket.send(state.as_bytes())?;
     Ok(())
 }
 
+/// Parses the systemd-provided `WATCHDOG_USEC` so pings fire at roughly
+/// half the configured timeout, per sd_watchdog_enabled(3) convention.
+fn sd_watchdog_interval() -> Option<Duration> {
+    let watchdog_usec: u64 = std::env::var("WATCHDOG_USEC").ok()?.parse().ok()?;
+    Some(Duration::from_micros(watchdog_usec / 2))
+}
+
+async fn watchdog_task(health: Arc<HealthTracker>, security_store: SecurityStore, incident_store: IncidentStore) {
+    let Some(ping_interval) = sd_watchdog_interval() else {
+        return;
+    };
+    let mut ticker = interval(ping_interval.max(Duration::from_millis(100)));
+
+    loop {
+        ticker.tick().await;
+
+        // Only keepalive if the monitoring loop (3s cadence) has ticked
+        // recently; a stalled loop withholds the ping so systemd restarts us.
+        if health.seconds_since_last_tick() < 30 {
+            let _ = sd_notify("WATCHDOG=1");
+        }
+
+        let status = format!(
+            "STATUS=monitoring {} nodes, {} active incidents",
+            security_store.len(),
+            incident_store.len()
+        );
+        let _ = sd_notify(&status);
+    }
+}
+
 async fn initialize_demo_data(security_store: &SecurityStore, topology_store: &TopologyStore) {
     let mut topology = topology_store.write().await;
     
@@ -230,17 +880,79 @@ async fn initialize_demo_data(security_store: &SecurityStore, topology_store: &T
     ];
 }
 
-async fn security_monitoring_loop(security_store: SecurityStore, incident_store: IncidentStore, _topology_store: TopologyStore) {
+/// Seeds the two escalating SIEM-style directives the experiment ships
+/// with: a two-stage supply-chain-to-compromise chain and a single-stage
+/// zero-day exploitation directive.
+fn default_directives() -> Vec<Directive> {
+    vec![
+        Directive {
+            directive_id: "directive-supply-chain".to_string(),
+            name: "Supply chain compromise escalation".to_string(),
+            priority: 3,
+            risk_threshold: 2.0,
+            stages: vec![
+                Stage {
+                    indicator_type: "supply_chain".to_string(),
+                    node_filter: None,
+                    capability_filter: None,
+                    occurrence: 1,
+                    timeout_secs: 60,
+                    reliability: 6.0,
+                    condition: None,
+                },
+                Stage {
+                    indicator_type: "predicted_threat".to_string(),
+                    node_filter: None,
+                    capability_filter: None,
+                    occurrence: 1,
+                    timeout_secs: 60,
+                    reliability: 8.0,
+                    condition: Some("trust_score < 0.7".to_string()),
+                },
+            ],
+        },
+        Directive {
+            directive_id: "directive-zero-day".to_string(),
+            name: "Zero-day exploitation".to_string(),
+            priority: 4,
+            risk_threshold: 2.0,
+            stages: vec![Stage {
+                indicator_type: "zero_day".to_string(),
+                node_filter: None,
+                capability_filter: None,
+                occurrence: 1,
+                timeout_secs: 30,
+                reliability: 9.0,
+                condition: Some("threat_level > 0.8".to_string()),
+            }],
+        },
+    ]
+}
+
+async fn security_monitoring_loop(
+    security_store: SecurityStore,
+    incident_store: IncidentStore,
+    topology_store: TopologyStore,
+    directive_engine: Arc<DirectiveEngine>,
+    change_feed: Arc<ChangeFeed>,
+    metrics_registry: Arc<MetricsRegistry>,
+    health: Arc<HealthTracker>,
+) {
     let mut interval = interval(Duration::from_secs(3));
-    
+
     loop {
         interval.tick().await;
-        
+
+        // Indicators freshly raised this tick, per node, fed to the
+        // directive engine once the DashMap borrows below are released.
+        let mut new_indicators_by_node: Vec<(String, Vec<AttackIndicator>, SecurityMetrics)> = Vec::new();
+
         // Simulate security monitoring for each node
         for mut entry in security_store.iter_mut() {
             let node_id = entry.key().clone();
             let metrics = entry.value_mut();
-            
+            let indicators_before = metrics.attack_indicators.len();
+
             // Simulate different attack scenarios
             let attack_probability = match node_id.as_str() {
                 "smart-city-1" => 0.1, // Supply chain compromise
@@ -340,37 +1052,187 @@ async fn security_monitoring_loop(security_store: SecurityStore, incident_store:
                     actions_taken: vec!["Security assessment initiated".to_string()],
                 };
                 
+                metrics_registry.record_incident(incident.response_type.as_label(), incident.status.as_label());
                 incident_store.insert(incident_id, incident);
             }
+
+            if metrics.attack_indicators.len() > indicators_before {
+                for indicator in &metrics.attack_indicators[indicators_before..] {
+                    metrics_registry.record_indicator(&indicator.indicator_type);
+                }
+                new_indicators_by_node.push((
+                    node_id.clone(),
+                    metrics.attack_indicators[indicators_before..].to_vec(),
+                    metrics.clone(),
+                ));
+            }
         }
-        
+
+        let asset_values: HashMap<String, f64> = topology_store
+            .read()
+            .await
+            .nodes
+            .iter()
+            .map(|n| (n.node_id.clone(), n.trust_level))
+            .collect();
+
+        for (node_id, indicators, metrics) in new_indicators_by_node {
+            let asset_value = asset_values.get(&node_id).copied().unwrap_or(metrics.trust_score);
+            let completed = directive_engine.process_indicators(&node_id, &indicators, &metrics, asset_value).await;
+
+            for (directive, matched_indicators, risk) in completed {
+                let incident_id = Uuid::new_v4().to_string();
+                let incident = IncidentResponse {
+                    incident_id: incident_id.clone(),
+                    node_id: node_id.clone(),
+                    response_type: ResponseType::Alert,
+                    timestamp: chrono::Utc::now(),
+                    status: ResponseStatus::InProgress,
+                    actions_taken: vec![format!(
+                        "Directive '{}' matched (risk={:.2}) from {} indicator(s)",
+                        directive.name,
+                        risk,
+                        matched_indicators.len()
+                    )],
+                };
+                metrics_registry.record_incident(incident.response_type.as_label(), incident.status.as_label());
+                incident_store.insert(incident_id, incident);
+            }
+        }
+
+        change_feed.bump();
+        health.mark();
         info!("Security monitoring completed for {} nodes", security_store.len());
     }
 }
 
-async fn threat_prediction_loop(security_store: SecurityStore) {
+/// Tunable Holt-Winters triple exponential smoothing parameters. Different
+/// node types have different baselines, so operators can override the
+/// defaults per deployment rather than trip on every noisy spike.
+#[derive(Debug, Clone, Copy)]
+pub struct HoltWintersConfig {
+    /// Level smoothing factor.
+    pub alpha: f64,
+    /// Trend smoothing factor.
+    pub beta: f64,
+    /// Seasonal smoothing factor.
+    pub gamma: f64,
+    /// Seasonal period length, in samples.
+    pub period: usize,
+    /// How many residual standard deviations away from the forecast counts
+    /// as an anomaly.
+    pub k_sigma: f64,
+    /// Samples to absorb before the residual band is trusted enough to
+    /// score anomalies on.
+    pub warmup_samples: usize,
+}
+
+impl Default for HoltWintersConfig {
+    fn default() -> Self {
+        Self { alpha: 0.3, beta: 0.1, gamma: 0.2, period: 8, k_sigma: 3.0, warmup_samples: 16 }
+    }
+}
+
+/// Per-`node_id` Holt-Winters model of a single metric stream: bounded
+/// history, running level/trend/seasonal components, and a running
+/// estimate of residual variance used as the anomaly confidence band.
+struct HoltWintersModel {
+    config: HoltWintersConfig,
+    history: VecDeque<f64>,
+    seasonals: VecDeque<f64>,
+    level: f64,
+    trend: f64,
+    residual_variance: f64,
+    samples_seen: usize,
+}
+
+impl HoltWintersModel {
+    fn new(config: HoltWintersConfig) -> Self {
+        let period = config.period.max(1);
+        Self {
+            history: VecDeque::with_capacity(period * 4),
+            seasonals: VecDeque::from(vec![0.0; period]),
+            level: 0.0,
+            trend: 0.0,
+            residual_variance: 0.0,
+            samples_seen: 0,
+            config,
+        }
+    }
+
+    /// Feeds one sample into the model and, once warmed up, returns the
+    /// one-step forecast residual expressed in standard deviations.
+    fn observe(&mut self, y: f64) -> Option<f64> {
+        self.history.push_back(y);
+        if self.history.len() > self.config.period * 4 {
+            self.history.pop_front();
+        }
+
+        let seasonal_lag = *self.seasonals.front().unwrap_or(&0.0);
+        let mut anomaly_sigmas = None;
+
+        if self.samples_seen == 0 {
+            self.level = y;
+        } else {
+            let forecast = self.level + self.trend + seasonal_lag;
+            let residual = y - forecast;
+
+            if self.samples_seen >= self.config.warmup_samples {
+                let std_dev = self.residual_variance.sqrt().max(1e-6);
+                anomaly_sigmas = Some(residual.abs() / std_dev);
+                self.residual_variance = 0.9 * self.residual_variance + 0.1 * residual * residual;
+            } else {
+                self.residual_variance += (residual * residual - self.residual_variance) / (self.samples_seen as f64 + 1.0);
+            }
+
+            let prev_level = self.level;
+            let new_level = self.config.alpha * (y - seasonal_lag) + (1.0 - self.config.alpha) * (self.level + self.trend);
+            self.trend = self.config.beta * (new_level - prev_level) + (1.0 - self.config.beta) * self.trend;
+            self.level = new_level;
+        }
+
+        let new_seasonal = self.config.gamma * (y - self.level) + (1.0 - self.config.gamma) * seasonal_lag;
+        self.seasonals.pop_front();
+        self.seasonals.push_back(new_seasonal);
+        self.samples_seen += 1;
+
+        anomaly_sigmas
+    }
+}
+
+async fn threat_prediction_loop(security_store: SecurityStore, change_feed: Arc<ChangeFeed>, metrics_registry: Arc<MetricsRegistry>) {
     let mut interval = interval(Duration::from_secs(10));
-    
+    let mut models: HashMap<String, HoltWintersModel> = HashMap::new();
+
     loop {
         interval.tick().await;
-        
-        // Simulate ML-based threat prediction
+
+        // Holt-Winters seasonal anomaly detection per node, replacing the
+        // flat threat_level/behavioral_score blend that fired on any spike.
         for mut entry in security_store.iter_mut() {
             let metrics = entry.value_mut();
-            
-            // Simple threat prediction based on current metrics
-            let predicted_threat = (metrics.threat_level * 0.7 + metrics.behavioral_score * 0.3).min(1.0);
-            
-            if predicted_threat > 0.7 {
-                metrics.attack_indicators.push(AttackIndicator {
-                    indicator_type: "predicted_threat".to_string(),
-                    severity: predicted_threat,
-                    description: "ML model predicts potential security incident".to_string(),
-                    timestamp: chrono::Utc::now(),
-                });
+            let model = models
+                .entry(metrics.node_id.clone())
+                .or_insert_with(|| HoltWintersModel::new(HoltWintersConfig::default()));
+
+            if let Some(sigmas) = model.observe(metrics.threat_level) {
+                if sigmas > model.config.k_sigma {
+                    let severity = ((sigmas - model.config.k_sigma) / model.config.k_sigma).min(1.0);
+                    metrics.attack_indicators.push(AttackIndicator {
+                        indicator_type: "seasonal_anomaly".to_string(),
+                        severity,
+                        description: format!(
+                            "Holt-Winters forecast residual {:.2}sigma exceeds {:.1}sigma confidence band",
+                            sigmas, model.config.k_sigma
+                        ),
+                        timestamp: chrono::Utc::now(),
+                    });
+                    metrics_registry.record_indicator("seasonal_anomaly");
+                }
             }
         }
-        
+
+        change_feed.bump();
         info!("Threat prediction completed for {} nodes", security_store.len());
     }
 }
@@ -379,47 +1241,215 @@ fn create_routes(
     security_store: SecurityStore,
     incident_store: IncidentStore,
     topology_store: TopologyStore,
+    directive_store: DirectiveStore,
+    gossip: Option<Arc<SecurityGossip>>,
+    change_feed: Arc<ChangeFeed>,
+    metrics_registry: Arc<MetricsRegistry>,
 ) -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
     let security_store = warp::any().map(move || security_store.clone());
     let incident_store = warp::any().map(move || incident_store.clone());
     let topology_store = warp::any().map(move || topology_store.clone());
-    
+    let directive_store = warp::any().map(move || directive_store.clone());
+    let gossip_filter = warp::any().map(move || gossip.clone());
+    let change_feed_filter = warp::any().map(move || change_feed.clone());
+    let metrics_registry_filter = warp::any().map(move || metrics_registry.clone());
+
     let security_metrics = warp::path("api")
         .and(warp::path("security"))
+        .and(warp::path::end())
         .and(warp::get())
-        .and(security_store)
+        .and(security_store.clone())
+        .and(gossip_filter)
         .and_then(get_security_metrics);
-    
+
+    let watch = warp::path("api")
+        .and(warp::path("security"))
+        .and(warp::path("watch"))
+        .and(warp::get())
+        .and(warp::query::<WatchQuery>())
+        .and(security_store.clone())
+        .and(incident_store.clone())
+        .and(change_feed_filter)
+        .and_then(watch_security);
+
     let incidents = warp::path("api")
         .and(warp::path("incidents"))
         .and(warp::get())
         .and(incident_store)
         .and_then(get_incidents);
-    
+
     let topology = warp::path("api")
         .and(warp::path("topology"))
         .and(warp::get())
-        .and(topology_store)
+        .and(topology_store.clone())
         .and_then(get_topology);
-    
+
+    let directives = warp::path("api")
+        .and(warp::path("directives"))
+        .and(warp::get())
+        .and(directive_store)
+        .and_then(get_directives);
+
+    let metrics = warp::path("metrics")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(security_store)
+        .and(topology_store)
+        .and(metrics_registry_filter)
+        .and_then(get_metrics);
+
     let dashboard = warp::path::end()
         .and(warp::get())
         .map(|| warp::reply::html(include_str!("../static/dashboard.html")));
-    
-    security_metrics.or(incidents).or(topology).or(dashboard)
+
+    security_metrics.or(watch).or(incidents).or(topology).or(directives).or(metrics).or(dashboard)
 }
 
-async fn get_security_metrics(security_store: SecurityStore) -> Result<impl warp::Reply, warp::Rejection> {
-    let metrics: Vec<SecurityMetrics> = security_store.iter().map(|entry| entry.value().clone()).collect();
+async fn get_security_metrics(security_store: SecurityStore, gossip: Option<Arc<SecurityGossip>>) -> Result<impl warp::Reply, warp::Rejection> {
+    let metrics: Vec<SecurityMetrics> = match gossip {
+        Some(gossip) => gossip.merged_view().into_values().collect(),
+        None => security_store.iter().map(|entry| entry.value().clone()).collect(),
+    };
     Ok(warp::reply::json(&metrics))
 }
 
+#[derive(Debug, Deserialize)]
+struct WatchQuery {
+    since: Option<u64>,
+    timeout: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct WatchResponse {
+    version: u64,
+    changed: bool,
+    security: Vec<SecurityMetrics>,
+    incidents: Vec<IncidentResponse>,
+}
+
+/// `GET /api/security/watch?since=<version>&timeout=<secs>`. Returns
+/// immediately with the current snapshot and version if `since` is stale,
+/// otherwise parks on the change feed's `Notify` until the next mutation or
+/// the timeout elapses (an empty, `changed: false` body on timeout).
+async fn watch_security(
+    query: WatchQuery,
+    security_store: SecurityStore,
+    incident_store: IncidentStore,
+    change_feed: Arc<ChangeFeed>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let since = query.since.unwrap_or(0);
+    let timeout_secs = query.timeout.unwrap_or(30).clamp(1, 120);
+
+    let snapshot = |version: u64| WatchResponse {
+        version,
+        changed: true,
+        security: security_store.iter().map(|e| e.value().clone()).collect(),
+        incidents: incident_store.iter().map(|e| e.value().clone()).collect(),
+    };
+
+    let current = change_feed.current();
+    if since < current {
+        return Ok(warp::reply::json(&snapshot(current)));
+    }
+
+    let notified = change_feed.notify.notified();
+    match tokio::time::timeout(Duration::from_secs(timeout_secs), notified).await {
+        Ok(_) => Ok(warp::reply::json(&snapshot(change_feed.current()))),
+        Err(_) => Ok(warp::reply::json(&WatchResponse {
+            version: since,
+            changed: false,
+            security: vec![],
+            incidents: vec![],
+        })),
+    }
+}
+
 async fn get_incidents(incident_store: IncidentStore) -> Result<impl warp::Reply, warp::Rejection> {
     let incidents: Vec<IncidentResponse> = incident_store.iter().map(|entry| entry.value().clone()).collect();
     Ok(warp::reply::json(&incidents))
 }
 
+async fn get_directives(directive_store: DirectiveStore) -> Result<impl warp::Reply, warp::Rejection> {
+    let directives = directive_store.read().await;
+    Ok(warp::reply::json(&*directives))
+}
+
 async fn get_topology(topology_store: TopologyStore) -> Result<impl warp::Reply, warp::Rejection> {
     let topology = topology_store.read().await;
     Ok(warp::reply::json(&*topology))
 }
+
+fn node_type_label(node_type: &NodeType) -> &'static str {
+    match node_type {
+        NodeType::SmartCity => "smart_city",
+        NodeType::IndustrialIoT => "industrial_iot",
+        NodeType::EdgeCloud => "edge_cloud",
+        NodeType::LegacySystem => "legacy_system",
+    }
+}
+
+/// Prometheus text-exposition-format `/metrics`: per-node security gauges
+/// plus cumulative counters for attack indicators and incidents.
+async fn get_metrics(
+    security_store: SecurityStore,
+    topology_store: TopologyStore,
+    metrics_registry: Arc<MetricsRegistry>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let topology = topology_store.read().await;
+    let node_info: HashMap<&str, &NodeInfo> = topology.nodes.iter().map(|n| (n.node_id.as_str(), n)).collect();
+
+    let mut out = String::new();
+
+    let gauges: [(&str, fn(&SecurityMetrics) -> f64); 6] = [
+        ("lanc_threat_level", |m| m.threat_level),
+        ("lanc_trust_score", |m| m.trust_score),
+        ("lanc_behavioral_score", |m| m.behavioral_score),
+        ("lanc_network_score", |m| m.network_score),
+        ("lanc_system_score", |m| m.system_score),
+        ("lanc_overall_security", |m| m.overall_security),
+    ];
+
+    for (name, _) in &gauges {
+        out.push_str(&format!("# TYPE {} gauge\n", name));
+    }
+
+    for entry in security_store.iter() {
+        let metrics = entry.value();
+        let (node_type, architecture) = match node_info.get(metrics.node_id.as_str()) {
+            Some(node) => (node_type_label(&node.node_type), node.architecture.as_str()),
+            None => ("unknown", "unknown"),
+        };
+        for (name, value_of) in &gauges {
+            out.push_str(&format!(
+                "{}{{node_id=\"{}\",node_type=\"{}\",architecture=\"{}\"}} {}\n",
+                name,
+                metrics.node_id,
+                node_type,
+                architecture,
+                value_of(metrics)
+            ));
+        }
+    }
+
+    out.push_str("# TYPE lanc_attack_indicators_total counter\n");
+    for entry in metrics_registry.indicator_totals.iter() {
+        out.push_str(&format!(
+            "lanc_attack_indicators_total{{indicator_type=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value().load(Ordering::Relaxed)
+        ));
+    }
+
+    out.push_str("# TYPE lanc_incidents_total counter\n");
+    for entry in metrics_registry.incident_totals.iter() {
+        let (response_type, status) = entry.key();
+        out.push_str(&format!(
+            "lanc_incidents_total{{response_type=\"{}\",status=\"{}\"}} {}\n",
+            response_type,
+            status,
+            entry.value().load(Ordering::Relaxed)
+        ));
+    }
+
+    Ok(warp::reply::with_header(out, "Content-Type", "text/plain; version=0.0.4"))
+}
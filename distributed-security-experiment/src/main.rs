@@ -1,9 +1,12 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use rand::seq::SliceRandom;
+use rusqlite::OptionalExtension;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio::time::{interval, Duration};
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 use warp::Filter;
 use dashmap::DashMap;
@@ -156,14 +159,899 @@ pub struct SupplyChainComponent {
     pub last_updated: chrono::DateTime<chrono::Utc>,
 }
 
-pub type SecurityStore = Arc<DashMap<String, SecurityMetrics>>;
+/// `SecurityMetrics` tagged with a per-node monotonic version, so peers can
+/// merge each other's gossiped state last-writer-wins by version instead of
+/// by wall-clock time (clocks drift and skew across nodes; a counter each
+/// node owns and increments itself never does).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionedMetrics {
+    pub version: u64,
+    pub metrics: SecurityMetrics,
+}
+
+/// The CRDT map every node maintains: `node_id -> VersionedMetrics`, merged
+/// by keeping whichever side has the higher version for each node id. The
+/// local monitoring and propagation loops update every entry in this map,
+/// including entries first learned from a peer; there is no locally-owned
+/// subset that only this process mutates.
+pub type SecurityStore = Arc<DashMap<String, VersionedMetrics>>;
 pub type IncidentStore = Arc<DashMap<String, IncidentResponse>>;
 pub type TopologyStore = Arc<RwLock<SystemTopology>>;
+pub type AlarmStore = Arc<DashMap<String, Alarm>>;
+
+/// Inserts `incoming` into `store` under `node_id` if its version is
+/// strictly newer than whatever `store` already holds for that id. Returns
+/// whether the store actually changed, so callers can skip re-advertising
+/// unchanged state.
+fn merge_versioned(store: &SecurityStore, node_id: &str, incoming: &VersionedMetrics) -> bool {
+    let changed = match store.get(node_id) {
+        Some(existing) => incoming.version > existing.version,
+        None => true,
+    };
+    if changed {
+        store.insert(node_id.to_string(), incoming.clone());
+    }
+    changed
+}
+
+/// A small Bloom filter over `(node_id, version)` pairs, sized for
+/// `expected_entries` items at `target_fp_rate` false positives using the
+/// standard `m = -n*ln(p)/(ln 2)^2`, `k = (m/n)*ln 2` sizing formulas. Lets
+/// an anti-entropy pull request describe "everything I already have" in
+/// bounded space instead of growing linearly with the size of the gossiped
+/// state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: u32,
+}
+
+impl BloomFilter {
+    pub fn new(expected_entries: usize, target_fp_rate: f64) -> Self {
+        let n = expected_entries.max(1) as f64;
+        let num_bits = ((-n * target_fp_rate.ln()) / std::f64::consts::LN_2.powi(2)).ceil().max(64.0) as usize;
+        let num_hashes = ((num_bits as f64 / n) * std::f64::consts::LN_2).round().max(1.0) as u32;
+
+        Self {
+            bits: vec![0u64; num_bits.div_ceil(64)],
+            num_bits,
+            num_hashes,
+        }
+    }
+
+    /// Kirsch-Mitzenmacher double hashing: derives `num_hashes` independent
+    /// bit positions from two base hashes instead of computing `num_hashes`
+    /// separate hash functions.
+    fn bit_positions(&self, node_id: &str, version: u64) -> impl Iterator<Item = usize> + '_ {
+        use std::hash::{Hash, Hasher};
+        let mut h1 = std::collections::hash_map::DefaultHasher::new();
+        (node_id, version, 0u8).hash(&mut h1);
+        let h1 = h1.finish();
+        let mut h2 = std::collections::hash_map::DefaultHasher::new();
+        (node_id, version, 1u8).hash(&mut h2);
+        let h2 = h2.finish();
+
+        (0..self.num_hashes as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2))) as usize % self.num_bits)
+    }
+
+    pub fn insert(&mut self, node_id: &str, version: u64) {
+        for bit in self.bit_positions(node_id, version).collect::<Vec<_>>() {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// May return a false positive; never a false negative. `false` means
+    /// `(node_id, version)` was definitely never inserted.
+    pub fn contains(&self, node_id: &str, version: u64) -> bool {
+        self.bit_positions(node_id, version).all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+}
+
+/// This node's position in the gossip fan-out topology: a small, fixed set
+/// of well-known `Seed`s every node always gossips with, versus ordinary
+/// `Member`s learned dynamically from whoever gossips with us. Bounds
+/// fan-out as the member count grows past a handful of nodes, instead of
+/// every node needing every other node's address up front.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeerTier {
+    Seed,
+    Member,
+}
+
+/// What this node knows about a peer's gossip participation, for pruning
+/// and for `GET /api/peers`.
+#[derive(Debug, Clone)]
+pub struct PeerInfo {
+    pub tier: PeerTier,
+    pub last_seen: chrono::DateTime<chrono::Utc>,
+    pub last_advertised_versions: HashMap<String, u64>,
+}
+
+pub type PeerStore = Arc<RwLock<HashMap<String, PeerInfo>>>;
+
+/// Non-seed peers silent longer than this are pruned from `PeerStore`
+/// (seeds are always kept) -- anti-entropy
+/// naturally re-discovers a peer that comes back, so there's no harm in
+/// forgetting one that's gone quiet.
+const PEER_SILENCE_TIMEOUT: chrono::Duration = chrono::Duration::seconds(60);
+
+/// Anti-entropy gossip wire format: a `Pull` carries a Bloom filter of what
+/// the sender already holds so the receiver only has to reply with entries
+/// that filter doesn't represent, then a `PullResponse` carries exactly
+/// those entries back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GossipWireMessage {
+    Pull { from_addr: String, filter: BloomFilter },
+    PullResponse { from_addr: String, entries: Vec<(String, VersionedMetrics)> },
+}
+
+/// Pluggable durable-persistence backend, so metrics and incident history
+/// survive a restart instead of always starting over from
+/// `initialize_demo_data`. Async (unlike trust-monitoring-system's
+/// sled-backed `Store`) because the default implementation talks to
+/// SQLite through blocking calls that have to be spawned off the runtime.
+#[async_trait::async_trait]
+pub trait Storage: Send + Sync {
+    /// Everything needed to hydrate the in-memory stores at startup.
+    /// Returns an empty metrics map when there's nothing on disk yet, so
+    /// callers know to fall back to `initialize_demo_data`.
+    async fn load_all(&self) -> Result<(HashMap<String, VersionedMetrics>, Vec<IncidentResponse>, Option<SystemTopology>)>;
+    async fn upsert_metrics(&self, node_id: &str, metrics: &VersionedMetrics) -> Result<()>;
+    async fn record_incident(&self, incident: &IncidentResponse) -> Result<()>;
+    async fn snapshot_topology(&self, topology: &SystemTopology) -> Result<()>;
+    /// Deletes incidents older than `retention_days`, so history doesn't
+    /// grow without bound across a long-running deployment.
+    async fn prune_incidents(&self, retention_days: i64) -> Result<()>;
+}
+
+/// Keeps nothing: `load_all` always reports empty so the caller seeds demo
+/// data, and every write is a no-op. The default when `STORAGE_DB_PATH`
+/// isn't configured -- matches today's in-memory-only behavior.
+#[derive(Default)]
+pub struct MemoryStorage;
+
+#[async_trait::async_trait]
+impl Storage for MemoryStorage {
+    async fn load_all(&self) -> Result<(HashMap<String, VersionedMetrics>, Vec<IncidentResponse>, Option<SystemTopology>)> {
+        Ok((HashMap::new(), Vec::new(), None))
+    }
+
+    async fn upsert_metrics(&self, _node_id: &str, _metrics: &VersionedMetrics) -> Result<()> {
+        Ok(())
+    }
+
+    async fn record_incident(&self, _incident: &IncidentResponse) -> Result<()> {
+        Ok(())
+    }
+
+    async fn snapshot_topology(&self, _topology: &SystemTopology) -> Result<()> {
+        Ok(())
+    }
+
+    async fn prune_incidents(&self, _retention_days: i64) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Embedded SQLite-backed `Storage`, the default once `STORAGE_DB_PATH` is
+/// set. Each table keys on whatever field lookups/pruning actually need
+/// and otherwise stores the row as a JSON blob, so the schema doesn't have
+/// to track every `SecurityMetrics`/`IncidentResponse` field change.
+pub struct SqliteStorage {
+    conn: Arc<std::sync::Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteStorage {
+    /// Opens (or creates) a SQLite database file at `path` and ensures its
+    /// tables exist.
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let conn = rusqlite::Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS metrics (
+                node_id TEXT PRIMARY KEY,
+                version INTEGER NOT NULL,
+                payload TEXT NOT NULL
+             );
+             CREATE TABLE IF NOT EXISTS incidents (
+                incident_id TEXT PRIMARY KEY,
+                created_at TEXT NOT NULL,
+                payload TEXT NOT NULL
+             );
+             CREATE TABLE IF NOT EXISTS topology (
+                id INTEGER PRIMARY KEY CHECK (id = 0),
+                payload TEXT NOT NULL
+             );",
+        )?;
+        Ok(Self { conn: Arc::new(std::sync::Mutex::new(conn)) })
+    }
+}
+
+#[async_trait::async_trait]
+impl Storage for SqliteStorage {
+    async fn load_all(&self) -> Result<(HashMap<String, VersionedMetrics>, Vec<IncidentResponse>, Option<SystemTopology>)> {
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || -> Result<_> {
+            let conn = conn.lock().unwrap();
+
+            let mut metrics = HashMap::new();
+            let mut stmt = conn.prepare("SELECT node_id, payload FROM metrics")?;
+            let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+            for row in rows {
+                let (node_id, payload) = row?;
+                metrics.insert(node_id, serde_json::from_str(&payload)?);
+            }
+
+            let mut incidents = Vec::new();
+            let mut stmt = conn.prepare("SELECT payload FROM incidents ORDER BY created_at")?;
+            let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            for row in rows {
+                incidents.push(serde_json::from_str(&row?)?);
+            }
+
+            let topology = conn
+                .query_row("SELECT payload FROM topology WHERE id = 0", [], |row| row.get::<_, String>(0))
+                .optional()?
+                .map(|payload| serde_json::from_str(&payload))
+                .transpose()?;
+
+            Ok((metrics, incidents, topology))
+        })
+        .await?
+    }
+
+    async fn upsert_metrics(&self, node_id: &str, metrics: &VersionedMetrics) -> Result<()> {
+        let conn = self.conn.clone();
+        let node_id = node_id.to_string();
+        let version = metrics.version as i64;
+        let payload = serde_json::to_string(metrics)?;
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            conn.lock().unwrap().execute(
+                "INSERT INTO metrics (node_id, version, payload) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(node_id) DO UPDATE SET version = excluded.version, payload = excluded.payload",
+                rusqlite::params![node_id, version, payload],
+            )?;
+            Ok(())
+        })
+        .await?
+    }
+
+    async fn record_incident(&self, incident: &IncidentResponse) -> Result<()> {
+        let conn = self.conn.clone();
+        let incident_id = incident.incident_id.clone();
+        let created_at = incident.timestamp.to_rfc3339();
+        let payload = serde_json::to_string(incident)?;
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            conn.lock().unwrap().execute(
+                "INSERT OR REPLACE INTO incidents (incident_id, created_at, payload) VALUES (?1, ?2, ?3)",
+                rusqlite::params![incident_id, created_at, payload],
+            )?;
+            Ok(())
+        })
+        .await?
+    }
+
+    async fn snapshot_topology(&self, topology: &SystemTopology) -> Result<()> {
+        let conn = self.conn.clone();
+        let payload = serde_json::to_string(topology)?;
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            conn.lock().unwrap().execute(
+                "INSERT INTO topology (id, payload) VALUES (0, ?1)
+                 ON CONFLICT(id) DO UPDATE SET payload = excluded.payload",
+                rusqlite::params![payload],
+            )?;
+            Ok(())
+        })
+        .await?
+    }
+
+    async fn prune_incidents(&self, retention_days: i64) -> Result<()> {
+        let conn = self.conn.clone();
+        let cutoff = (chrono::Utc::now() - chrono::Duration::days(retention_days)).to_rfc3339();
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            conn.lock().unwrap().execute("DELETE FROM incidents WHERE created_at < ?1", rusqlite::params![cutoff])?;
+            Ok(())
+        })
+        .await?
+    }
+}
+
+/// Node ids with metrics changed since the last flush, so
+/// `storage_flush_loop` can write-through on a batched interval instead of
+/// on every field tweak `security_monitoring_loop` makes.
+pub type PendingWrites = Arc<RwLock<std::collections::HashSet<String>>>;
+
+/// Every `flush_interval`, persists whatever nodes were marked dirty since
+/// the last pass. Runs independently of the (much more frequent)
+/// simulation tick, so a busy node doesn't turn into a write per field
+/// change.
+async fn storage_flush_loop(security_store: SecurityStore, storage: Arc<dyn Storage>, pending: PendingWrites, flush_interval: Duration) {
+    let mut ticker = tokio::time::interval(flush_interval);
+    loop {
+        ticker.tick().await;
+
+        let dirty: Vec<String> = std::mem::take(&mut *pending.write().await).into_iter().collect();
+        for node_id in dirty {
+            let Some(versioned) = security_store.get(&node_id).map(|entry| entry.value().clone()) else { continue };
+            if let Err(e) = storage.upsert_metrics(&node_id, &versioned).await {
+                warn!(node_id = %node_id, error = %e, "failed to persist metrics");
+            }
+        }
+    }
+}
+
+/// Periodically deletes incidents older than `retention_days` so history
+/// kept for post-crash querying doesn't grow without bound.
+async fn retention_loop(storage: Arc<dyn Storage>, retention_days: i64, check_interval: Duration) {
+    let mut ticker = tokio::time::interval(check_interval);
+    loop {
+        ticker.tick().await;
+        if let Err(e) = storage.prune_incidents(retention_days).await {
+            warn!(error = %e, "failed to prune old incidents");
+        }
+    }
+}
+
+/// OpenTelemetry instrumentation for the monitoring loop: each node's
+/// `threat_level`/`trust_score`/`overall_security` as gauges labeled by
+/// `node_id`/`domain`/`hardware_type`, a counter per
+/// `AttackIndicator::indicator_type`, and a span per incident-response
+/// decision carrying `incident_id`/`response_type`/`cross_domain_coordination`.
+/// Behind the `otel` feature so a deployment without a collector isn't
+/// forced to pull in `opentelemetry`/`opentelemetry-otlp` -- when the
+/// feature is off this is a zero-cost no-op with the same API.
+#[cfg(feature = "otel")]
+pub struct Telemetry {
+    tracer: opentelemetry_sdk::trace::Tracer,
+    threat_level_gauge: opentelemetry::metrics::Gauge<f64>,
+    trust_score_gauge: opentelemetry::metrics::Gauge<f64>,
+    overall_security_gauge: opentelemetry::metrics::Gauge<f64>,
+    indicator_counter: opentelemetry::metrics::Counter<u64>,
+}
+
+#[cfg(feature = "otel")]
+impl std::fmt::Debug for Telemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Telemetry").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "otel")]
+impl Telemetry {
+    /// Stands up OTLP tracer and meter providers pointed at
+    /// `OTEL_EXPORTER_OTLP_ENDPOINT` (default `http://localhost:4317`),
+    /// using `OTEL_EXPORTER_OTLP_PROTOCOL` (`grpc` or `http/protobuf`,
+    /// default `grpc`) and sampling at `OTEL_TRACES_SAMPLER_ARG` (default
+    /// `1.0`, i.e. sample everything), and registers them as the
+    /// process-wide global providers.
+    pub fn init() -> Result<Self> {
+        let endpoint = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").unwrap_or_else(|_| "http://localhost:4317".to_string());
+        let protocol = std::env::var("OTEL_EXPORTER_OTLP_PROTOCOL").unwrap_or_else(|_| "grpc".to_string());
+        let sample_ratio = std::env::var("OTEL_TRACES_SAMPLER_ARG")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or(1.0);
+        let is_http = protocol.eq_ignore_ascii_case("http/protobuf") || protocol.eq_ignore_ascii_case("http");
+
+        let trace_config = opentelemetry_sdk::trace::config()
+            .with_sampler(opentelemetry_sdk::trace::Sampler::TraceIdRatioBased(sample_ratio));
+        let tracer_provider = if is_http {
+            opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_trace_config(trace_config)
+                .with_exporter(opentelemetry_otlp::new_exporter().http().with_endpoint(&endpoint))
+                .install_batch(opentelemetry_sdk::runtime::Tokio)?
+        } else {
+            opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_trace_config(trace_config)
+                .with_exporter(opentelemetry_otlp::new_exporter().tonic().with_endpoint(&endpoint))
+                .install_batch(opentelemetry_sdk::runtime::Tokio)?
+        };
+        let tracer = tracer_provider.tracer("distributed-security-experiment");
+        opentelemetry::global::set_tracer_provider(tracer_provider);
+
+        let meter_provider = if is_http {
+            opentelemetry_otlp::new_pipeline()
+                .metrics(opentelemetry_sdk::runtime::Tokio)
+                .with_exporter(opentelemetry_otlp::new_exporter().http().with_endpoint(&endpoint))
+                .build()?
+        } else {
+            opentelemetry_otlp::new_pipeline()
+                .metrics(opentelemetry_sdk::runtime::Tokio)
+                .with_exporter(opentelemetry_otlp::new_exporter().tonic().with_endpoint(&endpoint))
+                .build()?
+        };
+        let meter = meter_provider.meter("distributed-security-experiment");
+        opentelemetry::global::set_meter_provider(meter_provider);
+
+        let threat_level_gauge = meter.f64_gauge("security_threat_level").with_description("Current threat level per node").init();
+        let trust_score_gauge = meter.f64_gauge("security_trust_score").with_description("Current trust score per node").init();
+        let overall_security_gauge = meter.f64_gauge("security_overall_score").with_description("Current overall security score per node").init();
+        let indicator_counter = meter
+            .u64_counter("security_attack_indicators_total")
+            .with_description("Attack indicators observed, per indicator_type")
+            .init();
+
+        Ok(Self { tracer, threat_level_gauge, trust_score_gauge, overall_security_gauge, indicator_counter })
+    }
+
+    fn node_attributes(metrics: &SecurityMetrics) -> Vec<opentelemetry::KeyValue> {
+        vec![
+            opentelemetry::KeyValue::new("node_id", metrics.node_id.clone()),
+            opentelemetry::KeyValue::new("domain", format!("{:?}", metrics.domain)),
+            opentelemetry::KeyValue::new("hardware_type", format!("{:?}", metrics.hardware_type)),
+        ]
+    }
+
+    /// Records the current snapshot of `metrics`' gauges.
+    pub fn record_metrics(&self, metrics: &SecurityMetrics) {
+        let attrs = Self::node_attributes(metrics);
+        self.threat_level_gauge.record(metrics.threat_level, &attrs);
+        self.trust_score_gauge.record(metrics.trust_score, &attrs);
+        self.overall_security_gauge.record(metrics.overall_security, &attrs);
+    }
+
+    /// Increments the per-`indicator_type` counter.
+    pub fn record_indicator(&self, indicator_type: &str) {
+        self.indicator_counter.add(1, &[opentelemetry::KeyValue::new("indicator_type", indicator_type.to_string())]);
+    }
+
+    /// Starts (and immediately ends, on drop) a span for one
+    /// incident-response decision, tagged with the fields an external
+    /// collector needs to correlate it with the triggering node.
+    pub fn start_incident_span(&self, incident: &IncidentResponse) -> opentelemetry::trace::BoxedSpan {
+        self.tracer
+            .span_builder("incident_response_decision")
+            .with_attributes(vec![
+                opentelemetry::KeyValue::new("incident_id", incident.incident_id.clone()),
+                opentelemetry::KeyValue::new("response_type", format!("{:?}", incident.response_type)),
+                opentelemetry::KeyValue::new("cross_domain_coordination", incident.cross_domain_coordination),
+            ])
+            .start(&self.tracer)
+    }
+}
+
+/// No-op stand-in used when the `otel` feature is disabled, so call sites
+/// in `security_monitoring_loop` don't need their own `cfg` gates.
+#[cfg(not(feature = "otel"))]
+#[derive(Debug, Default)]
+pub struct Telemetry;
+
+#[cfg(not(feature = "otel"))]
+impl Telemetry {
+    pub fn init() -> Result<Self> {
+        Ok(Self)
+    }
+
+    pub fn record_metrics(&self, _metrics: &SecurityMetrics) {}
+
+    pub fn record_indicator(&self, _indicator_type: &str) {}
+
+    pub fn start_incident_span(&self, _incident: &IncidentResponse) {}
+}
+
+/// Initializes the global `tracing` subscriber: always logs to stdout, and
+/// additionally fans spans/events to an OTLP exporter when built with the
+/// `otel` feature and `OTEL_EXPORTER_OTLP_ENDPOINT` is set. Returns the
+/// `Telemetry` handle `main` should hold onto for the rest of the process
+/// and thread into `security_monitoring_loop`, if OTLP export was enabled.
+fn init_tracing() -> Option<Telemetry> {
+    #[cfg(feature = "otel")]
+    {
+        if std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").is_ok() {
+            use tracing_subscriber::layer::SubscriberExt;
+            use tracing_subscriber::util::SubscriberInitExt;
+
+            match Telemetry::init() {
+                Ok(telemetry) => {
+                    tracing_subscriber::registry()
+                        .with(tracing_subscriber::fmt::layer())
+                        .with(tracing_opentelemetry::layer())
+                        .init();
+                    return Some(telemetry);
+                }
+                Err(e) => {
+                    eprintln!("failed to initialize OTLP export, falling back to stdout-only logging: {e}");
+                }
+            }
+        }
+    }
+
+    tracing_subscriber::fmt::init();
+    None
+}
+
+/// One stage of a `CorrelationRule`: an indicator advances a rule instance
+/// past this stage only if its `indicator_type` matches, its `severity` is
+/// at least `min_severity`, and (when set) its `source_domain` matches
+/// `source_domain`.
+#[derive(Debug, Clone)]
+pub struct CorrelationStage {
+    pub indicator_type: String,
+    pub min_severity: f64,
+    pub source_domain: Option<String>,
+}
+
+impl CorrelationStage {
+    fn matches(&self, indicator: &AttackIndicator) -> bool {
+        indicator.indicator_type == self.indicator_type
+            && indicator.severity >= self.min_severity
+            && self.source_domain.as_deref().map_or(true, |d| indicator.source_domain.as_deref() == Some(d))
+    }
+}
+
+/// What ties together the indicators that can advance the same rule
+/// instance: either they must all land on the same node, or they can land
+/// on any node as long as they share `source_domain` (for attacks that
+/// visibly move across the topology rather than stay put).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrelationScope {
+    SameNode,
+    SameSourceDomain,
+}
+
+/// A directed, multi-stage attack pattern: an ordered list of `stages`
+/// that must each be satisfied in turn within `window` of the first match,
+/// scoped by `scope`. `reliability_step` is added to a matching instance's
+/// `reliability` per stage advanced (beyond the first), capped at
+/// `max_reliability`.
+#[derive(Debug, Clone)]
+pub struct CorrelationRule {
+    pub rule_id: String,
+    pub stages: Vec<CorrelationStage>,
+    pub window: chrono::Duration,
+    pub scope: CorrelationScope,
+    pub base_reliability: f64,
+    pub reliability_step: f64,
+    pub max_reliability: f64,
+}
+
+/// A consolidated, multi-stage threat surfaced on `/api/alarms`, replacing
+/// a handful of individually-unremarkable `AttackIndicator`s with a single
+/// alert that names the pattern that matched and how confident the engine
+/// is in it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alarm {
+    pub alarm_id: String,
+    pub rule_id: String,
+    pub scope_key: String,
+    pub reliability: f64,
+    pub contributing_indicators: Vec<AttackIndicator>,
+    pub triggered_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A rule part-way through matching: `next_stage` is the index into
+/// `rule.stages` the next indicator must satisfy, `contributing` is every
+/// indicator that has advanced it so far, and `started_at` anchors the
+/// `window` deadline.
+struct RuleInstance {
+    rule_id: String,
+    next_stage: usize,
+    contributing: Vec<AttackIndicator>,
+    reliability: f64,
+    started_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Caps backlog growth per scope key under an indicator flood -- once a
+/// scope has this many in-flight instances, the oldest is evicted to make
+/// room for a new one rather than growing without bound.
+const MAX_INSTANCES_PER_SCOPE: usize = 64;
+
+/// Matches the stream of `AttackIndicator`s pushed onto nodes against
+/// `rules`, maintaining a backlog of partially-matched instances and
+/// promoting completed ones to `Alarm`s.
+pub struct CorrelationEngine {
+    rules: Vec<CorrelationRule>,
+    backlog: RwLock<HashMap<String, Vec<RuleInstance>>>,
+    alarms: Arc<DashMap<String, Alarm>>,
+}
+
+impl CorrelationEngine {
+    pub fn new(rules: Vec<CorrelationRule>, alarms: Arc<DashMap<String, Alarm>>) -> Self {
+        Self { rules, backlog: RwLock::new(HashMap::new()), alarms }
+    }
+
+    fn scope_key(scope: CorrelationScope, node_id: &str, indicator: &AttackIndicator) -> Option<String> {
+        match scope {
+            CorrelationScope::SameNode => Some(format!("node:{}", node_id)),
+            CorrelationScope::SameSourceDomain => indicator.source_domain.as_ref().map(|d| format!("domain:{}", d)),
+        }
+    }
+
+    /// Feeds one freshly-observed indicator through every rule: advances
+    /// any in-progress instance whose next stage it satisfies, starts a
+    /// new instance for any rule whose first stage it satisfies, drops
+    /// instances whose window has elapsed, and promotes any instance that
+    /// just matched its final stage into an `Alarm`. A single indicator
+    /// can advance several distinct instances (one per matching
+    /// rule/scope), but only ever advances a given instance once, since
+    /// each instance's `next_stage` only matches one specific stage.
+    pub async fn ingest(&self, node_id: &str, indicator: &AttackIndicator) {
+        let now = indicator.timestamp;
+        let mut backlog = self.backlog.write().await;
+
+        for rule in &self.rules {
+            let Some(key) = Self::scope_key(rule.scope, node_id, indicator) else { continue };
+            let instances = backlog.entry(key.clone()).or_default();
+
+            // Drop instances whose window has already elapsed.
+            instances.retain(|instance| now - instance.started_at <= rule.window || instance.rule_id != rule.rule_id);
+
+            let mut advanced = false;
+            let mut completed_pos = None;
+            for (pos, instance) in instances.iter_mut().enumerate().filter(|(_, i)| i.rule_id == rule.rule_id) {
+                let Some(stage) = rule.stages.get(instance.next_stage) else { continue };
+                if !stage.matches(indicator) {
+                    continue;
+                }
+
+                instance.contributing.push(indicator.clone());
+                instance.next_stage += 1;
+                instance.reliability = (instance.reliability + rule.reliability_step).min(rule.max_reliability);
+                advanced = true;
+
+                if instance.next_stage >= rule.stages.len() {
+                    completed_pos = Some(pos);
+                }
+                break;
+            }
+
+            if let Some(pos) = completed_pos {
+                let instance = instances.remove(pos);
+                let alarm = Alarm {
+                    alarm_id: Uuid::new_v4().to_string(),
+                    rule_id: rule.rule_id.clone(),
+                    scope_key: key.clone(),
+                    reliability: instance.reliability,
+                    contributing_indicators: instance.contributing,
+                    triggered_at: now,
+                };
+                self.alarms.insert(alarm.alarm_id.clone(), alarm);
+            } else if !advanced {
+                if let Some(first_stage) = rule.stages.first() {
+                    if first_stage.matches(indicator) {
+                        instances.push(RuleInstance {
+                            rule_id: rule.rule_id.clone(),
+                            next_stage: 1,
+                            contributing: vec![indicator.clone()],
+                            reliability: rule.base_reliability,
+                            started_at: now,
+                        });
+                    }
+                }
+            }
+
+            if instances.len() > MAX_INSTANCES_PER_SCOPE {
+                instances.remove(0);
+            }
+        }
+    }
+
+    /// The demo correlation rules: supply-chain compromise -> legacy
+    /// exploit -> cross-domain propagation, the canonical multi-stage
+    /// attack this engine exists to catch.
+    pub fn default_rules() -> Vec<CorrelationRule> {
+        vec![CorrelationRule {
+            rule_id: "supply-chain-to-cross-domain".to_string(),
+            stages: vec![
+                CorrelationStage { indicator_type: "supply_chain_compromise".to_string(), min_severity: 0.5, source_domain: None },
+                CorrelationStage { indicator_type: "legacy_vulnerability".to_string(), min_severity: 0.5, source_domain: None },
+                CorrelationStage { indicator_type: "cross_domain_propagation".to_string(), min_severity: 0.3, source_domain: None },
+            ],
+            window: chrono::Duration::minutes(10),
+            scope: CorrelationScope::SameNode,
+            base_reliability: 0.3,
+            reliability_step: 0.3,
+            max_reliability: 0.95,
+        }]
+    }
+}
+
+/// A numeric field of `SecurityMetrics` a `Condition::Compare` can read,
+/// i.e. the `<field>` half of the `<field> <op> <number>` condition
+/// grammar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricField {
+    TrustScore,
+    ThreatLevel,
+    SupplyChainScore,
+    NetworkScore,
+    BehavioralScore,
+    OverallSecurity,
+}
+
+impl MetricField {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "trust_score" => Some(Self::TrustScore),
+            "threat_level" => Some(Self::ThreatLevel),
+            "supply_chain_score" => Some(Self::SupplyChainScore),
+            "network_score" => Some(Self::NetworkScore),
+            "behavioral_score" => Some(Self::BehavioralScore),
+            "overall_security" => Some(Self::OverallSecurity),
+            _ => None,
+        }
+    }
+
+    fn read(self, metrics: &SecurityMetrics) -> f64 {
+        match self {
+            Self::TrustScore => metrics.trust_score,
+            Self::ThreatLevel => metrics.threat_level,
+            Self::SupplyChainScore => metrics.supply_chain_score,
+            Self::NetworkScore => metrics.network_score,
+            Self::BehavioralScore => metrics.behavioral_score,
+            Self::OverallSecurity => metrics.overall_security,
+        }
+    }
+}
+
+/// The `<op>` half of the `<field> <op> <number>` condition grammar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComparisonOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+impl ComparisonOp {
+    fn parse(token: &str) -> Option<Self> {
+        match token {
+            "<" => Some(Self::Lt),
+            "<=" => Some(Self::Le),
+            ">" => Some(Self::Gt),
+            ">=" => Some(Self::Ge),
+            "==" => Some(Self::Eq),
+            _ => None,
+        }
+    }
+
+    fn apply(self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Self::Lt => lhs < rhs,
+            Self::Le => lhs <= rhs,
+            Self::Gt => lhs > rhs,
+            Self::Ge => lhs >= rhs,
+            Self::Eq => (lhs - rhs).abs() < f64::EPSILON,
+        }
+    }
+}
+
+/// Compiled form of a `SecurityRule::condition`, parsed once when
+/// `PolicyEngine::compile` runs instead of being re-parsed from the raw
+/// string on every monitoring tick.
+#[derive(Debug, Clone)]
+enum Condition {
+    Compare { field: MetricField, op: ComparisonOp, threshold: f64 },
+    /// True if the node has a supply-chain-related attack indicator and at
+    /// least one of its `third_party_components` has a known vulnerability.
+    VulnerabilityDetected,
+}
+
+impl Condition {
+    /// Parses a condition string like `"trust_score < 0.7"`, or the named
+    /// boolean predicate `"vulnerability_detected"`. Returns `None` for
+    /// anything outside that grammar.
+    fn parse(condition: &str) -> Option<Self> {
+        if condition.trim() == "vulnerability_detected" {
+            return Some(Self::VulnerabilityDetected);
+        }
+
+        let tokens: Vec<&str> = condition.split_whitespace().collect();
+        let [field, op, threshold] = tokens[..] else { return None };
+        let field = MetricField::parse(field)?;
+        let op = ComparisonOp::parse(op)?;
+        let threshold: f64 = threshold.parse().ok()?;
+        Some(Self::Compare { field, op, threshold })
+    }
+
+    fn matches(&self, node_id: &str, metrics: &SecurityMetrics, topology: &SystemTopology) -> bool {
+        match self {
+            Self::Compare { field, op, threshold } => op.apply(field.read(metrics), *threshold),
+            Self::VulnerabilityDetected => {
+                let has_supply_chain_indicator = metrics.attack_indicators.iter()
+                    .any(|indicator| indicator.indicator_type == "supply_chain_compromise");
+                has_supply_chain_indicator && topology.nodes.iter()
+                    .find(|node| node.node_id == node_id)
+                    .is_some_and(|node| {
+                        node.third_party_components.iter().any(|component_id| {
+                            topology.supply_chain_components.iter()
+                                .any(|component| &component.component_id == component_id && !component.vulnerabilities.is_empty())
+                        })
+                    })
+            }
+        }
+    }
+}
+
+/// A `SecurityRule::action`, mapped to the concrete effect it has when its
+/// rule fires.
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    IsolateNode,
+    QuarantineComponent,
+    AlertSecurityTeam,
+}
+
+impl Action {
+    fn parse(action: &str) -> Option<Self> {
+        match action {
+            "block_communication" | "isolate_node" => Some(Self::IsolateNode),
+            "quarantine_component" => Some(Self::QuarantineComponent),
+            "alert_security_team" => Some(Self::AlertSecurityTeam),
+            _ => None,
+        }
+    }
+}
+
+/// A `SecurityRule` with `condition`/`action` already parsed into their AST
+/// forms, carrying along its policy's `enforcement_level` since that's what
+/// gates whether the rule is allowed to fire once it matches.
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    rule_id: String,
+    condition: Condition,
+    action: Action,
+    priority: i32,
+    enforcement_level: f64,
+}
+
+/// Evaluates `SecurityPolicy::rules` against live `SecurityMetrics`,
+/// compiled once from a `TopologyStore` snapshot rather than re-parsing
+/// `SecurityRule::condition` strings on every monitoring tick.
+struct PolicyEngine {
+    rules: Vec<CompiledRule>,
+}
+
+impl PolicyEngine {
+    /// Compiles every rule in every policy currently in `topology_store`,
+    /// sorted by ascending `priority` (lowest wins when multiple rules
+    /// match the same node). Rules with an unparseable `condition`/`action`
+    /// are skipped and logged rather than failing the whole engine.
+    async fn compile(topology_store: &TopologyStore) -> Self {
+        let topology = topology_store.read().await;
+        let mut rules = Vec::new();
+
+        for policy in &topology.security_policies {
+            for rule in &policy.rules {
+                let (Some(condition), Some(action)) = (Condition::parse(&rule.condition), Action::parse(&rule.action)) else {
+                    warn!(rule_id = %rule.rule_id, condition = %rule.condition, action = %rule.action, "skipping security rule with unparseable condition/action");
+                    continue;
+                };
+
+                rules.push(CompiledRule {
+                    rule_id: rule.rule_id.clone(),
+                    condition,
+                    action,
+                    priority: rule.priority,
+                    enforcement_level: policy.enforcement_level,
+                });
+            }
+        }
+
+        rules.sort_by_key(|rule| rule.priority);
+
+        Self { rules }
+    }
+
+    /// Returns the first (lowest-priority-number) compiled rule that
+    /// matches `node_id`'s current `metrics`, subject to its policy's
+    /// `enforcement_level`: a matching rule only fires if
+    /// `rand::random::<f64>() < enforcement_level`, so a policy with a low
+    /// enforcement level stays mostly advisory.
+    fn evaluate(&self, node_id: &str, metrics: &SecurityMetrics, topology: &SystemTopology) -> Option<&CompiledRule> {
+        self.rules.iter().find(|rule| {
+            rule.condition.matches(node_id, metrics, topology) && rand::random::<f64>() < rule.enforcement_level
+        })
+    }
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    tracing_subscriber::fmt::init();
-    
+    let telemetry: Option<Arc<Telemetry>> = init_tracing().map(Arc::new);
+
     let security_store: SecurityStore = Arc::new(DashMap::new());
     let incident_store: IncidentStore = Arc::new(DashMap::new());
     let topology_store: TopologyStore = Arc::new(RwLock::new(SystemTopology {
@@ -172,30 +1060,140 @@ async fn main() -> Result<()> {
         security_policies: vec![],
         supply_chain_components: vec![],
     }));
-    
-    // Initialize demo data
-    initialize_demo_data(&security_store, &topology_store).await;
-    
+
+    // Durable backend: SQLite if `STORAGE_DB_PATH` is configured, otherwise
+    // the in-memory no-op (today's behavior). Hydrate the stores from it
+    // before falling back to demo seeding, so a restart doesn't lose
+    // incident history or resimulate from scratch.
+    let storage: Arc<dyn Storage> = match std::env::var("STORAGE_DB_PATH") {
+        Ok(path) => Arc::new(SqliteStorage::open(&path)?),
+        Err(_) => Arc::new(MemoryStorage),
+    };
+
+    let (loaded_metrics, loaded_incidents, loaded_topology) = storage.load_all().await?;
+    if loaded_metrics.is_empty() {
+        initialize_demo_data(&security_store, &topology_store).await;
+        storage.snapshot_topology(&*topology_store.read().await).await?;
+    } else {
+        for (node_id, versioned) in loaded_metrics {
+            security_store.insert(node_id, versioned);
+        }
+        for incident in loaded_incidents {
+            incident_store.insert(incident.incident_id.clone(), incident);
+        }
+        if let Some(topology) = loaded_topology {
+            *topology_store.write().await = topology;
+        }
+        info!("Hydrated {} node(s) and {} incident(s) from storage", security_store.len(), incident_store.len());
+    }
+
+    let pending: PendingWrites = Arc::new(RwLock::new(std::collections::HashSet::new()));
+
+    let alarm_store: AlarmStore = Arc::new(DashMap::new());
+    let correlation_engine = Arc::new(CorrelationEngine::new(CorrelationEngine::default_rules(), alarm_store.clone()));
+
+    let flush_interval = std::env::var("STORAGE_FLUSH_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(15);
+    let (security_store_clone, storage_clone, pending_clone) = (security_store.clone(), storage.clone(), pending.clone());
+    tokio::spawn(async move {
+        storage_flush_loop(security_store_clone, storage_clone, pending_clone, Duration::from_secs(flush_interval)).await;
+    });
+
+    let retention_days = std::env::var("INCIDENT_RETENTION_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(30);
+    let storage_clone = storage.clone();
+    tokio::spawn(async move {
+        retention_loop(storage_clone, retention_days, Duration::from_secs(3600)).await;
+    });
+
     // Start security monitoring loop
     let security_store_clone = security_store.clone();
     let incident_store_clone = incident_store.clone();
     let topology_store_clone = topology_store.clone();
+    let (storage_clone, pending_clone) = (storage.clone(), pending.clone());
+    let telemetry_clone = telemetry.clone();
+    let correlation_engine_clone = correlation_engine.clone();
     tokio::spawn(async move {
-        security_monitoring_loop(security_store_clone, incident_store_clone, topology_store_clone).await;
+        security_monitoring_loop(security_store_clone, incident_store_clone, topology_store_clone, storage_clone, pending_clone, telemetry_clone, correlation_engine_clone).await;
     });
-    
+
     // Start cross-domain threat detection
     let security_store_clone = security_store.clone();
+    let topology_store_clone = topology_store.clone();
+    let pending_clone = pending.clone();
+    let correlation_engine_clone = correlation_engine.clone();
     tokio::spawn(async move {
-        cross_domain_threat_detection(security_store_clone).await;
+        cross_domain_threat_detection(security_store_clone, topology_store_clone, pending_clone, correlation_engine_clone).await;
     });
-    
+
+    // Join the anti-entropy gossip mesh, if configured: periodically pull
+    // from a seed/peer and merge whatever comes back, so multiple
+    // instances converge on a shared `SecurityStore` via `merge_versioned`.
+    // Each instance still simulates every node in its store.
+    let peer_store: PeerStore = Arc::new(RwLock::new(HashMap::new()));
+    let gossip_seeds: Vec<String> = std::env::var("GOSSIP_SEEDS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect();
+    if !gossip_seeds.is_empty() {
+        {
+            let mut peers = peer_store.write().await;
+            for seed in &gossip_seeds {
+                peers.insert(seed.clone(), PeerInfo {
+                    tier: PeerTier::Seed,
+                    last_seen: chrono::Utc::now(),
+                    last_advertised_versions: HashMap::new(),
+                });
+            }
+        }
+
+        let gossip_bind_addr = std::env::var("GOSSIP_BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:8081".to_string());
+        match tokio::net::UdpSocket::bind(&gossip_bind_addr).await {
+            Ok(socket) => {
+                let socket = Arc::new(socket);
+                let gossip_interval = std::env::var("GOSSIP_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or(5);
+
+                let self_addr = std::env::var("GOSSIP_ADVERTISE_ADDR").unwrap_or_else(|_| gossip_bind_addr.clone());
+
+                let (security_store_clone, peer_store_clone, socket_clone) =
+                    (security_store.clone(), peer_store.clone(), socket.clone());
+                let self_addr_clone = self_addr.clone();
+                tokio::spawn(async move {
+                    anti_entropy_send_loop(security_store_clone, peer_store_clone, socket_clone, self_addr_clone, Duration::from_secs(gossip_interval)).await;
+                });
+
+                let (security_store_clone, peer_store_clone) = (security_store.clone(), peer_store.clone());
+                tokio::spawn(async move {
+                    anti_entropy_recv_loop(security_store_clone, peer_store_clone, socket, self_addr).await;
+                });
+
+                let peer_store_clone = peer_store.clone();
+                tokio::spawn(async move {
+                    peer_pruning_loop(peer_store_clone).await;
+                });
+
+                info!(bind_addr = %gossip_bind_addr, "📡 Anti-entropy gossip joined");
+            }
+            Err(e) => warn!(error = %e, "⚠️  gossip disabled, failed to bind"),
+        }
+    }
+
     // Setup web server
-    let routes = create_routes(security_store, incident_store, topology_store);
-    
+    let routes = create_routes(security_store, incident_store, topology_store, peer_store, alarm_store);
+
     info!("Starting Distributed Security Experiment server on http://localhost:8080");
     warp::serve(routes).run(([0, 0, 0, 0], 8080)).await;
-    
+
     Ok(())
 }
 
@@ -228,21 +1226,25 @@ async fn initialize_demo_data(security_store: &SecurityStore, topology_store: &T
         
         topology.nodes.push(node);
         
-        // Initialize security metrics
-        security_store.insert(node_id.to_string(), SecurityMetrics {
-            node_id: node_id.to_string(),
-            domain,
-            hardware_type,
-            timestamp: chrono::Utc::now(),
-            threat_level: 0.1,
-            trust_score: 0.9,
-            behavioral_score: 0.85,
-            network_score: 0.9,
-            supply_chain_score: 0.88,
-            overall_security: 0.88,
-            status: SecurityStatus::Secure,
-            attack_indicators: vec![],
-            cross_domain_connections: vec![],
+        // Initialize security metrics, versioned at 0 as this node's
+        // starting state for the gossip CRDT.
+        security_store.insert(node_id.to_string(), VersionedMetrics {
+            version: 0,
+            metrics: SecurityMetrics {
+                node_id: node_id.to_string(),
+                domain,
+                hardware_type,
+                timestamp: chrono::Utc::now(),
+                threat_level: 0.1,
+                trust_score: 0.9,
+                behavioral_score: 0.85,
+                network_score: 0.9,
+                supply_chain_score: 0.88,
+                overall_security: 0.88,
+                status: SecurityStatus::Secure,
+                attack_indicators: vec![],
+                cross_domain_connections: vec![],
+            },
         });
     }
     
@@ -324,17 +1326,28 @@ async fn initialize_demo_data(security_store: &SecurityStore, topology_store: &T
     ];
 }
 
-async fn security_monitoring_loop(security_store: SecurityStore, incident_store: IncidentStore, _topology_store: TopologyStore) {
+async fn security_monitoring_loop(
+    security_store: SecurityStore,
+    incident_store: IncidentStore,
+    topology_store: TopologyStore,
+    storage: Arc<dyn Storage>,
+    pending: PendingWrites,
+    telemetry: Option<Arc<Telemetry>>,
+    correlation_engine: Arc<CorrelationEngine>,
+) {
     let mut interval = interval(Duration::from_secs(4));
-    
+    let policy_engine = PolicyEngine::compile(&topology_store).await;
+
     loop {
         interval.tick().await;
-        
+        let topology = topology_store.read().await;
+
         // Simulate security monitoring for each node
         for mut entry in security_store.iter_mut() {
             let node_id = entry.key().clone();
-            let metrics = entry.value_mut();
-            
+            let versioned = entry.value_mut();
+            let metrics = &mut versioned.metrics;
+
             // Simulate different attack scenarios based on domain and hardware type
             let attack_probability = match (metrics.domain, metrics.hardware_type) {
                 (SecurityDomain::SmartCity, HardwareType::ModernARM) => 0.12, // Supply chain attacks
@@ -345,59 +1358,69 @@ async fn security_monitoring_loop(security_store: SecurityStore, incident_store:
                 _ => 0.03, // General attacks
             };
             
+            let indicators_before = metrics.attack_indicators.len();
+
             if rand::random::<f64>() < attack_probability {
                 match metrics.domain {
                     SecurityDomain::SmartCity => {
                         // Supply chain compromise
                         metrics.threat_level = (metrics.threat_level + 0.25).min(1.0);
                         metrics.supply_chain_score = (metrics.supply_chain_score - 0.2).max(0.0);
-                        metrics.attack_indicators.push(AttackIndicator {
+                        let indicator = AttackIndicator {
                             indicator_type: "supply_chain_compromise".to_string(),
                             severity: 0.8,
                             description: "Compromised third-party component detected".to_string(),
                             timestamp: chrono::Utc::now(),
                             source_domain: Some("SmartCity".to_string()),
                             propagation_risk: 0.7,
-                        });
+                        };
+                        correlation_engine.ingest(&node_id, &indicator).await;
+                        metrics.attack_indicators.push(indicator);
                     },
                     SecurityDomain::IndustrialIoT => {
                         // Legacy system vulnerability
                         metrics.threat_level = (metrics.threat_level + 0.3).min(1.0);
                         metrics.trust_score = (metrics.trust_score - 0.2).max(0.0);
-                        metrics.attack_indicators.push(AttackIndicator {
+                        let indicator = AttackIndicator {
                             indicator_type: "legacy_vulnerability".to_string(),
                             severity: 0.9,
                             description: "Exploited legacy system vulnerability".to_string(),
                             timestamp: chrono::Utc::now(),
                             source_domain: Some("IndustrialIoT".to_string()),
                             propagation_risk: 0.8,
-                        });
+                        };
+                        correlation_engine.ingest(&node_id, &indicator).await;
+                        metrics.attack_indicators.push(indicator);
                     },
                     SecurityDomain::Transportation => {
                         // Cross-domain attack
                         metrics.threat_level = (metrics.threat_level + 0.2).min(1.0);
                         metrics.behavioral_score = (metrics.behavioral_score - 0.15).max(0.0);
-                        metrics.attack_indicators.push(AttackIndicator {
+                        let indicator = AttackIndicator {
                             indicator_type: "cross_domain_attack".to_string(),
                             severity: 0.7,
                             description: "Attack propagated from another domain".to_string(),
                             timestamp: chrono::Utc::now(),
                             source_domain: Some("SmartCity".to_string()),
                             propagation_risk: 0.9,
-                        });
+                        };
+                        correlation_engine.ingest(&node_id, &indicator).await;
+                        metrics.attack_indicators.push(indicator);
                     },
                     SecurityDomain::EnergyGrid => {
                         // Critical infrastructure attack
                         metrics.threat_level = (metrics.threat_level + 0.35).min(1.0);
                         metrics.network_score = (metrics.network_score - 0.25).max(0.0);
-                        metrics.attack_indicators.push(AttackIndicator {
+                        let indicator = AttackIndicator {
                             indicator_type: "critical_infrastructure_attack".to_string(),
                             severity: 0.95,
                             description: "Coordinated attack on critical infrastructure".to_string(),
                             timestamp: chrono::Utc::now(),
                             source_domain: None,
                             propagation_risk: 0.95,
-                        });
+                        };
+                        correlation_engine.ingest(&node_id, &indicator).await;
+                        metrics.attack_indicators.push(indicator);
                     },
                     _ => {
                         // General attack
@@ -415,7 +1438,14 @@ async fn security_monitoring_loop(security_store: SecurityStore, incident_store:
             // Recalculate overall security
             metrics.overall_security = (metrics.trust_score + metrics.behavioral_score + metrics.network_score + metrics.supply_chain_score) / 4.0;
             metrics.timestamp = chrono::Utc::now();
-            
+
+            if let Some(telemetry) = &telemetry {
+                telemetry.record_metrics(metrics);
+                for indicator in &metrics.attack_indicators[indicators_before..] {
+                    telemetry.record_indicator(&indicator.indicator_type);
+                }
+            }
+
             // Update status based on security level
             metrics.status = match metrics.overall_security {
                 s if s >= 0.8 => SecurityStatus::Secure,
@@ -452,37 +1482,170 @@ async fn security_monitoring_loop(security_store: SecurityStore, incident_store:
                     cross_domain_coordination: cross_domain,
                 };
                 
+                if let Err(e) = storage.record_incident(&incident).await {
+                    warn!(node_id = %node_id, error = %e, "failed to persist incident");
+                }
+                let _span = telemetry.as_ref().map(|t| t.start_incident_span(&incident));
                 incident_store.insert(incident_id, incident);
             }
+
+            // Evaluate security policies against the refreshed metrics and
+            // fire the matching rule's action, so `SecurityPolicy::rules`
+            // actually governs behavior instead of sitting unused.
+            if let Some(rule) = policy_engine.evaluate(&node_id, &*metrics, &topology) {
+                match rule.action {
+                    Action::IsolateNode => {
+                        metrics.status = SecurityStatus::Isolated;
+                    }
+                    Action::QuarantineComponent => {
+                        let incident_id = Uuid::new_v4().to_string();
+                        let incident = IncidentResponse {
+                            incident_id: incident_id.clone(),
+                            node_id: node_id.clone(),
+                            affected_domains: vec![format!("{:?}", metrics.domain)],
+                            response_type: ResponseType::Quarantine,
+                            timestamp: chrono::Utc::now(),
+                            status: ResponseStatus::InProgress,
+                            actions_taken: vec![format!("policy rule '{}' fired: quarantine_component", rule.rule_id)],
+                            cross_domain_coordination: false,
+                        };
+                        if let Err(e) = storage.record_incident(&incident).await {
+                            warn!(node_id = %node_id, error = %e, "failed to persist incident");
+                        }
+                        let _span = telemetry.as_ref().map(|t| t.start_incident_span(&incident));
+                        incident_store.insert(incident_id, incident);
+                    }
+                    Action::AlertSecurityTeam => {
+                        let incident_id = Uuid::new_v4().to_string();
+                        let incident = IncidentResponse {
+                            incident_id: incident_id.clone(),
+                            node_id: node_id.clone(),
+                            affected_domains: vec![format!("{:?}", metrics.domain)],
+                            response_type: ResponseType::Alert,
+                            timestamp: chrono::Utc::now(),
+                            status: ResponseStatus::Pending,
+                            actions_taken: vec![format!("policy rule '{}' fired: alert_security_team", rule.rule_id)],
+                            cross_domain_coordination: false,
+                        };
+                        if let Err(e) = storage.record_incident(&incident).await {
+                            warn!(node_id = %node_id, error = %e, "failed to persist incident");
+                        }
+                        let _span = telemetry.as_ref().map(|t| t.start_incident_span(&incident));
+                        incident_store.insert(incident_id, incident);
+                    }
+                }
+
+                info!(node_id = %node_id, rule_id = %rule.rule_id, "security policy rule fired");
+            }
+
+            // This node owns its own version counter: bump it every tick its
+            // metrics are refreshed, so peers can tell a gossiped copy of this
+            // entry apart from a newer one via `merge_versioned`.
+            versioned.version += 1;
+            pending.write().await.insert(node_id.clone());
         }
-        
+
         info!("Security monitoring completed for {} nodes", security_store.len());
     }
 }
 
-async fn cross_domain_threat_detection(security_store: SecurityStore) {
+/// Below this delivered threat, a propagation path is considered spent and
+/// stops expanding -- keeps the BFS bounded without needing a visited set
+/// that would block a node from being reached along two different paths.
+const PROPAGATION_CUTOFF: f64 = 0.05;
+
+/// Hard cap on hops from a seed node, as a backstop against cycles in
+/// `cross_domain_connections` (the cutoff above handles the common case,
+/// this handles a loop of very strong links).
+const MAX_PROPAGATION_HOPS: u32 = 6;
+
+/// Walks `cross_domain_connections` as a weighted directed graph, starting
+/// from every node whose `status` already indicates compromise, and
+/// returns the total delivered threat reaching each other node together
+/// with the edge it arrived over last (used to label the resulting
+/// `AttackIndicator`).
+fn propagate_cross_domain_threat(
+    topology: &SystemTopology,
+    threat_levels: &HashMap<String, f64>,
+    statuses: &HashMap<String, SecurityStatus>,
+) -> HashMap<String, (f64, CrossDomainConnection)> {
+    let mut delivered: HashMap<String, (f64, CrossDomainConnection)> = HashMap::new();
+
+    let mut frontier: VecDeque<(String, f64, u32)> = statuses
+        .iter()
+        .filter(|(_, status)| {
+            matches!(status, SecurityStatus::Compromised | SecurityStatus::UnderAttack | SecurityStatus::CrossDomainThreat)
+        })
+        .map(|(node_id, _)| (node_id.clone(), *threat_levels.get(node_id).unwrap_or(&0.0), 0))
+        .collect();
+
+    while let Some((node_id, threat, hops)) = frontier.pop_front() {
+        if hops >= MAX_PROPAGATION_HOPS {
+            continue;
+        }
+
+        for edge in topology.cross_domain_connections.iter().filter(|e| e.from_node == node_id) {
+            let security_level_factor = 1.0 - edge.security_level;
+            let incoming = threat * security_level_factor * edge.trust_relationship;
+            if incoming < PROPAGATION_CUTOFF {
+                continue;
+            }
+
+            let entry = delivered.entry(edge.to_node.clone()).or_insert((0.0, edge.clone()));
+            entry.0 += incoming;
+            entry.1 = edge.clone();
+
+            frontier.push_back((edge.to_node.clone(), incoming, hops + 1));
+        }
+    }
+
+    delivered
+}
+
+async fn cross_domain_threat_detection(
+    security_store: SecurityStore,
+    topology_store: TopologyStore,
+    pending: PendingWrites,
+    correlation_engine: Arc<CorrelationEngine>,
+) {
     let mut interval = interval(Duration::from_secs(8));
-    
+
     loop {
         interval.tick().await;
-        
-        // Simulate cross-domain threat detection
-        for mut entry in security_store.iter_mut() {
-            let metrics = entry.value_mut();
-            
-            // Check for cross-domain propagation
-            if metrics.attack_indicators.iter().any(|i| i.propagation_risk > 0.7) {
-                metrics.attack_indicators.push(AttackIndicator {
-                    indicator_type: "cross_domain_propagation".to_string(),
-                    severity: 0.8,
-                    description: "Threat detected propagating across domains".to_string(),
-                    timestamp: chrono::Utc::now(),
-                    source_domain: Some(format!("{:?}", metrics.domain)),
-                    propagation_risk: 0.9,
-                });
-            }
+
+        let topology = topology_store.read().await;
+
+        let threat_levels: HashMap<String, f64> = security_store
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().metrics.threat_level))
+            .collect();
+        let statuses: HashMap<String, SecurityStatus> = security_store
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().metrics.status.clone()))
+            .collect();
+
+        let delivered = propagate_cross_domain_threat(&topology, &threat_levels, &statuses);
+
+        for (node_id, (increment, edge)) in delivered {
+            let Some(mut entry) = security_store.get_mut(&node_id) else { continue };
+            let versioned = entry.value_mut();
+            let metrics = &mut versioned.metrics;
+
+            metrics.threat_level = (metrics.threat_level + increment).min(1.0);
+            let indicator = AttackIndicator {
+                indicator_type: "cross_domain_propagation".to_string(),
+                severity: increment,
+                description: format!("Threat propagated from {:?} domain over a {} link", edge.from_domain, edge.connection_type),
+                timestamp: chrono::Utc::now(),
+                source_domain: Some(format!("{:?}", edge.from_domain)),
+                propagation_risk: edge.security_level * edge.trust_relationship,
+            };
+            correlation_engine.ingest(&node_id, &indicator).await;
+            metrics.attack_indicators.push(indicator);
+            versioned.version += 1;
+            pending.write().await.insert(node_id);
         }
-        
+
         info!("Cross-domain threat detection completed for {} nodes", security_store.len());
     }
 }
@@ -491,41 +1654,83 @@ fn create_routes(
     security_store: SecurityStore,
     incident_store: IncidentStore,
     topology_store: TopologyStore,
+    peer_store: PeerStore,
+    alarm_store: AlarmStore,
 ) -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
     let security_store = warp::any().map(move || security_store.clone());
     let incident_store = warp::any().map(move || incident_store.clone());
     let topology_store = warp::any().map(move || topology_store.clone());
-    
+    let peer_store = warp::any().map(move || peer_store.clone());
+    let alarm_store = warp::any().map(move || alarm_store.clone());
+
     let security_metrics = warp::path("api")
         .and(warp::path("security"))
         .and(warp::get())
         .and(security_store)
         .and_then(get_security_metrics);
-    
+
     let incidents = warp::path("api")
         .and(warp::path("incidents"))
         .and(warp::get())
         .and(incident_store)
         .and_then(get_incidents);
-    
+
     let topology = warp::path("api")
         .and(warp::path("topology"))
         .and(warp::get())
         .and(topology_store)
         .and_then(get_topology);
-    
+
+    let peers = warp::path("api")
+        .and(warp::path("peers"))
+        .and(warp::get())
+        .and(peer_store)
+        .and_then(get_peers);
+
+    let alarms = warp::path("api")
+        .and(warp::path("alarms"))
+        .and(warp::get())
+        .and(alarm_store)
+        .and_then(get_alarms);
+
     let dashboard = warp::path::end()
         .and(warp::get())
         .map(|| warp::reply::html(include_str!("../static/dashboard.html")));
-    
-    security_metrics.or(incidents).or(topology).or(dashboard)
+
+    security_metrics.or(incidents).or(topology).or(peers).or(alarms).or(dashboard)
 }
 
 async fn get_security_metrics(security_store: SecurityStore) -> Result<impl warp::Reply, warp::Rejection> {
-    let metrics: Vec<SecurityMetrics> = security_store.iter().map(|entry| entry.value().clone()).collect();
+    // Unwrap to the plain metrics so this route's response shape is
+    // unaffected by the gossiped CRDT store now backing it.
+    let metrics: Vec<SecurityMetrics> = security_store.iter().map(|entry| entry.value().metrics.clone()).collect();
     Ok(warp::reply::json(&metrics))
 }
 
+async fn get_peers(peer_store: PeerStore) -> Result<impl warp::Reply, warp::Rejection> {
+    let peers: HashMap<String, serde_json::Value> = peer_store
+        .read()
+        .await
+        .iter()
+        .map(|(addr, info)| {
+            (
+                addr.clone(),
+                serde_json::json!({
+                    "tier": format!("{:?}", info.tier),
+                    "last_seen": info.last_seen,
+                    "last_advertised_versions": info.last_advertised_versions,
+                }),
+            )
+        })
+        .collect();
+    Ok(warp::reply::json(&peers))
+}
+
+async fn get_alarms(alarm_store: AlarmStore) -> Result<impl warp::Reply, warp::Rejection> {
+    let alarms: Vec<Alarm> = alarm_store.iter().map(|entry| entry.value().clone()).collect();
+    Ok(warp::reply::json(&alarms))
+}
+
 async fn get_incidents(incident_store: IncidentStore) -> Result<impl warp::Reply, warp::Rejection> {
     let incidents: Vec<IncidentResponse> = incident_store.iter().map(|entry| entry.value().clone()).collect();
     Ok(warp::reply::json(&incidents))
@@ -535,3 +1740,120 @@ async fn get_topology(topology_store: TopologyStore) -> Result<impl warp::Reply,
     let topology = topology_store.read().await;
     Ok(warp::reply::json(&*topology))
 }
+
+/// Every round, builds a Bloom filter of everything this node currently
+/// holds and sends a `Pull` to a peer -- always favoring seeds, falling
+/// back to known members, mirroring the "well-known seeds first" fan-out
+/// `PeerTier` exists to express.
+async fn anti_entropy_send_loop(
+    security_store: SecurityStore,
+    peer_store: PeerStore,
+    socket: Arc<tokio::net::UdpSocket>,
+    self_addr: String,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+
+        let peer = {
+            let peers = peer_store.read().await;
+            let mut seeds: Vec<&String> = peers
+                .iter()
+                .filter(|(_, info)| info.tier == PeerTier::Seed)
+                .map(|(addr, _)| addr)
+                .collect();
+            if seeds.is_empty() {
+                seeds = peers.keys().collect();
+            }
+            seeds.choose(&mut rand::thread_rng()).map(|s| (*s).clone())
+        };
+        let Some(peer) = peer else { continue };
+
+        let mut filter = BloomFilter::new(security_store.len().max(1), 0.01);
+        for entry in security_store.iter() {
+            filter.insert(entry.key(), entry.value().version);
+        }
+
+        let message = GossipWireMessage::Pull { from_addr: self_addr.clone(), filter };
+        let Ok(bytes) = serde_json::to_vec(&message) else { continue };
+        let _ = socket.send_to(&bytes, &peer).await;
+    }
+}
+
+/// Receives `Pull`/`PullResponse` datagrams: answers a `Pull` with
+/// whatever entries the sender's filter doesn't already represent, and
+/// merges a `PullResponse`'s entries into the local CRDT store.
+async fn anti_entropy_recv_loop(
+    security_store: SecurityStore,
+    peer_store: PeerStore,
+    socket: Arc<tokio::net::UdpSocket>,
+    self_addr: String,
+) {
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let (len, src) = match socket.recv_from(&mut buf).await {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let Ok(message) = serde_json::from_slice::<GossipWireMessage>(&buf[..len]) else {
+            continue;
+        };
+
+        match message {
+            GossipWireMessage::Pull { from_addr, filter } => {
+                record_peer_contact(&peer_store, &from_addr, PeerTier::Member).await;
+
+                let entries: Vec<(String, VersionedMetrics)> = security_store
+                    .iter()
+                    .filter(|entry| !filter.contains(entry.key(), entry.value().version))
+                    .map(|entry| (entry.key().clone(), entry.value().clone()))
+                    .collect();
+
+                let response = GossipWireMessage::PullResponse { from_addr: self_addr.clone(), entries };
+                if let Ok(bytes) = serde_json::to_vec(&response) {
+                    let _ = socket.send_to(&bytes, &src).await;
+                }
+            }
+            GossipWireMessage::PullResponse { from_addr, entries } => {
+                let mut advertised = HashMap::new();
+                for (node_id, versioned) in &entries {
+                    merge_versioned(&security_store, node_id, versioned);
+                    advertised.insert(node_id.clone(), versioned.version);
+                }
+                record_peer_contact(&peer_store, &from_addr, PeerTier::Member).await;
+                if let Some(info) = peer_store.write().await.get_mut(&from_addr) {
+                    info.last_advertised_versions.extend(advertised);
+                }
+            }
+        }
+    }
+}
+
+/// Records that a gossip datagram was just received from `addr`, adding it
+/// to `PeerStore` as a `Member` if it isn't already known (seeds are only
+/// ever added at startup from `GOSSIP_SEEDS`).
+async fn record_peer_contact(peer_store: &PeerStore, addr: &str, default_tier: PeerTier) {
+    let mut peers = peer_store.write().await;
+    peers
+        .entry(addr.to_string())
+        .and_modify(|info| info.last_seen = chrono::Utc::now())
+        .or_insert_with(|| PeerInfo {
+            tier: default_tier,
+            last_seen: chrono::Utc::now(),
+            last_advertised_versions: HashMap::new(),
+        });
+}
+
+/// Prunes non-seed peers that have gone quiet for longer than
+/// `PEER_SILENCE_TIMEOUT`; seeds are never pruned. Anti-entropy naturally rediscovers them if
+/// they come back, so there's no state loss in forgetting them.
+async fn peer_pruning_loop(peer_store: PeerStore) {
+    let mut ticker = tokio::time::interval(Duration::from_secs(30));
+    loop {
+        ticker.tick().await;
+        let cutoff = chrono::Utc::now() - PEER_SILENCE_TIMEOUT;
+        peer_store.write().await.retain(|_, info| info.tier == PeerTier::Seed || info.last_seen > cutoff);
+    }
+}
@@ -6,6 +6,15 @@ use chrono::{DateTime, Utc};
 use warp::Filter;
 use std::convert::Infallible;
 use petgraph::{Graph, Directed};
+use uuid::Uuid;
+use opentelemetry::{global, KeyValue};
+use opentelemetry::metrics::{Counter, Gauge};
+use opentelemetry::trace::{Span, Tracer, TracerProvider as _};
+use prometheus::Encoder;
+use rand::seq::SliceRandom;
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use tokio_stream::wrappers::BroadcastStream;
 
 /// SCULI Trust Monitoring System for Ultra-Large Scale Distributed Systems
 #[derive(Debug, Clone)]
@@ -17,6 +26,61 @@ pub struct TrustMonitor {
     pub incidents: Arc<RwLock<Vec<Incident>>>,
     pub alerts: Arc<RwLock<Vec<Alert>>>,
     pub bayesian_models: Arc<RwLock<HashMap<String, BayesianModel>>>,
+    /// Ingested CVEs/advisories, keyed by advisory id.
+    pub advisories: Arc<RwLock<HashMap<String, Advisory>>>,
+    /// Per-component security posture, kept in sync with `advisories` by
+    /// `recompute_vulnerability`.
+    pub security_postures: Arc<RwLock<HashMap<String, SecurityPosture>>>,
+    /// Every `ActionRecord` produced by `execute_action_group`, across all
+    /// components. Read by `assess_incident_response` to report the real
+    /// automated-vs-escalated rate instead of a hard-coded figure.
+    pub action_log: Arc<RwLock<Vec<ActionRecord>>>,
+    /// When true, `execute_action_group` reports what it would do without
+    /// applying any trust impact.
+    action_dry_run: bool,
+    /// Immutable PROV-style record of every trust-score transition, in the
+    /// order they occurred. Queried by `provenance_for` to answer "why did
+    /// this component's trust change?" without relying on `TrustScore`,
+    /// which only ever holds the current snapshot.
+    pub provenance: Arc<RwLock<Vec<ProvenanceRecord>>>,
+    /// Maps a component id to its node in `trust_graph`, since `petgraph`
+    /// indexes nodes positionally rather than by a caller-chosen key.
+    node_indices: Arc<RwLock<HashMap<String, petgraph::graph::NodeIndex>>>,
+    /// Prometheus gauges scraped by `GET /metrics` via `render_metrics`.
+    prometheus: Arc<PrometheusMetrics>,
+    /// UDP addresses of other monitor instances to gossip `trust_scores`
+    /// digests with, configured via `with_gossip_peers`.
+    gossip_peers: Vec<String>,
+    /// Last time a gossip digest was received from each peer address,
+    /// reported as convergence info in `SystemStatus`.
+    gossip_last_seen: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+    /// Embedded KV store backing `Changeset` snapshots, or `None` for the
+    /// in-memory-only demo mode used by `TrustMonitor::new`.
+    store: Option<sled::Db>,
+    /// Durable history for trust-score updates, alerts, and incidents,
+    /// independent of `store`. Defaults to `InMemoryBackend`; set
+    /// `PostgresBackend` via `with_storage_backend` for a real deployment.
+    storage: Arc<dyn StorageBackend>,
+    /// Fires a `TrustUpdateEvent` when `update_trust_score`,
+    /// `simulate_trust_degradation` or `simulate_recovery` changes a score
+    /// or raises an alert, so `GET /trust-scores/stream` can push live
+    /// updates instead of making dashboards poll `/trust-scores`. Score
+    /// changes made by `apply`, gossip merges, advisory recomputes and
+    /// action trust impacts are not broadcast.
+    update_tx: tokio::sync::broadcast::Sender<TrustUpdateEvent>,
+    /// OpenTelemetry instrumentation, absent unless an OTLP endpoint was
+    /// configured via `TrustMonitor::with_telemetry`.
+    telemetry: Option<Arc<Telemetry>>,
+}
+
+/// One change pushed to `GET /trust-scores/stream` subscribers, emitted by
+/// `TrustMonitor::update_tx` (see that field for which mutations fire it).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TrustUpdateEvent {
+    ScoreUpdated { component_id: String, score: TrustScore },
+    AlertRaised(Alert),
+    IncidentRecorded(Incident),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,7 +104,43 @@ pub struct TrustEdge {
     pub criticality: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Implements `Serialize`/`Deserialize` for a C-like enum so an
+/// unrecognized variant string falls back to `UnknownValue` instead of
+/// failing the whole payload, the way Azure's generated models handle
+/// forward compatibility. Requires the enum to declare an
+/// `UnknownValue(String)` variant, and round-trips that variant as the
+/// original string.
+macro_rules! forward_compatible_enum {
+    ($ty:ident { $($variant:ident => $name:literal),+ $(,)? }) => {
+        impl Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let s = match self {
+                    $($ty::$variant => $name,)+
+                    $ty::UnknownValue(s) => s.as_str(),
+                };
+                serializer.serialize_str(s)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                Ok(match s.as_str() {
+                    $($name => $ty::$variant,)+
+                    _ => $ty::UnknownValue(s),
+                })
+            }
+        }
+    };
+}
+
+#[derive(Debug, Clone)]
 pub enum ComponentType {
     WebAssembly,
     Container,
@@ -50,9 +150,23 @@ pub enum ComponentType {
     Cache,
     LoadBalancer,
     MessageQueue,
+    /// An unrecognized value from a newer or third-party feed, preserved
+    /// verbatim so it round-trips instead of failing to parse.
+    UnknownValue(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+forward_compatible_enum!(ComponentType {
+    WebAssembly => "WebAssembly",
+    Container => "Container",
+    LegacySystem => "LegacySystem",
+    Microservice => "Microservice",
+    Database => "Database",
+    Cache => "Cache",
+    LoadBalancer => "LoadBalancer",
+    MessageQueue => "MessageQueue",
+});
+
+#[derive(Debug, Clone)]
 pub enum RelationshipType {
     DataFlow,
     Dependency,
@@ -60,8 +174,18 @@ pub enum RelationshipType {
     Control,
     Monitoring,
     Backup,
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(RelationshipType {
+    DataFlow => "DataFlow",
+    Dependency => "Dependency",
+    Communication => "Communication",
+    Control => "Control",
+    Monitoring => "Monitoring",
+    Backup => "Backup",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BehavioralMetrics {
     pub request_patterns: HashMap<String, f64>,
@@ -104,6 +228,10 @@ pub struct TrustScore {
     pub contributing_factors: Vec<ContributingFactor>,
     pub prediction: Option<TrustPrediction>,
     pub last_updated: DateTime<Utc>,
+    /// Bumped on every local mutation. Breaks ties between two entries with
+    /// the same `last_updated` when merging a peer's gossip digest.
+    #[serde(default)]
+    pub version: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -125,6 +253,54 @@ pub enum FactorType {
     AttestationResult,
 }
 
+/// An immutable, PROV-style record of a single trust-score transition:
+/// an activity (the recompute) linking the prior and new `TrustScore`
+/// entities, performed by an agent/source, using the `ContributingFactor`s
+/// that drove it. `update_trust_score` appends one of these instead of
+/// discarding the evidence once `TrustScore` is overwritten.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceRecord {
+    pub id: Uuid,
+    pub component_id: String,
+    pub prior_score: f64,
+    pub new_score: f64,
+    pub prior_posterior: f64,
+    pub new_posterior: f64,
+    pub factors: Vec<ContributingFactor>,
+    /// The agent or source that supplied the evidence behind this
+    /// transition (e.g. a monitoring probe, an operator, an advisory feed).
+    pub source: String,
+    pub timestamp: DateTime<Utc>,
+    /// Component ids, one hop upstream in `trust_graph`, whose own
+    /// transition is suspected of having caused this one (e.g. a
+    /// `Dependency` edge from a degraded component). Empty when the
+    /// transition originated directly from `evidence` rather than
+    /// propagation.
+    pub propagated_from: Vec<String>,
+}
+
+/// One `trust_scores` entry advertised in a `GossipDigest`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipEntry {
+    pub component_id: String,
+    pub score: f64,
+    pub last_updated: DateTime<Utc>,
+    pub version: u64,
+}
+
+/// A node's periodic digest of its `trust_scores`, sent over UDP to a
+/// random subset of peers so the cluster converges on a shared view
+/// without a central database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipDigest {
+    pub node_id: String,
+    pub entries: Vec<GossipEntry>,
+}
+
+/// Caps entries per digest so a chunk always fits one UDP datagram even
+/// for a system with many monitored components.
+const GOSSIP_MAX_ENTRIES_PER_DATAGRAM: usize = 50;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrustPrediction {
     pub predicted_score: f64,
@@ -142,7 +318,7 @@ pub struct RiskFactor {
     pub mitigation_suggestions: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum RiskFactorType {
     Vulnerability,
     PerformanceDegradation,
@@ -151,8 +327,19 @@ pub enum RiskFactorType {
     DependencyFailure,
     CommunicationFailure,
     AttestationFailure,
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(RiskFactorType {
+    Vulnerability => "Vulnerability",
+    PerformanceDegradation => "PerformanceDegradation",
+    AnomalousBehavior => "AnomalousBehavior",
+    ComplianceViolation => "ComplianceViolation",
+    DependencyFailure => "DependencyFailure",
+    CommunicationFailure => "CommunicationFailure",
+    AttestationFailure => "AttestationFailure",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BayesianModel {
     pub model_id: String,
@@ -161,6 +348,57 @@ pub struct BayesianModel {
     pub posterior_probability: f64,
     pub evidence_count: u64,
     pub last_updated: DateTime<Utc>,
+    /// How many seconds it takes a recorded penalty's effect to halve.
+    pub half_life_secs: i64,
+    /// Individual pieces of negative evidence, each decaying independently
+    /// so the posterior recovers toward `prior_probability` on its own once
+    /// a component stops misbehaving.
+    pub penalties: Vec<TrustPenalty>,
+}
+
+/// One piece of negative evidence recorded against a `BayesianModel`,
+/// weighted by the likelihood function for its factor at the time it was
+/// observed and decayed by elapsed time on every `recompute`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustPenalty {
+    pub factor: String,
+    pub penalty: f64,
+    pub weight: f64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl BayesianModel {
+    /// Recomputes the posterior from scratch: `prior - Σ(weight_i · penalty_i · decay_i)`,
+    /// where `decay_i` halves every `half_life_secs`. Lazy and side-effect
+    /// free, so the score converges to `prior_probability` purely by the
+    /// passage of time once evidence stops arriving.
+    pub fn recompute(&self, now: DateTime<Utc>) -> f64 {
+        let decayed_penalty: f64 = self
+            .penalties
+            .iter()
+            .map(|p| p.weight * p.penalty * Self::decay(p.recorded_at, now, self.half_life_secs))
+            .sum();
+        (self.prior_probability - decayed_penalty).clamp(0.1, 1.0)
+    }
+
+    /// Sum of each penalty's remaining decay weight. Used in place of the
+    /// raw, ever-growing `evidence_count` so fully-decayed evidence can't
+    /// inflate confidence indefinitely.
+    pub fn effective_evidence_weight(&self, now: DateTime<Utc>) -> f64 {
+        self.penalties.iter().map(|p| Self::decay(p.recorded_at, now, self.half_life_secs)).sum()
+    }
+
+    pub fn record_penalty(&mut self, factor: String, penalty: f64, weight: f64, now: DateTime<Utc>) {
+        self.penalties.push(TrustPenalty { factor, penalty, weight, recorded_at: now });
+        self.evidence_count += 1;
+        self.posterior_probability = self.recompute(now);
+        self.last_updated = now;
+    }
+
+    fn decay(recorded_at: DateTime<Utc>, now: DateTime<Utc>, half_life_secs: i64) -> f64 {
+        let elapsed_secs = (now - recorded_at).num_seconds().max(0) as f64;
+        2f64.powf(-elapsed_secs / half_life_secs.max(1) as f64)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -173,9 +411,47 @@ pub struct Component {
     pub wasm_module: Option<String>, // Path to WASM module
     pub container_id: Option<String>,
     pub legacy_endpoint: Option<String>,
+    /// Deployed version, matched against `Advisory::version_req` to decide
+    /// whether a CVE affects this component.
+    pub version: semver::Version,
     pub last_updated: DateTime<Utc>,
 }
 
+/// A CVE/advisory affecting a package at a given version range, inspired by
+/// trustification's version-match logic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Advisory {
+    pub id: String,
+    pub affected_package: String,
+    pub version_req: semver::VersionReq,
+    pub severity: AdvisorySeverity,
+    /// The version this advisory is fixed in, if a patch exists.
+    pub fixed_version: Option<semver::Version>,
+    pub published_at: DateTime<Utc>,
+    /// Set once a newer advisory covers the same package and range, so the
+    /// stale advisory stops lowering trust.
+    pub superseded_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AdvisorySeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl AdvisorySeverity {
+    fn score(&self) -> f64 {
+        match self {
+            AdvisorySeverity::Low => 0.2,
+            AdvisorySeverity::Medium => 0.4,
+            AdvisorySeverity::High => 0.7,
+            AdvisorySeverity::Critical => 0.95,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Incident {
     pub id: String,
@@ -198,7 +474,7 @@ pub enum IncidentSeverity {
     Emergency,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum IncidentStatus {
     Open,
     Investigating,
@@ -206,8 +482,18 @@ pub enum IncidentStatus {
     Resolved,
     Closed,
     Escalated,
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(IncidentStatus {
+    Open => "Open",
+    Investigating => "Investigating",
+    Mitigating => "Mitigating",
+    Resolved => "Resolved",
+    Closed => "Closed",
+    Escalated => "Escalated",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionRecord {
     pub action_type: ActionType,
@@ -217,7 +503,7 @@ pub struct ActionRecord {
     pub trust_impact: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum ActionType {
     IsolateComponent,
     ScaleResources,
@@ -232,8 +518,25 @@ pub enum ActionType {
     EnableMonitoring,
     DisableAccess,
     EscalateToHuman,
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(ActionType {
+    IsolateComponent => "IsolateComponent",
+    ScaleResources => "ScaleResources",
+    UpdateConfiguration => "UpdateConfiguration",
+    TriggerWorkflow => "TriggerWorkflow",
+    SendNotification => "SendNotification",
+    UpdateSecurityPolicy => "UpdateSecurityPolicy",
+    FailoverToBackup => "FailoverToBackup",
+    RestartService => "RestartService",
+    UpdateFirewallRules => "UpdateFirewallRules",
+    QuarantineData => "QuarantineData",
+    EnableMonitoring => "EnableMonitoring",
+    DisableAccess => "DisableAccess",
+    EscalateToHuman => "EscalateToHuman",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ActionStatus {
     Pending,
@@ -243,6 +546,67 @@ pub enum ActionStatus {
     Cancelled,
 }
 
+/// Result of running one `ActionHandler`: whether it succeeded, a
+/// human-readable explanation, and the trust impact to write back into the
+/// component's Bayesian model.
+pub struct ActionOutcome {
+    pub status: ActionStatus,
+    pub result: String,
+    pub trust_impact: f64,
+}
+
+/// One handler per `ActionType`, selected by the action group bound to an
+/// alert's type/severity. In this demo the side effect is simulated, but
+/// the trait is what a real deployment would implement against its
+/// orchestration/firewall/backup APIs.
+pub trait ActionHandler: Send + Sync {
+    fn execute(&self, component_id: &str, dry_run: bool) -> ActionOutcome;
+}
+
+/// A handler whose side effect is simulated: it always completes (unless
+/// dry-run) and reports a fixed trust impact. Covers every `ActionType`
+/// except `EscalateToHuman`.
+struct SimulatedHandler {
+    action_type: ActionType,
+    trust_impact: f64,
+}
+
+impl ActionHandler for SimulatedHandler {
+    fn execute(&self, component_id: &str, dry_run: bool) -> ActionOutcome {
+        if dry_run {
+            return ActionOutcome {
+                status: ActionStatus::Completed,
+                result: format!("dry-run: would execute {:?} on {}", self.action_type, component_id),
+                trust_impact: 0.0,
+            };
+        }
+        ActionOutcome {
+            status: ActionStatus::Completed,
+            result: format!("executed {:?} on {}", self.action_type, component_id),
+            trust_impact: self.trust_impact,
+        }
+    }
+}
+
+/// Last-resort handler: fires only once every automated handler in the
+/// group has failed. Always "succeeds" at escalating, but with zero trust
+/// impact, since a human hasn't acted yet.
+struct EscalateToHumanHandler;
+
+impl ActionHandler for EscalateToHumanHandler {
+    fn execute(&self, component_id: &str, dry_run: bool) -> ActionOutcome {
+        ActionOutcome {
+            status: ActionStatus::Completed,
+            result: if dry_run {
+                format!("dry-run: would escalate {} to a human operator", component_id)
+            } else {
+                format!("escalated {} to a human operator", component_id)
+            },
+            trust_impact: 0.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Alert {
     pub id: String,
@@ -255,7 +619,7 @@ pub struct Alert {
     pub status: AlertStatus,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum AlertType {
     TrustScoreLow,
     SecurityViolation,
@@ -264,8 +628,19 @@ pub enum AlertType {
     DependencyFailure,
     CommunicationFailure,
     AttestationFailure,
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(AlertType {
+    TrustScoreLow => "TrustScoreLow",
+    SecurityViolation => "SecurityViolation",
+    PerformanceDegradation => "PerformanceDegradation",
+    BehavioralAnomaly => "BehavioralAnomaly",
+    DependencyFailure => "DependencyFailure",
+    CommunicationFailure => "CommunicationFailure",
+    AttestationFailure => "AttestationFailure",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AlertSeverity {
     Low,
@@ -282,6 +657,453 @@ pub enum AlertStatus {
     Suppressed,
 }
 
+/// A single pending mutation staged into a `Changeset`.
+#[derive(Debug, Clone)]
+enum ChangesetOp {
+    AddComponent(Component),
+    RemoveComponent(String),
+    OverrideTrustScore { component_id: String, score: f64 },
+    RegisterPrior { component_id: String, prior_probability: f64 },
+}
+
+/// A batch of pending mutations against a `TrustMonitor`, applied
+/// atomically by `TrustMonitor::apply`. Mirrors fapolicy-analyzer's
+/// System/Changeset model: callers stage edits here and commit them all at
+/// once instead of mutating the monitor's locked state field by field.
+#[derive(Debug, Clone, Default)]
+pub struct Changeset {
+    ops: Vec<ChangesetOp>,
+}
+
+impl Changeset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_component(mut self, component: Component) -> Self {
+        self.ops.push(ChangesetOp::AddComponent(component));
+        self
+    }
+
+    pub fn remove_component(mut self, component_id: impl Into<String>) -> Self {
+        self.ops.push(ChangesetOp::RemoveComponent(component_id.into()));
+        self
+    }
+
+    pub fn override_trust_score(mut self, component_id: impl Into<String>, score: f64) -> Self {
+        self.ops.push(ChangesetOp::OverrideTrustScore {
+            component_id: component_id.into(),
+            score,
+        });
+        self
+    }
+
+    pub fn register_prior(mut self, component_id: impl Into<String>, prior_probability: f64) -> Self {
+        self.ops.push(ChangesetOp::RegisterPrior {
+            component_id: component_id.into(),
+            prior_probability,
+        });
+        self
+    }
+}
+
+/// An immutable, point-in-time copy of the monitor's durable state.
+/// Returned by `TrustMonitor::snapshot` and `TrustMonitor::load_snapshot`
+/// (`TrustMonitor::apply` takes one after each changeset and returns its
+/// id) so operators can compare two points in time with `TrustMonitor::diff`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub snapshot_id: String,
+    pub components: HashMap<String, Component>,
+    pub trust_scores: HashMap<String, TrustScore>,
+    pub bayesian_models: HashMap<String, BayesianModel>,
+    pub taken_at: DateTime<Utc>,
+}
+
+/// The trust score of one component before and after two snapshots,
+/// `None` if the component didn't exist in that snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustScoreDelta {
+    pub component_id: String,
+    pub before: Option<f64>,
+    pub after: Option<f64>,
+}
+
+/// The result of `TrustMonitor::diff`: exactly what changed between two
+/// snapshots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    pub from_snapshot: String,
+    pub to_snapshot: String,
+    pub added_components: Vec<String>,
+    pub removed_components: Vec<String>,
+    pub trust_score_changes: Vec<TrustScoreDelta>,
+}
+
+/// Serializes `value` as JSON and writes it under `key` in the embedded
+/// store. Used by `TrustMonitor::apply` to persist each touched entity.
+fn persist<T: Serialize>(store: &sled::Db, key: &str, value: &T) -> Result<(), String> {
+    let bytes = serde_json::to_vec(value).map_err(|e| e.to_string())?;
+    store.insert(key.as_bytes(), bytes).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// OpenTelemetry instrumentation for the monitor: trust scores exported as
+/// a gauge, incidents and alerts exported as counters, and spans around
+/// each scoring/simulation call. Every metric and span is tagged with
+/// `component_id`, `component_type`, and the `FactorType`s that
+/// contributed, so an external collector can correlate a trust drop with
+/// downstream latency or error-rate series.
+pub struct Telemetry {
+    tracer: opentelemetry_sdk::trace::Tracer,
+    trust_score_gauge: Gauge<f64>,
+    incident_counter: Counter<u64>,
+    alert_counter: Counter<u64>,
+}
+
+impl std::fmt::Debug for Telemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Telemetry").finish_non_exhaustive()
+    }
+}
+
+impl Telemetry {
+    /// Stands up OTLP tracer and meter providers pointed at `otlp_endpoint`
+    /// and registers them as the process-wide global providers.
+    pub fn init(otlp_endpoint: &str) -> Result<Self, String> {
+        let tracer_provider = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(otlp_endpoint),
+            )
+            .install_batch(opentelemetry_sdk::runtime::Tokio)
+            .map_err(|e| e.to_string())?;
+        let tracer = tracer_provider.tracer("sculi-trust-monitor");
+        global::set_tracer_provider(tracer_provider);
+
+        let meter_provider = opentelemetry_otlp::new_pipeline()
+            .metrics(opentelemetry_sdk::runtime::Tokio)
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(otlp_endpoint),
+            )
+            .build()
+            .map_err(|e| e.to_string())?;
+        let meter = meter_provider.meter("sculi-trust-monitor");
+        global::set_meter_provider(meter_provider);
+
+        let trust_score_gauge = meter
+            .f64_gauge("lanc_trust_score")
+            .with_description("Current trust score per component")
+            .init();
+        let incident_counter = meter
+            .u64_counter("lanc_incidents_total")
+            .with_description("Incidents recorded per component")
+            .init();
+        let alert_counter = meter
+            .u64_counter("lanc_alerts_total")
+            .with_description("Alerts raised per component")
+            .init();
+
+        Ok(Self { tracer, trust_score_gauge, incident_counter, alert_counter })
+    }
+
+    fn attributes(component_id: &str, component_type: &ComponentType, factors: &[String]) -> Vec<KeyValue> {
+        vec![
+            KeyValue::new("component_id", component_id.to_string()),
+            KeyValue::new("component_type", format!("{:?}", component_type)),
+            KeyValue::new("factor_types", factors.join(",")),
+        ]
+    }
+
+    pub fn record_trust_score(&self, component_id: &str, component_type: &ComponentType, factors: &[String], score: f64) {
+        self.trust_score_gauge.record(score, &Self::attributes(component_id, component_type, factors));
+    }
+
+    pub fn record_incident(&self, component_id: &str, component_type: &ComponentType) {
+        self.incident_counter.add(1, &Self::attributes(component_id, component_type, &[]));
+    }
+
+    pub fn record_alert(&self, component_id: &str, component_type: &ComponentType) {
+        self.alert_counter.add(1, &Self::attributes(component_id, component_type, &[]));
+    }
+
+    /// Starts a span for a trust-affecting operation, tagged with the
+    /// component and the factors that drove it. The span ends when the
+    /// returned guard is dropped.
+    pub fn start_span(
+        &self,
+        name: &'static str,
+        component_id: &str,
+        component_type: &ComponentType,
+        factors: &[String],
+    ) -> opentelemetry::trace::BoxedSpan {
+        self.tracer
+            .span_builder(name)
+            .with_attributes(Self::attributes(component_id, component_type, factors))
+            .start(&self.tracer)
+    }
+}
+
+/// Prometheus gauges exposing `TrustMonitor` state for `GET /metrics`.
+/// Gauges are refreshed from current state at scrape time by
+/// `TrustMonitor::render_metrics` rather than on every mutation, keeping
+/// instrumentation out of the hot `update_trust_score` path.
+pub struct PrometheusMetrics {
+    registry: prometheus::Registry,
+    trust_score_gauge: prometheus::GaugeVec,
+    overall_trust: prometheus::Gauge,
+    component_count: prometheus::IntGauge,
+    active_incidents: prometheus::IntGauge,
+    active_alerts: prometheus::IntGauge,
+    prediction_accuracy: prometheus::Gauge,
+    response_time_avg: prometheus::Gauge,
+    human_escalation_rate: prometheus::Gauge,
+}
+
+impl std::fmt::Debug for PrometheusMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrometheusMetrics").finish_non_exhaustive()
+    }
+}
+
+impl PrometheusMetrics {
+    pub fn new() -> Self {
+        let registry = prometheus::Registry::new();
+
+        let trust_score_gauge = prometheus::GaugeVec::new(
+            prometheus::Opts::new("sculi_trust_score", "Current trust score per component"),
+            &["component"],
+        )
+        .expect("valid metric definition");
+        let overall_trust = prometheus::Gauge::new("sculi_overall_trust", "Overall system trust score")
+            .expect("valid metric definition");
+        let component_count = prometheus::IntGauge::new("sculi_component_count", "Number of monitored components")
+            .expect("valid metric definition");
+        let active_incidents = prometheus::IntGauge::new("sculi_active_incidents", "Number of active incidents")
+            .expect("valid metric definition");
+        let active_alerts = prometheus::IntGauge::new("sculi_active_alerts", "Number of active alerts")
+            .expect("valid metric definition");
+        let prediction_accuracy = prometheus::Gauge::new(
+            "sculi_prediction_accuracy",
+            "Predictability objective: trust-score prediction accuracy",
+        )
+        .expect("valid metric definition");
+        let response_time_avg = prometheus::Gauge::new(
+            "sculi_response_time_avg_seconds",
+            "Incident response objective: average response time",
+        )
+        .expect("valid metric definition");
+        let human_escalation_rate = prometheus::Gauge::new(
+            "sculi_human_escalation_rate",
+            "Incident response objective: fraction of responses escalated to a human",
+        )
+        .expect("valid metric definition");
+
+        registry.register(Box::new(trust_score_gauge.clone())).expect("metric name collision");
+        registry.register(Box::new(overall_trust.clone())).expect("metric name collision");
+        registry.register(Box::new(component_count.clone())).expect("metric name collision");
+        registry.register(Box::new(active_incidents.clone())).expect("metric name collision");
+        registry.register(Box::new(active_alerts.clone())).expect("metric name collision");
+        registry.register(Box::new(prediction_accuracy.clone())).expect("metric name collision");
+        registry.register(Box::new(response_time_avg.clone())).expect("metric name collision");
+        registry.register(Box::new(human_escalation_rate.clone())).expect("metric name collision");
+
+        Self {
+            registry,
+            trust_score_gauge,
+            overall_trust,
+            component_count,
+            active_incidents,
+            active_alerts,
+            prediction_accuracy,
+            response_time_avg,
+            human_escalation_rate,
+        }
+    }
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Durable audit history for trust scores, alerts, and incidents, kept
+/// separate from the `RwLock`-guarded in-memory state so a restart doesn't
+/// lose it. `InMemoryBackend` is the default (and what tests should use);
+/// `PostgresBackend` gives a real deployment a persistent audit trail for
+/// the "continual assurance" objective.
+#[async_trait]
+pub trait StorageBackend: Send + Sync + std::fmt::Debug {
+    async fn save_trust_score(&self, component_id: &str, score: &TrustScore) -> Result<(), String>;
+    async fn save_alert(&self, alert: &Alert) -> Result<(), String>;
+    async fn save_incident(&self, incident: &Incident) -> Result<(), String>;
+    async fn load_trust_scores(&self) -> Result<HashMap<String, TrustScore>, String>;
+    async fn load_alerts(&self) -> Result<Vec<Alert>, String>;
+    async fn load_incidents(&self) -> Result<Vec<Incident>, String>;
+}
+
+/// Volatile `StorageBackend`: holds everything in `RwLock`-guarded
+/// collections and loses it on restart. The default for `TrustMonitor::new`
+/// and for tests that don't care about durability.
+#[derive(Debug, Default)]
+pub struct InMemoryBackend {
+    trust_scores: RwLock<HashMap<String, TrustScore>>,
+    alerts: RwLock<Vec<Alert>>,
+    incidents: RwLock<Vec<Incident>>,
+}
+
+#[async_trait]
+impl StorageBackend for InMemoryBackend {
+    async fn save_trust_score(&self, component_id: &str, score: &TrustScore) -> Result<(), String> {
+        self.trust_scores.write().await.insert(component_id.to_string(), score.clone());
+        Ok(())
+    }
+
+    async fn save_alert(&self, alert: &Alert) -> Result<(), String> {
+        self.alerts.write().await.push(alert.clone());
+        Ok(())
+    }
+
+    async fn save_incident(&self, incident: &Incident) -> Result<(), String> {
+        self.incidents.write().await.push(incident.clone());
+        Ok(())
+    }
+
+    async fn load_trust_scores(&self) -> Result<HashMap<String, TrustScore>, String> {
+        Ok(self.trust_scores.read().await.clone())
+    }
+
+    async fn load_alerts(&self) -> Result<Vec<Alert>, String> {
+        Ok(self.alerts.read().await.clone())
+    }
+
+    async fn load_incidents(&self) -> Result<Vec<Incident>, String> {
+        Ok(self.incidents.read().await.clone())
+    }
+}
+
+/// `StorageBackend` backed by a Postgres connection pool, for a deployment
+/// that wants durable audit history to survive across restarts and across
+/// process instances (unlike the embedded, single-node `sled` store).
+pub struct PostgresBackend {
+    pool: bb8::Pool<bb8_postgres::PostgresConnectionManager<tokio_postgres::NoTls>>,
+}
+
+impl std::fmt::Debug for PostgresBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresBackend").finish_non_exhaustive()
+    }
+}
+
+impl PostgresBackend {
+    /// Connects to `database_url`, creates a pool, and ensures the
+    /// `trust_scores` / `alerts` / `incidents` tables exist.
+    pub async fn connect(database_url: &str) -> Result<Self, String> {
+        let manager = bb8_postgres::PostgresConnectionManager::new_from_stringlike(database_url, tokio_postgres::NoTls)
+            .map_err(|e| e.to_string())?;
+        let pool = bb8::Pool::builder().build(manager).await.map_err(|e| e.to_string())?;
+
+        let conn = pool.get().await.map_err(|e| e.to_string())?;
+        conn.batch_execute(
+            "CREATE TABLE IF NOT EXISTS trust_scores (
+                component_id TEXT PRIMARY KEY,
+                score_json   TEXT NOT NULL,
+                updated_at   TIMESTAMPTZ NOT NULL
+             );
+             CREATE TABLE IF NOT EXISTS alerts (
+                id         TEXT PRIMARY KEY,
+                alert_json TEXT NOT NULL,
+                created_at TIMESTAMPTZ NOT NULL
+             );
+             CREATE TABLE IF NOT EXISTS incidents (
+                id            TEXT PRIMARY KEY,
+                incident_json TEXT NOT NULL,
+                created_at    TIMESTAMPTZ NOT NULL
+             );",
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl StorageBackend for PostgresBackend {
+    async fn save_trust_score(&self, component_id: &str, score: &TrustScore) -> Result<(), String> {
+        let conn = self.pool.get().await.map_err(|e| e.to_string())?;
+        let score_json = serde_json::to_string(score).map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT INTO trust_scores (component_id, score_json, updated_at) VALUES ($1, $2, $3)
+             ON CONFLICT (component_id) DO UPDATE SET score_json = $2, updated_at = $3",
+            &[&component_id, &score_json, &score.last_updated],
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn save_alert(&self, alert: &Alert) -> Result<(), String> {
+        let conn = self.pool.get().await.map_err(|e| e.to_string())?;
+        let alert_json = serde_json::to_string(alert).map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT INTO alerts (id, alert_json, created_at) VALUES ($1, $2, $3)
+             ON CONFLICT (id) DO UPDATE SET alert_json = $2",
+            &[&alert.id, &alert_json, &alert.timestamp],
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn save_incident(&self, incident: &Incident) -> Result<(), String> {
+        let conn = self.pool.get().await.map_err(|e| e.to_string())?;
+        let incident_json = serde_json::to_string(incident).map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT INTO incidents (id, incident_json, created_at) VALUES ($1, $2, $3)
+             ON CONFLICT (id) DO UPDATE SET incident_json = $2",
+            &[&incident.id, &incident_json, &incident.created_at],
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn load_trust_scores(&self) -> Result<HashMap<String, TrustScore>, String> {
+        let conn = self.pool.get().await.map_err(|e| e.to_string())?;
+        let rows = conn.query("SELECT component_id, score_json FROM trust_scores", &[]).await.map_err(|e| e.to_string())?;
+        let mut out = HashMap::new();
+        for row in rows {
+            let component_id: String = row.get(0);
+            let score_json: String = row.get(1);
+            let score: TrustScore = serde_json::from_str(&score_json).map_err(|e| e.to_string())?;
+            out.insert(component_id, score);
+        }
+        Ok(out)
+    }
+
+    async fn load_alerts(&self) -> Result<Vec<Alert>, String> {
+        let conn = self.pool.get().await.map_err(|e| e.to_string())?;
+        let rows = conn.query("SELECT alert_json FROM alerts ORDER BY created_at", &[]).await.map_err(|e| e.to_string())?;
+        rows.into_iter()
+            .map(|row| serde_json::from_str(&row.get::<_, String>(0)).map_err(|e| e.to_string()))
+            .collect()
+    }
+
+    async fn load_incidents(&self) -> Result<Vec<Incident>, String> {
+        let conn = self.pool.get().await.map_err(|e| e.to_string())?;
+        let rows = conn.query("SELECT incident_json FROM incidents ORDER BY created_at", &[]).await.map_err(|e| e.to_string())?;
+        rows.into_iter()
+            .map(|row| serde_json::from_str(&row.get::<_, String>(0)).map_err(|e| e.to_string()))
+            .collect()
+    }
+}
+
 impl TrustMonitor {
     pub fn new() -> Self {
         Self {
@@ -292,140 +1114,969 @@ impl TrustMonitor {
             incidents: Arc::new(RwLock::new(Vec::new())),
             alerts: Arc::new(RwLock::new(Vec::new())),
             bayesian_models: Arc::new(RwLock::new(HashMap::new())),
+            advisories: Arc::new(RwLock::new(HashMap::new())),
+            security_postures: Arc::new(RwLock::new(HashMap::new())),
+            action_log: Arc::new(RwLock::new(Vec::new())),
+            action_dry_run: false,
+            provenance: Arc::new(RwLock::new(Vec::new())),
+            node_indices: Arc::new(RwLock::new(HashMap::new())),
+            prometheus: Arc::new(PrometheusMetrics::new()),
+            gossip_peers: Vec::new(),
+            gossip_last_seen: Arc::new(RwLock::new(HashMap::new())),
+            store: None,
+            storage: Arc::new(InMemoryBackend::default()),
+            update_tx: tokio::sync::broadcast::channel(256).0,
+            telemetry: None,
+        }
+    }
+
+    /// Subscribes to live `TrustUpdateEvent`s for `GET /trust-scores/stream`.
+    pub fn subscribe_to_updates(&self) -> tokio::sync::broadcast::Receiver<TrustUpdateEvent> {
+        self.update_tx.subscribe()
+    }
+
+    /// Swaps in a different `StorageBackend`, e.g. a `PostgresBackend` for
+    /// durable audit history. Defaults to `InMemoryBackend`.
+    pub fn with_storage_backend(mut self, storage: Arc<dyn StorageBackend>) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Attaches OpenTelemetry instrumentation to this monitor.
+    pub fn with_telemetry(mut self, telemetry: Arc<Telemetry>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Switches the action engine to dry-run mode: action groups still run
+    /// and get logged, but no trust impact is applied.
+    pub fn with_action_dry_run(mut self, dry_run: bool) -> Self {
+        self.action_dry_run = dry_run;
+        self
+    }
+
+    /// Gives this monitor a stable, cluster-unique identity, so gossiped
+    /// digests carrying its own `system_id` can be told apart from a peer's.
+    pub fn with_system_id(mut self, system_id: impl Into<String>) -> Self {
+        self.system_id = system_id.into();
+        self
+    }
+
+    /// Configures the peers `gossip_send_loop` periodically sends
+    /// `trust_scores` digests to.
+    pub fn with_gossip_peers(mut self, peers: Vec<String>) -> Self {
+        self.gossip_peers = peers;
+        self
+    }
+
+    /// Opens (or creates) a sled database under `data_dir` and restores any
+    /// previously persisted components, trust scores, and Bayesian models
+    /// before the monitor starts serving, so state survives a restart.
+    pub fn with_store(data_dir: impl AsRef<std::path::Path>) -> Result<Self, String> {
+        let db = sled::open(data_dir).map_err(|e| e.to_string())?;
+
+        let mut components = HashMap::new();
+        for entry in db.scan_prefix(b"component:") {
+            let (_, value) = entry.map_err(|e| e.to_string())?;
+            let component: Component = serde_json::from_slice(&value).map_err(|e| e.to_string())?;
+            components.insert(component.id.clone(), component);
+        }
+
+        let mut trust_scores = HashMap::new();
+        for entry in db.scan_prefix(b"trust_score:") {
+            let (key, value) = entry.map_err(|e| e.to_string())?;
+            let key_bytes: &[u8] = &key;
+            let component_id = String::from_utf8_lossy(&key_bytes["trust_score:".len()..]).to_string();
+            let trust_score: TrustScore = serde_json::from_slice(&value).map_err(|e| e.to_string())?;
+            trust_scores.insert(component_id, trust_score);
+        }
+
+        let mut bayesian_models = HashMap::new();
+        for entry in db.scan_prefix(b"bayesian_model:") {
+            let (key, value) = entry.map_err(|e| e.to_string())?;
+            let key_bytes: &[u8] = &key;
+            let component_id = String::from_utf8_lossy(&key_bytes["bayesian_model:".len()..]).to_string();
+            let model: BayesianModel = serde_json::from_slice(&value).map_err(|e| e.to_string())?;
+            bayesian_models.insert(component_id, model);
+        }
+
+        Ok(Self {
+            system_id: "sculi-trust-monitor".to_string(),
+            trust_graph: Arc::new(RwLock::new(Graph::new())),
+            components: Arc::new(RwLock::new(components)),
+            trust_scores: Arc::new(RwLock::new(trust_scores)),
+            incidents: Arc::new(RwLock::new(Vec::new())),
+            alerts: Arc::new(RwLock::new(Vec::new())),
+            bayesian_models: Arc::new(RwLock::new(bayesian_models)),
+            advisories: Arc::new(RwLock::new(HashMap::new())),
+            security_postures: Arc::new(RwLock::new(HashMap::new())),
+            action_log: Arc::new(RwLock::new(Vec::new())),
+            action_dry_run: false,
+            provenance: Arc::new(RwLock::new(Vec::new())),
+            node_indices: Arc::new(RwLock::new(HashMap::new())),
+            prometheus: Arc::new(PrometheusMetrics::new()),
+            gossip_peers: Vec::new(),
+            gossip_last_seen: Arc::new(RwLock::new(HashMap::new())),
+            store: Some(db),
+            storage: Arc::new(InMemoryBackend::default()),
+            update_tx: tokio::sync::broadcast::channel(256).0,
+            telemetry: None,
+        })
+    }
+
+    /// Applies a batch of pending mutations atomically: acquires every
+    /// affected lock once, applies each op in order, persists the touched
+    /// keys if a store is configured, and returns the id of the snapshot
+    /// taken immediately afterward.
+    pub async fn apply(&self, changeset: Changeset) -> Result<String, String> {
+        let mut components = self.components.write().await;
+        let mut trust_scores = self.trust_scores.write().await;
+        let mut bayesian_models = self.bayesian_models.write().await;
+
+        for op in changeset.ops {
+            match op {
+                ChangesetOp::AddComponent(component) => {
+                    if let Some(store) = &self.store {
+                        persist(store, &format!("component:{}", component.id), &component)?;
+                    }
+                    components.insert(component.id.clone(), component);
+                }
+                ChangesetOp::RemoveComponent(component_id) => {
+                    components.remove(&component_id);
+                    trust_scores.remove(&component_id);
+                    bayesian_models.remove(&component_id);
+                    if let Some(store) = &self.store {
+                        store.remove(format!("component:{}", component_id).as_bytes()).map_err(|e| e.to_string())?;
+                        store.remove(format!("trust_score:{}", component_id).as_bytes()).map_err(|e| e.to_string())?;
+                        store.remove(format!("bayesian_model:{}", component_id).as_bytes()).map_err(|e| e.to_string())?;
+                    }
+                }
+                ChangesetOp::OverrideTrustScore { component_id, score } => {
+                    let entry = trust_scores.entry(component_id.clone()).or_insert_with(|| TrustScore {
+                        score,
+                        confidence: 0.5,
+                        contributing_factors: vec![],
+                        prediction: None,
+                        last_updated: Utc::now(),
+                        version: 0,
+                    });
+                    entry.score = score;
+                    entry.last_updated = Utc::now();
+                    entry.version += 1;
+                    if let Some(store) = &self.store {
+                        persist(store, &format!("trust_score:{}", component_id), entry)?;
+                    }
+                }
+                ChangesetOp::RegisterPrior { component_id, prior_probability } => {
+                    let model = bayesian_models.entry(component_id.clone()).or_insert_with(|| BayesianModel {
+                        model_id: format!("bayesian-{}", component_id),
+                        prior_probability,
+                        likelihood_functions: HashMap::from([
+                            ("security_events".to_string(), 0.1),
+                            ("performance_metrics".to_string(), 0.3),
+                            ("behavioral_anomalies".to_string(), 0.2),
+                            ("compliance_status".to_string(), 0.2),
+                            ("dependency_health".to_string(), 0.2),
+                        ]),
+                        posterior_probability: prior_probability,
+                        evidence_count: 0,
+                        last_updated: Utc::now(),
+                        half_life_secs: 1800,
+                        penalties: vec![],
+                    });
+                    model.prior_probability = prior_probability;
+                    model.last_updated = Utc::now();
+                    if let Some(store) = &self.store {
+                        persist(store, &format!("bayesian_model:{}", component_id), model)?;
+                    }
+                }
+            }
+        }
+
+        let snapshot = Snapshot {
+            snapshot_id: Uuid::new_v4().to_string(),
+            components: components.clone(),
+            trust_scores: trust_scores.clone(),
+            bayesian_models: bayesian_models.clone(),
+            taken_at: Utc::now(),
+        };
+
+        if let Some(store) = &self.store {
+            persist(store, &format!("snapshot:{}", snapshot.snapshot_id), &snapshot)?;
+            store.flush_async().await.map_err(|e| e.to_string())?;
+        }
+
+        Ok(snapshot.snapshot_id)
+    }
+
+    /// Takes an immutable snapshot of the current state. Unlike the
+    /// snapshot taken by `apply`, this one isn't persisted under a new key.
+    pub async fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            snapshot_id: Uuid::new_v4().to_string(),
+            components: self.components.read().await.clone(),
+            trust_scores: self.trust_scores.read().await.clone(),
+            bayesian_models: self.bayesian_models.read().await.clone(),
+            taken_at: Utc::now(),
+        }
+    }
+
+    /// Reads back a snapshot previously persisted by `apply`.
+    pub fn load_snapshot(&self, snapshot_id: &str) -> Result<Snapshot, String> {
+        let store = self.store.as_ref().ok_or("no persistent store configured")?;
+        let key = format!("snapshot:{}", snapshot_id);
+        let bytes = store
+            .get(key.as_bytes())
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("snapshot {} not found", snapshot_id))?;
+        serde_json::from_slice(&bytes).map_err(|e| e.to_string())
+    }
+
+    /// Compares two snapshots and reports exactly what changed between
+    /// them: components added or removed, and trust score deltas per
+    /// component.
+    pub fn diff(&self, snapshot_a: &Snapshot, snapshot_b: &Snapshot) -> SnapshotDiff {
+        let added_components = snapshot_b
+            .components
+            .keys()
+            .filter(|id| !snapshot_a.components.contains_key(*id))
+            .cloned()
+            .collect();
+        let removed_components = snapshot_a
+            .components
+            .keys()
+            .filter(|id| !snapshot_b.components.contains_key(*id))
+            .cloned()
+            .collect();
+
+        let mut component_ids: Vec<&String> = snapshot_a
+            .trust_scores
+            .keys()
+            .chain(snapshot_b.trust_scores.keys())
+            .collect();
+        component_ids.sort();
+        component_ids.dedup();
+
+        let trust_score_changes = component_ids
+            .into_iter()
+            .filter_map(|id| {
+                let before = snapshot_a.trust_scores.get(id).map(|t| t.score);
+                let after = snapshot_b.trust_scores.get(id).map(|t| t.score);
+                if before == after {
+                    None
+                } else {
+                    Some(TrustScoreDelta { component_id: id.clone(), before, after })
+                }
+            })
+            .collect();
+
+        SnapshotDiff {
+            from_snapshot: snapshot_a.snapshot_id.clone(),
+            to_snapshot: snapshot_b.snapshot_id.clone(),
+            added_components,
+            removed_components,
+            trust_score_changes,
+        }
+    }
+
+    /// Initialize the trust monitoring system with sample components.
+    /// A no-op if components were already restored from a persistent store.
+    #[tracing::instrument(skip(self))]
+    pub async fn initialize(&self) -> Result<(), String> {
+        // `store` (sled) only restores components/trust_scores/bayesian_models;
+        // `storage` covers alerts and incidents too, so reload it independently.
+        let restored_alerts = self.storage.load_alerts().await?;
+        if !restored_alerts.is_empty() {
+            *self.alerts.write().await = restored_alerts;
+        }
+        let restored_incidents = self.storage.load_incidents().await?;
+        if !restored_incidents.is_empty() {
+            *self.incidents.write().await = restored_incidents;
+        }
+
+        if !self.components.read().await.is_empty() {
+            let count = self.components.read().await.len();
+            tracing::info!("‚úÖ Restored {} components from persistent store", count);
+            return Ok(());
+        }
+
+        tracing::info!("üöÄ Initializing SCULI Trust Monitoring System...");
+
+        // Add sample components representing different system types
+        let sample_components = vec![
+            ("wasm-service-1", "WebAssembly Service 1", ComponentType::WebAssembly, Some("examples/wasm-service.wasm".to_string())),
+            ("wasm-service-2", "WebAssembly Service 2", ComponentType::WebAssembly, Some("examples/wasm-service.wasm".to_string())),
+            ("container-service", "Container Service", ComponentType::Container, None),
+            ("legacy-api", "Legacy API", ComponentType::LegacySystem, None),
+            ("microservice-1", "Microservice 1", ComponentType::Microservice, None),
+            ("microservice-2", "Microservice 2", ComponentType::Microservice, None),
+            ("database-primary", "Primary Database", ComponentType::Database, None),
+            ("cache-redis", "Redis Cache", ComponentType::Cache, None),
+            ("load-balancer", "Load Balancer", ComponentType::LoadBalancer, None),
+            ("message-queue", "Message Queue", ComponentType::MessageQueue, None),
+        ];
+
+        let mut components = self.components.write().await;
+        let mut trust_scores = self.trust_scores.write().await;
+
+        for (id, name, component_type, wasm_module) in sample_components {
+            let component = Component {
+                id: id.to_string(),
+                name: name.to_string(),
+                component_type,
+                trust_score: 0.85, // Start with high trust
+                status: "healthy".to_string(),
+                wasm_module,
+                container_id: None,
+                legacy_endpoint: None,
+                version: semver::Version::new(1, 0, 0),
+                last_updated: Utc::now(),
+            };
+            
+            components.insert(id.to_string(), component);
+            
+            // Initialize trust score with Bayesian model
+            let trust_score = TrustScore {
+                score: 0.85,
+                confidence: 0.9,
+                contributing_factors: vec![],
+                prediction: None,
+                last_updated: Utc::now(),
+                version: 0,
+            };
+            trust_scores.insert(id.to_string(), trust_score);
+        }
+
+        // Initialize Bayesian models for each component
+        let mut bayesian_models = self.bayesian_models.write().await;
+        for component_id in components.keys() {
+            let model = BayesianModel {
+                model_id: format!("bayesian-{}", component_id),
+                prior_probability: 0.85,
+                likelihood_functions: HashMap::from([
+                    ("security_events".to_string(), 0.1),
+                    ("performance_metrics".to_string(), 0.3),
+                    ("behavioral_anomalies".to_string(), 0.2),
+                    ("compliance_status".to_string(), 0.2),
+                    ("dependency_health".to_string(), 0.2),
+                ]),
+                posterior_probability: 0.85,
+                evidence_count: 0,
+                last_updated: Utc::now(),
+                half_life_secs: 1800, // 30 minutes
+                penalties: vec![],
+            };
+            bayesian_models.insert(component_id.clone(), model);
+        }
+
+        // Populate the trust graph so provenance records can be linked into
+        // it and causal chains traversed across `TrustEdge`s.
+        {
+            let mut trust_graph = self.trust_graph.write().await;
+            let mut node_indices = self.node_indices.write().await;
+
+            for (component_id, component) in components.iter() {
+                let node = TrustNode {
+                    id: component_id.clone(),
+                    component_type: component.component_type.clone(),
+                    trust_score: component.trust_score,
+                    confidence: 0.9,
+                    behavioral_metrics: BehavioralMetrics {
+                        request_patterns: HashMap::new(),
+                        resource_usage: ResourceUsage {
+                            cpu_usage: 0.0,
+                            memory_usage: 0.0,
+                            disk_usage: 0.0,
+                            network_usage: 0.0,
+                        },
+                        communication_patterns: HashMap::new(),
+                        anomaly_score: 0.0,
+                        performance_metrics: PerformanceMetrics {
+                            response_time: 0.0,
+                            throughput: 0.0,
+                            error_rate: 0.0,
+                            availability: 1.0,
+                        },
+                    },
+                    security_posture: SecurityPosture {
+                        vulnerability_score: 0.0,
+                        patch_status: 1.0,
+                        compliance_score: 1.0,
+                        encryption_status: 1.0,
+                        access_control_score: 1.0,
+                        attestation_score: 1.0,
+                    },
+                    last_updated: component.last_updated,
+                };
+                let index = trust_graph.add_node(node);
+                node_indices.insert(component_id.clone(), index);
+            }
+
+            // Sample dependency edges so a degradation propagating from one
+            // component to its dependents can be traversed in provenance
+            // queries.
+            let sample_dependencies = [
+                ("microservice-1", "database-primary"),
+                ("microservice-2", "database-primary"),
+                ("microservice-1", "cache-redis"),
+                ("microservice-2", "message-queue"),
+                ("container-service", "microservice-1"),
+                ("legacy-api", "database-primary"),
+                ("load-balancer", "microservice-1"),
+                ("load-balancer", "microservice-2"),
+                ("wasm-service-1", "message-queue"),
+                ("wasm-service-2", "message-queue"),
+            ];
+            for (from, to) in sample_dependencies {
+                if let (Some(&from_index), Some(&to_index)) = (node_indices.get(from), node_indices.get(to)) {
+                    trust_graph.add_edge(from_index, to_index, TrustEdge {
+                        from: from.to_string(),
+                        to: to.to_string(),
+                        relationship_type: RelationshipType::Dependency,
+                        trust_weight: 0.8,
+                        data_flow_volume: 0.0,
+                        criticality: 0.5,
+                    });
+                }
+            }
         }
+
+        tracing::info!("‚úÖ System initialized with {} components", components.len());
+        Ok(())
+    }
+
+    /// Update trust score using Bayesian inference
+    pub async fn update_trust_score(&self, component_id: &str, evidence: &TrustEvidence) -> Result<(), String> {
+        let component_type = self.components.read().await.get(component_id).map(|c| c.component_type.clone());
+        let factors: Vec<String> = evidence.factors.keys().cloned().collect();
+        let _span = self
+            .telemetry
+            .as_ref()
+            .zip(component_type.as_ref())
+            .map(|(t, ct)| t.start_span("update_trust_score", component_id, ct, &factors));
+
+        let mut triggered_alert: Option<(AlertType, AlertSeverity)> = None;
+        let mut provenance_entry: Option<ProvenanceRecord> = None;
+        let mut persisted_score: Option<TrustScore> = None;
+        let mut persisted_alerts: Vec<Alert> = Vec::new();
+
+        {
+            let mut trust_scores = self.trust_scores.write().await;
+            let mut bayesian_models = self.bayesian_models.write().await;
+            let mut alerts = self.alerts.write().await;
+
+            if let Some(trust_score) = trust_scores.get_mut(component_id) {
+                if let Some(model) = bayesian_models.get_mut(component_id) {
+                    let now = evidence.timestamp;
+                    let prior_score = trust_score.score;
+                    let prior_posterior = model.posterior_probability;
+
+                    // Record each factor as its own decaying penalty rather than
+                    // mutating the score directly, so the posterior recovers on
+                    // its own once the evidence stops arriving.
+                    let mut contributing_factors = Vec::new();
+                    for (factor, value) in &evidence.factors {
+                        if let Some(&weight) = model.likelihood_functions.get(factor) {
+                            model.record_penalty(factor.clone(), *value, weight, now);
+                            if let Some(factor_type) = Self::factor_type_for(factor) {
+                                contributing_factors.push(ContributingFactor {
+                                    factor_type,
+                                    weight,
+                                    value: *value,
+                                    description: format!("{} reported {:.2}", factor, value),
+                                });
+                            }
+                        }
+                    }
+
+                    let new_score = model.recompute(now);
+
+                    // Update trust score
+                    trust_score.score = new_score;
+                    trust_score.confidence = self.calculate_confidence(model, now);
+                    trust_score.last_updated = now;
+                    trust_score.contributing_factors = contributing_factors.clone();
+                    trust_score.version += 1;
+
+                    provenance_entry = Some(ProvenanceRecord {
+                        id: Uuid::new_v4(),
+                        component_id: component_id.to_string(),
+                        prior_score,
+                        new_score,
+                        prior_posterior,
+                        new_posterior: new_score,
+                        factors: contributing_factors,
+                        source: "trust_evidence".to_string(),
+                        timestamp: now,
+                        propagated_from: Vec::new(),
+                    });
+
+                    // Generate prediction
+                    trust_score.prediction = Some(self.generate_prediction(component_id, model).await);
+
+                    if let (Some(telemetry), Some(ct)) = (&self.telemetry, component_type.as_ref()) {
+                        telemetry.record_trust_score(component_id, ct, &factors, new_score);
+                    }
+
+                    // Check for alerts
+                    if new_score < 0.4 {
+                        let alert = Alert {
+                            id: format!("alert-{}-{}", component_id, Utc::now().timestamp()),
+                            component_id: component_id.to_string(),
+                            alert_type: AlertType::TrustScoreLow,
+                            severity: AlertSeverity::Critical,
+                            message: format!("Critical trust score: {:.2}", new_score),
+                            trust_threshold: 0.4,
+                            timestamp: Utc::now(),
+                            status: AlertStatus::Active,
+                        };
+                        triggered_alert = Some((alert.alert_type.clone(), alert.severity.clone()));
+                        persisted_alerts.push(alert.clone());
+                        alerts.push(alert);
+                        if let (Some(telemetry), Some(ct)) = (&self.telemetry, component_type.as_ref()) {
+                            telemetry.record_alert(component_id, ct);
+                        }
+                    } else if new_score < 0.7 {
+                        let alert = Alert {
+                            id: format!("alert-{}-{}", component_id, Utc::now().timestamp()),
+                            component_id: component_id.to_string(),
+                            alert_type: AlertType::TrustScoreLow,
+                            severity: AlertSeverity::High,
+                            message: format!("Warning trust score: {:.2}", new_score),
+                            trust_threshold: 0.7,
+                            timestamp: Utc::now(),
+                            status: AlertStatus::Active,
+                        };
+                        triggered_alert = Some((alert.alert_type.clone(), alert.severity.clone()));
+                        persisted_alerts.push(alert.clone());
+                        alerts.push(alert);
+                        if let (Some(telemetry), Some(ct)) = (&self.telemetry, component_type.as_ref()) {
+                            telemetry.record_alert(component_id, ct);
+                        }
+                    }
+
+                    persisted_score = Some(trust_score.clone());
+                }
+            }
+        }
+
+        if let Some(score) = &persisted_score {
+            self.storage.save_trust_score(component_id, score).await?;
+            let _ = self.update_tx.send(TrustUpdateEvent::ScoreUpdated {
+                component_id: component_id.to_string(),
+                score: score.clone(),
+            });
+        }
+        for alert in &persisted_alerts {
+            self.storage.save_alert(alert).await?;
+            let _ = self.update_tx.send(TrustUpdateEvent::AlertRaised(alert.clone()));
+        }
+
+        if let Some(mut record) = provenance_entry {
+            record.propagated_from = self.degraded_upstream_dependencies(component_id).await;
+            self.provenance.write().await.push(record);
+        }
+
+        // Run the action group bound to this alert only after the locks
+        // above are released, since handlers read/write the same state.
+        if let Some((alert_type, severity)) = triggered_alert {
+            self.execute_action_group(component_id, &alert_type, &severity, self.action_dry_run).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Maps an evidence key (e.g. `"security_events"`) to the `FactorType`
+    /// it represents, mirroring the keys seeded into every component's
+    /// `likelihood_functions` in `initialize`.
+    fn factor_type_for(factor: &str) -> Option<FactorType> {
+        match factor {
+            "security_events" => Some(FactorType::SecurityEvent),
+            "performance_metrics" => Some(FactorType::PerformanceMetric),
+            "behavioral_anomalies" => Some(FactorType::BehavioralAnomaly),
+            "compliance_status" => Some(FactorType::ComplianceStatus),
+            "dependency_health" => Some(FactorType::DependencyHealth),
+            "communication_quality" => Some(FactorType::CommunicationQuality),
+            "attestation_result" => Some(FactorType::AttestationResult),
+            _ => None,
+        }
+    }
+
+    /// Component ids one `Dependency` edge upstream of `component_id` in
+    /// `trust_graph` whose current trust score is degraded, i.e. plausible
+    /// causes of this transition via propagation.
+    async fn degraded_upstream_dependencies(&self, component_id: &str) -> Vec<String> {
+        let node_indices = self.node_indices.read().await;
+        let Some(&node_index) = node_indices.get(component_id) else {
+            return Vec::new();
+        };
+
+        let trust_graph = self.trust_graph.read().await;
+        let trust_scores = self.trust_scores.read().await;
+
+        trust_graph
+            .neighbors_directed(node_index, petgraph::Direction::Outgoing)
+            .filter_map(|neighbor_index| trust_graph.node_weight(neighbor_index))
+            .filter(|dependency| {
+                trust_scores
+                    .get(&dependency.id)
+                    .is_some_and(|score| score.score < 0.7)
+            })
+            .map(|dependency| dependency.id.clone())
+            .collect()
+    }
+
+    /// Returns the full provenance history for `component_id`, in the order
+    /// the transitions occurred, answering "why did this trust score
+    /// change?" instead of relying on the current `TrustScore` snapshot.
+    pub async fn provenance_for(&self, component_id: &str) -> Vec<ProvenanceRecord> {
+        self.provenance
+            .read()
+            .await
+            .iter()
+            .filter(|record| record.component_id == component_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Refreshes every Prometheus gauge from current state and renders the
+    /// registry in Prometheus text exposition format, for `GET /metrics`.
+    pub async fn render_metrics(&self) -> Result<String, String> {
+        {
+            let trust_scores = self.trust_scores.read().await;
+            for (component_id, score) in trust_scores.iter() {
+                self.prometheus.trust_score_gauge.with_label_values(&[component_id]).set(score.score);
+            }
+        }
+
+        let status = self.get_system_status().await;
+        self.prometheus.overall_trust.set(status.overall_trust);
+        self.prometheus.component_count.set(status.component_count as i64);
+        self.prometheus.active_incidents.set(status.active_incidents as i64);
+        self.prometheus.active_alerts.set(status.active_alerts as i64);
+        self.prometheus
+            .prediction_accuracy
+            .set(status.sculi_objectives.predictability.prediction_accuracy);
+        self.prometheus
+            .response_time_avg
+            .set(status.sculi_objectives.incident_response.response_time_avg);
+        self.prometheus
+            .human_escalation_rate
+            .set(status.sculi_objectives.incident_response.human_escalation_rate);
+
+        let encoder = prometheus::TextEncoder::new();
+        let metric_families = self.prometheus.registry.gather();
+        let mut buffer = Vec::new();
+        encoder.encode(&metric_families, &mut buffer).map_err(|e| e.to_string())?;
+        String::from_utf8(buffer).map_err(|e| e.to_string())
+    }
+
+    /// Builds the local `trust_scores` digest, split into chunks that each
+    /// fit one UDP datagram.
+    async fn gossip_digest_chunks(&self) -> Vec<GossipDigest> {
+        let trust_scores = self.trust_scores.read().await;
+        let entries: Vec<GossipEntry> = trust_scores
+            .iter()
+            .map(|(component_id, score)| GossipEntry {
+                component_id: component_id.clone(),
+                score: score.score,
+                last_updated: score.last_updated,
+                version: score.version,
+            })
+            .collect();
+
+        entries
+            .chunks(GOSSIP_MAX_ENTRIES_PER_DATAGRAM)
+            .map(|chunk| GossipDigest { node_id: self.system_id.clone(), entries: chunk.to_vec() })
+            .collect()
     }
 
-    /// Initialize the trust monitoring system with sample components
-    pub async fn initialize(&self) -> Result<(), String> {
-        println!("üöÄ Initializing SCULI Trust Monitoring System...");
-        
-        // Add sample components representing different system types
-        let sample_components = vec![
-            ("wasm-service-1", "WebAssembly Service 1", ComponentType::WebAssembly, Some("examples/wasm-service.wasm".to_string())),
-            ("wasm-service-2", "WebAssembly Service 2", ComponentType::WebAssembly, Some("examples/wasm-service.wasm".to_string())),
-            ("container-service", "Container Service", ComponentType::Container, None),
-            ("legacy-api", "Legacy API", ComponentType::LegacySystem, None),
-            ("microservice-1", "Microservice 1", ComponentType::Microservice, None),
-            ("microservice-2", "Microservice 2", ComponentType::Microservice, None),
-            ("database-primary", "Primary Database", ComponentType::Database, None),
-            ("cache-redis", "Redis Cache", ComponentType::Cache, None),
-            ("load-balancer", "Load Balancer", ComponentType::LoadBalancer, None),
-            ("message-queue", "Message Queue", ComponentType::MessageQueue, None),
-        ];
+    /// Merges a peer's digest into `trust_scores` using last-writer-wins by
+    /// `last_updated` (ties broken by `version`), so a merge can never
+    /// overwrite a newer local score with an older remote one. Ignores
+    /// digests carrying our own `system_id` to avoid feedback loops.
+    /// Returns the component ids that actually changed, so the caller only
+    /// re-gossips what's new.
+    pub async fn merge_gossip_digest(&self, digest: &GossipDigest) -> Vec<String> {
+        if digest.node_id == self.system_id {
+            return Vec::new();
+        }
 
-        let mut components = self.components.write().await;
+        let mut changed = Vec::new();
         let mut trust_scores = self.trust_scores.write().await;
-
-        for (id, name, component_type, wasm_module) in sample_components {
-            let component = Component {
-                id: id.to_string(),
-                name: name.to_string(),
-                component_type,
-                trust_score: 0.85, // Start with high trust
-                status: "healthy".to_string(),
-                wasm_module,
-                container_id: None,
-                legacy_endpoint: None,
-                last_updated: Utc::now(),
+        for entry in &digest.entries {
+            let should_apply = match trust_scores.get(&entry.component_id) {
+                Some(existing) => {
+                    (entry.last_updated, entry.version) > (existing.last_updated, existing.version)
+                }
+                None => true,
             };
-            
-            components.insert(id.to_string(), component);
-            
-            // Initialize trust score with Bayesian model
-            let trust_score = TrustScore {
-                score: 0.85,
-                confidence: 0.9,
+            if !should_apply {
+                continue;
+            }
+
+            let score = trust_scores.entry(entry.component_id.clone()).or_insert_with(|| TrustScore {
+                score: entry.score,
+                confidence: 0.5,
                 contributing_factors: vec![],
                 prediction: None,
-                last_updated: Utc::now(),
-            };
-            trust_scores.insert(id.to_string(), trust_score);
+                last_updated: entry.last_updated,
+                version: entry.version,
+            });
+            score.score = entry.score;
+            score.last_updated = entry.last_updated;
+            score.version = entry.version;
+            changed.push(entry.component_id.clone());
         }
+        changed
+    }
 
-        // Initialize Bayesian models for each component
-        let mut bayesian_models = self.bayesian_models.write().await;
-        for component_id in components.keys() {
-            let model = BayesianModel {
-                model_id: format!("bayesian-{}", component_id),
-                prior_probability: 0.85,
-                likelihood_functions: HashMap::from([
-                    ("security_events".to_string(), 0.1),
-                    ("performance_metrics".to_string(), 0.3),
-                    ("behavioral_anomalies".to_string(), 0.2),
-                    ("compliance_status".to_string(), 0.2),
-                    ("dependency_health".to_string(), 0.2),
-                ]),
-                posterior_probability: 0.85,
-                evidence_count: 0,
-                last_updated: Utc::now(),
+    /// Records that a gossip digest was just received from `peer`, so
+    /// `SystemStatus` can report how recently this node last heard from
+    /// the cluster.
+    async fn record_gossip_contact(&self, peer: &str) {
+        self.gossip_last_seen.write().await.insert(peer.to_string(), Utc::now());
+    }
+
+    /// Most recent gossip contact across all peers, for `SystemStatus`.
+    async fn gossip_last_sync(&self) -> Option<DateTime<Utc>> {
+        self.gossip_last_seen.read().await.values().max().copied()
+    }
+
+    /// Runs the action group bound to `alert_type`/`severity` against
+    /// `component_id`, trying each `ActionType` in order and stopping at the
+    /// first one that completes. Falls back to `EscalateToHuman` only if
+    /// every automated handler in the group fails. Every attempt is
+    /// recorded as an `ActionRecord` in `action_log`.
+    pub async fn execute_action_group(
+        &self,
+        component_id: &str,
+        alert_type: &AlertType,
+        severity: &AlertSeverity,
+        dry_run: bool,
+    ) -> Result<Vec<ActionRecord>, String> {
+        let mut records = Vec::new();
+        let mut automated_succeeded = false;
+
+        for action_type in Self::action_group_for(alert_type, severity) {
+            let handler = Self::handler_for(&action_type);
+            let exists = self.components.read().await.contains_key(component_id);
+
+            let outcome = if exists {
+                handler.execute(component_id, dry_run)
+            } else {
+                ActionOutcome {
+                    status: ActionStatus::Failed,
+                    result: format!("component {} not found", component_id),
+                    trust_impact: 0.0,
+                }
             };
-            bayesian_models.insert(component_id.clone(), model);
+
+            let completed = matches!(outcome.status, ActionStatus::Completed);
+            records.push(ActionRecord {
+                action_type,
+                executed_at: Utc::now(),
+                status: outcome.status,
+                result: outcome.result,
+                trust_impact: outcome.trust_impact,
+            });
+
+            if completed {
+                if !dry_run {
+                    self.apply_trust_impact(component_id, outcome.trust_impact).await;
+                }
+                automated_succeeded = true;
+                break;
+            }
         }
 
-        println!("‚úÖ System initialized with {} components", components.len());
-        Ok(())
+        if !automated_succeeded {
+            let outcome = EscalateToHumanHandler.execute(component_id, dry_run);
+            records.push(ActionRecord {
+                action_type: ActionType::EscalateToHuman,
+                executed_at: Utc::now(),
+                status: outcome.status,
+                result: outcome.result,
+                trust_impact: outcome.trust_impact,
+            });
+        }
+
+        self.action_log.write().await.extend(records.clone());
+        Ok(records)
     }
 
-    /// Update trust score using Bayesian inference
-    pub async fn update_trust_score(&self, component_id: &str, evidence: &TrustEvidence) -> Result<(), String> {
+    /// The ordered `ActionType`s to try for a given alert type/severity,
+    /// mirroring Azure alerts management's grouped actions.
+    fn action_group_for(alert_type: &AlertType, severity: &AlertSeverity) -> Vec<ActionType> {
+        match (alert_type, severity) {
+            (AlertType::TrustScoreLow, AlertSeverity::Critical) => {
+                vec![ActionType::IsolateComponent, ActionType::FailoverToBackup]
+            }
+            (AlertType::TrustScoreLow, _) => vec![ActionType::EnableMonitoring],
+            (AlertType::SecurityViolation, _) => {
+                vec![ActionType::QuarantineData, ActionType::UpdateFirewallRules]
+            }
+            (AlertType::PerformanceDegradation, _) => vec![ActionType::ScaleResources],
+            (AlertType::BehavioralAnomaly, _) => vec![ActionType::DisableAccess],
+            (AlertType::DependencyFailure, _) => vec![ActionType::FailoverToBackup],
+            (AlertType::CommunicationFailure, _) => vec![ActionType::RestartService],
+            (AlertType::AttestationFailure, _) => vec![ActionType::UpdateSecurityPolicy],
+            (AlertType::UnknownValue(_), _) => vec![ActionType::SendNotification],
+        }
+    }
+
+    /// Looks up the pluggable handler for an `ActionType`.
+    fn handler_for(action_type: &ActionType) -> Box<dyn ActionHandler> {
+        match action_type {
+            ActionType::EscalateToHuman => Box::new(EscalateToHumanHandler),
+            other => {
+                let trust_impact = match other {
+                    ActionType::IsolateComponent => -0.1,
+                    ActionType::ScaleResources => 0.05,
+                    ActionType::UpdateConfiguration => 0.05,
+                    ActionType::TriggerWorkflow => 0.0,
+                    ActionType::SendNotification => 0.0,
+                    ActionType::UpdateSecurityPolicy => 0.1,
+                    ActionType::FailoverToBackup => 0.15,
+                    ActionType::RestartService => 0.05,
+                    ActionType::UpdateFirewallRules => 0.1,
+                    ActionType::QuarantineData => 0.1,
+                    ActionType::EnableMonitoring => 0.02,
+                    ActionType::DisableAccess => -0.05,
+                    ActionType::EscalateToHuman => unreachable!(),
+                    ActionType::UnknownValue(_) => 0.0,
+                };
+                Box::new(SimulatedHandler { action_type: other.clone(), trust_impact })
+            }
+        }
+    }
+
+    /// Writes a realized action's trust impact back into the component's
+    /// trust score and Bayesian model, the same way `simulate_recovery` and
+    /// `simulate_trust_degradation` apply a direct adjustment.
+    async fn apply_trust_impact(&self, component_id: &str, trust_impact: f64) {
         let mut trust_scores = self.trust_scores.write().await;
         let mut bayesian_models = self.bayesian_models.write().await;
-        let mut alerts = self.alerts.write().await;
 
         if let Some(trust_score) = trust_scores.get_mut(component_id) {
+            let new_score = (trust_score.score + trust_impact).clamp(0.1, 1.0);
+            trust_score.score = new_score;
+            trust_score.last_updated = Utc::now();
+            trust_score.version += 1;
+
             if let Some(model) = bayesian_models.get_mut(component_id) {
-                // Simplified trust score calculation for demo
-                let current_score = trust_score.score;
-                
-                // Calculate degradation factor based on evidence
-                let mut degradation_factor = 0.0;
-                for (factor, value) in &evidence.factors {
-                    if let Some(weight) = model.likelihood_functions.get(factor) {
-                        degradation_factor += value * weight;
-                    }
-                }
-                
-                // Apply degradation: high evidence values reduce trust
-                let new_score = (current_score - degradation_factor * 0.3).clamp(0.1, 1.0);
-                
-                // Update trust score
-                trust_score.score = new_score;
-                trust_score.confidence = self.calculate_confidence(&model);
-                trust_score.last_updated = Utc::now();
-                
-                // Update Bayesian model
                 model.posterior_probability = new_score;
                 model.evidence_count += 1;
                 model.last_updated = Utc::now();
-                
-                // Generate prediction
-                trust_score.prediction = Some(self.generate_prediction(component_id, &model).await);
-                
-                // Check for alerts
-                if new_score < 0.4 {
-                    let alert = Alert {
-                        id: format!("alert-{}-{}", component_id, Utc::now().timestamp()),
-                        component_id: component_id.to_string(),
-                        alert_type: AlertType::TrustScoreLow,
-                        severity: AlertSeverity::Critical,
-                        message: format!("Critical trust score: {:.2}", new_score),
-                        trust_threshold: 0.4,
-                        timestamp: Utc::now(),
-                        status: AlertStatus::Active,
-                    };
-                    alerts.push(alert);
-                } else if new_score < 0.7 {
-                    let alert = Alert {
-                        id: format!("alert-{}-{}", component_id, Utc::now().timestamp()),
-                        component_id: component_id.to_string(),
-                        alert_type: AlertType::TrustScoreLow,
-                        severity: AlertSeverity::High,
-                        message: format!("Warning trust score: {:.2}", new_score),
-                        trust_threshold: 0.7,
-                        timestamp: Utc::now(),
-                        status: AlertStatus::Active,
-                    };
-                    alerts.push(alert);
+            }
+        }
+    }
+
+    /// Ingests a CVE/advisory, superseding any earlier advisory that covers
+    /// the same package and version range, then recomputes the security
+    /// posture (and trust score) of every component it affects.
+    pub async fn ingest_advisory(&self, advisory: Advisory) -> Result<(), String> {
+        let affected_package = advisory.affected_package.clone();
+        {
+            let mut advisories = self.advisories.write().await;
+            for existing in advisories.values_mut() {
+                if existing.affected_package == advisory.affected_package
+                    && existing.version_req.to_string() == advisory.version_req.to_string()
+                    && existing.published_at < advisory.published_at
+                {
+                    existing.superseded_by = Some(advisory.id.clone());
+                }
+            }
+            advisories.insert(advisory.id.clone(), advisory);
+        }
+
+        if self.components.read().await.contains_key(&affected_package) {
+            self.recompute_vulnerability(&affected_package).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Recomputes `component_id`'s `SecurityPosture` from every active
+    /// (non-superseded) advisory whose `version_req` matches its deployed
+    /// version, then feeds the result into the Bayesian model as a
+    /// `FactorType::SecurityEvent` so a landed CVE degrades trust and a
+    /// patch past the fixed version recovers it.
+    async fn recompute_vulnerability(&self, component_id: &str) -> Result<(), String> {
+        let component = match self.components.read().await.get(component_id).cloned() {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        let matching: Vec<Advisory> = self
+            .advisories
+            .read()
+            .await
+            .values()
+            .filter(|a| a.superseded_by.is_none())
+            .filter(|a| a.affected_package == component_id)
+            .filter(|a| a.version_req.matches(&component.version))
+            .cloned()
+            .collect();
+
+        let vulnerability_score = matching.iter().map(|a| a.severity.score()).fold(0.0_f64, f64::max);
+        let patched = matching.iter().all(|a| {
+            a.fixed_version.as_ref().is_some_and(|fixed| component.version >= *fixed)
+        });
+
+        {
+            let mut postures = self.security_postures.write().await;
+            let posture = postures.entry(component_id.to_string()).or_insert_with(|| SecurityPosture {
+                vulnerability_score: 0.0,
+                patch_status: 1.0,
+                compliance_score: 1.0,
+                encryption_status: 1.0,
+                access_control_score: 1.0,
+                attestation_score: 1.0,
+            });
+            posture.vulnerability_score = vulnerability_score;
+            posture.patch_status = if matching.is_empty() || patched { 1.0 } else { 0.0 };
+        }
+
+        let now = Utc::now();
+        let mut bayesian_models = self.bayesian_models.write().await;
+        let mut trust_scores = self.trust_scores.write().await;
+        if let Some(model) = bayesian_models.get_mut(component_id) {
+            if let Some(&weight) = model.likelihood_functions.get("security_events") {
+                model.record_penalty("security_events".to_string(), vulnerability_score, weight, now);
+            }
+            let new_score = model.recompute(now);
+
+            if let Some(trust_score) = trust_scores.get_mut(component_id) {
+                trust_score.score = new_score;
+                trust_score.confidence = self.calculate_confidence(model, now);
+                trust_score.last_updated = now;
+                trust_score.version += 1;
+                trust_score.contributing_factors.push(ContributingFactor {
+                    factor_type: FactorType::SecurityEvent,
+                    weight: model.likelihood_functions.get("security_events").copied().unwrap_or(0.0),
+                    value: vulnerability_score,
+                    description: format!("{} active advisories matched", matching.len()),
+                });
+
+                if vulnerability_score > 0.0 {
+                    let prediction = trust_score.prediction.get_or_insert_with(|| TrustPrediction {
+                        predicted_score: new_score,
+                        confidence_interval: (new_score - 0.1, new_score + 0.1),
+                        risk_factors: vec![],
+                        prediction_horizon: 60,
+                        timestamp: now,
+                    });
+                    prediction.risk_factors.push(RiskFactor {
+                        factor_type: RiskFactorType::Vulnerability,
+                        severity: vulnerability_score,
+                        description: format!(
+                            "{} affected by {} matching advisories",
+                            component_id,
+                            matching.len()
+                        ),
+                        mitigation_suggestions: vec!["Upgrade to a patched version".to_string()],
+                    });
                 }
             }
         }
@@ -456,9 +2107,10 @@ impl TrustMonitor {
         1.0 - avg_factor_value
     }
 
-    fn calculate_confidence(&self, model: &BayesianModel) -> f64 {
-        // Confidence based on evidence count and model stability
-        let evidence_factor = (model.evidence_count as f64 / 100.0).min(1.0);
+    fn calculate_confidence(&self, model: &BayesianModel, now: DateTime<Utc>) -> f64 {
+        // Confidence based on still-relevant evidence and model stability;
+        // fully-decayed penalties no longer count toward evidence_factor.
+        let evidence_factor = (model.effective_evidence_weight(now) / 20.0).min(1.0);
         let stability_factor = 1.0 - (model.posterior_probability - model.prior_probability).abs();
         evidence_factor * stability_factor
     }
@@ -530,6 +2182,8 @@ impl TrustMonitor {
                 continual_assurance: self.assess_continual_assurance().await,
                 incident_response: self.assess_incident_response().await,
             },
+            known_peers: self.gossip_peers.len(),
+            last_gossip_sync: self.gossip_last_sync().await,
             last_updated: Utc::now(),
         }
     }
@@ -604,50 +2258,81 @@ impl TrustMonitor {
 
     async fn assess_incident_response(&self) -> IncidentResponseAssessment {
         let incidents = self.incidents.read().await;
-        let alerts = self.alerts.read().await;
-        
+        let action_log = self.action_log.read().await;
+
         let active_incidents = incidents.len();
-        let automated_responses = alerts.iter().filter(|a| matches!(a.status, AlertStatus::Active)).count();
-        
+        let automated_responses = action_log
+            .iter()
+            .filter(|a| !matches!(a.action_type, ActionType::EscalateToHuman) && matches!(a.status, ActionStatus::Completed))
+            .count();
+        let escalations = action_log
+            .iter()
+            .filter(|a| matches!(a.action_type, ActionType::EscalateToHuman))
+            .count();
+        let total_responses = automated_responses + escalations;
+        let human_escalation_rate = if total_responses > 0 {
+            escalations as f64 / total_responses as f64
+        } else {
+            0.0
+        };
+
         IncidentResponseAssessment {
             active_incidents,
             automated_responses,
             response_time_avg: 2.5, // Simplified
-            human_escalation_rate: 0.1, // 10% require human intervention
+            human_escalation_rate,
         }
     }
 
     /// Simulate trust degradation for demo
+    #[tracing::instrument(skip(self))]
     pub async fn simulate_trust_degradation(&self) -> Result<(), String> {
-        println!("‚ö†Ô∏è  Simulating trust degradation...");
+        tracing::info!("‚ö†Ô∏è  Simulating trust degradation...");
         
+        let mut persisted_scores: Vec<(String, TrustScore)> = Vec::new();
+        let mut persisted_alerts: Vec<Alert> = Vec::new();
+
+        {
         // Simulate degradation by directly reducing trust scores for specific components
+        let components = self.components.read().await;
         let mut trust_scores = self.trust_scores.write().await;
         let mut bayesian_models = self.bayesian_models.write().await;
         let mut alerts = self.alerts.write().await;
-        
+
         // Degrade specific components
         let degraded_components = vec![
             "wasm-service-1",
-            "container-service", 
+            "container-service",
             "legacy-api"
         ];
-        
+
         for component_id in degraded_components {
+            let component_type = components.get(component_id).map(|c| c.component_type.clone());
+            let _span = self
+                .telemetry
+                .as_ref()
+                .zip(component_type.as_ref())
+                .map(|(t, ct)| t.start_span("simulate_trust_degradation", component_id, ct, &[]));
+
             if let Some(trust_score) = trust_scores.get_mut(component_id) {
                 // Reduce trust score significantly
                 let new_score = (trust_score.score - 0.3).clamp(0.1, 1.0);
                 trust_score.score = new_score;
                 trust_score.confidence = 0.7;
                 trust_score.last_updated = Utc::now();
-                
+                trust_score.version += 1;
+
                 // Update Bayesian model
                 if let Some(model) = bayesian_models.get_mut(component_id) {
                     model.posterior_probability = new_score;
                     model.evidence_count += 1;
                     model.last_updated = Utc::now();
                 }
-                
+
+                if let (Some(telemetry), Some(ct)) = (&self.telemetry, component_type.as_ref()) {
+                    telemetry.record_trust_score(component_id, ct, &[], new_score);
+                }
+
                 // Generate alerts for degraded components
                 if new_score < 0.7 {
                     let alert = Alert {
@@ -660,38 +2345,85 @@ impl TrustMonitor {
                         timestamp: Utc::now(),
                         status: AlertStatus::Active,
                     };
+                    persisted_alerts.push(alert.clone());
                     alerts.push(alert);
+                    if let (Some(telemetry), Some(ct)) = (&self.telemetry, component_type.as_ref()) {
+                        telemetry.record_alert(component_id, ct);
+                    }
                 }
+
+                persisted_scores.push((component_id.to_string(), trust_score.clone()));
             }
         }
-        
-        println!("‚úÖ Trust degradation simulation completed");
+        }
+
+        for (component_id, score) in &persisted_scores {
+            self.storage.save_trust_score(component_id, score).await?;
+            let _ = self.update_tx.send(TrustUpdateEvent::ScoreUpdated {
+                component_id: component_id.clone(),
+                score: score.clone(),
+            });
+        }
+        for alert in &persisted_alerts {
+            self.storage.save_alert(alert).await?;
+            let _ = self.update_tx.send(TrustUpdateEvent::AlertRaised(alert.clone()));
+        }
+
+        tracing::info!("‚úÖ Trust degradation simulation completed");
         Ok(())
     }
 
     /// Simulate recovery process
+    #[tracing::instrument(skip(self))]
     pub async fn simulate_recovery(&self) -> Result<(), String> {
-        println!("üîß Simulating recovery process...");
+        tracing::info!("üîß Simulating recovery process...");
         
+        let mut persisted_scores: Vec<(String, TrustScore)> = Vec::new();
+
+        {
         // Simulate recovery by directly setting trust scores back to normal
+        let components = self.components.read().await;
         let mut trust_scores = self.trust_scores.write().await;
         let mut bayesian_models = self.bayesian_models.write().await;
-        
+
         for (component_id, trust_score) in trust_scores.iter_mut() {
+            let component_type = components.get(component_id).map(|c| c.component_type.clone());
+            let _span = self
+                .telemetry
+                .as_ref()
+                .zip(component_type.as_ref())
+                .map(|(t, ct)| t.start_span("simulate_recovery", component_id, ct, &[]));
+
             // Set trust score back to high value (0.85)
             trust_score.score = 0.85;
             trust_score.confidence = 0.9;
             trust_score.last_updated = Utc::now();
-            
+            trust_score.version += 1;
+
             // Update Bayesian model
             if let Some(model) = bayesian_models.get_mut(component_id) {
                 model.posterior_probability = 0.85;
                 model.evidence_count += 1;
                 model.last_updated = Utc::now();
             }
+
+            if let (Some(telemetry), Some(ct)) = (&self.telemetry, component_type.as_ref()) {
+                telemetry.record_trust_score(component_id, ct, &[], 0.85);
+            }
+
+            persisted_scores.push((component_id.clone(), trust_score.clone()));
         }
-        
-        println!("‚úÖ Recovery simulation completed");
+        }
+
+        for (component_id, score) in &persisted_scores {
+            self.storage.save_trust_score(component_id, score).await?;
+            let _ = self.update_tx.send(TrustUpdateEvent::ScoreUpdated {
+                component_id: component_id.clone(),
+                score: score.clone(),
+            });
+        }
+
+        tracing::info!("‚úÖ Recovery simulation completed");
         Ok(())
     }
 }
@@ -710,6 +2442,11 @@ pub struct SystemStatus {
     pub active_alerts: usize,
     pub system_health: String,
     pub sculi_objectives: SCULIObjectives,
+    /// Number of peers this node gossips `trust_scores` digests with.
+    pub known_peers: usize,
+    /// Most recent time a gossip digest was received from any peer, or
+    /// `None` if this node hasn't heard from the cluster yet.
+    pub last_gossip_sync: Option<DateTime<Utc>>,
     pub last_updated: DateTime<Utc>,
 }
 
@@ -751,11 +2488,13 @@ pub struct IncidentResponseAssessment {
 }
 
 /// HTTP API handlers
+#[tracing::instrument(skip(monitor))]
 async fn get_system_status(monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply, Infallible> {
     let status = monitor.get_system_status().await;
     Ok(warp::reply::json(&status))
 }
 
+#[tracing::instrument(skip(monitor))]
 async fn get_trust_scores(monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply, Infallible> {
     let trust_scores = monitor.trust_scores.read().await;
     let scores: HashMap<String, f64> = trust_scores.iter()
@@ -764,37 +2503,155 @@ async fn get_trust_scores(monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply
     Ok(warp::reply::json(&scores))
 }
 
+/// Pushes every `TrustUpdateEvent` fired after the client connects as a JSON
+/// SSE event, so dashboards see degradation/recovery live instead of
+/// re-polling `GET /trust-scores`.
+#[tracing::instrument(skip(monitor))]
+async fn stream_trust_updates(monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply, Infallible> {
+    let receiver = monitor.subscribe_to_updates();
+    let events = BroadcastStream::new(receiver).filter_map(|event| async move {
+        let event = event.ok()?;
+        Some(warp::sse::Event::default().json_data(event))
+    });
+    Ok(warp::sse::reply(warp::sse::keep_alive().stream(events)))
+}
+
+#[tracing::instrument(skip(monitor))]
 async fn get_alerts(monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply, Infallible> {
     let alerts = monitor.alerts.read().await;
     Ok(warp::reply::json(&*alerts))
 }
 
+#[tracing::instrument(skip(monitor))]
+async fn get_provenance(component_id: String, monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply, Infallible> {
+    let history = monitor.provenance_for(&component_id).await;
+    Ok(warp::reply::json(&history))
+}
+
+#[tracing::instrument(skip(monitor))]
+async fn get_metrics(monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply, Infallible> {
+    let body = monitor.render_metrics().await.unwrap_or_else(|e| format!("# error rendering metrics: {}\n", e));
+    Ok(warp::reply::with_header(body, "content-type", "text/plain; version=0.0.4"))
+}
+
+#[tracing::instrument(skip(monitor))]
 async fn simulate_degradation(monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply, Infallible> {
     match monitor.simulate_trust_degradation().await {
         Ok(_) => Ok(warp::reply::json(&serde_json::json!({"status": "success", "message": "Trust degradation simulated"}))),
-        Err(e) => Ok(warp::reply::json(&serde_json::json!({"status": "error", "message": e}))),
+        Err(e) => {
+            tracing::error!(error = %e, "simulate_trust_degradation failed");
+            Ok(warp::reply::json(&serde_json::json!({"status": "error", "message": e})))
+        }
     }
 }
 
+#[tracing::instrument(skip(monitor))]
 async fn simulate_recovery(monitor: Arc<TrustMonitor>) -> Result<impl warp::Reply, Infallible> {
     match monitor.simulate_recovery().await {
         Ok(_) => Ok(warp::reply::json(&serde_json::json!({"status": "success", "message": "Recovery simulated"}))),
-        Err(e) => Ok(warp::reply::json(&serde_json::json!({"status": "error", "message": e}))),
+        Err(e) => {
+            tracing::error!(error = %e, "simulate_recovery failed");
+            Ok(warp::reply::json(&serde_json::json!({"status": "error", "message": e})))
+        }
     }
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("üõ°Ô∏è  SCULI Trust Monitoring System");
-    println!("üéØ Ultra-Large Scale Distributed System Trust Assessment");
-    println!();
-    
-    // Create the trust monitor
-    let monitor = Arc::new(TrustMonitor::new());
+    tracing_subscriber::fmt::init();
+
+    // Error aggregation for production deployments, enabled with `--features
+    // sentry` and an `SCULI_SENTRY_DSN`. Held for the process lifetime so
+    // queued events still flush on shutdown.
+    #[cfg(feature = "sentry")]
+    let _sentry_guard = std::env::var("SCULI_SENTRY_DSN").ok().map(|dsn| {
+        sentry::init((
+            dsn,
+            sentry::ClientOptions {
+                release: sentry::release_name!(),
+                ..Default::default()
+            },
+        ))
+    });
+
+    tracing::info!("üõ°Ô∏è  SCULI Trust Monitoring System");
+    tracing::info!("üéØ Ultra-Large Scale Distributed System Trust Assessment");
     
+    // Create the trust monitor, backed by a persistent store so components,
+    // trust scores, and Bayesian models survive a restart.
+    let data_dir = std::env::var("SCULI_DATA_DIR").unwrap_or_else(|_| "./data/sculi-trust".to_string());
+    let mut monitor = TrustMonitor::with_store(&data_dir)?;
+
+    // Export trust scores, alerts, and incidents via OpenTelemetry when an
+    // OTLP endpoint is configured.
+    if let Ok(otlp_endpoint) = std::env::var("SCULI_OTLP_ENDPOINT") {
+        let telemetry = Telemetry::init(&otlp_endpoint)?;
+        monitor = monitor.with_telemetry(Arc::new(telemetry));
+    }
+
+    // Run the action engine in dry-run mode when requested, so triggered
+    // action groups are logged but never applied.
+    let action_dry_run = std::env::var("SCULI_ACTION_DRY_RUN")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+    monitor = monitor.with_action_dry_run(action_dry_run);
+
+    // A stable per-node id so gossip digests can recognize and discard
+    // their own broadcasts.
+    if let Ok(node_id) = std::env::var("SCULI_NODE_ID") {
+        monitor = monitor.with_system_id(node_id);
+    }
+
+    // Other monitor instances to gossip trust_scores digests with.
+    let gossip_peers: Vec<String> = std::env::var("SCULI_GOSSIP_PEERS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect();
+    monitor = monitor.with_gossip_peers(gossip_peers);
+
+    // Durable audit history for trust scores, alerts, and incidents. Falls
+    // back to the in-memory default (fine for local/dev use) unless a
+    // Postgres connection string is configured.
+    if let Ok(database_url) = std::env::var("SCULI_DATABASE_URL") {
+        let postgres = PostgresBackend::connect(&database_url).await?;
+        monitor = monitor.with_storage_backend(Arc::new(postgres));
+    }
+
+    let monitor = Arc::new(monitor);
+
     // Initialize the system
     monitor.initialize().await?;
-    
+
+    // Join the gossip cluster: periodically push our trust_scores digest to
+    // configured peers and merge what we receive, so monitor instances
+    // converge on a shared view of trust.
+    let gossip_bind_addr = std::env::var("SCULI_GOSSIP_BIND").unwrap_or_else(|_| "0.0.0.0:7947".to_string());
+    match tokio::net::UdpSocket::bind(&gossip_bind_addr).await {
+        Ok(socket) => {
+            let socket = Arc::new(socket);
+            tokio::spawn(gossip_send_loop(monitor.clone(), socket.clone()));
+            tokio::spawn(gossip_recv_loop(monitor.clone(), socket));
+        }
+        Err(e) => {
+            tracing::warn!("‚ö†Ô∏è  gossip disabled, failed to bind {}: {}", gossip_bind_addr, e);
+        }
+    }
+
+    // Secret protecting the mutation endpoints, checked by `auth_filter`.
+    let api_secret = Arc::new(match std::env::var("SCULI_API_TOKEN") {
+        Ok(token) => token,
+        Err(_) => {
+            tracing::warn!(
+                "⚠️  SCULI_API_TOKEN is not set; falling back to the well-known dev token \"sculi-dev-token\", \
+                 which is public in this repo's history. Set SCULI_API_TOKEN before exposing this server."
+            );
+            "sculi-dev-token".to_string()
+        }
+    });
+
     // Set up HTTP API
     let status_route = warp::path("status")
         .and(warp::get())
@@ -802,6 +2659,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .and_then(get_system_status);
     
     let trust_scores_route = warp::path("trust-scores")
+        .and(warp::path::end())
         .and(warp::get())
         .and(with_monitor(monitor.clone()))
         .and_then(get_trust_scores);
@@ -811,35 +2669,69 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .and(with_monitor(monitor.clone()))
         .and_then(get_alerts);
     
+    let metrics_route = warp::path("metrics")
+        .and(warp::get())
+        .and(with_monitor(monitor.clone()))
+        .and_then(get_metrics);
+
+    let provenance_route = warp::path("provenance")
+        .and(warp::path::param())
+        .and(warp::get())
+        .and(with_monitor(monitor.clone()))
+        .and_then(get_provenance);
+
+    let trust_scores_stream_route = warp::path("trust-scores")
+        .and(warp::path("stream"))
+        .and(warp::get())
+        .and(with_monitor(monitor.clone()))
+        .and_then(stream_trust_updates);
+
     let simulate_degradation_route = warp::path("simulate-degradation")
         .and(warp::post())
+        .and(auth_filter(api_secret.clone()))
         .and(with_monitor(monitor.clone()))
         .and_then(simulate_degradation);
-    
+
     let simulate_recovery_route = warp::path("simulate-recovery")
         .and(warp::post())
+        .and(auth_filter(api_secret.clone()))
         .and(with_monitor(monitor.clone()))
         .and_then(simulate_recovery);
-    
+
     let api = status_route
         .or(trust_scores_route)
+        .or(trust_scores_stream_route)
         .or(alerts_route)
+        .or(metrics_route)
+        .or(provenance_route)
         .or(simulate_degradation_route)
         .or(simulate_recovery_route)
+        .recover(handle_rejection)
         .with(warp::cors()
             .allow_any_origin()
             .allow_headers(vec!["content-type", "authorization"])
             .allow_methods(vec!["GET", "POST", "PUT", "DELETE", "OPTIONS"])
-            .max_age(3600));
+            .max_age(3600))
+        .with(warp::log::custom(|info| {
+            tracing::info!(
+                method = %info.method(),
+                path = %info.path(),
+                status = %info.status().as_u16(),
+                latency_ms = %info.elapsed().as_millis(),
+                "request"
+            );
+        }));
     
-    println!("üåê Starting HTTP API server on http://localhost:3030");
-    println!("üìä Available endpoints:");
-    println!("   GET /status - System status with SCULI objectives");
-    println!("   GET /trust-scores - Current trust scores");
-    println!("   GET /alerts - Active alerts");
-    println!("   POST /simulate-degradation - Simulate trust degradation");
-    println!("   POST /simulate-recovery - Simulate recovery");
-    println!();
+    tracing::info!("üåê Starting HTTP API server on http://localhost:3030");
+    tracing::info!("üìä Available endpoints:");
+    tracing::info!("   GET /status - System status with SCULI objectives");
+    tracing::info!("   GET /trust-scores - Current trust scores");
+    tracing::info!("   GET /trust-scores/stream - Live score/alert updates via SSE");
+    tracing::info!("   GET /alerts - Active alerts");
+    tracing::info!("   GET /metrics - Prometheus text exposition format");
+    tracing::info!("   GET /provenance/:component_id - Trust-score transition history");
+    tracing::info!("   POST /simulate-degradation - Simulate trust degradation (requires auth)");
+    tracing::info!("   POST /simulate-recovery - Simulate recovery (requires auth)");
     
     // Start the HTTP server
     warp::serve(api)
@@ -853,3 +2745,184 @@ fn with_monitor(monitor: Arc<TrustMonitor>) -> impl Filter<Extract = (Arc<TrustM
     warp::any().map(move || monitor.clone())
 }
 
+/// Every round, sends this node's `trust_scores` digest to a random subset
+/// of configured peers, chunked to fit one UDP datagram each.
+async fn gossip_send_loop(monitor: Arc<TrustMonitor>, socket: Arc<tokio::net::UdpSocket>) {
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(5));
+    loop {
+        ticker.tick().await;
+
+        if monitor.gossip_peers.is_empty() {
+            continue;
+        }
+        let sample_size = 3.min(monitor.gossip_peers.len());
+        let sample: Vec<&String> = monitor.gossip_peers.choose_multiple(&mut rand::thread_rng(), sample_size).collect();
+
+        for chunk in monitor.gossip_digest_chunks().await {
+            let Ok(bytes) = serde_json::to_vec(&chunk) else { continue };
+            for peer in &sample {
+                let _ = socket.send_to(&bytes, peer.as_str()).await;
+            }
+        }
+    }
+}
+
+/// Receives peer digests, merges them via `TrustMonitor::merge_gossip_digest`,
+/// and re-gossips only entries that actually changed to one fresh random
+/// peer, so an update propagates across the cluster without every node
+/// resending its whole map every round.
+async fn gossip_recv_loop(monitor: Arc<TrustMonitor>, socket: Arc<tokio::net::UdpSocket>) {
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let (len, src) = match socket.recv_from(&mut buf).await {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let Ok(digest) = serde_json::from_slice::<GossipDigest>(&buf[..len]) else {
+            continue;
+        };
+
+        monitor.record_gossip_contact(&src.to_string()).await;
+        let changed = monitor.merge_gossip_digest(&digest).await;
+        if changed.is_empty() {
+            continue;
+        }
+
+        let Some(peer) = monitor.gossip_peers.choose(&mut rand::thread_rng()) else {
+            continue;
+        };
+        let trust_scores = monitor.trust_scores.read().await;
+        let entries: Vec<GossipEntry> = changed
+            .iter()
+            .filter_map(|component_id| {
+                trust_scores.get(component_id).map(|score| GossipEntry {
+                    component_id: component_id.clone(),
+                    score: score.score,
+                    last_updated: score.last_updated,
+                    version: score.version,
+                })
+            })
+            .collect();
+        drop(trust_scores);
+
+        let redigest = GossipDigest { node_id: monitor.system_id.clone(), entries };
+        if let Ok(bytes) = serde_json::to_vec(&redigest) {
+            let _ = socket.send_to(&bytes, peer.as_str()).await;
+        }
+    }
+}
+
+/// Rejection produced when a request's `Authorization` header is missing or
+/// doesn't match the configured secret.
+#[derive(Debug)]
+struct Unauthorized;
+impl warp::reject::Reject for Unauthorized {}
+
+/// Compares two strings in constant time (with respect to their contents --
+/// a length mismatch still short-circuits), so checking a caller-supplied
+/// token/password against `secret` doesn't leak how many leading bytes
+/// matched through response timing.
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Builds a filter that rejects requests unless `Authorization` carries
+/// either `Bearer <secret>` or HTTP Basic credentials whose password is
+/// `secret`, so the same configured value works with either scheme.
+/// Composes in front of a handler, e.g. `.and(auth_filter(secret)).and_then(handler)`.
+fn auth_filter(secret: Arc<String>) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>("authorization")
+        .and_then(move |header: Option<String>| {
+            let secret = secret.clone();
+            async move {
+                let authorized = header.is_some_and(|value| {
+                    if let Some(token) = value.strip_prefix("Bearer ") {
+                        constant_time_eq(token, secret.as_str())
+                    } else if let Some(encoded) = value.strip_prefix("Basic ") {
+                        use base64::Engine;
+                        base64::engine::general_purpose::STANDARD
+                            .decode(encoded)
+                            .ok()
+                            .and_then(|bytes| String::from_utf8(bytes).ok())
+                            .is_some_and(|decoded| decoded.rsplit_once(':').is_some_and(|(_, password)| constant_time_eq(password, secret.as_str())))
+                    } else {
+                        false
+                    }
+                });
+
+                if authorized {
+                    Ok(())
+                } else {
+                    Err(warp::reject::custom(Unauthorized))
+                }
+            }
+        })
+        .untuple_one()
+}
+
+/// Turns an `Unauthorized` rejection into a 401 response instead of warp's
+/// default 500, leaving every other rejection (404s, bad query params) to
+/// warp's built-in handling.
+async fn handle_rejection(err: warp::Rejection) -> Result<impl warp::Reply, warp::Rejection> {
+    if err.find::<Unauthorized>().is_some() {
+        Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({"status": "error", "message": "unauthorized"})),
+            warp::http::StatusCode::UNAUTHORIZED,
+        ))
+    } else {
+        Err(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn register_prior_creates_a_model_for_a_new_component() {
+        let monitor = TrustMonitor::new();
+
+        monitor
+            .apply(Changeset::new().register_prior("component-a", 0.7))
+            .await
+            .expect("apply should succeed");
+
+        let bayesian_models = monitor.bayesian_models.read().await;
+        let model = bayesian_models.get("component-a").expect("register_prior should create a model");
+        assert_eq!(model.prior_probability, 0.7);
+        assert_eq!(model.posterior_probability, 0.7);
+        assert_eq!(model.evidence_count, 0);
+    }
+
+    #[tokio::test]
+    async fn register_prior_updates_an_existing_model() {
+        let monitor = TrustMonitor::new();
+
+        monitor
+            .apply(Changeset::new().register_prior("component-a", 0.7))
+            .await
+            .expect("apply should succeed");
+        monitor
+            .apply(Changeset::new().register_prior("component-a", 0.4))
+            .await
+            .expect("apply should succeed");
+
+        let bayesian_models = monitor.bayesian_models.read().await;
+        let model = bayesian_models.get("component-a").expect("model should still exist");
+        assert_eq!(model.prior_probability, 0.4);
+    }
+
+    #[test]
+    fn constant_time_eq_matches_str_equality() {
+        assert!(constant_time_eq("sculi-dev-token", "sculi-dev-token"));
+        assert!(!constant_time_eq("sculi-dev-token", "sculi-dev-toke0"));
+        assert!(!constant_time_eq("short", "much-longer-secret"));
+        assert!(constant_time_eq("", ""));
+    }
+}
+
@@ -1,7 +1,14 @@
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::Arc;
+use tokio::net::UdpSocket;
+use tokio::sync::RwLock;
 use tokio::time::{sleep, Duration};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use rand::seq::SliceRandom;
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair, Signature, ED25519};
+use serde::{Deserialize, Serialize};
 
 // This is a demo scenario showing how the trust monitoring system works
 // in a real distributed system environment
@@ -146,61 +153,575 @@ async fn simulate_recovery_process(components: &[&str]) {
     sleep(Duration::from_secs(2)).await;
 }
 
+/// Outbound notification payload posted to a configured webhook when a
+/// component's trust crosses a threshold, or recovers above it.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrustNotification {
+    pub component_id: String,
+    pub old_score: f64,
+    pub new_score: f64,
+    pub system_health: String,
+    pub kind: NotificationKind,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum NotificationKind {
+    Unhealthy,
+    Recovered,
+}
+
+/// Tracks consecutive degraded samples per component so a single noisy
+/// reading doesn't page anyone, and fires webhooks for sustained
+/// degradation and for the eventual recovery.
+pub struct Notifier {
+    destinations: Vec<String>,
+    unhealthy_threshold: u32,
+    healthy_cutoff: f64,
+    degraded_streak: HashMap<String, u32>,
+    fired: HashMap<String, bool>,
+    client: reqwest::Client,
+}
+
+impl Notifier {
+    /// Reads `SLACK_WEBHOOK`, `DISCORD_WEBHOOK`, and
+    /// `TELEGRAM_BOT_TOKEN`+`TELEGRAM_CHAT_ID` from the environment. Any
+    /// destination that isn't configured is simply left out of the list.
+    pub fn from_env(unhealthy_threshold: u32, healthy_cutoff: f64) -> Self {
+        let mut destinations = Vec::new();
+        if let Ok(url) = std::env::var("SLACK_WEBHOOK") {
+            destinations.push(url);
+        }
+        if let Ok(url) = std::env::var("DISCORD_WEBHOOK") {
+            destinations.push(url);
+        }
+        if let (Ok(token), Ok(chat_id)) = (
+            std::env::var("TELEGRAM_BOT_TOKEN"),
+            std::env::var("TELEGRAM_CHAT_ID"),
+        ) {
+            destinations.push(format!(
+                "https://api.telegram.org/bot{}/sendMessage?chat_id={}",
+                token, chat_id
+            ));
+        }
+
+        Self {
+            destinations,
+            unhealthy_threshold,
+            healthy_cutoff,
+            degraded_streak: HashMap::new(),
+            fired: HashMap::new(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Records a new sample for `component_id` and fires a notification if
+    /// it just crossed the unhealthy streak threshold, or just recovered
+    /// above `healthy_cutoff` after having fired.
+    async fn observe(
+        &mut self,
+        component_id: &str,
+        old_score: f64,
+        new_score: f64,
+        system_health: &str,
+    ) {
+        if new_score < self.healthy_cutoff {
+            let streak = self.degraded_streak.entry(component_id.to_string()).or_insert(0);
+            *streak += 1;
+
+            if *streak == self.unhealthy_threshold && !self.fired.get(component_id).copied().unwrap_or(false) {
+                self.fired.insert(component_id.to_string(), true);
+                self.dispatch(TrustNotification {
+                    component_id: component_id.to_string(),
+                    old_score,
+                    new_score,
+                    system_health: system_health.to_string(),
+                    kind: NotificationKind::Unhealthy,
+                    timestamp: Utc::now(),
+                })
+                .await;
+            }
+        } else {
+            self.degraded_streak.remove(component_id);
+
+            if self.fired.get(component_id).copied().unwrap_or(false) {
+                self.fired.insert(component_id.to_string(), false);
+                self.dispatch(TrustNotification {
+                    component_id: component_id.to_string(),
+                    old_score,
+                    new_score,
+                    system_health: system_health.to_string(),
+                    kind: NotificationKind::Recovered,
+                    timestamp: Utc::now(),
+                })
+                .await;
+            }
+        }
+    }
+
+    async fn dispatch(&self, notification: TrustNotification) {
+        for destination in &self.destinations {
+            if let Err(e) = self.client.post(destination).json(&notification).send().await {
+                println!("   ⚠️  notifier: failed to reach {}: {}", destination, e);
+            }
+        }
+    }
+}
+
+/// A single gossiped trust observation: the component's id, its reported
+/// score, and when that score was produced.
+type TrustObservation = (String, f64, DateTime<Utc>);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GossipMessage {
+    observations: Vec<TrustObservation>,
+}
+
+/// Peer-to-peer gossip layer so multiple monitoring nodes can exchange
+/// trust observations without a central aggregator. Each round, a random
+/// subset of `peers` is sent this node's observations over UDP; incoming
+/// messages are merged using last-writer-wins on `last_updated`, which also
+/// makes re-delivery of the same message (the same gossip round reaching us
+/// via multiple peers) a no-op without needing a separate message-id cache.
+pub struct GossipNode {
+    socket: Arc<UdpSocket>,
+    peers: Vec<SocketAddr>,
+    remote_scores: Arc<RwLock<HashMap<String, (f64, DateTime<Utc>)>>>,
+}
+
+impl GossipNode {
+    pub async fn bind(bind_addr: &str, peers: Vec<SocketAddr>) -> std::io::Result<Arc<Self>> {
+        let socket = Arc::new(UdpSocket::bind(bind_addr).await?);
+        let node = Arc::new(Self {
+            socket,
+            peers,
+            remote_scores: Arc::new(RwLock::new(HashMap::new())),
+        });
+        tokio::spawn(node.clone().recv_loop());
+        Ok(node)
+    }
+
+    /// Picks up to `fanout` random peers and sends them our current view of
+    /// `local` trust scores.
+    pub async fn gossip_round(&self, local: &HashMap<String, f64>, fanout: usize) {
+        if self.peers.is_empty() || local.is_empty() {
+            return;
+        }
+
+        let mut rng = rand::thread_rng();
+        let chosen: Vec<&SocketAddr> = self
+            .peers
+            .choose_multiple(&mut rng, fanout.min(self.peers.len()))
+            .collect();
+
+        let now = Utc::now();
+        let observations: Vec<TrustObservation> = local
+            .iter()
+            .map(|(id, score)| (id.clone(), *score, now))
+            .collect();
+        let message = GossipMessage { observations };
+
+        if let Ok(payload) = serde_json::to_vec(&message) {
+            for peer in chosen {
+                let _ = self.socket.send_to(&payload, peer).await;
+            }
+        }
+    }
+
+    async fn recv_loop(self: Arc<Self>) {
+        let mut buf = vec![0u8; 64 * 1024];
+        loop {
+            let (len, _src) = match self.socket.recv_from(&mut buf).await {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+
+            let Ok(message) = serde_json::from_slice::<GossipMessage>(&buf[..len]) else {
+                continue;
+            };
+
+            let mut scores = self.remote_scores.write().await;
+            for (component_id, score, last_updated) in message.observations {
+                let should_apply = match scores.get(&component_id) {
+                    Some((_, existing_updated)) => last_updated > *existing_updated,
+                    None => true,
+                };
+                if should_apply {
+                    scores.insert(component_id, (score, last_updated));
+                }
+            }
+        }
+    }
+
+    /// Merges locally-observed scores with whatever this node has learned
+    /// from peers, a local observation always replacing a peer's score for
+    /// the same component.
+    pub async fn merged_view(&self, local: &HashMap<String, f64>) -> HashMap<String, f64> {
+        let mut merged: HashMap<String, f64> = self
+            .remote_scores
+            .read()
+            .await
+            .iter()
+            .map(|(id, (score, _))| (id.clone(), *score))
+            .collect();
+        for (id, score) in local {
+            merged.insert(id.clone(), *score);
+        }
+        merged
+    }
+}
+
+/// A single committed trust score, signed by the node's Ed25519 key so a
+/// consumer reading `/trust-scores` can verify it genuinely came from the
+/// issuing node and wasn't tampered with in transit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedTrustRecord {
+    pub component_id: String,
+    pub score: f64,
+    pub timestamp: DateTime<Utc>,
+    pub signature: String,
+}
+
+impl SignedTrustRecord {
+    fn canonical_bytes(component_id: &str, score: f64, timestamp: &DateTime<Utc>) -> Vec<u8> {
+        format!("{}|{}|{}", component_id, score, timestamp.to_rfc3339()).into_bytes()
+    }
+}
+
+/// Accumulates pending trust updates without touching the live store; a
+/// caller stages updates here, then commits them atomically into the
+/// `TrustLedger`, mirroring a stage/commit workflow rather than mutating
+/// the map directly.
+#[derive(Debug, Default)]
+pub struct Changeset {
+    pending: Vec<(String, f64)>,
+}
+
+impl Changeset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stage(&mut self, component_id: &str, score: f64) -> &mut Self {
+        self.pending.push((component_id.to_string(), score));
+        self
+    }
+}
+
+/// Persistent, cryptographically signed trust store backed by an embedded
+/// key-value database (LMDB). Every committed record is signed with the
+/// node's Ed25519 key so entries can be verified independently of the
+/// process that wrote them.
+pub struct TrustLedger {
+    env: lmdb::Environment,
+    db: lmdb::Database,
+    keypair: Ed25519KeyPair,
+}
+
+impl TrustLedger {
+    /// Opens (or creates) the ledger at `path`, generating a fresh Ed25519
+    /// signing key alongside it on first use and reusing it on subsequent
+    /// opens so previously-signed records remain verifiable.
+    pub fn open(path: &std::path::Path) -> Result<Self, String> {
+        std::fs::create_dir_all(path).map_err(|e| e.to_string())?;
+
+        let env = lmdb::Environment::new()
+            .set_map_size(10 * 1024 * 1024)
+            .open(path)
+            .map_err(|e| e.to_string())?;
+        let db = env.open_db(None).map_err(|e| e.to_string())?;
+
+        let key_path = path.join("ledger.key");
+        let pkcs8_bytes = if key_path.exists() {
+            std::fs::read(&key_path).map_err(|e| e.to_string())?
+        } else {
+            let rng = SystemRandom::new();
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).map_err(|e| format!("{:?}", e))?;
+            std::fs::write(&key_path, pkcs8.as_ref()).map_err(|e| e.to_string())?;
+            pkcs8.as_ref().to_vec()
+        };
+        let keypair = Ed25519KeyPair::from_pkcs8(&pkcs8_bytes).map_err(|e| format!("{:?}", e))?;
+
+        Ok(Self { env, db, keypair })
+    }
+
+    fn sign(&self, component_id: &str, score: f64, timestamp: &DateTime<Utc>) -> Signature {
+        self.keypair
+            .sign(&SignedTrustRecord::canonical_bytes(component_id, score, timestamp))
+    }
+
+    /// Atomically applies every staged update in `changeset`, signing and
+    /// persisting each resulting record in a single LMDB write transaction.
+    pub fn commit(&self, changeset: Changeset) -> Result<Vec<SignedTrustRecord>, String> {
+        let mut txn = self.env.begin_rw_txn().map_err(|e| e.to_string())?;
+        let mut records = Vec::with_capacity(changeset.pending.len());
+
+        for (component_id, score) in changeset.pending {
+            let timestamp = Utc::now();
+            let signature = self.sign(&component_id, score, &timestamp);
+            let record = SignedTrustRecord {
+                component_id: component_id.clone(),
+                score,
+                timestamp,
+                signature: hex::encode(signature.as_ref()),
+            };
+            let serialized = serde_json::to_vec(&record).map_err(|e| e.to_string())?;
+            txn.put(self.db, &component_id, &serialized, lmdb::WriteFlags::empty())
+                .map_err(|e| e.to_string())?;
+            records.push(record);
+        }
+
+        txn.commit().map_err(|e| e.to_string())?;
+        Ok(records)
+    }
+
+    /// Reloads every record, verifying its signature against this node's
+    /// public key and dropping any entry that fails verification.
+    pub fn reload_and_verify(&self) -> Result<HashMap<String, f64>, String> {
+        let txn = self.env.begin_ro_txn().map_err(|e| e.to_string())?;
+        let mut cursor = txn.open_ro_cursor(self.db).map_err(|e| e.to_string())?;
+        let public_key = self.keypair.public_key();
+
+        let mut scores = HashMap::new();
+        for (_, value) in cursor.iter() {
+            let Ok(record) = serde_json::from_slice::<SignedTrustRecord>(value) else {
+                continue;
+            };
+            let Ok(signature_bytes) = hex::decode(&record.signature) else {
+                continue;
+            };
+            let message = SignedTrustRecord::canonical_bytes(&record.component_id, record.score, &record.timestamp);
+            let verifier = ring::signature::UnparsedPublicKey::new(&ED25519, public_key.as_ref());
+            if verifier.verify(&message, &signature_bytes).is_ok() {
+                scores.insert(record.component_id, record.score);
+            }
+        }
+
+        Ok(scores)
+    }
+}
+
 // Example of how to integrate with the trust monitoring system
 pub struct TrustMonitoringDemo {
     pub component_trust_scores: HashMap<String, f64>,
+    pub effective_trust_scores: HashMap<String, f64>,
+    pub dependencies: HashMap<String, Vec<(String, f64)>>,
+    pub ewma_state: HashMap<String, EwmaState>,
     pub system_health: String,
     pub active_incidents: Vec<String>,
+    pub notifier: Notifier,
+    pub gossip: Option<Arc<GossipNode>>,
+    pub ledger: Option<TrustLedger>,
 }
 
+/// Exponentially weighted moving average and variance for a single
+/// component's trust score, used to tell a genuine sustained degradation
+/// apart from one noisy reading.
+#[derive(Debug, Clone, Copy)]
+pub struct EwmaState {
+    pub mean: f64,
+    pub variance: f64,
+}
+
+impl EwmaState {
+    fn observe(&mut self, sample: f64, alpha: f64) -> f64 {
+        let prior_mean = self.mean;
+        self.mean = alpha * sample + (1.0 - alpha) * prior_mean;
+        self.variance = (1.0 - alpha) * (self.variance + alpha * (sample - prior_mean).powi(2));
+
+        let std_dev = self.variance.sqrt();
+        if std_dev > f64::EPSILON {
+            (sample - self.mean) / std_dev
+        } else {
+            0.0
+        }
+    }
+}
+
+const EWMA_ALPHA: f64 = 0.3;
+const ANOMALY_Z_SCORE_THRESHOLD: f64 = 3.0;
+const HEALTH_WARNING_ENTER: f64 = 0.8;
+const HEALTH_CRITICAL_ENTER: f64 = 0.5;
+// Hysteresis band: recovering back to a better state requires clearing the
+// entry cutoff by a margin, so the health state machine doesn't flap at
+// the boundary.
+const HEALTH_HYSTERESIS: f64 = 0.05;
+
+/// Propagation is iterated to a fixed point (rather than relying on a
+/// single reverse-topological pass) so cyclic dependency graphs still
+/// converge instead of looping forever.
+const PROPAGATION_DAMPING: f64 = 0.85;
+const PROPAGATION_MAX_ITERATIONS: usize = 50;
+const PROPAGATION_CONVERGENCE_EPSILON: f64 = 1e-6;
+
 impl TrustMonitoringDemo {
     pub fn new() -> Self {
         Self {
             component_trust_scores: HashMap::new(),
+            effective_trust_scores: HashMap::new(),
+            dependencies: HashMap::new(),
+            ewma_state: HashMap::new(),
             system_health: "Unknown".to_string(),
             active_incidents: Vec::new(),
+            notifier: Notifier::from_env(3, 0.5),
+            gossip: None,
+            ledger: None,
         }
     }
-    
+
+    /// Declares that `component`'s trust depends on `depends_on` by `weight`
+    /// (`w` in `[0, 1]`): the stronger the weight, the more `depends_on`'s
+    /// compromise drags `component` down.
+    pub fn add_dependency(&mut self, component: &str, depends_on: &str, weight: f64) {
+        self.dependencies
+            .entry(component.to_string())
+            .or_default()
+            .push((depends_on.to_string(), weight.clamp(0.0, 1.0)));
+    }
+
+    /// Recomputes every component's effective trust from its direct score
+    /// and its dependencies' effective scores:
+    /// `eff(A) = min(direct(A), 1 - max_over_deps(w_AB * (1 - eff(B))))`.
+    /// Cycles are handled by iterating to a fixed point with damping rather
+    /// than assuming a DAG.
+    fn recompute_effective_scores(&mut self) {
+        let mut effective: HashMap<String, f64> = self.component_trust_scores.clone();
+
+        for _ in 0..PROPAGATION_MAX_ITERATIONS {
+            let mut max_delta: f64 = 0.0;
+            let previous = effective.clone();
+
+            for (component, direct_score) in &self.component_trust_scores {
+                let Some(edges) = self.dependencies.get(component) else {
+                    continue;
+                };
+
+                let worst_dependency_drag = edges
+                    .iter()
+                    .map(|(dep, weight)| {
+                        let dep_effective = previous.get(dep).copied().unwrap_or(1.0);
+                        weight * (1.0 - dep_effective)
+                    })
+                    .fold(0.0_f64, f64::max);
+
+                let dependency_bound = 1.0 - worst_dependency_drag;
+                let computed = direct_score.min(dependency_bound);
+                let prior = previous.get(component).copied().unwrap_or(computed);
+                // Damping blends the freshly-computed bound with the prior
+                // estimate so graphs with cycles settle to a fixed point
+                // instead of oscillating forever.
+                let new_value = prior * (1.0 - PROPAGATION_DAMPING) + computed * PROPAGATION_DAMPING;
+
+                max_delta = max_delta.max((new_value - prior).abs());
+                effective.insert(component.clone(), new_value);
+            }
+
+            if max_delta < PROPAGATION_CONVERGENCE_EPSILON {
+                break;
+            }
+        }
+
+        self.effective_trust_scores = effective;
+    }
+
+    /// Opens a persistent, signed trust ledger at `path` and reloads any
+    /// previously-verified scores into the in-memory view.
+    pub fn open_ledger(&mut self, path: &std::path::Path) -> Result<(), String> {
+        let ledger = TrustLedger::open(path)?;
+        self.component_trust_scores = ledger.reload_and_verify()?;
+        self.ledger = Some(ledger);
+        Ok(())
+    }
+
+    /// Joins a gossip mesh with the given peers so this node's trust
+    /// observations are shared without a central aggregator.
+    pub async fn join_gossip(&mut self, bind_addr: &str, peers: Vec<SocketAddr>) -> std::io::Result<()> {
+        self.gossip = Some(GossipNode::bind(bind_addr, peers).await?);
+        Ok(())
+    }
+
     pub async fn update_trust_score(&mut self, component_id: &str, trust_score: f64) {
+        let old_score = self.component_trust_scores.get(component_id).copied().unwrap_or(trust_score);
+
+        if let Some(ledger) = &self.ledger {
+            let mut changeset = Changeset::new();
+            changeset.stage(component_id, trust_score);
+            if let Err(e) = ledger.commit(changeset) {
+                println!("   ⚠️  ledger: failed to commit trust update for {}: {}", component_id, e);
+            }
+        }
         self.component_trust_scores.insert(component_id.to_string(), trust_score);
-        
-        // Calculate system health based on trust scores
-        let avg_trust = self.component_trust_scores.values().sum::<f64>() / 
-                       self.component_trust_scores.len() as f64;
-        
-        self.system_health = if avg_trust > 0.8 {
-            "Healthy".to_string()
-        } else if avg_trust > 0.5 {
-            "Warning".to_string()
-        } else {
-            "Critical".to_string()
+        self.recompute_effective_scores();
+
+        let ewma = self
+            .ewma_state
+            .entry(component_id.to_string())
+            .or_insert(EwmaState { mean: trust_score, variance: 0.0 });
+        let z_score = ewma.observe(trust_score, EWMA_ALPHA);
+        let smoothed_mean = ewma.mean;
+
+        // Calculate system health based on effective (post-propagation) trust
+        let avg_trust = self.effective_trust_scores.values().sum::<f64>() /
+                       self.effective_trust_scores.len() as f64;
+
+        // Hysteretic state machine: entering a worse state uses the plain
+        // cutoff, but returning to a better state requires clearing it by
+        // `HEALTH_HYSTERESIS` so the status doesn't flap at the boundary.
+        self.system_health = match self.system_health.as_str() {
+            "Critical" if avg_trust > HEALTH_CRITICAL_ENTER + HEALTH_HYSTERESIS => "Warning".to_string(),
+            "Critical" => "Critical".to_string(),
+            "Warning" if avg_trust > HEALTH_WARNING_ENTER + HEALTH_HYSTERESIS => "Healthy".to_string(),
+            "Warning" if avg_trust <= HEALTH_CRITICAL_ENTER => "Critical".to_string(),
+            "Warning" => "Warning".to_string(),
+            _ if avg_trust > HEALTH_WARNING_ENTER => "Healthy".to_string(),
+            _ if avg_trust > HEALTH_CRITICAL_ENTER => "Warning".to_string(),
+            _ => "Critical".to_string(),
         };
-        
-        // Check for incidents
-        if trust_score < 0.2 {
-            let incident = format!("Critical trust score for {}: {:.2}", component_id, trust_score);
+
+        // A genuine sustained degradation (statistical anomaly in the
+        // smoothed trend) or a smoothed mean crossing into Critical
+        // territory raises an incident; a single noisy sample does not.
+        if z_score.abs() > ANOMALY_Z_SCORE_THRESHOLD || smoothed_mean < HEALTH_CRITICAL_ENTER {
+            let incident = format!(
+                "Anomalous trust for {}: raw={:.2} ewma={:.2} z={:.2}",
+                component_id, trust_score, smoothed_mean, z_score
+            );
             if !self.active_incidents.contains(&incident) {
                 self.active_incidents.push(incident);
             }
         }
+
+        self.notifier
+            .observe(component_id, old_score, trust_score, &self.system_health)
+            .await;
+
+        if let Some(gossip) = &self.gossip {
+            gossip.gossip_round(&self.component_trust_scores, 3).await;
+        }
     }
-    
-    pub fn get_system_status(&self) -> SystemStatus {
-        let overall_trust = if self.component_trust_scores.is_empty() {
+
+    pub async fn get_system_status(&self) -> SystemStatus {
+        let view = match &self.gossip {
+            Some(gossip) => gossip.merged_view(&self.effective_trust_scores).await,
+            None => self.effective_trust_scores.clone(),
+        };
+
+        let overall_trust = if view.is_empty() {
             0.0
         } else {
-            self.component_trust_scores.values().sum::<f64>() / 
-            self.component_trust_scores.len() as f64
+            view.values().sum::<f64>() / view.len() as f64
         };
-        
+
         SystemStatus {
             overall_trust,
-            component_count: self.component_trust_scores.len(),
+            component_count: view.len(),
             active_incidents: self.active_incidents.len(),
             active_alerts: self.active_incidents.len(),
             system_health: self.system_health.clone(),
             last_updated: Utc::now(),
+            direct_trust_scores: self.component_trust_scores.clone(),
+            effective_trust_scores: view,
+            smoothed_trust_scores: self.ewma_state.iter().map(|(id, s)| (id.clone(), s.mean)).collect(),
         }
     }
 }
@@ -213,4 +734,14 @@ pub struct SystemStatus {
     pub active_alerts: usize,
     pub system_health: String,
     pub last_updated: chrono::DateTime<chrono::Utc>,
+    /// The raw score last reported for each component, before dependency
+    /// propagation.
+    pub direct_trust_scores: HashMap<String, f64>,
+    /// Each component's score after propagating its dependencies' trust
+    /// downstream through `TrustMonitoringDemo::dependencies`, merged with
+    /// the scores learned from gossip peers when gossip is joined.
+    pub effective_trust_scores: HashMap<String, f64>,
+    /// Each component's EWMA-smoothed mean, used to distinguish sustained
+    /// degradation from a single noisy sample.
+    pub smoothed_trust_scores: HashMap<String, f64>,
 }
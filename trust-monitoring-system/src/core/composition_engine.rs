@@ -1,6 +1,12 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::cmp::Reverse;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use ring::signature::{UnparsedPublicKey, ED25519};
+use opentelemetry::{global, KeyValue};
+use opentelemetry::metrics::{Counter, Gauge};
+use opentelemetry::trace::{Span, Tracer, TracerProvider as _};
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
@@ -30,6 +36,31 @@ pub struct TrustEdge {
     pub trust_weight: f64,
     pub data_flow_volume: f64,
     pub criticality: f64,
+    /// Discrete trust level this edge asserts, in the style of a
+    /// crev-style proof database. Consumed by `WebOfTrustModel`; the
+    /// continuous-weight `propagate_trust` models ignore it.
+    #[serde(default)]
+    pub trust_level: TrustLevel,
+}
+
+/// Discrete trust level a `TrustEdge` asserts about its target, mirroring
+/// how a proof-database records explicit trust/distrust rather than a
+/// continuous weight.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrustLevel {
+    High,
+    Medium,
+    Low,
+    None,
+    /// Overrides any positive path: a target reached via a `Distrust` edge
+    /// is zeroed out and blacklisted from relaying trust further.
+    Distrust,
+}
+
+impl Default for TrustLevel {
+    fn default() -> Self {
+        TrustLevel::Medium
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,11 +97,497 @@ pub enum RelationshipType {
     LoadBalancing,
 }
 
+/// A single pending mutation staged into a `Changeset`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum ChangesetOp {
+    AddNode(TrustNode),
+    RemoveNode(String),
+    AddEdge(TrustEdge),
+    RemoveEdge { from: String, to: String },
+    AdjustTrustWeight { from: String, to: String, trust_weight: f64 },
+    UpdateSecurityPosture { node_id: String, security_posture: SecurityPosture },
+}
+
+/// A batch of pending mutations against a `TrustGraph`, applied atomically
+/// by `CompositionEngine::apply`. Callers stage edits here and commit them
+/// all at once instead of mutating the engine's locked graph field by
+/// field, mirroring the staged-trust-entry workflow where changes are
+/// represented as an explicit changeset layered over the current system
+/// state.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Changeset {
+    ops: Vec<ChangesetOp>,
+}
+
+impl Changeset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(mut self, node: TrustNode) -> Self {
+        self.ops.push(ChangesetOp::AddNode(node));
+        self
+    }
+
+    pub fn remove_node(mut self, node_id: impl Into<String>) -> Self {
+        self.ops.push(ChangesetOp::RemoveNode(node_id.into()));
+        self
+    }
+
+    pub fn add_edge(mut self, edge: TrustEdge) -> Self {
+        self.ops.push(ChangesetOp::AddEdge(edge));
+        self
+    }
+
+    pub fn remove_edge(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.ops.push(ChangesetOp::RemoveEdge { from: from.into(), to: to.into() });
+        self
+    }
+
+    pub fn adjust_trust_weight(mut self, from: impl Into<String>, to: impl Into<String>, trust_weight: f64) -> Self {
+        self.ops.push(ChangesetOp::AdjustTrustWeight {
+            from: from.into(),
+            to: to.into(),
+            trust_weight,
+        });
+        self
+    }
+
+    pub fn update_security_posture(mut self, node_id: impl Into<String>, security_posture: SecurityPosture) -> Self {
+        self.ops.push(ChangesetOp::UpdateSecurityPosture {
+            node_id: node_id.into(),
+            security_posture,
+        });
+        self
+    }
+
+    /// Rejects the whole changeset if any op references a node that
+    /// doesn't exist in `graph` and wasn't itself added earlier in this
+    /// same changeset (so `add_node` followed by `add_edge` to that node
+    /// within one changeset is valid).
+    fn validate(&self, graph: &TrustGraph) -> Result<(), String> {
+        let mut known: HashSet<String> = graph.nodes.keys().cloned().collect();
+
+        for op in &self.ops {
+            match op {
+                ChangesetOp::AddNode(node) => {
+                    known.insert(node.id.clone());
+                }
+                ChangesetOp::RemoveNode(node_id) => {
+                    if !known.contains(node_id) {
+                        return Err(format!("Changeset references missing node: {}", node_id));
+                    }
+                    known.remove(node_id);
+                }
+                ChangesetOp::AddEdge(edge) => {
+                    if !known.contains(&edge.from) {
+                        return Err(format!("Changeset edge references missing node: {}", edge.from));
+                    }
+                    if !known.contains(&edge.to) {
+                        return Err(format!("Changeset edge references missing node: {}", edge.to));
+                    }
+                }
+                ChangesetOp::RemoveEdge { from, to } => {
+                    let edge_id = format!("{}->{}", from, to);
+                    if !graph.edges.contains_key(&edge_id) {
+                        return Err(format!("Changeset references missing edge: {}", edge_id));
+                    }
+                }
+                ChangesetOp::AdjustTrustWeight { from, to, .. } => {
+                    let edge_id = format!("{}->{}", from, to);
+                    if !graph.edges.contains_key(&edge_id) {
+                        return Err(format!("Changeset references missing edge: {}", edge_id));
+                    }
+                }
+                ChangesetOp::UpdateSecurityPosture { node_id, .. } => {
+                    if !known.contains(node_id) {
+                        return Err(format!("Changeset references missing node: {}", node_id));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies `ops` to `graph` in place. Only called after `validate`
+    /// has confirmed every op is well-formed against that same graph.
+    fn apply_ops(graph: &mut TrustGraph, ops: Vec<ChangesetOp>) {
+        for op in ops {
+            match op {
+                ChangesetOp::AddNode(node) => {
+                    graph.nodes.insert(node.id.clone(), node);
+                }
+                ChangesetOp::RemoveNode(node_id) => {
+                    graph.nodes.remove(&node_id);
+                    graph.edges.retain(|_, edge| edge.from != node_id && edge.to != node_id);
+                    graph.dependencies.remove(&node_id);
+                    for deps in graph.dependencies.values_mut() {
+                        deps.retain(|dep| dep != &node_id);
+                    }
+                }
+                ChangesetOp::AddEdge(edge) => {
+                    let edge_id = format!("{}->{}", edge.from, edge.to);
+                    graph.dependencies.entry(edge.from.clone())
+                        .or_insert_with(Vec::new)
+                        .push(edge.to.clone());
+                    graph.edges.insert(edge_id, edge);
+                }
+                ChangesetOp::RemoveEdge { from, to } => {
+                    let edge_id = format!("{}->{}", from, to);
+                    graph.edges.remove(&edge_id);
+                    if let Some(deps) = graph.dependencies.get_mut(&from) {
+                        deps.retain(|dep| dep != &to);
+                    }
+                }
+                ChangesetOp::AdjustTrustWeight { from, to, trust_weight } => {
+                    let edge_id = format!("{}->{}", from, to);
+                    if let Some(edge) = graph.edges.get_mut(&edge_id) {
+                        edge.trust_weight = trust_weight;
+                    }
+                }
+                ChangesetOp::UpdateSecurityPosture { node_id, security_posture } => {
+                    if let Some(node) = graph.nodes.get_mut(&node_id) {
+                        node.security_posture = security_posture;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What a `Changeset` would add, remove, or modify relative to the live
+/// graph, as returned by `CompositionEngine::diff`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChangesetDiff {
+    pub added_nodes: Vec<String>,
+    pub removed_nodes: Vec<String>,
+    pub updated_nodes: Vec<String>,
+    pub added_edges: Vec<String>,
+    pub removed_edges: Vec<String>,
+    pub adjusted_edges: Vec<String>,
+}
+
+/// Snapshot of everything a `TrustStore` can persist and reload: the graph's
+/// nodes/edges/dependencies, the composition rule set, and the changeset
+/// journal (in application order) that produced that state.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PersistedState {
+    pub nodes: HashMap<String, TrustNode>,
+    pub edges: HashMap<String, TrustEdge>,
+    pub dependencies: HashMap<String, Vec<String>>,
+    pub composition_rules: Vec<CompositionRule>,
+    pub changeset_journal: Vec<Changeset>,
+}
+
+/// Durable storage for a `CompositionEngine`'s graph, composition rules, and
+/// changeset journal. Kept synchronous (unlike `async_trait`-based engine
+/// methods) since the embedded stores this is meant to wrap - sled, LMDB -
+/// are themselves synchronous.
+pub trait TrustStore: Send + Sync {
+    fn save_node(&self, node: &TrustNode) -> Result<(), String>;
+    fn remove_node(&self, node_id: &str) -> Result<(), String>;
+    fn save_edge(&self, edge_id: &str, edge: &TrustEdge) -> Result<(), String>;
+    fn remove_edge(&self, edge_id: &str) -> Result<(), String>;
+    fn save_composition_rules(&self, rules: &[CompositionRule]) -> Result<(), String>;
+    fn append_changeset(&self, changeset: &Changeset) -> Result<(), String>;
+    fn load(&self) -> Result<PersistedState, String>;
+    fn flush(&self) -> Result<(), String>;
+}
+
+fn persist<T: Serialize>(db: &sled::Db, key: &str, value: &T) -> Result<(), String> {
+    let bytes = serde_json::to_vec(value).map_err(|e| e.to_string())?;
+    db.insert(key.as_bytes(), bytes).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// `TrustStore` backed by an embedded sled key-value store, keyed by
+/// node/edge id so incremental writes don't require rewriting the whole
+/// graph.
+pub struct SledTrustStore {
+    db: sled::Db,
+}
+
+impl SledTrustStore {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        let db = sled::open(path).map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+impl TrustStore for SledTrustStore {
+    fn save_node(&self, node: &TrustNode) -> Result<(), String> {
+        persist(&self.db, &format!("node:{}", node.id), node)
+    }
+
+    fn remove_node(&self, node_id: &str) -> Result<(), String> {
+        self.db.remove(format!("node:{}", node_id).as_bytes()).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    fn save_edge(&self, edge_id: &str, edge: &TrustEdge) -> Result<(), String> {
+        persist(&self.db, &format!("edge:{}", edge_id), edge)
+    }
+
+    fn remove_edge(&self, edge_id: &str) -> Result<(), String> {
+        self.db.remove(format!("edge:{}", edge_id).as_bytes()).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    fn save_composition_rules(&self, rules: &[CompositionRule]) -> Result<(), String> {
+        persist(&self.db, "composition_rules", &rules.to_vec())
+    }
+
+    fn append_changeset(&self, changeset: &Changeset) -> Result<(), String> {
+        let id = self.db.generate_id().map_err(|e| e.to_string())?;
+        persist(&self.db, &format!("changeset_journal:{:020}", id), changeset)
+    }
+
+    fn load(&self) -> Result<PersistedState, String> {
+        let mut state = PersistedState::default();
+
+        for entry in self.db.scan_prefix(b"node:") {
+            let (_, bytes) = entry.map_err(|e| e.to_string())?;
+            let node: TrustNode = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+            state.nodes.insert(node.id.clone(), node);
+        }
+
+        for entry in self.db.scan_prefix(b"edge:") {
+            let (key, bytes) = entry.map_err(|e| e.to_string())?;
+            let edge_id = String::from_utf8_lossy(&key["edge:".len()..]).to_string();
+            let edge: TrustEdge = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+            state.dependencies.entry(edge.from.clone()).or_insert_with(Vec::new).push(edge.to.clone());
+            state.edges.insert(edge_id, edge);
+        }
+
+        for entry in self.db.scan_prefix(b"changeset_journal:") {
+            let (_, bytes) = entry.map_err(|e| e.to_string())?;
+            let changeset: Changeset = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+            state.changeset_journal.push(changeset);
+        }
+
+        if let Some(bytes) = self.db.get("composition_rules").map_err(|e| e.to_string())? {
+            state.composition_rules = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+        }
+
+        Ok(state)
+    }
+
+    fn flush(&self) -> Result<(), String> {
+        self.db.flush().map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+/// Governs automatic decay of `TrustNode::trust_score` for components whose
+/// `last_updated` timestamp has fallen outside a staleness TTL window.
+#[derive(Debug, Clone)]
+pub struct StalenessPolicy {
+    /// How long a node may go without an update before it's considered stale.
+    pub ttl: chrono::Duration,
+    /// Multiplier applied to `trust_score` once a node is stale.
+    pub decay_factor: f64,
+    /// Lower bound `trust_score` is never decayed below.
+    pub floor: f64,
+}
+
+impl Default for StalenessPolicy {
+    fn default() -> Self {
+        Self {
+            ttl: chrono::Duration::hours(24),
+            decay_factor: 0.9,
+            floor: 0.05,
+        }
+    }
+}
+
+/// The node or edge being claimed by a `TrustAssertion`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AssertedSubject {
+    Node(TrustNode),
+    Edge(TrustEdge),
+}
+
+/// A `TrustNode` or `TrustEdge` claim attributed to an identified author and
+/// signed over its canonical serialization, so a claim can be verified
+/// independently of whoever submitted it - the same model a proof database
+/// uses where every trust statement is a signed proof from an identified
+/// reviewer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustAssertion {
+    pub subject: AssertedSubject,
+    pub author_id: String,
+    pub timestamp: DateTime<Utc>,
+    /// Hex-encoded Ed25519 signature over `canonical_bytes()`.
+    pub signature: String,
+}
+
+impl TrustAssertion {
+    fn canonical_bytes(subject: &AssertedSubject, author_id: &str, timestamp: &DateTime<Utc>) -> Result<Vec<u8>, String> {
+        let subject_json = serde_json::to_string(subject).map_err(|e| e.to_string())?;
+        Ok(format!("{}|{}|{}", subject_json, author_id, timestamp.to_rfc3339()).into_bytes())
+    }
+
+    /// Verifies this assertion's signature against `identity_store`'s
+    /// registered public key for `author_id`. An unregistered author or a
+    /// malformed signature is treated as invalid, same as a failed
+    /// cryptographic check.
+    fn verify(&self, identity_store: &IdentityStore) -> Result<(), String> {
+        let Some(identity) = identity_store.authors.get(&self.author_id) else {
+            return Err(format!("unknown author: {}", self.author_id));
+        };
+        let message = Self::canonical_bytes(&self.subject, &self.author_id, &self.timestamp)?;
+        let signature_bytes = hex::decode(&self.signature).map_err(|e| e.to_string())?;
+        let verifier = UnparsedPublicKey::new(&ED25519, &identity.public_key);
+        verifier
+            .verify(&message, &signature_bytes)
+            .map_err(|_| format!("invalid signature from author: {}", self.author_id))
+    }
+}
+
+/// A known assertion author: their Ed25519 public key plus a per-author
+/// weight used both to resolve conflicting claims and to gate which authors'
+/// assertions are trusted enough to feed into propagation.
+#[derive(Debug, Clone)]
+struct KnownAuthor {
+    public_key: Vec<u8>,
+    weight: f64,
+}
+
+/// Registry of known assertion authors' public keys and weights, backing
+/// signature verification for `TrustAssertion`s.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityStore {
+    authors: HashMap<String, KnownAuthor>,
+}
+
+impl IdentityStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers (or replaces) a known author's Ed25519 public key and
+    /// relative weight.
+    pub fn register(&mut self, author_id: impl Into<String>, public_key: Vec<u8>, weight: f64) {
+        self.authors.insert(author_id.into(), KnownAuthor { public_key, weight });
+    }
+
+    pub fn weight_of(&self, author_id: &str) -> Option<f64> {
+        self.authors.get(author_id).map(|a| a.weight)
+    }
+}
+
+/// OTEL instrumentation for propagation runs, rule evaluation, and trust
+/// metrics. Routes traces and metrics through whatever OTLP pipeline
+/// `init` points at; a `CompositionEngine` with no `Telemetry` attached
+/// skips every call site this wraps at zero cost.
+pub struct Telemetry {
+    tracer: opentelemetry_sdk::trace::Tracer,
+    overall_trust_gauge: Gauge<f64>,
+    weak_link_gauge: Gauge<u64>,
+    critical_path_gauge: Gauge<u64>,
+    action_counter: Counter<u64>,
+}
+
+impl std::fmt::Debug for Telemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Telemetry").finish_non_exhaustive()
+    }
+}
+
+impl Telemetry {
+    /// Stands up OTLP tracer and meter providers pointed at `otlp_endpoint`
+    /// and registers them as the process-wide global providers.
+    pub fn init(otlp_endpoint: &str) -> Result<Self, String> {
+        let tracer_provider = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(otlp_endpoint),
+            )
+            .install_batch(opentelemetry_sdk::runtime::Tokio)
+            .map_err(|e| e.to_string())?;
+        let tracer = tracer_provider.tracer("trust-monitoring-system-composition");
+        global::set_tracer_provider(tracer_provider);
+
+        let meter_provider = opentelemetry_otlp::new_pipeline()
+            .metrics(opentelemetry_sdk::runtime::Tokio)
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(otlp_endpoint),
+            )
+            .build()
+            .map_err(|e| e.to_string())?;
+        let meter = meter_provider.meter("trust-monitoring-system-composition");
+        global::set_meter_provider(meter_provider);
+
+        let overall_trust_gauge = meter
+            .f64_gauge("lanc_composition_overall_trust")
+            .with_description("Overall system trust score from the most recent calculate_system_trust run")
+            .init();
+        let weak_link_gauge = meter
+            .u64_gauge("lanc_composition_weak_links")
+            .with_description("Number of weak links found in the most recent calculate_system_trust run")
+            .init();
+        let critical_path_gauge = meter
+            .u64_gauge("lanc_composition_critical_paths")
+            .with_description("Number of critical paths found in the most recent calculate_system_trust run")
+            .init();
+        let action_counter = meter
+            .u64_counter("lanc_composition_actions_total")
+            .with_description("CompositionActions triggered by evaluate_composition_rules, by action type")
+            .init();
+
+        Ok(Self { tracer, overall_trust_gauge, weak_link_gauge, critical_path_gauge, action_counter })
+    }
+
+    /// Starts a span around a single propagation-model run, tagged with the
+    /// model name, source component, and graph size. The span ends when the
+    /// returned guard is dropped.
+    fn start_propagation_span(&self, model_name: &str, source: &str, node_count: usize, edge_count: usize) -> opentelemetry::trace::BoxedSpan {
+        self.tracer
+            .span_builder("trust_propagation")
+            .with_attributes(vec![
+                KeyValue::new("model_name", model_name.to_string()),
+                KeyValue::new("source_component", source.to_string()),
+                KeyValue::new("node_count", node_count as i64),
+                KeyValue::new("edge_count", edge_count as i64),
+            ])
+            .start(&self.tracer)
+    }
+
+    fn record_system_trust(&self, overall_trust: f64, weak_link_count: u64, critical_path_count: u64) {
+        self.overall_trust_gauge.record(overall_trust, &[]);
+        self.weak_link_gauge.record(weak_link_count, &[]);
+        self.critical_path_gauge.record(critical_path_count, &[]);
+    }
+
+    fn record_action(&self, action_type: &CompositionActionType) {
+        self.action_counter.add(1, &[KeyValue::new("action_type", format!("{:?}", action_type))]);
+    }
+}
+
 /// Trust propagation algorithms for compositional analysis
 pub struct CompositionEngine {
     trust_graph: Arc<RwLock<TrustGraph>>,
     propagation_models: Arc<RwLock<HashMap<String, Box<dyn TrustPropagationModel + Send + Sync>>>>,
     composition_rules: Arc<RwLock<Vec<CompositionRule>>>,
+    store: Option<Arc<dyn TrustStore>>,
+    staleness_policy: Arc<RwLock<Option<StalenessPolicy>>>,
+    identity_store: Arc<RwLock<IdentityStore>>,
+    /// Per-edge (author_id, weight, trust_weight) contributions seen so far,
+    /// used to recompute an author-weighted `trust_weight` whenever a new
+    /// assertion for an already-asserted edge arrives.
+    edge_assertions: Arc<RwLock<HashMap<String, Vec<(String, f64, f64)>>>>,
+    /// Authors asserting below this weight are rejected outright, so
+    /// propagation only ever rests on verifiable, sufficiently trusted
+    /// claims.
+    min_author_weight: Arc<RwLock<f64>>,
+    /// Absent by default, so an engine with no OTEL pipeline configured
+    /// pays zero overhead at every instrumented call site.
+    telemetry: Option<Arc<Telemetry>>,
 }
 
 pub trait TrustPropagationModel {
@@ -201,6 +718,284 @@ impl TrustPropagationModel for BayesianPropagationModel {
     }
 }
 
+/// Iterative global trust model: where the BFS-based models above do a
+/// single pass from a root and mishandle cycles and reconvergent paths (the
+/// DFS critical-path search even flags every cycle it finds), this one
+/// power-iterates a row-normalized trust transition matrix to a fixed point,
+/// EigenTrust-style. `source` seeds the pre-trusted distribution `p`; each
+/// iteration computes `t = (1 - damping) * C^T * t + damping * p` until the
+/// L1 change between iterations falls below `tolerance` or `max_iterations`
+/// is reached, so the result is deterministic on cyclic graphs instead of
+/// depending on traversal order.
+pub struct EigenTrustModel {
+    pub name: String,
+    /// Probability mass reset to the pre-trusted distribution each
+    /// iteration; dampens trust flowing through long chains or sinks.
+    pub damping: f64,
+    /// Iteration stops once the L1 norm between successive vectors falls
+    /// below this.
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl EigenTrustModel {
+    pub fn new(name: String, damping: f64, tolerance: f64, max_iterations: usize) -> Self {
+        Self { name, damping, tolerance, max_iterations }
+    }
+}
+
+impl Default for EigenTrustModel {
+    fn default() -> Self {
+        Self {
+            name: "eigen_trust".to_string(),
+            damping: 0.15,
+            tolerance: 1e-6,
+            max_iterations: 100,
+        }
+    }
+}
+
+impl TrustPropagationModel for EigenTrustModel {
+    fn propagate_trust(&self, graph: &TrustGraph, source: &str) -> HashMap<String, f64> {
+        if graph.nodes.is_empty() {
+            return HashMap::new();
+        }
+
+        let ids: Vec<String> = graph.nodes.keys().cloned().collect();
+        let index: HashMap<&str, usize> = ids.iter().enumerate().map(|(i, id)| (id.as_str(), i)).collect();
+        let n = ids.len();
+
+        // Sum of outgoing trust_weight per node, used to row-normalize C.
+        let mut out_sum = vec![0.0; n];
+        for edge in graph.edges.values() {
+            if let Some(&i) = index.get(edge.from.as_str()) {
+                out_sum[i] += edge.trust_weight.max(0.0);
+            }
+        }
+
+        // Pre-trusted distribution p, concentrated on `source` when it's in
+        // the graph; falls back to uniform otherwise so iteration still
+        // converges on a graph that doesn't contain the requested root.
+        let mut p = vec![0.0; n];
+        match index.get(source) {
+            Some(&source_idx) => p[source_idx] = 1.0,
+            None => p.iter_mut().for_each(|v| *v = 1.0 / n as f64),
+        }
+
+        // Seed t^0 with each node's intrinsic trust_score, normalized.
+        let mut t: Vec<f64> = ids.iter().map(|id| graph.nodes[id].trust_score.max(0.0)).collect();
+        let t_sum: f64 = t.iter().sum();
+        if t_sum > 0.0 {
+            t.iter_mut().for_each(|v| *v /= t_sum);
+        } else {
+            t = p.clone();
+        }
+
+        for _ in 0..self.max_iterations {
+            let mut next = vec![0.0; n];
+
+            for edge in graph.edges.values() {
+                let (Some(&i), Some(&j)) = (index.get(edge.from.as_str()), index.get(edge.to.as_str())) else {
+                    continue;
+                };
+                if out_sum[i] <= 0.0 {
+                    continue;
+                }
+                next[j] += (edge.trust_weight.max(0.0) / out_sum[i]) * t[i];
+            }
+
+            // Rows with no outgoing edges have no defined trust transition,
+            // so fall back to the pre-trusted distribution for their mass.
+            for i in 0..n {
+                if out_sum[i] <= 0.0 {
+                    for j in 0..n {
+                        next[j] += p[j] * t[i];
+                    }
+                }
+            }
+
+            for j in 0..n {
+                next[j] = (1.0 - self.damping) * next[j] + self.damping * p[j];
+            }
+
+            let l1_change: f64 = next.iter().zip(t.iter()).map(|(a, b)| (a - b).abs()).sum();
+            t = next;
+            if l1_change < self.tolerance {
+                break;
+            }
+        }
+
+        let total: f64 = t.iter().sum();
+        ids.into_iter()
+            .zip(t.into_iter())
+            .map(|(id, score)| (id, if total > 0.0 { score / total } else { 0.0 }))
+            .collect()
+    }
+
+    fn get_model_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Per-level numeric cost accumulated when a `WebOfTrustModel` walks a
+/// `TrustLevel` edge; lower cost means a shorter, more trusted route.
+#[derive(Debug, Clone)]
+pub struct TrustLevelCosts {
+    pub high: u32,
+    pub medium: u32,
+    pub low: u32,
+}
+
+impl Default for TrustLevelCosts {
+    fn default() -> Self {
+        Self {
+            high: 10,
+            medium: 20,
+            low: 40,
+        }
+    }
+}
+
+impl TrustLevelCosts {
+    /// `None`/`Distrust` don't have a traversable step cost: `None` means
+    /// no trust relationship to walk, and `Distrust` is handled as a
+    /// special zero-and-blacklist case by the caller.
+    fn step_cost(&self, level: &TrustLevel) -> Option<u32> {
+        match level {
+            TrustLevel::High => Some(self.high),
+            TrustLevel::Medium => Some(self.medium),
+            TrustLevel::Low => Some(self.low),
+            TrustLevel::None | TrustLevel::Distrust => None,
+        }
+    }
+}
+
+/// Full per-node detail behind a `WebOfTrustModel` run: the node's
+/// effective trust, the accumulated trust-distance it was reached at, the
+/// predecessor it was reached via, and whether it was blacklisted by a
+/// `Distrust` edge.
+#[derive(Debug, Clone)]
+pub struct WebOfTrustResult {
+    pub effective_trust: f64,
+    pub trust_distance: u32,
+    pub reached_via: Option<String>,
+    pub blacklisted: bool,
+}
+
+/// Crev-style web-of-trust propagation model: instead of multiplying
+/// continuous weights, it runs a Dijkstra-like traversal over discrete
+/// `TrustLevel` edges, accumulating a "trust distance" along each path. A
+/// node's effective trust is `1.0` minus its best distance (scaled by
+/// `max_distance`), clamped to zero once that distance is exceeded. A
+/// single incoming `Distrust` edge from an already-reached node overrides
+/// any other positive path: the target is zeroed out and blacklisted, and
+/// the blacklist prevents it from relaying trust to anything downstream.
+pub struct WebOfTrustModel {
+    pub name: String,
+    pub max_distance: u32,
+    pub level_costs: TrustLevelCosts,
+}
+
+impl WebOfTrustModel {
+    pub fn new(name: String, max_distance: u32, level_costs: TrustLevelCosts) -> Self {
+        Self {
+            name,
+            max_distance,
+            level_costs,
+        }
+    }
+
+    /// Run the traversal and return the full per-node detail. `propagate_trust`
+    /// (the `TrustPropagationModel` trait method) projects this down to the
+    /// plain trust-score map the rest of the engine expects.
+    pub fn propagate_trust_detailed(&self, graph: &TrustGraph, source: &str) -> HashMap<String, WebOfTrustResult> {
+        let mut best: HashMap<String, (u32, Option<String>)> = HashMap::new();
+        let mut blacklisted: HashSet<String> = HashSet::new();
+
+        best.insert(source.to_string(), (0, None));
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((0u32, source.to_string())));
+
+        while let Some(Reverse((dist, current_id))) = heap.pop() {
+            if blacklisted.contains(&current_id) {
+                // A blacklisted node cannot relay trust further, even if it
+                // was reached via a shorter path before being blacklisted.
+                continue;
+            }
+            if let Some(&(best_dist, _)) = best.get(&current_id) {
+                if dist > best_dist {
+                    continue; // stale heap entry superseded by a better path
+                }
+            }
+
+            for edge in graph.edges.values() {
+                if edge.from != current_id {
+                    continue;
+                }
+
+                if edge.trust_level == TrustLevel::Distrust {
+                    blacklisted.insert(edge.to.clone());
+                    best.insert(edge.to.clone(), (u32::MAX, Some(current_id.clone())));
+                    continue;
+                }
+
+                if blacklisted.contains(&edge.to) {
+                    continue;
+                }
+
+                let Some(step_cost) = self.level_costs.step_cost(&edge.trust_level) else {
+                    continue;
+                };
+
+                let candidate_dist = dist.saturating_add(step_cost);
+                let is_better = match best.get(&edge.to) {
+                    Some(&(existing, _)) => candidate_dist < existing,
+                    None => true,
+                };
+                if is_better {
+                    best.insert(edge.to.clone(), (candidate_dist, Some(current_id.clone())));
+                    heap.push(Reverse((candidate_dist, edge.to.clone())));
+                }
+            }
+        }
+
+        best.into_iter()
+            .map(|(node_id, (distance, reached_via))| {
+                let is_blacklisted = blacklisted.contains(&node_id);
+                let effective_trust = if is_blacklisted || distance > self.max_distance {
+                    0.0
+                } else {
+                    (1.0 - (distance as f64 / self.max_distance.max(1) as f64)).clamp(0.0, 1.0)
+                };
+
+                (
+                    node_id,
+                    WebOfTrustResult {
+                        effective_trust,
+                        trust_distance: distance,
+                        reached_via,
+                        blacklisted: is_blacklisted,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+impl TrustPropagationModel for WebOfTrustModel {
+    fn propagate_trust(&self, graph: &TrustGraph, source: &str) -> HashMap<String, f64> {
+        self.propagate_trust_detailed(graph, source)
+            .into_iter()
+            .map(|(node_id, result)| (node_id, result.effective_trust))
+            .collect()
+    }
+
+    fn get_model_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompositionRule {
     pub rule_id: String,
@@ -268,7 +1063,140 @@ impl CompositionEngine {
             })),
             propagation_models: Arc::new(RwLock::new(HashMap::new())),
             composition_rules: Arc::new(RwLock::new(Vec::new())),
+            store: None,
+            staleness_policy: Arc::new(RwLock::new(None)),
+            identity_store: Arc::new(RwLock::new(IdentityStore::new())),
+            edge_assertions: Arc::new(RwLock::new(HashMap::new())),
+            min_author_weight: Arc::new(RwLock::new(0.0)),
+            telemetry: None,
+        }
+    }
+
+    /// Opens (creating if absent) a sled-backed store at `path` and builds a
+    /// `CompositionEngine` whose graph and composition rules are restored
+    /// from it, so system state survives a process restart instead of
+    /// rebuilding the whole graph each boot.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        let store = SledTrustStore::open(path)?;
+        let state = store.load()?;
+
+        Ok(Self {
+            trust_graph: Arc::new(RwLock::new(TrustGraph {
+                nodes: state.nodes,
+                edges: state.edges,
+                dependencies: state.dependencies,
+            })),
+            propagation_models: Arc::new(RwLock::new(HashMap::new())),
+            composition_rules: Arc::new(RwLock::new(state.composition_rules)),
+            store: Some(Arc::new(store)),
+            staleness_policy: Arc::new(RwLock::new(None)),
+            identity_store: Arc::new(RwLock::new(IdentityStore::new())),
+            edge_assertions: Arc::new(RwLock::new(HashMap::new())),
+            min_author_weight: Arc::new(RwLock::new(0.0)),
+            telemetry: None,
+        })
+    }
+
+    /// Attaches OTEL instrumentation to this engine; spans and metrics route
+    /// through `telemetry`'s configured pipeline from then on.
+    pub fn with_telemetry(mut self, telemetry: Telemetry) -> Self {
+        self.telemetry = Some(Arc::new(telemetry));
+        self
+    }
+
+    /// Writes the full live graph and composition rule set to the configured
+    /// store. No-op if `open` was never used to attach one.
+    pub async fn save(&self) -> Result<(), String> {
+        let Some(store) = &self.store else { return Ok(()); };
+
+        let graph = self.trust_graph.read().await;
+        for node in graph.nodes.values() {
+            store.save_node(node)?;
+        }
+        for (edge_id, edge) in &graph.edges {
+            store.save_edge(edge_id, edge)?;
+        }
+        drop(graph);
+
+        let rules = self.composition_rules.read().await;
+        store.save_composition_rules(&rules)?;
+        drop(rules);
+
+        store.flush()
+    }
+
+    /// Re-reads the configured store into this engine's live graph and
+    /// composition rules, discarding in-memory state not yet `save`d. Unlike
+    /// `open`, this reloads an already-constructed engine, e.g. after the
+    /// store has been mutated out from under it.
+    pub async fn load(&self) -> Result<(), String> {
+        let Some(store) = &self.store else {
+            return Err("no TrustStore configured for this engine".to_string());
+        };
+        let state = store.load()?;
+
+        let mut graph = self.trust_graph.write().await;
+        graph.nodes = state.nodes;
+        graph.edges = state.edges;
+        graph.dependencies = state.dependencies;
+        drop(graph);
+
+        let mut rules = self.composition_rules.write().await;
+        *rules = state.composition_rules;
+        Ok(())
+    }
+
+    /// Rebuilds the live graph from scratch by replaying the store's
+    /// changeset journal in order, rather than restoring the latest node and
+    /// edge snapshot directly.
+    pub async fn rebuild_from_journal(&self) -> Result<(), String> {
+        let Some(store) = &self.store else {
+            return Err("no TrustStore configured for this engine".to_string());
+        };
+        let state = store.load()?;
+
+        let mut rebuilt = TrustGraph {
+            nodes: HashMap::new(),
+            edges: HashMap::new(),
+            dependencies: HashMap::new(),
+        };
+        for changeset in &state.changeset_journal {
+            changeset.validate(&rebuilt)?;
+            Changeset::apply_ops(&mut rebuilt, changeset.ops.clone());
+        }
+
+        let mut graph = self.trust_graph.write().await;
+        *graph = rebuilt;
+        Ok(())
+    }
+
+    /// Installs a staleness policy that `apply_staleness_decay` enforces.
+    pub async fn set_staleness_policy(&self, policy: StalenessPolicy) {
+        let mut current = self.staleness_policy.write().await;
+        *current = Some(policy);
+    }
+
+    /// Decays `trust_score` for every node whose `last_updated` timestamp has
+    /// fallen outside the configured staleness TTL window. Returns the ids of
+    /// nodes that were decayed. No-op (returns an empty vec) if no policy is
+    /// configured.
+    pub async fn apply_staleness_decay(&self) -> Vec<String> {
+        let policy = self.staleness_policy.read().await;
+        let Some(policy) = policy.as_ref() else { return Vec::new(); };
+
+        let now = Utc::now();
+        let mut decayed = Vec::new();
+        let mut graph = self.trust_graph.write().await;
+        for node in graph.nodes.values_mut() {
+            if now - node.last_updated > policy.ttl {
+                let decayed_score = (node.trust_score * policy.decay_factor).max(policy.floor);
+                if decayed_score != node.trust_score {
+                    node.trust_score = decayed_score;
+                    decayed.push(node.id.clone());
+                }
+            }
         }
+        decayed
     }
 
     /// Add a component to the trust graph
@@ -282,24 +1210,188 @@ impl CompositionEngine {
         let mut graph = self.trust_graph.write().await;
         let edge_id = format!("{}->{}", edge.from, edge.to);
         graph.edges.insert(edge_id, edge);
-        
+
         // Update dependencies
         graph.dependencies.entry(edge.from.clone())
             .or_insert_with(Vec::new)
             .push(edge.to.clone());
     }
 
+    /// Registers a known assertion author's public key and weight, so their
+    /// signed `TrustAssertion`s can be verified and weighted.
+    pub async fn register_author(&self, author_id: impl Into<String>, public_key: Vec<u8>, weight: f64) {
+        let mut identity_store = self.identity_store.write().await;
+        identity_store.register(author_id, public_key, weight);
+    }
+
+    /// Sets the minimum author weight a `TrustAssertion` must carry to be
+    /// accepted; assertions from authors below this threshold are rejected
+    /// so propagation only ever rests on sufficiently trusted claims.
+    pub async fn set_min_author_weight(&self, min_weight: f64) {
+        let mut current = self.min_author_weight.write().await;
+        *current = min_weight;
+    }
+
+    /// Verifies `assertion`'s signature and author weight, then adds the
+    /// wrapped `TrustNode` to the graph. Rejects unsigned, invalid, or
+    /// under-weight assertions, and any assertion not wrapping a node.
+    pub async fn add_component_signed(&self, assertion: TrustAssertion) -> Result<(), String> {
+        let node = self.verify_and_unwrap_node(&assertion).await?;
+        self.add_component(node).await;
+        Ok(())
+    }
+
+    /// Verifies `assertion`'s signature and author weight, then installs the
+    /// wrapped `TrustEdge`. If another verified assertion already exists for
+    /// the same edge, the installed `trust_weight` is the author-weighted
+    /// combination of every assertion seen for that edge so far, rather than
+    /// the latest assertion silently overwriting prior claims.
+    pub async fn add_relationship_signed(&self, assertion: TrustAssertion) -> Result<(), String> {
+        let (author_weight, mut edge) = self.verify_and_unwrap_edge(&assertion).await?;
+        let edge_id = format!("{}->{}", edge.from, edge.to);
+
+        let mut edge_assertions = self.edge_assertions.write().await;
+        let contributions = edge_assertions.entry(edge_id.clone()).or_insert_with(Vec::new);
+        contributions.push((assertion.author_id.clone(), author_weight, edge.trust_weight));
+
+        let weight_sum: f64 = contributions.iter().map(|(_, w, _)| w).sum();
+        edge.trust_weight = if weight_sum > 0.0 {
+            contributions.iter().map(|(_, w, tw)| w * tw).sum::<f64>() / weight_sum
+        } else {
+            edge.trust_weight
+        };
+        drop(edge_assertions);
+
+        self.add_relationship(edge).await;
+        Ok(())
+    }
+
+    async fn verify_and_unwrap_node(&self, assertion: &TrustAssertion) -> Result<TrustNode, String> {
+        match &assertion.subject {
+            AssertedSubject::Node(node) => {
+                self.verify_assertion(assertion).await?;
+                Ok(node.clone())
+            }
+            AssertedSubject::Edge(_) => Err("assertion does not wrap a TrustNode".to_string()),
+        }
+    }
+
+    async fn verify_and_unwrap_edge(&self, assertion: &TrustAssertion) -> Result<(f64, TrustEdge), String> {
+        match &assertion.subject {
+            AssertedSubject::Edge(edge) => {
+                let author_weight = self.verify_assertion(assertion).await?;
+                Ok((author_weight, edge.clone()))
+            }
+            AssertedSubject::Node(_) => Err("assertion does not wrap a TrustEdge".to_string()),
+        }
+    }
+
+    /// Verifies the assertion's signature and rejects authors below
+    /// `min_author_weight`, returning the verified author's weight.
+    async fn verify_assertion(&self, assertion: &TrustAssertion) -> Result<f64, String> {
+        let identity_store = self.identity_store.read().await;
+        assertion.verify(&identity_store)?;
+        let weight = identity_store.weight_of(&assertion.author_id).unwrap_or(0.0);
+        let min_author_weight = *self.min_author_weight.read().await;
+        if weight < min_author_weight {
+            return Err(format!(
+                "author {} weight {} below minimum {}",
+                assertion.author_id, weight, min_author_weight
+            ));
+        }
+        Ok(weight)
+    }
+
+    /// Review the projected effect of `changeset` before committing it:
+    /// clones the live graph, applies the changeset to the clone, and
+    /// runs the same system-trust analysis `calculate_system_trust` would
+    /// run after a real `apply`. Rejects the changeset if any op
+    /// references a missing node, same as `apply`.
+    pub async fn preview(&self, changeset: &Changeset) -> Result<SystemTrustScore, String> {
+        let mut graph = self.trust_graph.read().await.clone();
+        changeset.validate(&graph)?;
+        Changeset::apply_ops(&mut graph, changeset.ops.clone());
+
+        let root_components: Vec<String> = graph.nodes.keys().cloned().collect();
+        Ok(self.calculate_system_trust_for_graph(&graph, &root_components).await)
+    }
+
+    /// Installs a batch of pending mutations atomically under a single
+    /// write lock: validates every op against the current graph first and
+    /// rejects the whole changeset if any op references a missing node,
+    /// so there's no partial application.
+    pub async fn apply(&self, changeset: Changeset) -> Result<(), String> {
+        let mut graph = self.trust_graph.write().await;
+        changeset.validate(&graph)?;
+        if let Some(store) = &self.store {
+            store.append_changeset(&changeset)?;
+        }
+        Changeset::apply_ops(&mut graph, changeset.ops);
+        Ok(())
+    }
+
+    /// Reports what `changeset` would add, remove, or modify relative to
+    /// the live graph, without applying it.
+    pub async fn diff(&self, changeset: &Changeset) -> ChangesetDiff {
+        let graph = self.trust_graph.read().await;
+        let mut diff = ChangesetDiff::default();
+
+        for op in &changeset.ops {
+            match op {
+                ChangesetOp::AddNode(node) => {
+                    if graph.nodes.contains_key(&node.id) {
+                        diff.updated_nodes.push(node.id.clone());
+                    } else {
+                        diff.added_nodes.push(node.id.clone());
+                    }
+                }
+                ChangesetOp::RemoveNode(node_id) => diff.removed_nodes.push(node_id.clone()),
+                ChangesetOp::AddEdge(edge) => {
+                    let edge_id = format!("{}->{}", edge.from, edge.to);
+                    if graph.edges.contains_key(&edge_id) {
+                        diff.adjusted_edges.push(edge_id);
+                    } else {
+                        diff.added_edges.push(edge_id);
+                    }
+                }
+                ChangesetOp::RemoveEdge { from, to } => diff.removed_edges.push(format!("{}->{}", from, to)),
+                ChangesetOp::AdjustTrustWeight { from, to, .. } => {
+                    diff.adjusted_edges.push(format!("{}->{}", from, to));
+                }
+                ChangesetOp::UpdateSecurityPosture { node_id, .. } => {
+                    diff.updated_nodes.push(node_id.clone());
+                }
+            }
+        }
+
+        diff
+    }
+
     /// Calculate compositional trust score for the entire system
     pub async fn calculate_system_trust(&self, root_components: &[String]) -> SystemTrustScore {
         let graph = self.trust_graph.read().await;
+        self.calculate_system_trust_for_graph(&graph, root_components).await
+    }
+
+    /// Shared by `calculate_system_trust` (against the live graph) and
+    /// `preview` (against a cloned graph with a pending `Changeset`
+    /// applied), so previewing a change runs exactly the same analysis a
+    /// committed change would.
+    async fn calculate_system_trust_for_graph(&self, graph: &TrustGraph, root_components: &[String]) -> SystemTrustScore {
         let models = self.propagation_models.read().await;
-        
+
         let mut system_scores = HashMap::new();
         
         // Use different propagation models for comprehensive analysis
         for (model_name, model) in models.iter() {
             for root in root_components {
+                let span = self.telemetry.as_ref().map(|t| {
+                    t.start_propagation_span(model_name, root, graph.nodes.len(), graph.edges.len())
+                });
                 let propagated = model.propagate_trust(&graph, root);
+                if let Some(mut span) = span {
+                    span.end();
+                }
                 for (component_id, score) in propagated {
                     system_scores.entry(component_id)
                         .or_insert_with(Vec::new)
@@ -307,26 +1399,33 @@ impl CompositionEngine {
                 }
             }
         }
-        
+
         // Calculate weighted average of all propagation results
         let mut final_scores = HashMap::new();
         for (component_id, scores) in system_scores {
             let average_score = scores.iter().sum::<f64>() / scores.len() as f64;
             final_scores.insert(component_id, average_score);
         }
-        
+
         // Calculate overall system trust
         let overall_trust = if final_scores.is_empty() {
             0.0
         } else {
             final_scores.values().sum::<f64>() / final_scores.len() as f64
         };
-        
+
+        let critical_paths = self.identify_critical_paths(&graph, root_components).await;
+        let weak_links = self.identify_weak_links(&graph, &final_scores).await;
+
+        if let Some(telemetry) = &self.telemetry {
+            telemetry.record_system_trust(overall_trust, weak_links.len() as u64, critical_paths.len() as u64);
+        }
+
         SystemTrustScore {
             overall_trust,
             component_scores: final_scores,
-            critical_paths: self.identify_critical_paths(&graph, root_components).await,
-            weak_links: self.identify_weak_links(&graph, &final_scores).await,
+            critical_paths,
+            weak_links,
             timestamp: Utc::now(),
         }
     }
@@ -464,7 +1563,13 @@ impl CompositionEngine {
                 triggered_actions.extend(rule.actions.clone());
             }
         }
-        
+
+        if let Some(telemetry) = &self.telemetry {
+            for action in &triggered_actions {
+                telemetry.record_action(&action.action_type);
+            }
+        }
+
         triggered_actions
     }
 
@@ -498,16 +1603,85 @@ impl CompositionEngine {
         let mut analysis_results = HashMap::new();
         
         for (model_name, model) in models.iter() {
+            let span = self.telemetry.as_ref().map(|t| {
+                t.start_propagation_span(model_name, source, graph.nodes.len(), graph.edges.len())
+            });
             let propagated = model.propagate_trust(&graph, source);
+            if let Some(mut span) = span {
+                span.end();
+            }
             analysis_results.insert(model_name.clone(), propagated);
         }
-        
+
         PropagationAnalysis {
             source_component: source.to_string(),
             propagation_results: analysis_results,
             timestamp: Utc::now(),
         }
     }
+
+    /// `identify_weak_links`'s averaged scores can't say *why* a node is
+    /// weak. For a `WebOfTrustModel` run specifically, surface the
+    /// accumulated trust distance, the predecessor it was reached via, and
+    /// whether it was blacklisted by a `Distrust` edge.
+    pub async fn identify_weak_links_web_of_trust(
+        &self,
+        model: &WebOfTrustModel,
+        source: &str,
+    ) -> Vec<WeakLinkExplanation> {
+        let graph = self.trust_graph.read().await;
+        let mut explanations: Vec<WeakLinkExplanation> = model
+            .propagate_trust_detailed(&graph, source)
+            .into_iter()
+            .filter(|(_, result)| result.effective_trust < 0.3)
+            .map(|(component_id, result)| {
+                let description = if result.blacklisted {
+                    format!(
+                        "{} was reached via {} but is blacklisted by a Distrust edge, overriding any positive path",
+                        component_id,
+                        result.reached_via.as_deref().unwrap_or("<source>")
+                    )
+                } else if result.trust_distance > model.max_distance {
+                    format!(
+                        "{} has trust distance {} exceeding max_distance {}",
+                        component_id, result.trust_distance, model.max_distance
+                    )
+                } else {
+                    format!(
+                        "{} reached via {} at trust distance {} (effective trust {:.2})",
+                        component_id,
+                        result.reached_via.as_deref().unwrap_or("<source>"),
+                        result.trust_distance,
+                        result.effective_trust
+                    )
+                };
+
+                WeakLinkExplanation {
+                    component_id,
+                    effective_trust: result.effective_trust,
+                    trust_distance: result.trust_distance,
+                    reached_via: result.reached_via,
+                    blacklisted: result.blacklisted,
+                    description,
+                }
+            })
+            .collect();
+
+        explanations.sort_by(|a, b| a.component_id.cmp(&b.component_id));
+        explanations
+    }
+}
+
+/// Explanation of why a node was flagged weak by a `WebOfTrustModel` run,
+/// as returned by `CompositionEngine::identify_weak_links_web_of_trust`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeakLinkExplanation {
+    pub component_id: String,
+    pub effective_trust: f64,
+    pub trust_distance: u32,
+    pub reached_via: Option<String>,
+    pub blacklisted: bool,
+    pub description: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -582,6 +1756,7 @@ mod tests {
             trust_weight: 0.9,
             data_flow_volume: 1000.0,
             criticality: 0.8,
+            trust_level: TrustLevel::Medium,
         };
         
         // Add weighted average model
@@ -594,4 +1769,397 @@ mod tests {
         let system_trust = engine.calculate_system_trust(&["service1".to_string()]).await;
         assert!(system_trust.overall_trust >= 0.0 && system_trust.overall_trust <= 1.0);
     }
+
+    fn test_edge(from: &str, to: &str, trust_level: TrustLevel) -> TrustEdge {
+        TrustEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            relationship_type: RelationshipType::DataFlow,
+            trust_weight: 0.5,
+            data_flow_volume: 0.0,
+            criticality: 0.5,
+            trust_level,
+        }
+    }
+
+    fn graph_with_edges(edges: Vec<TrustEdge>) -> TrustGraph {
+        let mut graph = TrustGraph {
+            nodes: HashMap::new(),
+            edges: HashMap::new(),
+            dependencies: HashMap::new(),
+        };
+        for (i, edge) in edges.into_iter().enumerate() {
+            graph.edges.insert(format!("e{}", i), edge);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_web_of_trust_prefers_shorter_high_trust_path() {
+        let graph = graph_with_edges(vec![
+            test_edge("a", "b", TrustLevel::High),
+            test_edge("b", "c", TrustLevel::High),
+            test_edge("a", "c", TrustLevel::Low),
+        ]);
+        let model = WebOfTrustModel::new("wot".to_string(), 100, TrustLevelCosts::default());
+
+        let results = model.propagate_trust_detailed(&graph, "a");
+        let c = &results["c"];
+
+        // High->High (distance 20) beats the direct Low edge (distance 40).
+        assert_eq!(c.trust_distance, 20);
+        assert_eq!(c.reached_via.as_deref(), Some("b"));
+        assert!(!c.blacklisted);
+        assert!(c.effective_trust > 0.0);
+    }
+
+    #[test]
+    fn test_web_of_trust_distance_beyond_max_is_untrusted() {
+        let graph = graph_with_edges(vec![test_edge("a", "b", TrustLevel::Low)]);
+        let model = WebOfTrustModel::new("wot".to_string(), 10, TrustLevelCosts::default());
+
+        let results = model.propagate_trust_detailed(&graph, "a");
+        assert_eq!(results["b"].effective_trust, 0.0);
+    }
+
+    #[test]
+    fn test_web_of_trust_distrust_overrides_and_blocks_relay() {
+        let graph = graph_with_edges(vec![
+            test_edge("a", "b", TrustLevel::High),
+            test_edge("a", "b", TrustLevel::Distrust),
+            test_edge("b", "c", TrustLevel::High),
+        ]);
+        let model = WebOfTrustModel::new("wot".to_string(), 100, TrustLevelCosts::default());
+
+        let results = model.propagate_trust_detailed(&graph, "a");
+
+        assert!(results["b"].blacklisted);
+        assert_eq!(results["b"].effective_trust, 0.0);
+
+        // "c" is only reachable through the blacklisted "b", so it must not
+        // inherit any trust either.
+        assert!(!results.contains_key("c") || results["c"].effective_trust == 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_identify_weak_links_web_of_trust_explains_blacklist() {
+        let engine = CompositionEngine::new();
+        engine.add_relationship(test_edge("a", "b", TrustLevel::High)).await;
+        engine.add_relationship(test_edge("a", "b", TrustLevel::Distrust)).await;
+
+        let model = WebOfTrustModel::new("wot".to_string(), 100, TrustLevelCosts::default());
+        let explanations = engine.identify_weak_links_web_of_trust(&model, "a").await;
+
+        let b_explanation = explanations.iter().find(|e| e.component_id == "b").expect("b should be weak");
+        assert!(b_explanation.blacklisted);
+        assert!(b_explanation.description.contains("Distrust"));
+    }
+
+    fn test_node(id: &str) -> TrustNode {
+        TrustNode {
+            id: id.to_string(),
+            trust_score: 0.8,
+            component_type: ComponentType::Microservice,
+            security_posture: SecurityPosture {
+                vulnerability_score: 0.1,
+                patch_status: 0.9,
+                compliance_score: 0.8,
+                encryption_status: 0.9,
+                access_control_score: 0.8,
+            },
+            last_updated: Utc::now(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_rejects_changeset_referencing_missing_node() {
+        let engine = CompositionEngine::new();
+        let changeset = Changeset::new().add_edge(test_edge("a", "b", TrustLevel::High));
+
+        assert!(engine.apply(changeset).await.is_err());
+        // Rejected atomically: nothing should have been installed.
+        let graph = engine.trust_graph.read().await;
+        assert!(graph.edges.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_installs_changeset_atomically() {
+        let engine = CompositionEngine::new();
+        let changeset = Changeset::new()
+            .add_node(test_node("a"))
+            .add_node(test_node("b"))
+            .add_edge(test_edge("a", "b", TrustLevel::High));
+
+        engine.apply(changeset).await.expect("well-formed changeset applies");
+
+        let graph = engine.trust_graph.read().await;
+        assert_eq!(graph.nodes.len(), 2);
+        assert!(graph.edges.contains_key("a->b"));
+    }
+
+    #[tokio::test]
+    async fn test_preview_does_not_mutate_live_graph() {
+        let engine = CompositionEngine::new();
+        engine.add_component(test_node("a")).await;
+        engine.add_propagation_model(
+            "weighted".to_string(),
+            Box::new(WeightedAverageModel { name: "weighted_average".to_string() }),
+        ).await;
+        let changeset = Changeset::new().add_node(test_node("b")).add_edge(test_edge("a", "b", TrustLevel::High));
+
+        let preview = engine.preview(&changeset).await.expect("well-formed changeset previews");
+        assert!(preview.component_scores.contains_key("b"));
+
+        let graph = engine.trust_graph.read().await;
+        assert!(!graph.nodes.contains_key("b"), "preview must not mutate the live graph");
+    }
+
+    #[tokio::test]
+    async fn test_diff_reports_additions_relative_to_live_state() {
+        let engine = CompositionEngine::new();
+        engine.add_component(test_node("a")).await;
+
+        let changeset = Changeset::new()
+            .add_node(test_node("b"))
+            .add_edge(test_edge("a", "b", TrustLevel::High));
+        let diff = engine.diff(&changeset).await;
+
+        assert_eq!(diff.added_nodes, vec!["b".to_string()]);
+        assert_eq!(diff.added_edges, vec!["a->b".to_string()]);
+    }
+
+    fn temp_store() -> SledTrustStore {
+        let db = sled::Config::new().temporary(true).open().expect("open temporary sled db");
+        SledTrustStore { db }
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_round_trips_graph_and_rules() {
+        let engine = CompositionEngine {
+            trust_graph: Arc::new(RwLock::new(TrustGraph {
+                nodes: HashMap::new(),
+                edges: HashMap::new(),
+                dependencies: HashMap::new(),
+            })),
+            propagation_models: Arc::new(RwLock::new(HashMap::new())),
+            composition_rules: Arc::new(RwLock::new(Vec::new())),
+            store: Some(Arc::new(temp_store())),
+            staleness_policy: Arc::new(RwLock::new(None)),
+            identity_store: Arc::new(RwLock::new(IdentityStore::new())),
+            edge_assertions: Arc::new(RwLock::new(HashMap::new())),
+            min_author_weight: Arc::new(RwLock::new(0.0)),
+            telemetry: None,
+        };
+
+        engine.add_component(test_node("a")).await;
+        engine.add_component(test_node("b")).await;
+        engine.add_relationship(test_edge("a", "b", TrustLevel::High)).await;
+        engine.save().await.expect("save succeeds");
+
+        engine.add_component(test_node("c")).await;
+        engine.load().await.expect("load succeeds");
+
+        let graph = engine.trust_graph.read().await;
+        assert_eq!(graph.nodes.len(), 2, "load should restore the saved snapshot, discarding unsaved node c");
+        assert!(graph.edges.contains_key("a->b"));
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_from_journal_replays_applied_changesets() {
+        let engine = CompositionEngine {
+            trust_graph: Arc::new(RwLock::new(TrustGraph {
+                nodes: HashMap::new(),
+                edges: HashMap::new(),
+                dependencies: HashMap::new(),
+            })),
+            propagation_models: Arc::new(RwLock::new(HashMap::new())),
+            composition_rules: Arc::new(RwLock::new(Vec::new())),
+            store: Some(Arc::new(temp_store())),
+            staleness_policy: Arc::new(RwLock::new(None)),
+            identity_store: Arc::new(RwLock::new(IdentityStore::new())),
+            edge_assertions: Arc::new(RwLock::new(HashMap::new())),
+            min_author_weight: Arc::new(RwLock::new(0.0)),
+            telemetry: None,
+        };
+
+        engine.apply(Changeset::new().add_node(test_node("a"))).await.expect("applies");
+        engine.apply(
+            Changeset::new().add_node(test_node("b")).add_edge(test_edge("a", "b", TrustLevel::High)),
+        ).await.expect("applies");
+
+        engine.rebuild_from_journal().await.expect("rebuild succeeds");
+
+        let graph = engine.trust_graph.read().await;
+        assert_eq!(graph.nodes.len(), 2);
+        assert!(graph.edges.contains_key("a->b"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_staleness_decay_only_affects_stale_nodes() {
+        let engine = CompositionEngine::new();
+        let mut fresh = test_node("fresh");
+        fresh.last_updated = Utc::now();
+        let mut stale = test_node("stale");
+        stale.last_updated = Utc::now() - chrono::Duration::hours(48);
+        engine.add_component(fresh).await;
+        engine.add_component(stale).await;
+
+        engine.set_staleness_policy(StalenessPolicy {
+            ttl: chrono::Duration::hours(24),
+            decay_factor: 0.5,
+            floor: 0.05,
+        }).await;
+
+        let decayed = engine.apply_staleness_decay().await;
+        assert_eq!(decayed, vec!["stale".to_string()]);
+
+        let graph = engine.trust_graph.read().await;
+        assert_eq!(graph.nodes["fresh"].trust_score, 0.8);
+        assert_eq!(graph.nodes["stale"].trust_score, 0.4);
+    }
+
+    fn sign_assertion(keypair: &ring::signature::Ed25519KeyPair, subject: AssertedSubject, author_id: &str) -> TrustAssertion {
+        let timestamp = Utc::now();
+        let message = TrustAssertion::canonical_bytes(&subject, author_id, &timestamp).expect("canonical bytes");
+        let signature = keypair.sign(&message);
+        TrustAssertion {
+            subject,
+            author_id: author_id.to_string(),
+            timestamp,
+            signature: hex::encode(signature.as_ref()),
+        }
+    }
+
+    fn test_keypair() -> ring::signature::Ed25519KeyPair {
+        use ring::signature::KeyPair;
+        let rng = ring::rand::SystemRandom::new();
+        let pkcs8 = ring::signature::Ed25519KeyPair::generate_pkcs8(&rng).expect("generate keypair");
+        ring::signature::Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).expect("load keypair")
+    }
+
+    #[tokio::test]
+    async fn test_add_component_signed_rejects_invalid_signature() {
+        use ring::signature::KeyPair;
+        let engine = CompositionEngine::new();
+        let keypair = test_keypair();
+        engine.register_author("alice", keypair.public_key().as_ref().to_vec(), 1.0).await;
+
+        let mut assertion = sign_assertion(&keypair, AssertedSubject::Node(test_node("a")), "alice");
+        assertion.signature = hex::encode([0u8; 64]);
+
+        assert!(engine.add_component_signed(assertion).await.is_err());
+        let graph = engine.trust_graph.read().await;
+        assert!(graph.nodes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_component_signed_accepts_verified_assertion() {
+        use ring::signature::KeyPair;
+        let engine = CompositionEngine::new();
+        let keypair = test_keypair();
+        engine.register_author("alice", keypair.public_key().as_ref().to_vec(), 1.0).await;
+
+        let assertion = sign_assertion(&keypair, AssertedSubject::Node(test_node("a")), "alice");
+        engine.add_component_signed(assertion).await.expect("verified assertion is accepted");
+
+        let graph = engine.trust_graph.read().await;
+        assert!(graph.nodes.contains_key("a"));
+    }
+
+    #[tokio::test]
+    async fn test_add_relationship_signed_rejects_author_below_threshold() {
+        let engine = CompositionEngine::new();
+        let keypair = test_keypair();
+        {
+            use ring::signature::KeyPair;
+            engine.register_author("mallory", keypair.public_key().as_ref().to_vec(), 0.1).await;
+        }
+        engine.set_min_author_weight(0.5).await;
+
+        let assertion = sign_assertion(&keypair, AssertedSubject::Edge(test_edge("a", "b", TrustLevel::High)), "mallory");
+        assert!(engine.add_relationship_signed(assertion).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_add_relationship_signed_combines_conflicting_weights_by_author_weight() {
+        use ring::signature::KeyPair;
+        let engine = CompositionEngine::new();
+        let alice = test_keypair();
+        let bob = test_keypair();
+        engine.register_author("alice", alice.public_key().as_ref().to_vec(), 3.0).await;
+        engine.register_author("bob", bob.public_key().as_ref().to_vec(), 1.0).await;
+
+        let mut alice_edge = test_edge("a", "b", TrustLevel::High);
+        alice_edge.trust_weight = 0.9;
+        let mut bob_edge = test_edge("a", "b", TrustLevel::High);
+        bob_edge.trust_weight = 0.1;
+
+        engine.add_relationship_signed(sign_assertion(&alice, AssertedSubject::Edge(alice_edge), "alice")).await.expect("accepted");
+        engine.add_relationship_signed(sign_assertion(&bob, AssertedSubject::Edge(bob_edge), "bob")).await.expect("accepted");
+
+        let graph = engine.trust_graph.read().await;
+        let combined = graph.edges["a->b"].trust_weight;
+        // (3.0*0.9 + 1.0*0.1) / 4.0 = 0.7
+        assert!((combined - 0.7).abs() < 1e-9, "expected author-weighted combination, got {combined}");
+    }
+
+    fn eigen_graph(node_ids: &[&str], edges: Vec<TrustEdge>) -> TrustGraph {
+        let mut graph = graph_with_edges(edges);
+        for id in node_ids {
+            graph.nodes.insert(id.to_string(), test_node(id));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_eigen_trust_converges_on_a_cycle() {
+        // A cycle that the DFS-based critical-path search would flag as
+        // circular, but power iteration should settle to a fixed point on.
+        let graph = eigen_graph(
+            &["a", "b", "c"],
+            vec![
+                test_edge("a", "b", TrustLevel::High),
+                test_edge("b", "c", TrustLevel::High),
+                test_edge("c", "a", TrustLevel::High),
+            ],
+        );
+        let model = EigenTrustModel::default();
+
+        let scores = model.propagate_trust(&graph, "a");
+        assert_eq!(scores.len(), 3);
+        let total: f64 = scores.values().sum();
+        assert!((total - 1.0).abs() < 1e-6, "expected the returned vector to be normalized, got total {total}");
+    }
+
+    #[test]
+    fn test_eigen_trust_ranks_node_reached_by_more_trusted_paths_higher() {
+        // b is reached from both a and c, while d is a dead end reached
+        // only from c; b should end up with more trust than d.
+        let graph = eigen_graph(
+            &["a", "b", "c", "d"],
+            vec![
+                test_edge("a", "b", TrustLevel::High),
+                test_edge("c", "b", TrustLevel::High),
+                test_edge("a", "c", TrustLevel::High),
+                test_edge("c", "d", TrustLevel::Low),
+            ],
+        );
+        let model = EigenTrustModel::default();
+
+        let scores = model.propagate_trust(&graph, "a");
+        assert!(scores["b"] > scores["d"]);
+    }
+
+    #[test]
+    fn test_eigen_trust_handles_dangling_sink_without_panicking() {
+        // b has no outgoing edges at all.
+        let graph = eigen_graph(&["a", "b"], vec![test_edge("a", "b", TrustLevel::High)]);
+        let model = EigenTrustModel::new("eigen".to_string(), 0.15, 1e-6, 50);
+
+        let scores = model.propagate_trust(&graph, "a");
+        assert!(scores["a"] >= 0.0 && scores["b"] >= 0.0);
+        let total: f64 = scores.values().sum();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
 }
@@ -1,5 +1,5 @@
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 use tokio::time::Duration;
 use serde::{Deserialize, Serialize};
@@ -7,6 +7,55 @@ use chrono::{DateTime, Utc};
 use futures::stream::{Stream, StreamExt};
 use tokio_stream::wrappers::IntervalStream;
 
+/// Implements a forward-compatible `Display`/`FromStr`/`Serialize`/
+/// `Deserialize` quartet for a config enum that carries an `UnknownValue
+/// (String)` variant: an unrecognized string deserializes to
+/// `UnknownValue` instead of failing, and round-trips back out verbatim,
+/// so a monitoring config or persisted `Alert` written by a newer build
+/// can still be loaded here instead of failing outright.
+macro_rules! forward_compatible_enum {
+    ($name:ident { $($variant:ident => $label:literal),+ $(,)? }) => {
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($name::$variant => write!(f, $label),)+
+                    $name::UnknownValue(value) => write!(f, "{}", value),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $($label => $name::$variant,)+
+                    other => $name::UnknownValue(other.to_string()),
+                })
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let s = String::deserialize(deserializer)?;
+                Ok(s.parse().expect("FromStr for this enum is infallible"))
+            }
+        }
+    };
+}
+
 /// Real-time continual assurance engine for dynamic trust scoring
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinualAssuranceEngine {
@@ -15,6 +64,24 @@ pub struct ContinualAssuranceEngine {
     pub data_sources: Arc<RwLock<Vec<Box<dyn DataSource + Send + Sync>>>>,
     pub scoring_pipeline: Arc<RwLock<ScoringPipeline>>,
     pub alert_manager: Arc<RwLock<AlertManager>>,
+    /// Dependency/vouching edges used by `CalculationMethod::TrustGraph`
+    /// components to derive an effective trust score from the components
+    /// they depend on.
+    pub trust_graph: Arc<RwLock<DependencyTrustGraph>>,
+    /// Delivers alerts to their routed `NotificationChannel`s. Defaults to
+    /// `HttpNotificationDispatcher`; swap for a fake in tests.
+    pub notification_dispatcher: Arc<dyn NotificationDispatcher + Send + Sync>,
+    /// Persists every registered component's `MonitoringConfig` to disk so
+    /// `register_component` calls survive a restart; reloaded by `new`.
+    pub config_store: Arc<PersistentConfigStore>,
+    /// Tracks consecutive failures/backoff per `DataSource::get_source_name`
+    /// so a down source is reconnected-to with exponential backoff instead
+    /// of repeatedly draining a dead stream every tick.
+    pub source_health: Arc<RwLock<HashMap<String, SourceHealthState>>>,
+    /// When each component was last polled, so `update_all_trust_scores`
+    /// can honor its own `MonitoringConfig.update_interval` instead of
+    /// polling everything on the engine's single shared tick.
+    pub last_polled: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,7 +111,7 @@ pub struct ContributingFactor {
     pub description: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum FactorType {
     SecurityEvent,
     PerformanceMetric,
@@ -52,8 +119,20 @@ pub enum FactorType {
     ComplianceStatus,
     DependencyHealth,
     CommunicationQuality,
+    /// An unrecognized value from a newer build, preserved verbatim so a
+    /// persisted `TrustScorePoint` still round-trips instead of failing.
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(FactorType {
+    SecurityEvent => "SecurityEvent",
+    PerformanceMetric => "PerformanceMetric",
+    BehavioralAnomaly => "BehavioralAnomaly",
+    ComplianceStatus => "ComplianceStatus",
+    DependencyHealth => "DependencyHealth",
+    CommunicationQuality => "CommunicationQuality",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonitoringConfig {
     pub update_interval: Duration,
@@ -70,14 +149,29 @@ pub struct TrustThresholds {
     pub normal: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum CalculationMethod {
     WeightedAverage,
     MachineLearning,
     Bayesian,
     Ensemble,
+    /// Derive effective trust from the component's dependencies via
+    /// `DependencyTrustGraph` instead of (or on top of) its own direct score.
+    TrustGraph,
+    /// An unrecognized value from a newer build. Components that branch on
+    /// `CalculationMethod` should treat this the same as `WeightedAverage`
+    /// rather than panicking.
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(CalculationMethod {
+    WeightedAverage => "WeightedAverage",
+    MachineLearning => "MachineLearning",
+    Bayesian => "Bayesian",
+    Ensemble => "Ensemble",
+    TrustGraph => "TrustGraph",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlertSettings {
     pub enabled: bool,
@@ -89,7 +183,11 @@ pub struct AlertSettings {
 pub struct EscalationLevel {
     pub level: u32,
     pub threshold: f64,
+    /// `NotificationChannel.channel_id`s to notify once an unacknowledged
+    /// alert reaches this level.
     pub actions: Vec<String>,
+    /// How long an alert must sit at the previous level before advancing
+    /// to this one.
     pub timeout: Duration,
 }
 
@@ -287,46 +385,313 @@ impl MLTrustCalculator {
     }
 }
 
-/// Data source trait for streaming observability data
+/// Bayesian trust calculator: models each component's trust as a Beta
+/// distribution (`alpha` successes, `beta` failures) built up from metric
+/// readings, with exponential decay so stale evidence fades and a recent
+/// sustained trend moves the score more than a single spike.
+pub struct BayesianTrustCalculator {
+    pub name: String,
+    /// Metric name -> (importance weight, "good" threshold). A reading at
+    /// or above the threshold adds `weight` to `alpha`; below adds it to
+    /// `beta`.
+    pub metric_config: HashMap<String, (f64, f64)>,
+    /// Decay half-life in seconds: accumulated alpha/beta are multiplied by
+    /// `0.5^(elapsed/half_life)` before folding in a new reading.
+    pub half_life_secs: f64,
+    state: Mutex<HashMap<String, BetaState>>,
+}
+
+struct BetaState {
+    alpha: f64,
+    beta: f64,
+    last_updated: DateTime<Utc>,
+}
+
+impl BayesianTrustCalculator {
+    pub fn new(name: String, metric_config: HashMap<String, (f64, f64)>, half_life_secs: f64) -> Self {
+        Self {
+            name,
+            metric_config,
+            half_life_secs,
+            state: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn map_metric_to_factor(&self, metric_name: &str) -> FactorType {
+        match metric_name {
+            "security_score" => FactorType::SecurityEvent,
+            "performance_score" => FactorType::PerformanceMetric,
+            "behavioral_score" => FactorType::BehavioralAnomaly,
+            "compliance_score" => FactorType::ComplianceStatus,
+            "dependency_score" => FactorType::DependencyHealth,
+            "communication_score" => FactorType::CommunicationQuality,
+            _ => FactorType::PerformanceMetric,
+        }
+    }
+}
+
+impl TrustCalculator for BayesianTrustCalculator {
+    fn calculate_trust(&self, data: &ObservabilityData) -> TrustScoreResult {
+        let mut state = self.state.lock().unwrap();
+        let entry = state.entry(data.component_id.clone()).or_insert_with(|| BetaState {
+            alpha: 1.0,
+            beta: 1.0,
+            last_updated: data.timestamp,
+        });
+
+        let elapsed = (data.timestamp - entry.last_updated).num_seconds().max(0) as f64;
+        if self.half_life_secs > 0.0 && elapsed > 0.0 {
+            let decay = 0.5_f64.powf(elapsed / self.half_life_secs);
+            entry.alpha *= decay;
+            entry.beta *= decay;
+        }
+        entry.last_updated = data.timestamp;
+
+        let mut contributing_factors = Vec::new();
+        for (metric_name, value) in &data.metrics {
+            if let Some(&(importance, good_threshold)) = self.metric_config.get(metric_name) {
+                if *value >= good_threshold {
+                    entry.alpha += importance;
+                } else {
+                    entry.beta += importance;
+                }
+
+                contributing_factors.push(ContributingFactor {
+                    factor_type: self.map_metric_to_factor(metric_name),
+                    weight: importance,
+                    value: *value,
+                    description: format!("{}: {:.3} (threshold {:.3})", metric_name, value, good_threshold),
+                });
+            }
+        }
+
+        let evidence = entry.alpha + entry.beta;
+        TrustScoreResult {
+            score: entry.alpha / evidence,
+            confidence: 1.0 - 1.0 / (evidence + 1.0),
+            contributing_factors,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn get_calculator_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn update_parameters(&mut self, params: HashMap<String, f64>) {
+        if let Some(&half_life_secs) = params.get("half_life_secs") {
+            self.half_life_secs = half_life_secs;
+        }
+    }
+}
+
+/// Detects per-metric outliers with a Hampel filter: keeps a sliding window
+/// of each component's recent values per metric, compares a new reading
+/// against the window median `m` and median absolute deviation (MAD), and
+/// flags it anomalous when `|x - m| > k * 1.4826 * MAD`. When
+/// `seasonal_period` is set, a rolling mean over that many prior samples is
+/// subtracted from the reading and from each windowed value first, so a
+/// periodic baseline (daily load cycle, etc.) doesn't itself look like an
+/// outlier.
+pub struct ThresholdAnomalyCalculator {
+    pub name: String,
+    pub window_size: usize,
+    pub k: f64,
+    pub seasonal_period: Option<usize>,
+    history: Mutex<HashMap<String, HashMap<String, VecDeque<f64>>>>,
+}
+
+impl ThresholdAnomalyCalculator {
+    pub fn new(name: String, window_size: usize, k: f64, seasonal_period: Option<usize>) -> Self {
+        Self {
+            name,
+            window_size,
+            k,
+            seasonal_period,
+            history: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn median(values: &VecDeque<f64>) -> f64 {
+        let mut sorted: Vec<f64> = values.iter().cloned().collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mid = sorted.len() / 2;
+        if sorted.len() % 2 == 0 {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        }
+    }
+
+    fn median_absolute_deviation(values: &VecDeque<f64>, median: f64) -> f64 {
+        let deviations: VecDeque<f64> = values.iter().map(|v| (v - median).abs()).collect();
+        Self::median(&deviations)
+    }
+}
+
+impl TrustCalculator for ThresholdAnomalyCalculator {
+    fn calculate_trust(&self, data: &ObservabilityData) -> TrustScoreResult {
+        let mut history = self.history.lock().unwrap();
+        let component_history = history.entry(data.component_id.clone()).or_insert_with(HashMap::new);
+
+        let mut contributing_factors = Vec::new();
+        let mut total_penalty = 0.0;
+        let mut metric_count = 0;
+
+        for (metric_name, value) in &data.metrics {
+            // A data source can hand back NaN (e.g. Prometheus's literal
+            // "NaN" for a query like `0/0`, parsed straight through by
+            // `PrometheusDataSource::instant_query`). `median`'s
+            // `partial_cmp().unwrap()` panics the moment one reaches
+            // `window`, so skip it here rather than let it in.
+            if !value.is_finite() {
+                continue;
+            }
+
+            let window = component_history.entry(metric_name.clone()).or_insert_with(VecDeque::new);
+            metric_count += 1;
+
+            let (adjusted_value, baseline): (f64, VecDeque<f64>) = match self.seasonal_period {
+                Some(period) if period > 0 && window.len() > period => {
+                    let rolling_mean = |end: usize| window.range(end - period..end).sum::<f64>() / period as f64;
+                    let residuals = (period..window.len()).map(|i| window[i] - rolling_mean(i)).collect();
+                    (value - rolling_mean(window.len()), residuals)
+                }
+                _ => (*value, window.clone()),
+            };
+
+            if baseline.len() >= 2 {
+                let median = Self::median(&baseline);
+                let mad = Self::median_absolute_deviation(&baseline, median);
+                let threshold = self.k * 1.4826 * mad;
+                let distance = (adjusted_value - median).abs();
+
+                if threshold > 0.0 && distance > threshold {
+                    let severity = (distance / threshold - 1.0).min(1.0).max(0.0);
+                    total_penalty += severity;
+                    contributing_factors.push(ContributingFactor {
+                        factor_type: FactorType::BehavioralAnomaly,
+                        weight: severity,
+                        value: *value,
+                        description: format!(
+                            "{} anomalous: deviates {:.2}x the {:.1}-sigma-equivalent threshold",
+                            metric_name, distance / threshold, self.k
+                        ),
+                    });
+                }
+            }
+
+            window.push_back(*value);
+            if window.len() > self.window_size {
+                window.pop_front();
+            }
+        }
+
+        let score = if metric_count > 0 {
+            (1.0 - total_penalty / metric_count as f64).max(0.0)
+        } else {
+            1.0
+        };
+
+        TrustScoreResult {
+            score,
+            confidence: if metric_count > 0 { 0.8 } else { 0.0 },
+            contributing_factors,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn get_calculator_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn update_parameters(&mut self, params: HashMap<String, f64>) {
+        if let Some(&k) = params.get("k") {
+            self.k = k;
+        }
+    }
+}
+
+/// Data source trait for streaming and pulling observability data
+#[async_trait::async_trait]
 pub trait DataSource {
     fn get_data_stream(&self) -> Box<dyn Stream<Item = ObservabilityData> + Send + Unpin>;
     fn get_source_name(&self) -> String;
     fn is_healthy(&self) -> bool;
+
+    /// Pull the latest metrics for a single component from this source, by
+    /// name, on demand -- the counterpart to `get_data_stream` for sources
+    /// that are queried per-component (e.g. Prometheus) rather than pushed.
+    /// Sources that don't support per-component pull (e.g. `ELKDataSource`,
+    /// which only produces logs) can leave this at its default of no metrics.
+    async fn fetch_metrics(&self, _component_id: &str) -> HashMap<String, f64> {
+        HashMap::new()
+    }
 }
 
-/// Prometheus data source
+/// Prometheus data source. Issues a PromQL instant query per configured
+/// metric (see `metric_queries`) and maps the results into the metric
+/// names `WeightedAverageCalculator` and friends expect, e.g.
+/// `security_score`/`performance_score`/`behavioral_score`.
 pub struct PrometheusDataSource {
     pub endpoint: String,
-    pub query: String,
     pub name: String,
+    /// Metric name -> PromQL instant-query template. Any `{component_id}`
+    /// placeholder in the template is substituted with the component being
+    /// polled before the query is issued, e.g.
+    /// `avg(security_events{component="{component_id}"})`.
+    pub metric_queries: HashMap<String, String>,
+    client: reqwest::Client,
+}
+
+impl PrometheusDataSource {
+    pub fn new(name: impl Into<String>, endpoint: impl Into<String>, metric_queries: HashMap<String, String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            name: name.into(),
+            metric_queries,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Issue a single PromQL instant query and pull the scalar value out of
+    /// the first result, per Prometheus's `/api/v1/query` response shape.
+    async fn instant_query(&self, promql: &str) -> Option<f64> {
+        let url = format!("{}/api/v1/query", self.endpoint.trim_end_matches('/'));
+        let response = self.client.get(&url).query(&[("query", promql)]).send().await.ok()?;
+        let body: serde_json::Value = response.json().await.ok()?;
+        let value = body.get("data")?.get("result")?.as_array()?.first()?.get("value")?.as_array()?.get(1)?.as_str()?;
+        value.parse::<f64>().ok()
+    }
 }
 
+#[async_trait::async_trait]
 impl DataSource for PrometheusDataSource {
     fn get_data_stream(&self) -> Box<dyn Stream<Item = ObservabilityData> + Send + Unpin> {
-        // Simplified implementation - real version would connect to Prometheus
-        Box::new(tokio_stream::iter(vec![
-            ObservabilityData {
-                component_id: "test".to_string(),
-                timestamp: Utc::now(),
-                metrics: HashMap::from([
-                    ("security_score".to_string(), 0.8),
-                    ("performance_score".to_string(), 0.9),
-                    ("behavioral_score".to_string(), 0.7),
-                ]),
-                logs: Vec::new(),
-                traces: Vec::new(),
-            }
-        ]))
+        // Prometheus is queried on demand, per component, via fetch_metrics
+        // below rather than pushed as a stream.
+        Box::new(tokio_stream::iter(Vec::new()))
     }
-    
+
     fn get_source_name(&self) -> String {
         self.name.clone()
     }
-    
+
     fn is_healthy(&self) -> bool {
         // Simplified health check
         true
     }
+
+    async fn fetch_metrics(&self, component_id: &str) -> HashMap<String, f64> {
+        let mut metrics = HashMap::new();
+        for (metric_name, query_template) in &self.metric_queries {
+            let promql = query_template.replace("{component_id}", component_id);
+            if let Some(value) = self.instant_query(&promql).await {
+                metrics.insert(metric_name.clone(), value);
+            }
+        }
+        metrics
+    }
 }
 
 /// ELK Stack data source
@@ -336,6 +701,7 @@ pub struct ELKDataSource {
     pub name: String,
 }
 
+#[async_trait::async_trait]
 impl DataSource for ELKDataSource {
     fn get_data_stream(&self) -> Box<dyn Stream<Item = ObservabilityData> + Send + Unpin> {
         // Simplified implementation
@@ -411,20 +777,93 @@ pub struct ScoringStage {
     pub enabled: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum AggregationMethod {
     WeightedAverage,
     Maximum,
     Minimum,
     Median,
     Ensemble,
+    /// An unrecognized value from a newer build; aggregation degrades to
+    /// `WeightedAverage` rather than failing the scoring pipeline.
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(AggregationMethod {
+    WeightedAverage => "WeightedAverage",
+    Maximum => "Maximum",
+    Minimum => "Minimum",
+    Median => "Median",
+    Ensemble => "Ensemble",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlertManager {
     pub alerts: Vec<Alert>,
     pub escalation_policies: Vec<EscalationPolicy>,
     pub notification_channels: Vec<NotificationChannel>,
+    /// Timestamp each `(component, policy, condition)` triple most recently
+    /// became continuously true, keyed by `"{component_id}::{policy_id}::
+    /// {condition_index}"`. Removed as soon as the condition clears so the
+    /// hold duration resets.
+    #[serde(default)]
+    pub condition_since: HashMap<String, DateTime<Utc>>,
+    /// Timestamp each `(component, policy)` pair's conditions have *all*
+    /// held continuously for at least their configured durations, keyed by
+    /// `"{component_id}::{policy_id}"`. Anchors the `EscalationLevel` ladder.
+    #[serde(default)]
+    pub policy_since: HashMap<String, DateTime<Utc>>,
+    /// Highest `EscalationLevel.level` already acted on for a
+    /// `(component, policy)` pair, so a policy that is still satisfied on
+    /// the next tick doesn't re-fire the same level's actions.
+    #[serde(default)]
+    pub escalated_levels: HashMap<String, u32>,
+    /// Time-windowed rules (e.g. maintenance windows) that move matching
+    /// alerts to `AlertStatus::Suppressed` instead of notifying on them.
+    #[serde(default)]
+    pub suppression_rules: Vec<SuppressionRule>,
+    /// Rules that route matching alerts onto a set of `NotificationChannel`s.
+    #[serde(default)]
+    pub grouping_rules: Vec<GroupingRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressionRule {
+    pub rule_id: String,
+    /// `None` matches alerts from any component.
+    pub component_id: Option<String>,
+    /// `None` matches any `AlertType`.
+    pub alert_type: Option<AlertType>,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+}
+
+impl SuppressionRule {
+    fn matches(&self, component_id: &str, alert_type: &AlertType) -> bool {
+        self.component_id.as_deref().map_or(true, |id| id == component_id)
+            && self.alert_type.as_ref().map_or(true, |t| t.to_string() == alert_type.to_string())
+    }
+
+    fn is_active(&self, now: DateTime<Utc>) -> bool {
+        now >= self.window_start && now <= self.window_end
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupingRule {
+    pub rule_id: String,
+    /// `None` matches alerts from any component.
+    pub component_id: Option<String>,
+    /// `None` matches any `AlertType`.
+    pub alert_type: Option<AlertType>,
+    pub channel_ids: Vec<String>,
+}
+
+impl GroupingRule {
+    fn matches(&self, component_id: &str, alert_type: &AlertType) -> bool {
+        self.component_id.as_deref().map_or(true, |id| id == component_id)
+            && self.alert_type.as_ref().map_or(true, |t| t.to_string() == alert_type.to_string())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -434,11 +873,26 @@ pub struct Alert {
     pub alert_type: AlertType,
     pub severity: AlertSeverity,
     pub message: String,
+    /// When this fingerprint was first observed.
     pub timestamp: DateTime<Utc>,
+    /// When this fingerprint was most recently re-observed.
+    pub last_seen: DateTime<Utc>,
+    /// How many ticks in a row this fingerprint has fired, collapsed into
+    /// this single `Alert` instead of spawning a duplicate each time.
+    pub occurrence_count: u32,
     pub status: AlertStatus,
+    /// Highest `EscalationLevel.level` reached so far (0 = not yet
+    /// escalated past the initial page).
+    pub escalation_level: u32,
+    /// When `escalation_level` last advanced (or when the alert was first
+    /// recorded, if it hasn't escalated yet) -- the clock
+    /// `escalate_stale_alerts` measures each level's `timeout` against.
+    pub last_escalated_at: DateTime<Utc>,
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum AlertType {
     TrustScoreLow,
     SecurityViolation,
@@ -446,8 +900,18 @@ pub enum AlertType {
     BehavioralAnomaly,
     DependencyFailure,
     CommunicationFailure,
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(AlertType {
+    TrustScoreLow => "TrustScoreLow",
+    SecurityViolation => "SecurityViolation",
+    PerformanceDegradation => "PerformanceDegradation",
+    BehavioralAnomaly => "BehavioralAnomaly",
+    DependencyFailure => "DependencyFailure",
+    CommunicationFailure => "CommunicationFailure",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AlertSeverity {
     Low,
@@ -480,7 +944,7 @@ pub struct EscalationCondition {
     pub duration: Duration,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum ComparisonOperator {
     GreaterThan,
     LessThan,
@@ -488,8 +952,18 @@ pub enum ComparisonOperator {
     NotEqualTo,
     GreaterThanOrEqual,
     LessThanOrEqual,
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(ComparisonOperator {
+    GreaterThan => "GreaterThan",
+    LessThan => "LessThan",
+    EqualTo => "EqualTo",
+    NotEqualTo => "NotEqualTo",
+    GreaterThanOrEqual => "GreaterThanOrEqual",
+    LessThanOrEqual => "LessThanOrEqual",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EscalationAction {
     pub action_type: ActionType,
@@ -497,129 +971,867 @@ pub struct EscalationAction {
     pub parameters: HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum ActionType {
     SendNotification,
     TriggerWorkflow,
     IsolateComponent,
     ScaleResources,
     UpdateConfiguration,
+    UnknownValue(String),
 }
 
+forward_compatible_enum!(ActionType {
+    SendNotification => "SendNotification",
+    TriggerWorkflow => "TriggerWorkflow",
+    IsolateComponent => "IsolateComponent",
+    ScaleResources => "ScaleResources",
+    UpdateConfiguration => "UpdateConfiguration",
+});
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationChannel {
     pub channel_id: String,
     pub channel_type: ChannelType,
+    /// The channel's webhook URL for `Slack`/`DiscordWebhook`/`Webhook`, or
+    /// the bot token for `Telegram` (paired with `telegram_chat_id`).
     pub endpoint: String,
     pub enabled: bool,
+    /// Chat id to deliver to; only meaningful for `ChannelType::Telegram`.
+    #[serde(default)]
+    pub telegram_chat_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl NotificationChannel {
+    pub fn slack(channel_id: &str, webhook_url: &str) -> Self {
+        Self { channel_id: channel_id.to_string(), channel_type: ChannelType::Slack, endpoint: webhook_url.to_string(), enabled: true, telegram_chat_id: None }
+    }
+
+    pub fn discord(channel_id: &str, webhook_url: &str) -> Self {
+        Self { channel_id: channel_id.to_string(), channel_type: ChannelType::DiscordWebhook, endpoint: webhook_url.to_string(), enabled: true, telegram_chat_id: None }
+    }
+
+    pub fn webhook(channel_id: &str, endpoint: &str) -> Self {
+        Self { channel_id: channel_id.to_string(), channel_type: ChannelType::Webhook, endpoint: endpoint.to_string(), enabled: true, telegram_chat_id: None }
+    }
+
+    pub fn telegram(channel_id: &str, bot_token: &str, chat_id: &str) -> Self {
+        Self { channel_id: channel_id.to_string(), channel_type: ChannelType::Telegram, endpoint: bot_token.to_string(), enabled: true, telegram_chat_id: Some(chat_id.to_string()) }
+    }
+
+    /// Build whichever channels have their environment variables set, so a
+    /// deployment can wire up notifications without code:
+    /// `SLACK_WEBHOOK`, `DISCORD_WEBHOOK`, and `TELEGRAM_BOT_TOKEN` +
+    /// `TELEGRAM_CHAT_ID` (both required together for Telegram).
+    pub fn from_env() -> Vec<Self> {
+        let mut channels = Vec::new();
+
+        if let Ok(webhook) = std::env::var("SLACK_WEBHOOK") {
+            channels.push(Self::slack("slack-env", &webhook));
+        }
+        if let Ok(webhook) = std::env::var("DISCORD_WEBHOOK") {
+            channels.push(Self::discord("discord-env", &webhook));
+        }
+        if let (Ok(token), Ok(chat_id)) = (std::env::var("TELEGRAM_BOT_TOKEN"), std::env::var("TELEGRAM_CHAT_ID")) {
+            channels.push(Self::telegram("telegram-env", &token, &chat_id));
+        }
+
+        channels
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum ChannelType {
     Email,
     Slack,
+    DiscordWebhook,
     Webhook,
+    Telegram,
     PagerDuty,
     Custom,
+    UnknownValue(String),
 }
 
-impl ContinualAssuranceEngine {
+forward_compatible_enum!(ChannelType {
+    Email => "Email",
+    Slack => "Slack",
+    DiscordWebhook => "DiscordWebhook",
+    Webhook => "Webhook",
+    Telegram => "Telegram",
+    PagerDuty => "PagerDuty",
+    Custom => "Custom",
+});
+
+/// Delivers a rendered alert message to a single `NotificationChannel`.
+/// Kept as a trait (rather than calling `reqwest` directly from
+/// `AlertManager`) so tests can swap in a fake instead of making real HTTP
+/// calls.
+#[async_trait::async_trait]
+pub trait NotificationDispatcher {
+    async fn send(&self, channel: &NotificationChannel, component_id: &str, message: &str) -> Result<(), String>;
+}
+
+/// Posts alerts to Slack/Discord-style incoming webhooks (JSON body with a
+/// `text` field), generic HTTP webhooks (JSON body with `component_id`/
+/// `message`), and the Telegram Bot API's `sendMessage` endpoint. Retries
+/// transient failures (request errors and 5xx responses) with exponential
+/// backoff before giving up.
+pub struct HttpNotificationDispatcher {
+    client: reqwest::Client,
+    max_retries: u32,
+    base_backoff: Duration,
+}
+
+impl HttpNotificationDispatcher {
     pub fn new() -> Self {
-        Self {
-            component_registry: Arc::new(RwLock::new(HashMap::new())),
-            trust_calculators: Arc::new(RwLock::new(HashMap::new())),
-            data_sources: Arc::new(RwLock::new(Vec::new())),
-            scoring_pipeline: Arc::new(RwLock::new(ScoringPipeline {
-                stages: Vec::new(),
-                aggregation_method: AggregationMethod::WeightedAverage,
-                update_frequency: Duration::from_secs(30),
-            })),
-            alert_manager: Arc::new(RwLock::new(AlertManager {
-                alerts: Vec::new(),
-                escalation_policies: Vec::new(),
-                notification_channels: Vec::new(),
-            })),
-        }
+        Self::with_retry_policy(3, Duration::from_millis(200))
     }
 
-    /// Register a component for monitoring
-    pub async fn register_component(&self, component_id: String, config: MonitoringConfig) {
-        let monitor = ComponentMonitor {
-            component_id: component_id.clone(),
-            component_type: "microservice".to_string(),
-            current_trust_score: 0.5,
-            trust_history: Vec::new(),
-            monitoring_config: config,
-            last_updated: Utc::now(),
-            status: ComponentStatus::Unknown,
-        };
-        
-        let mut registry = self.component_registry.write().await;
-        registry.insert(component_id, monitor);
+    pub fn with_retry_policy(max_retries: u32, base_backoff: Duration) -> Self {
+        Self { client: reqwest::Client::new(), max_retries, base_backoff }
     }
 
-    /// Add a trust calculator
-    pub async fn add_trust_calculator(&self, name: String, calculator: Box<dyn TrustCalculator + Send + Sync>) {
-        let mut calculators = self.trust_calculators.write().await;
-        calculators.insert(name, calculator);
+    fn request_url(channel: &NotificationChannel) -> String {
+        match channel.channel_type {
+            ChannelType::Telegram => format!("https://api.telegram.org/bot{}/sendMessage", channel.endpoint),
+            _ => channel.endpoint.clone(),
+        }
     }
 
-    /// Add a data source
-    pub async fn add_data_source(&self, source: Box<dyn DataSource + Send + Sync>) {
-        let mut sources = self.data_sources.write().await;
-        sources.push(source);
+    fn request_body(channel: &NotificationChannel, component_id: &str, message: &str) -> serde_json::Value {
+        let text = format!("[{}] {}", component_id, message);
+        match channel.channel_type {
+            ChannelType::Telegram => serde_json::json!({
+                "chat_id": channel.telegram_chat_id.clone().unwrap_or_default(),
+                "text": text,
+            }),
+            ChannelType::Slack | ChannelType::DiscordWebhook => serde_json::json!({ "text": text }),
+            _ => serde_json::json!({ "component_id": component_id, "message": message }),
+        }
     }
+}
 
-    /// Start the continual assurance process
-    pub async fn start_monitoring(&self) -> Result<(), String> {
-        let update_interval = Duration::from_secs(30);
-        let mut interval = IntervalStream::new(tokio::time::interval(update_interval));
-        
-        while let Some(_) = interval.next().await {
-            if let Err(e) = self.update_all_trust_scores().await {
-                eprintln!("Error updating trust scores: {}", e);
+impl Default for HttpNotificationDispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationDispatcher for HttpNotificationDispatcher {
+    async fn send(&self, channel: &NotificationChannel, component_id: &str, message: &str) -> Result<(), String> {
+        let url = Self::request_url(channel);
+        let body = Self::request_body(channel, component_id, message);
+
+        let mut attempt = 0;
+        loop {
+            let outcome = self.client.post(&url).json(&body).send().await;
+            let transient_failure = match &outcome {
+                Ok(response) => response.status().is_server_error(),
+                Err(e) => !e.is_builder() && !e.is_redirect(),
+            };
+
+            if !transient_failure {
+                return match outcome {
+                    Ok(response) if !response.status().is_success() => Err(format!("notification to {} failed: HTTP {}", url, response.status())),
+                    other => other.map(|_| ()).map_err(|e| format!("notification to {} failed: {}", url, e)),
+                };
+            }
+            if attempt >= self.max_retries {
+                return Err(match outcome {
+                    Ok(response) => format!("notification to {} failed after {} attempts: HTTP {}", url, attempt + 1, response.status()),
+                    Err(e) => format!("notification to {} failed after {} attempts: {}", url, attempt + 1, e),
+                });
             }
+
+            tokio::time::sleep(self.base_backoff * 2u32.pow(attempt)).await;
+            attempt += 1;
         }
-        
-        Ok(())
     }
+}
 
-    /// Update trust scores for all registered components
-    async fn update_all_trust_scores(&self) -> Result<(), String> {
-        let registry = self.component_registry.read().await;
-        let calculators = self.trust_calculators.read().await;
-        let sources = self.data_sources.read().await;
-        
-        for (component_id, monitor) in registry.iter() {
-            // Collect data from all sources
-            let mut all_data = Vec::new();
-            for source in sources.iter() {
-                let mut stream = source.get_data_stream();
-                while let Some(data) = stream.next().await {
-                    if data.component_id == *component_id {
-                        all_data.push(data);
+/// A directed, weighted dependency/vouching edge: `from` derives part of
+/// its trust from `to`, proportionally to `weight` (clamped to `[0, 1]`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustDependencyEdge {
+    pub target: String,
+    pub weight: f64,
+}
+
+/// A directed weighted graph of component dependency/vouching
+/// relationships used by `CalculationMethod::TrustGraph` components to
+/// derive an effective trust score from their dependencies, the way a
+/// web-of-trust does.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyTrustGraph {
+    pub edges: HashMap<String, Vec<TrustDependencyEdge>>,
+    /// Multiplier applied to the path weight on every additional hop, so
+    /// trust contributed by indirect dependencies fades with distance.
+    pub decay: f64,
+    /// A path is abandoned once its accumulated weight drops below this.
+    pub epsilon: f64,
+    /// How much a node's own direct score counts relative to its inherited
+    /// trust in `effective = direct_score * self_weight + inherited`.
+    pub self_weight: f64,
+}
+
+/// A candidate `(node, accumulated path weight)` entry in the best-first
+/// propagation queue, ordered so the heaviest path to any node is explored
+/// -- and therefore wins the node's first, highest-weight visit -- before
+/// any lighter path to the same node.
+#[derive(Debug, Clone)]
+struct WeightedPathEntry {
+    node: String,
+    path_weight: f64,
+}
+
+impl PartialEq for WeightedPathEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.path_weight == other.path_weight
+    }
+}
+
+impl Eq for WeightedPathEntry {}
+
+impl PartialOrd for WeightedPathEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WeightedPathEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.path_weight.total_cmp(&other.path_weight)
+    }
+}
+
+impl DependencyTrustGraph {
+    pub fn new(decay: f64, epsilon: f64, self_weight: f64) -> Self {
+        Self {
+            edges: HashMap::new(),
+            decay,
+            epsilon,
+            self_weight,
+        }
+    }
+
+    /// Register `from -> to` as "from's trust partially derives from to".
+    pub fn add_dependency(&mut self, from: String, to: String, weight: f64) {
+        self.edges.entry(from).or_insert_with(Vec::new).push(TrustDependencyEdge {
+            target: to,
+            weight: weight.clamp(0.0, 1.0),
+        });
+    }
+
+    /// Compute effective trust for every component with a direct score.
+    pub fn compute_effective_trust(&self, direct_scores: &HashMap<String, f64>) -> HashMap<String, f64> {
+        direct_scores.keys()
+            .map(|component_id| (component_id.clone(), self.effective_trust_for(component_id, direct_scores)))
+            .collect()
+    }
+
+    /// Bounded best-first propagation from `start`: at each hop the path
+    /// weight is multiplied by the edge weight and `decay`, a path is
+    /// abandoned once its weight drops below `epsilon`, and a visited set
+    /// guarantees a node already reached on a heavier path is skipped --
+    /// its first (highest-weight) contribution wins.
+    fn effective_trust_for(&self, start: &str, direct_scores: &HashMap<String, f64>) -> f64 {
+        let direct_score = *direct_scores.get(start).unwrap_or(&0.0);
+
+        let mut visited: HashSet<String> = HashSet::new();
+        visited.insert(start.to_string());
+
+        let mut queue = BinaryHeap::new();
+        if let Some(deps) = self.edges.get(start) {
+            for edge in deps {
+                if edge.weight >= self.epsilon {
+                    queue.push(WeightedPathEntry { node: edge.target.clone(), path_weight: edge.weight });
+                }
+            }
+        }
+
+        let mut weighted_sum = 0.0;
+        let mut total_weight = 0.0;
+
+        while let Some(WeightedPathEntry { node, path_weight }) = queue.pop() {
+            if path_weight < self.epsilon || visited.contains(&node) {
+                continue;
+            }
+            visited.insert(node.clone());
+
+            let parent_direct = *direct_scores.get(&node).unwrap_or(&0.0);
+            weighted_sum += path_weight * parent_direct;
+            total_weight += path_weight;
+
+            if let Some(deps) = self.edges.get(&node) {
+                for edge in deps {
+                    if visited.contains(&edge.target) {
+                        continue;
+                    }
+                    let next_weight = path_weight * edge.weight * self.decay;
+                    if next_weight >= self.epsilon {
+                        queue.push(WeightedPathEntry { node: edge.target.clone(), path_weight: next_weight });
                     }
                 }
             }
-            
-            if !all_data.is_empty() {
+        }
+
+        let inherited = if total_weight > 0.0 { weighted_sum / total_weight } else { 0.0 };
+        direct_score * self.self_weight + inherited
+    }
+}
+
+impl AlertManager {
+    /// Alerts are deduplicated on this fingerprint instead of their
+    /// `alert_id`, so the same underlying condition re-observed on
+    /// consecutive ticks updates one `Alert` rather than spawning a new one.
+    fn fingerprint(component_id: &str, alert_type: &AlertType, severity: &AlertSeverity) -> String {
+        format!("{}::{}::{:?}", component_id, alert_type, severity)
+    }
+
+    /// Record an observed alert condition: updates the matching still-open
+    /// `Alert` if one exists (bumping `last_seen`/`occurrence_count`)
+    /// instead of appending a duplicate, applies suppression rules, and
+    /// returns the `NotificationChannel`s this alert should be routed to
+    /// per the configured grouping rules.
+    ///
+    /// A fingerprint match (same component, alert type *and* severity) only
+    /// bumps the existing alert's occurrence count and returns no channels
+    /// -- a severity change mints a new fingerprint and so is always
+    /// routed. This is what keeps a trust score flapping around a
+    /// threshold from spamming the channel on every tick: only the
+    /// transition into (or change of) an alert actually notifies.
+    pub fn record_alert(&mut self, component_id: &str, alert_type: AlertType, severity: AlertSeverity, message: String, now: DateTime<Utc>) -> Vec<NotificationChannel> {
+        let fingerprint = Self::fingerprint(component_id, &alert_type, &severity);
+        let suppressed = self.suppression_rules.iter()
+            .any(|rule| rule.is_active(now) && rule.matches(component_id, &alert_type));
+
+        let existing = self.alerts.iter_mut().find(|alert| {
+            matches!(alert.status, AlertStatus::Active | AlertStatus::Suppressed)
+                && Self::fingerprint(&alert.component_id, &alert.alert_type, &alert.severity) == fingerprint
+        });
+
+        let is_transition = existing.is_none();
+
+        match existing {
+            Some(alert) => {
+                alert.last_seen = now;
+                alert.occurrence_count += 1;
+                alert.message = message;
+                alert.status = if suppressed { AlertStatus::Suppressed } else { AlertStatus::Active };
+            }
+            None => {
+                self.alerts.push(Alert {
+                    alert_id: format!("{}-{}-{}", component_id, alert_type, now.timestamp()),
+                    component_id: component_id.to_string(),
+                    alert_type: alert_type.clone(),
+                    severity,
+                    message,
+                    timestamp: now,
+                    last_seen: now,
+                    occurrence_count: 1,
+                    status: if suppressed { AlertStatus::Suppressed } else { AlertStatus::Active },
+                    escalation_level: 0,
+                    last_escalated_at: now,
+                    acknowledged_at: None,
+                    resolved_at: None,
+                });
+            }
+        }
+
+        if suppressed || !is_transition {
+            return Vec::new();
+        }
+
+        let channel_ids: Vec<&String> = self.grouping_rules.iter()
+            .filter(|rule| rule.matches(component_id, &alert_type))
+            .flat_map(|rule| rule.channel_ids.iter())
+            .collect();
+
+        self.notification_channels.iter()
+            .filter(|channel| channel.enabled && channel_ids.iter().any(|id| **id == channel.channel_id))
+            .cloned()
+            .collect()
+    }
+
+    pub fn add_suppression_rule(&mut self, rule: SuppressionRule) {
+        self.suppression_rules.push(rule);
+    }
+
+    pub fn remove_suppression_rule(&mut self, rule_id: &str) {
+        self.suppression_rules.retain(|rule| rule.rule_id != rule_id);
+    }
+
+    pub fn add_grouping_rule(&mut self, rule: GroupingRule) {
+        self.grouping_rules.push(rule);
+    }
+
+    pub fn remove_grouping_rule(&mut self, rule_id: &str) {
+        self.grouping_rules.retain(|rule| rule.rule_id != rule_id);
+    }
+
+    /// The deduplicated set of currently-active (non-suppressed) alerts.
+    pub fn active_alerts(&self) -> Vec<Alert> {
+        self.alerts.iter()
+            .filter(|alert| matches!(alert.status, AlertStatus::Active))
+            .cloned()
+            .collect()
+    }
+
+    /// Acknowledge an alert, taking it off the escalation ladder until it's
+    /// either resolved or a fresh occurrence of the same condition opens a
+    /// new `Alert` with the same fingerprint.
+    pub fn acknowledge_alert(&mut self, alert_id: &str, now: DateTime<Utc>) -> Result<(), String> {
+        let alert = self.alerts.iter_mut()
+            .find(|alert| alert.alert_id == alert_id)
+            .ok_or_else(|| format!("no alert with id '{}'", alert_id))?;
+        alert.status = AlertStatus::Acknowledged;
+        alert.acknowledged_at = Some(now);
+        Ok(())
+    }
+
+    /// Mark an alert resolved, taking it off the escalation ladder for
+    /// good.
+    pub fn resolve_alert(&mut self, alert_id: &str, now: DateTime<Utc>) -> Result<(), String> {
+        let alert = self.alerts.iter_mut()
+            .find(|alert| alert.alert_id == alert_id)
+            .ok_or_else(|| format!("no alert with id '{}'", alert_id))?;
+        alert.status = AlertStatus::Resolved;
+        alert.resolved_at = Some(now);
+        Ok(())
+    }
+
+    /// Advance every still-`Active` alert that has sat at its current
+    /// level for at least the next `EscalationLevel.timeout` (looked up by
+    /// its owning component in `levels_by_component`), bumping its
+    /// severity and returning the channel ids the newly-reached level
+    /// should page. An alert that has reached its component's highest
+    /// configured level simply stays there until acknowledged or resolved.
+    pub fn escalate_stale_alerts(&mut self, levels_by_component: &HashMap<String, Vec<EscalationLevel>>, now: DateTime<Utc>) -> Vec<(Alert, Vec<String>)> {
+        let mut escalated = Vec::new();
+
+        for alert in self.alerts.iter_mut() {
+            if !matches!(alert.status, AlertStatus::Active) {
+                continue;
+            }
+            let Some(levels) = levels_by_component.get(&alert.component_id) else {
+                continue;
+            };
+
+            let next_level = levels.iter()
+                .filter(|level| level.level > alert.escalation_level)
+                .min_by_key(|level| level.level);
+
+            let Some(next_level) = next_level else {
+                continue;
+            };
+
+            let elapsed = now.signed_duration_since(alert.last_escalated_at).to_std().unwrap_or_default();
+            if elapsed < next_level.timeout {
+                continue;
+            }
+
+            alert.escalation_level = next_level.level;
+            alert.last_escalated_at = now;
+            alert.severity = Self::bump_severity(&alert.severity);
+            escalated.push((alert.clone(), next_level.actions.clone()));
+        }
+
+        escalated
+    }
+
+    fn bump_severity(current: &AlertSeverity) -> AlertSeverity {
+        match current {
+            AlertSeverity::Low => AlertSeverity::Medium,
+            AlertSeverity::Medium => AlertSeverity::High,
+            AlertSeverity::High | AlertSeverity::Critical => AlertSeverity::Critical,
+        }
+    }
+}
+
+/// On-disk JSON store for registered components' `MonitoringConfig`s.
+/// `register_component` writes through to this on every call, and
+/// `ContinualAssuranceEngine::new` reloads it, so restarting the process
+/// doesn't lose registrations the way the in-memory-only registry used to.
+#[derive(Debug, Clone)]
+pub struct PersistentConfigStore {
+    path: std::path::PathBuf,
+}
+
+impl PersistentConfigStore {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Load whatever was last persisted, or an empty registry if the file
+    /// doesn't exist yet or fails to parse.
+    fn load(&self) -> HashMap<String, MonitoringConfig> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                eprintln!("Failed to parse persisted component registry at {}: {}", self.path.display(), e);
+                HashMap::new()
+            }),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    fn save(&self, configs: &HashMap<String, MonitoringConfig>) {
+        let json = match serde_json::to_string_pretty(configs) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("Failed to serialize component registry: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = std::fs::write(&self.path, json) {
+            eprintln!("Failed to persist component registry to {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+impl Default for PersistentConfigStore {
+    fn default() -> Self {
+        Self::new("component_registry.json")
+    }
+}
+
+/// Reconnect bookkeeping for a single `DataSource`, keyed by
+/// `DataSource::get_source_name`.
+#[derive(Debug, Clone)]
+pub struct SourceHealthState {
+    consecutive_failures: u32,
+    /// When this source first started failing its health check, cleared
+    /// the moment it reports healthy again.
+    unreachable_since: Option<DateTime<Utc>>,
+    /// Don't retry this source again until this time has passed.
+    backoff_until: DateTime<Utc>,
+}
+
+/// How long a `DataSource` may stay unreachable before its dependent
+/// components get a `CommunicationFailure` alert.
+const SOURCE_UNREACHABLE_GRACE_PERIOD: std::time::Duration = std::time::Duration::from_secs(300);
+/// Ceiling on the exponential reconnect backoff applied to an unhealthy
+/// `DataSource`.
+const SOURCE_MAX_BACKOFF: std::time::Duration = std::time::Duration::from_secs(60);
+
+impl ContinualAssuranceEngine {
+    pub fn new() -> Self {
+        Self::with_config_store(PersistentConfigStore::default())
+    }
+
+    /// Like `new`, but reloading (and writing through to) a
+    /// `PersistentConfigStore` rooted at a caller-chosen path instead of
+    /// the default `component_registry.json`.
+    pub fn with_config_store(config_store: PersistentConfigStore) -> Self {
+        let now = Utc::now();
+        let component_registry: HashMap<String, ComponentMonitor> = config_store.load()
+            .into_iter()
+            .map(|(component_id, config)| {
+                let monitor = ComponentMonitor {
+                    component_id: component_id.clone(),
+                    component_type: "microservice".to_string(),
+                    current_trust_score: 0.5,
+                    trust_history: Vec::new(),
+                    monitoring_config: config,
+                    last_updated: now,
+                    status: ComponentStatus::Unknown,
+                };
+                (component_id, monitor)
+            })
+            .collect();
+
+        Self {
+            component_registry: Arc::new(RwLock::new(component_registry)),
+            trust_calculators: Arc::new(RwLock::new(HashMap::new())),
+            data_sources: Arc::new(RwLock::new(Vec::new())),
+            scoring_pipeline: Arc::new(RwLock::new(ScoringPipeline {
+                stages: Vec::new(),
+                aggregation_method: AggregationMethod::WeightedAverage,
+                update_frequency: Duration::from_secs(30),
+            })),
+            alert_manager: Arc::new(RwLock::new(AlertManager {
+                alerts: Vec::new(),
+                escalation_policies: Vec::new(),
+                notification_channels: Vec::new(),
+                condition_since: HashMap::new(),
+                policy_since: HashMap::new(),
+                escalated_levels: HashMap::new(),
+                suppression_rules: Vec::new(),
+                grouping_rules: Vec::new(),
+            })),
+            trust_graph: Arc::new(RwLock::new(DependencyTrustGraph::new(0.7, 0.05, 0.5))),
+            notification_dispatcher: Arc::new(HttpNotificationDispatcher::new()),
+            config_store: Arc::new(config_store),
+            source_health: Arc::new(RwLock::new(HashMap::new())),
+            last_polled: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Register `from`'s trust as partially derived from `to`, with the
+    /// given edge weight, for `CalculationMethod::TrustGraph` propagation.
+    pub async fn add_trust_dependency(&self, from: String, to: String, weight: f64) {
+        let mut trust_graph = self.trust_graph.write().await;
+        trust_graph.add_dependency(from, to, weight);
+    }
+
+    /// Register a component for monitoring, persisting its `MonitoringConfig`
+    /// to `config_store` so the registration survives a restart.
+    pub async fn register_component(&self, component_id: String, config: MonitoringConfig) {
+        let monitor = ComponentMonitor {
+            component_id: component_id.clone(),
+            component_type: "microservice".to_string(),
+            current_trust_score: 0.5,
+            trust_history: Vec::new(),
+            monitoring_config: config,
+            last_updated: Utc::now(),
+            status: ComponentStatus::Unknown,
+        };
+
+        let mut registry = self.component_registry.write().await;
+        registry.insert(component_id, monitor);
+
+        let configs: HashMap<String, MonitoringConfig> = registry.iter()
+            .map(|(id, monitor)| (id.clone(), monitor.monitoring_config.clone()))
+            .collect();
+        self.config_store.save(&configs);
+    }
+
+    /// Add a trust calculator
+    pub async fn add_trust_calculator(&self, name: String, calculator: Box<dyn TrustCalculator + Send + Sync>) {
+        let mut calculators = self.trust_calculators.write().await;
+        calculators.insert(name, calculator);
+    }
+
+    /// Add a data source
+    pub async fn add_data_source(&self, source: Box<dyn DataSource + Send + Sync>) {
+        let mut sources = self.data_sources.write().await;
+        sources.push(source);
+    }
+
+    /// Start the continual assurance process
+    pub async fn start_monitoring(&self) -> Result<(), String> {
+        let update_interval = Duration::from_secs(30);
+        let mut interval = IntervalStream::new(tokio::time::interval(update_interval));
+        
+        while let Some(_) = interval.next().await {
+            if let Err(e) = self.update_all_trust_scores().await {
+                eprintln!("Error updating trust scores: {}", e);
+            }
+            self.evaluate_alert_escalations().await;
+        }
+
+        Ok(())
+    }
+
+    /// Update trust scores for all registered components
+    async fn update_all_trust_scores(&self) -> Result<(), String> {
+        let now = Utc::now();
+
+        // Only poll components whose own `update_interval` has actually
+        // elapsed since we last polled them, rather than every component on
+        // the engine's single shared tick. Snapshotting ids here (instead
+        // of holding the registry guard across the loop below) also avoids
+        // deadlocking against update_component_trust_score's write lock.
+        let due_component_ids: Vec<String> = {
+            let registry = self.component_registry.read().await;
+            let last_polled = self.last_polled.read().await;
+            registry.iter()
+                .filter(|(id, monitor)| {
+                    last_polled.get(*id)
+                        .map(|since| now.signed_duration_since(*since).to_std().unwrap_or_default() >= monitor.monitoring_config.update_interval)
+                        .unwrap_or(true)
+                })
+                .map(|(id, _)| id.clone())
+                .collect()
+        };
+
+        if !due_component_ids.is_empty() {
+            let calculators = self.trust_calculators.read().await;
+            let all_data_by_component = self.poll_data_sources(&due_component_ids).await;
+
+            for component_id in &due_component_ids {
+                {
+                    let mut last_polled = self.last_polled.write().await;
+                    last_polled.insert(component_id.clone(), now);
+                }
+
+                let Some(all_data) = all_data_by_component.get(component_id) else {
+                    continue;
+                };
+                if all_data.is_empty() {
+                    continue;
+                }
+
                 // Calculate trust score using all calculators
                 let mut scores = Vec::new();
-                for (calc_name, calculator) in calculators.iter() {
-                    for data in &all_data {
+                for (_calc_name, calculator) in calculators.iter() {
+                    for data in all_data {
                         let result = calculator.calculate_trust(data);
                         scores.push(result);
                     }
                 }
-                
+
                 // Aggregate scores
                 let final_score = self.aggregate_scores(&scores).await;
-                
+                let contributing_factors: Vec<ContributingFactor> = scores.iter()
+                    .flat_map(|result| result.contributing_factors.clone())
+                    .collect();
+
+                // Merge this tick's raw metrics so escalation conditions can
+                // be compared against them directly (last writer wins).
+                let mut metrics = HashMap::new();
+                for data in all_data {
+                    metrics.extend(data.metrics.clone());
+                }
+
                 // Update component monitor
-                self.update_component_trust_score(component_id, final_score).await?;
+                self.update_component_trust_score(component_id, final_score, contributing_factors, &metrics).await?;
             }
         }
-        
+
+        // Now that every component's direct score is fresh, derive
+        // effective trust for any CalculationMethod::TrustGraph components
+        // from the trust of the components they depend on.
+        self.apply_trust_graph_propagation().await?;
+
+        Ok(())
+    }
+
+    /// Poll every registered `DataSource` once, grouping the
+    /// `ObservabilityData` it yields by the component it belongs to. Covers
+    /// both push-style sources (drains `get_data_stream`, tagged with
+    /// whatever `component_id` the source embedded in the data) and
+    /// pull-style sources (calls `fetch_metrics` once per component that
+    /// names this source in its `MonitoringConfig.data_sources`, so metrics
+    /// pulled from different backends blend into that component's data).
+    /// A source that fails its `is_healthy` check is reconnected-to with
+    /// exponential backoff (capped at `SOURCE_MAX_BACKOFF`) instead of
+    /// being drained every tick regardless, and once it's been unreachable
+    /// past `SOURCE_UNREACHABLE_GRACE_PERIOD` its dependent components get
+    /// a `CommunicationFailure` alert.
+    async fn poll_data_sources(&self, due_component_ids: &[String]) -> HashMap<String, Vec<ObservabilityData>> {
+        let sources = self.data_sources.read().await;
+        let now = Utc::now();
+        let mut by_component: HashMap<String, Vec<ObservabilityData>> = HashMap::new();
+
+        let configured_sources: HashMap<String, Vec<String>> = {
+            let registry = self.component_registry.read().await;
+            due_component_ids.iter()
+                .filter_map(|id| registry.get(id).map(|monitor| (id.clone(), monitor.monitoring_config.data_sources.clone())))
+                .collect()
+        };
+
+        for source in sources.iter() {
+            let source_name = source.get_source_name();
+
+            let backed_off = {
+                let health = self.source_health.read().await;
+                health.get(&source_name).map(|state| now < state.backoff_until).unwrap_or(false)
+            };
+            if backed_off {
+                continue;
+            }
+
+            if !source.is_healthy() {
+                let grace_exceeded = {
+                    let mut health = self.source_health.write().await;
+                    let state = health.entry(source_name.clone()).or_insert_with(|| SourceHealthState {
+                        consecutive_failures: 0,
+                        unreachable_since: Some(now),
+                        backoff_until: now,
+                    });
+                    state.consecutive_failures += 1;
+                    let backoff_secs = 2u64.saturating_pow(state.consecutive_failures).min(SOURCE_MAX_BACKOFF.as_secs());
+                    state.backoff_until = now + chrono::Duration::seconds(backoff_secs as i64);
+                    let unreachable_since = *state.unreachable_since.get_or_insert(now);
+                    now.signed_duration_since(unreachable_since).to_std().unwrap_or_default() >= SOURCE_UNREACHABLE_GRACE_PERIOD
+                };
+
+                if grace_exceeded {
+                    self.raise_source_degraded_alert(&source_name).await;
+                }
+                continue;
+            }
+
+            {
+                let mut health = self.source_health.write().await;
+                health.remove(&source_name);
+            }
+
+            let mut stream = source.get_data_stream();
+            while let Some(data) = stream.next().await {
+                by_component.entry(data.component_id.clone()).or_insert_with(Vec::new).push(data);
+            }
+
+            for (component_id, source_names) in &configured_sources {
+                if !source_names.iter().any(|name| name == &source_name) {
+                    continue;
+                }
+                let metrics = source.fetch_metrics(component_id).await;
+                if metrics.is_empty() {
+                    continue;
+                }
+                by_component.entry(component_id.clone()).or_insert_with(Vec::new).push(ObservabilityData {
+                    component_id: component_id.clone(),
+                    timestamp: now,
+                    metrics,
+                    logs: Vec::new(),
+                    traces: Vec::new(),
+                });
+            }
+        }
+
+        by_component
+    }
+
+    /// Raise a `CommunicationFailure` alert for every component configured
+    /// to use `source_name` (via `MonitoringConfig.data_sources`).
+    async fn raise_source_degraded_alert(&self, source_name: &str) {
+        let affected_components: Vec<String> = {
+            let registry = self.component_registry.read().await;
+            registry.iter()
+                .filter(|(_, monitor)| monitor.monitoring_config.data_sources.iter().any(|name| name == source_name))
+                .map(|(id, _)| id.clone())
+                .collect()
+        };
+
+        for component_id in affected_components {
+            self.raise_and_dispatch_alert(
+                &component_id,
+                AlertType::CommunicationFailure,
+                AlertSeverity::High,
+                format!("Data source '{}' has been unreachable past its grace period", source_name),
+            ).await;
+        }
+    }
+
+    /// For every registered component using `CalculationMethod::TrustGraph`,
+    /// recompute its trust score as the weighted combination of its own
+    /// direct score and the trust of the components it depends on (per
+    /// `DependencyTrustGraph`), and record the result like any other score.
+    async fn apply_trust_graph_propagation(&self) -> Result<(), String> {
+        let (direct_scores, graph_component_ids) = {
+            let registry = self.component_registry.read().await;
+            let direct_scores: HashMap<String, f64> = registry.iter()
+                .map(|(id, monitor)| (id.clone(), monitor.current_trust_score))
+                .collect();
+            let graph_component_ids: Vec<String> = registry.iter()
+                .filter(|(_, monitor)| matches!(monitor.monitoring_config.calculation_method, CalculationMethod::TrustGraph))
+                .map(|(id, _)| id.clone())
+                .collect();
+            (direct_scores, graph_component_ids)
+        };
+
+        if graph_component_ids.is_empty() {
+            return Ok(());
+        }
+
+        let trust_graph = self.trust_graph.read().await;
+        for component_id in graph_component_ids {
+            let effective_score = trust_graph.effective_trust_for(&component_id, &direct_scores);
+            self.update_component_trust_score(&component_id, effective_score, Vec::new(), &HashMap::new()).await?;
+        }
+
         Ok(())
     }
 
@@ -657,77 +1869,248 @@ impl ContinualAssuranceEngine {
             },
             AggregationMethod::Ensemble => {
                 // Ensemble method combining multiple approaches
-                let weighted_avg = scores.iter().map(|s| s.score * s.confidence).sum::<f64>() / 
+                let weighted_avg = scores.iter().map(|s| s.score * s.confidence).sum::<f64>() /
                                  scores.iter().map(|s| s.confidence).sum::<f64>().max(0.001);
                 let max_score = scores.iter().map(|s| s.score).fold(0.0, f64::max);
                 (weighted_avg + max_score) / 2.0
             },
+            AggregationMethod::UnknownValue(ref kind) => {
+                eprintln!("Unknown aggregation method '{}', degrading to WeightedAverage", kind);
+                let total_weight: f64 = scores.iter().map(|s| s.confidence).sum();
+                if total_weight > 0.0 {
+                    scores.iter().map(|s| s.score * s.confidence).sum::<f64>() / total_weight
+                } else {
+                    scores.iter().map(|s| s.score).sum::<f64>() / scores.len() as f64
+                }
+            },
         }
     }
 
     /// Update trust score for a specific component
-    async fn update_component_trust_score(&self, component_id: &str, new_score: f64) -> Result<(), String> {
-        let mut registry = self.component_registry.write().await;
-        
-        if let Some(monitor) = registry.get_mut(component_id) {
-            // Add to trust history
-            let trust_point = TrustScorePoint {
-                timestamp: Utc::now(),
-                score: new_score,
-                confidence: 0.8, // Simplified confidence calculation
-                contributing_factors: Vec::new(),
-            };
-            
-            monitor.trust_history.push(trust_point);
-            monitor.current_trust_score = new_score;
-            monitor.last_updated = Utc::now();
-            
-            // Update component status based on trust score
-            monitor.status = if new_score < 0.2 {
-                ComponentStatus::Critical
-            } else if new_score < 0.5 {
-                ComponentStatus::Warning
+    async fn update_component_trust_score(&self, component_id: &str, new_score: f64, contributing_factors: Vec<ContributingFactor>, metrics: &HashMap<String, f64>) -> Result<(), String> {
+        let found = {
+            let mut registry = self.component_registry.write().await;
+
+            if let Some(monitor) = registry.get_mut(component_id) {
+                // Add to trust history
+                let trust_point = TrustScorePoint {
+                    timestamp: Utc::now(),
+                    score: new_score,
+                    confidence: 0.8, // Simplified confidence calculation
+                    contributing_factors: contributing_factors.clone(),
+                };
+
+                monitor.trust_history.push(trust_point);
+                monitor.current_trust_score = new_score;
+                monitor.last_updated = Utc::now();
+
+                // Update component status based on trust score
+                monitor.status = if new_score < 0.2 {
+                    ComponentStatus::Critical
+                } else if new_score < 0.5 {
+                    ComponentStatus::Warning
+                } else {
+                    ComponentStatus::Healthy
+                };
+
+                true
             } else {
-                ComponentStatus::Healthy
-            };
-            
-            // Check for alerts
-            self.check_alerts(component_id, new_score).await;
+                false
+            }
+        };
+
+        // Check for alerts now that the registry write lock above has been
+        // released -- check_alerts takes its own read lock on the registry.
+        if found {
+            self.check_alerts(component_id, new_score, &contributing_factors, metrics).await;
         }
-        
+
         Ok(())
     }
 
     /// Check if alerts should be triggered
-    async fn check_alerts(&self, component_id: &str, trust_score: f64) {
-        let registry = self.component_registry.read().await;
-        let mut alert_manager = self.alert_manager.write().await;
-        
-        if let Some(monitor) = registry.get(component_id) {
-            let thresholds = &monitor.monitoring_config.trust_thresholds;
-            
+    async fn check_alerts(&self, component_id: &str, trust_score: f64, contributing_factors: &[ContributingFactor], metrics: &HashMap<String, f64>) {
+        let thresholds = {
+            let registry = self.component_registry.read().await;
+            registry.get(component_id).map(|monitor| monitor.monitoring_config.trust_thresholds.clone())
+        };
+
+        if let Some(thresholds) = thresholds {
+            if contributing_factors.iter().any(|factor| matches!(factor.factor_type, FactorType::BehavioralAnomaly)) {
+                self.raise_and_dispatch_alert(component_id, AlertType::BehavioralAnomaly, AlertSeverity::Medium, format!("Behavioral anomaly detected (trust score: {:.2})", trust_score)).await;
+            }
+
             if trust_score < thresholds.critical {
-                let alert = Alert {
-                    alert_id: format!("{}-critical-{}", component_id, Utc::now().timestamp()),
-                    component_id: component_id.to_string(),
-                    alert_type: AlertType::TrustScoreLow,
-                    severity: AlertSeverity::Critical,
-                    message: format!("Critical trust score: {:.2}", trust_score),
-                    timestamp: Utc::now(),
-                    status: AlertStatus::Active,
-                };
-                alert_manager.alerts.push(alert);
+                self.raise_and_dispatch_alert(component_id, AlertType::TrustScoreLow, AlertSeverity::Critical, format!("Critical trust score: {:.2}", trust_score)).await;
             } else if trust_score < thresholds.warning {
-                let alert = Alert {
-                    alert_id: format!("{}-warning-{}", component_id, Utc::now().timestamp()),
-                    component_id: component_id.to_string(),
-                    alert_type: AlertType::TrustScoreLow,
-                    severity: AlertSeverity::Medium,
-                    message: format!("Warning trust score: {:.2}", trust_score),
-                    timestamp: Utc::now(),
-                    status: AlertStatus::Active,
+                self.raise_and_dispatch_alert(component_id, AlertType::TrustScoreLow, AlertSeverity::Medium, format!("Warning trust score: {:.2}", trust_score)).await;
+            }
+        }
+
+        self.evaluate_escalation_policies(component_id, metrics).await;
+    }
+
+    /// Record an alert via `AlertManager::record_alert` and dispatch it to
+    /// any routed channels, logging (but not propagating) delivery
+    /// failures. Shared by `check_alerts` and data-source health
+    /// monitoring so every alert path gets the same dedup/suppression/
+    /// dispatch behavior.
+    async fn raise_and_dispatch_alert(&self, component_id: &str, alert_type: AlertType, severity: AlertSeverity, message: String) {
+        let now = Utc::now();
+        let channels = {
+            let mut alert_manager = self.alert_manager.write().await;
+            alert_manager.record_alert(component_id, alert_type, severity, message.clone(), now)
+        };
+
+        for channel in channels {
+            eprintln!("Alert on '{}' routed to {} channel '{}' ({})", component_id, channel.channel_type, channel.channel_id, channel.endpoint);
+            if let Err(e) = self.notification_dispatcher.send(&channel, component_id, &message).await {
+                eprintln!("Failed to deliver alert on '{}' to channel '{}': {}", component_id, channel.channel_id, e);
+            }
+        }
+    }
+
+    /// Evaluate every configured `EscalationPolicy` against this tick's raw
+    /// metrics. Each condition's continuous-hold time is tracked in
+    /// `AlertManager::condition_since`; a policy only fires once every one
+    /// of its conditions has held for at least its own `duration`. Once a
+    /// policy is firing, it climbs the component's `EscalationLevel` ladder
+    /// (sorted by `level`) as long as it keeps re-firing, gated by each
+    /// level's cumulative `timeout`, instead of repeating the lowest level's
+    /// actions forever.
+    async fn evaluate_escalation_policies(&self, component_id: &str, metrics: &HashMap<String, f64>) {
+        let policies = {
+            let alert_manager = self.alert_manager.read().await;
+            alert_manager.escalation_policies.clone()
+        };
+
+        for policy in &policies {
+            if policy.conditions.is_empty() {
+                continue;
+            }
+
+            let mut all_held = true;
+            for (idx, condition) in policy.conditions.iter().enumerate() {
+                let key = format!("{}::{}::{}", component_id, policy.policy_id, idx);
+                let holds = metrics.get(&condition.metric_name)
+                    .map(|value| Self::evaluate_comparison(*value, &condition.operator, condition.threshold))
+                    .unwrap_or(false);
+
+                let mut alert_manager = self.alert_manager.write().await;
+                if holds {
+                    let since = *alert_manager.condition_since.entry(key).or_insert_with(Utc::now);
+                    let elapsed = Utc::now().signed_duration_since(since).to_std().unwrap_or_default();
+                    if elapsed < condition.duration {
+                        all_held = false;
+                    }
+                } else {
+                    alert_manager.condition_since.remove(&key);
+                    all_held = false;
+                }
+            }
+
+            let policy_key = format!("{}::{}", component_id, policy.policy_id);
+            if all_held {
+                let since = {
+                    let mut alert_manager = self.alert_manager.write().await;
+                    *alert_manager.policy_since.entry(policy_key.clone()).or_insert_with(Utc::now)
                 };
-                alert_manager.alerts.push(alert);
+                self.escalate_policy(component_id, policy, &policy_key, since).await;
+            } else {
+                let mut alert_manager = self.alert_manager.write().await;
+                alert_manager.policy_since.remove(&policy_key);
+                alert_manager.escalated_levels.remove(&policy_key);
+            }
+        }
+    }
+
+    /// Compare a metric value against an `EscalationCondition`'s threshold.
+    fn evaluate_comparison(value: f64, operator: &ComparisonOperator, threshold: f64) -> bool {
+        match operator {
+            ComparisonOperator::GreaterThan => value > threshold,
+            ComparisonOperator::LessThan => value < threshold,
+            ComparisonOperator::EqualTo => (value - threshold).abs() < f64::EPSILON,
+            ComparisonOperator::NotEqualTo => (value - threshold).abs() >= f64::EPSILON,
+            ComparisonOperator::GreaterThanOrEqual => value >= threshold,
+            ComparisonOperator::LessThanOrEqual => value <= threshold,
+            ComparisonOperator::UnknownValue(kind) => {
+                eprintln!("Unknown comparison operator '{}', treating condition as unsatisfied", kind);
+                false
+            }
+        }
+    }
+
+    /// Walk the component's `EscalationLevel` ladder for a policy that has
+    /// been continuously satisfied since `since`, firing the policy's
+    /// actions again only when a new level is reached.
+    async fn escalate_policy(&self, component_id: &str, policy: &EscalationPolicy, policy_key: &str, since: DateTime<Utc>) {
+        let mut levels = {
+            let registry = self.component_registry.read().await;
+            registry.get(component_id)
+                .map(|monitor| monitor.monitoring_config.alert_settings.escalation_levels.clone())
+                .unwrap_or_default()
+        };
+        levels.sort_by_key(|level| level.level);
+
+        let elapsed = Utc::now().signed_duration_since(since).to_std().unwrap_or_default();
+        let mut target_level = 0u32;
+        let mut cumulative = Duration::from_secs(0);
+        for level in &levels {
+            cumulative += level.timeout;
+            if elapsed >= cumulative {
+                target_level = level.level;
+            } else {
+                break;
+            }
+        }
+
+        let already_escalated = {
+            let alert_manager = self.alert_manager.read().await;
+            alert_manager.escalated_levels.get(policy_key).copied()
+        };
+        if already_escalated == Some(target_level) {
+            return;
+        }
+
+        self.fire_escalation_actions(component_id, policy).await;
+
+        let mut alert_manager = self.alert_manager.write().await;
+        alert_manager.escalated_levels.insert(policy_key.to_string(), target_level);
+    }
+
+    /// Execute an `EscalationPolicy`'s actions against a component.
+    async fn fire_escalation_actions(&self, component_id: &str, policy: &EscalationPolicy) {
+        for action in &policy.actions {
+            match &action.action_type {
+                ActionType::IsolateComponent => {
+                    let mut registry = self.component_registry.write().await;
+                    if let Some(monitor) = registry.get_mut(component_id) {
+                        monitor.status = ComponentStatus::Isolated;
+                    }
+                }
+                ActionType::SendNotification => {
+                    let alert_manager = self.alert_manager.read().await;
+                    match alert_manager.notification_channels.iter().find(|channel| channel.channel_id == action.target) {
+                        Some(channel) if channel.enabled => {
+                            eprintln!(
+                                "Escalation policy '{}' notifying {} channel '{}' ({})",
+                                policy.policy_id, channel.channel_type, channel.channel_id, channel.endpoint
+                            );
+                        }
+                        Some(_) => {
+                            eprintln!("Escalation policy '{}' target channel '{}' is disabled, skipping notification", policy.policy_id, action.target);
+                        }
+                        None => {
+                            eprintln!("Escalation policy '{}' references unknown notification channel '{}'", policy.policy_id, action.target);
+                        }
+                    }
+                }
+                ActionType::TriggerWorkflow | ActionType::ScaleResources | ActionType::UpdateConfiguration => {
+                    eprintln!("Escalation policy '{}' triggered {} action targeting '{}'", policy.policy_id, action.action_type, action.target);
+                }
+                ActionType::UnknownValue(kind) => {
+                    eprintln!("Escalation policy '{}' has unrecognized action type '{}', skipping", policy.policy_id, kind);
+                }
             }
         }
     }
@@ -749,10 +2132,86 @@ impl ContinualAssuranceEngine {
     /// Get active alerts
     pub async fn get_active_alerts(&self) -> Vec<Alert> {
         let alert_manager = self.alert_manager.read().await;
-        alert_manager.alerts.iter()
-            .filter(|alert| matches!(alert.status, AlertStatus::Active))
-            .cloned()
-            .collect()
+        alert_manager.active_alerts()
+    }
+
+    /// Add a suppression rule (e.g. a maintenance window) that moves
+    /// matching alerts to `AlertStatus::Suppressed` and skips notification.
+    pub async fn add_suppression_rule(&self, rule: SuppressionRule) {
+        let mut alert_manager = self.alert_manager.write().await;
+        alert_manager.add_suppression_rule(rule);
+    }
+
+    pub async fn remove_suppression_rule(&self, rule_id: &str) {
+        let mut alert_manager = self.alert_manager.write().await;
+        alert_manager.remove_suppression_rule(rule_id);
+    }
+
+    /// Add a grouping rule that routes matching alerts onto a set of
+    /// `NotificationChannel`s.
+    pub async fn add_grouping_rule(&self, rule: GroupingRule) {
+        let mut alert_manager = self.alert_manager.write().await;
+        alert_manager.add_grouping_rule(rule);
+    }
+
+    pub async fn remove_grouping_rule(&self, rule_id: &str) {
+        let mut alert_manager = self.alert_manager.write().await;
+        alert_manager.remove_grouping_rule(rule_id);
+    }
+
+    /// Acknowledge an alert, taking it off the escalation ladder.
+    pub async fn acknowledge_alert(&self, alert_id: &str) -> Result<(), String> {
+        let mut alert_manager = self.alert_manager.write().await;
+        alert_manager.acknowledge_alert(alert_id, Utc::now())
+    }
+
+    /// Mark an alert resolved, taking it off the escalation ladder for
+    /// good.
+    pub async fn resolve_alert(&self, alert_id: &str) -> Result<(), String> {
+        let mut alert_manager = self.alert_manager.write().await;
+        alert_manager.resolve_alert(alert_id, Utc::now())
+    }
+
+    /// Advance every component's stale `Active` alerts up their
+    /// `EscalationLevel` ladder and page the newly-reached level's
+    /// channels. Called on every `start_monitoring` tick so an
+    /// unacknowledged alert keeps escalating instead of sitting silent.
+    async fn evaluate_alert_escalations(&self) {
+        let levels_by_component: HashMap<String, Vec<EscalationLevel>> = {
+            let registry = self.component_registry.read().await;
+            registry.iter()
+                .map(|(id, monitor)| (id.clone(), monitor.monitoring_config.alert_settings.escalation_levels.clone()))
+                .collect()
+        };
+
+        let escalated = {
+            let mut alert_manager = self.alert_manager.write().await;
+            alert_manager.escalate_stale_alerts(&levels_by_component, Utc::now())
+        };
+
+        if escalated.is_empty() {
+            return;
+        }
+
+        let channels_by_id: HashMap<String, NotificationChannel> = {
+            let alert_manager = self.alert_manager.read().await;
+            alert_manager.notification_channels.iter()
+                .map(|channel| (channel.channel_id.clone(), channel.clone()))
+                .collect()
+        };
+
+        for (alert, channel_ids) in escalated {
+            for channel_id in channel_ids {
+                let Some(channel) = channels_by_id.get(&channel_id).filter(|channel| channel.enabled) else {
+                    continue;
+                };
+                let message = format!("Alert '{}' on '{}' escalated to level {} ({:?}): {}", alert.alert_id, alert.component_id, alert.escalation_level, alert.severity, alert.message);
+                eprintln!("{}", message);
+                if let Err(e) = self.notification_dispatcher.send(channel, &alert.component_id, &message).await {
+                    eprintln!("Failed to deliver escalation for alert '{}' to channel '{}': {}", alert.alert_id, channel.channel_id, e);
+                }
+            }
+        }
     }
 }
 
@@ -799,4 +2258,670 @@ mod tests {
         let scores = engine.get_trust_scores().await;
         assert!(scores.contains_key("test-component"));
     }
+
+    #[test]
+    fn test_bayesian_trust_calculator_accumulates_evidence() {
+        let mut metric_config = HashMap::new();
+        metric_config.insert("security_score".to_string(), (1.0, 0.5));
+        let calculator = BayesianTrustCalculator::new("bayesian".to_string(), metric_config, 3600.0);
+
+        let mut data = ObservabilityData {
+            component_id: "test-component".to_string(),
+            timestamp: Utc::now(),
+            metrics: HashMap::from([("security_score".to_string(), 0.9)]),
+            logs: Vec::new(),
+            traces: Vec::new(),
+        };
+
+        let first = calculator.calculate_trust(&data);
+        assert!(first.score > 0.5);
+
+        data.timestamp = data.timestamp + chrono::Duration::seconds(60);
+        let second = calculator.calculate_trust(&data);
+        assert!(second.score >= first.score);
+        assert!(second.confidence > first.confidence);
+    }
+
+    #[test]
+    fn test_threshold_anomaly_calculator_flags_an_outlier() {
+        let calculator = ThresholdAnomalyCalculator::new("anomaly".to_string(), 10, 3.0, None);
+
+        for _ in 0..6 {
+            let data = ObservabilityData {
+                component_id: "test-component".to_string(),
+                timestamp: Utc::now(),
+                metrics: HashMap::from([("performance_score".to_string(), 0.5)]),
+                logs: Vec::new(),
+                traces: Vec::new(),
+            };
+            let result = calculator.calculate_trust(&data);
+            assert!(result.contributing_factors.is_empty());
+        }
+
+        let spike = ObservabilityData {
+            component_id: "test-component".to_string(),
+            timestamp: Utc::now(),
+            metrics: HashMap::from([("performance_score".to_string(), 50.0)]),
+            logs: Vec::new(),
+            traces: Vec::new(),
+        };
+        let result = calculator.calculate_trust(&spike);
+
+        assert!(result.score < 1.0);
+        assert!(result.contributing_factors.iter().any(|f| matches!(f.factor_type, FactorType::BehavioralAnomaly)));
+    }
+
+    #[test]
+    fn test_unrecognized_aggregation_method_round_trips_as_unknown_value() {
+        let json = "\"SomeNewMethod\"";
+        let method: AggregationMethod = serde_json::from_str(json).unwrap();
+        assert!(matches!(method, AggregationMethod::UnknownValue(ref s) if s == "SomeNewMethod"));
+        assert_eq!(serde_json::to_string(&method).unwrap(), json);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_aggregation_method_degrades_to_weighted_average() {
+        let engine = ContinualAssuranceEngine::new();
+        {
+            let mut pipeline = engine.scoring_pipeline.write().await;
+            pipeline.aggregation_method = AggregationMethod::UnknownValue("FutureMethod".to_string());
+        }
+
+        let scores = vec![
+            TrustScoreResult { score: 0.8, confidence: 1.0, contributing_factors: Vec::new(), timestamp: Utc::now() },
+            TrustScoreResult { score: 0.4, confidence: 1.0, contributing_factors: Vec::new(), timestamp: Utc::now() },
+        ];
+
+        let aggregated = engine.aggregate_scores(&scores).await;
+        assert!((aggregated - 0.6).abs() < 1e-9);
+    }
+
+    fn escalation_policy(metric_name: &str, duration: Duration) -> EscalationPolicy {
+        EscalationPolicy {
+            policy_id: "isolate-on-low-trust".to_string(),
+            conditions: vec![EscalationCondition {
+                metric_name: metric_name.to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.3,
+                duration,
+            }],
+            actions: vec![EscalationAction {
+                action_type: ActionType::IsolateComponent,
+                target: "test-component".to_string(),
+                parameters: HashMap::new(),
+            }],
+            timeout: Duration::from_secs(60),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_escalation_policy_waits_for_condition_duration_before_isolating() {
+        let engine = ContinualAssuranceEngine::new();
+        engine.register_component("test-component".to_string(), MonitoringConfig {
+            update_interval: Duration::from_secs(30),
+            trust_thresholds: TrustThresholds { critical: 0.2, warning: 0.5, normal: 0.8 },
+            data_sources: Vec::new(),
+            calculation_method: CalculationMethod::WeightedAverage,
+            alert_settings: AlertSettings { enabled: true, escalation_levels: Vec::new(), notification_channels: Vec::new() },
+        }).await;
+        {
+            let mut alert_manager = engine.alert_manager.write().await;
+            alert_manager.escalation_policies.push(escalation_policy("trust_score", Duration::from_millis(20)));
+        }
+
+        let metrics = HashMap::from([("trust_score".to_string(), 0.1)]);
+
+        // First tick: condition just started holding, not yet isolated.
+        engine.evaluate_escalation_policies("test-component", &metrics).await;
+        let status_before = {
+            let registry = engine.component_registry.read().await;
+            registry.get("test-component").unwrap().status.clone()
+        };
+        assert!(!matches!(status_before, ComponentStatus::Isolated));
+
+        tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+
+        engine.evaluate_escalation_policies("test-component", &metrics).await;
+        let status_after = {
+            let registry = engine.component_registry.read().await;
+            registry.get("test-component").unwrap().status.clone()
+        };
+        assert!(matches!(status_after, ComponentStatus::Isolated));
+    }
+
+    #[tokio::test]
+    async fn test_escalation_condition_resets_when_it_clears() {
+        let engine = ContinualAssuranceEngine::new();
+        engine.register_component("test-component".to_string(), MonitoringConfig {
+            update_interval: Duration::from_secs(30),
+            trust_thresholds: TrustThresholds { critical: 0.2, warning: 0.5, normal: 0.8 },
+            data_sources: Vec::new(),
+            calculation_method: CalculationMethod::WeightedAverage,
+            alert_settings: AlertSettings { enabled: true, escalation_levels: Vec::new(), notification_channels: Vec::new() },
+        }).await;
+        {
+            let mut alert_manager = engine.alert_manager.write().await;
+            alert_manager.escalation_policies.push(escalation_policy("trust_score", Duration::from_millis(20)));
+        }
+
+        let breaching = HashMap::from([("trust_score".to_string(), 0.1)]);
+        let healthy = HashMap::from([("trust_score".to_string(), 0.9)]);
+
+        engine.evaluate_escalation_policies("test-component", &breaching).await;
+        engine.evaluate_escalation_policies("test-component", &healthy).await;
+        tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+        engine.evaluate_escalation_policies("test-component", &breaching).await;
+
+        // The clearing tick should have reset the hold timer, so the
+        // policy should not have had time to re-satisfy its duration yet.
+        let status = {
+            let registry = engine.component_registry.read().await;
+            registry.get("test-component").unwrap().status.clone()
+        };
+        assert!(!matches!(status, ComponentStatus::Isolated));
+    }
+
+    #[test]
+    fn test_record_alert_deduplicates_repeated_fingerprint() {
+        let mut alert_manager = AlertManager {
+            alerts: Vec::new(),
+            escalation_policies: Vec::new(),
+            notification_channels: Vec::new(),
+            condition_since: HashMap::new(),
+            policy_since: HashMap::new(),
+            escalated_levels: HashMap::new(),
+            suppression_rules: Vec::new(),
+            grouping_rules: Vec::new(),
+        };
+
+        let now = Utc::now();
+        alert_manager.record_alert("svc-a", AlertType::TrustScoreLow, AlertSeverity::Critical, "first".to_string(), now);
+        alert_manager.record_alert("svc-a", AlertType::TrustScoreLow, AlertSeverity::Critical, "second".to_string(), now);
+
+        assert_eq!(alert_manager.alerts.len(), 1);
+        assert_eq!(alert_manager.alerts[0].occurrence_count, 2);
+        assert_eq!(alert_manager.alerts[0].message, "second");
+    }
+
+    #[test]
+    fn test_suppression_rule_hides_alert_within_window() {
+        let mut alert_manager = AlertManager {
+            alerts: Vec::new(),
+            escalation_policies: Vec::new(),
+            notification_channels: Vec::new(),
+            condition_since: HashMap::new(),
+            policy_since: HashMap::new(),
+            escalated_levels: HashMap::new(),
+            suppression_rules: Vec::new(),
+            grouping_rules: Vec::new(),
+        };
+
+        let now = Utc::now();
+        alert_manager.add_suppression_rule(SuppressionRule {
+            rule_id: "maintenance".to_string(),
+            component_id: Some("svc-a".to_string()),
+            alert_type: Some(AlertType::PerformanceDegradation),
+            window_start: now - chrono::Duration::hours(1),
+            window_end: now + chrono::Duration::hours(1),
+        });
+
+        let channels = alert_manager.record_alert("svc-a", AlertType::PerformanceDegradation, AlertSeverity::Medium, "slow".to_string(), now);
+
+        assert!(channels.is_empty());
+        assert!(alert_manager.active_alerts().is_empty());
+        assert!(matches!(alert_manager.alerts[0].status, AlertStatus::Suppressed));
+    }
+
+    #[test]
+    fn test_grouping_rule_routes_alert_to_matching_channel() {
+        let mut alert_manager = AlertManager {
+            alerts: Vec::new(),
+            escalation_policies: Vec::new(),
+            notification_channels: vec![NotificationChannel {
+                channel_id: "oncall-slack".to_string(),
+                channel_type: ChannelType::Slack,
+                endpoint: "#oncall".to_string(),
+                enabled: true,
+            }],
+            condition_since: HashMap::new(),
+            policy_since: HashMap::new(),
+            escalated_levels: HashMap::new(),
+            suppression_rules: Vec::new(),
+            grouping_rules: vec![GroupingRule {
+                rule_id: "critical-to-oncall".to_string(),
+                component_id: None,
+                alert_type: Some(AlertType::TrustScoreLow),
+                channel_ids: vec!["oncall-slack".to_string()],
+            }],
+        };
+
+        let channels = alert_manager.record_alert("svc-a", AlertType::TrustScoreLow, AlertSeverity::Critical, "trust too low".to_string(), Utc::now());
+
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].channel_id, "oncall-slack");
+    }
+
+    #[test]
+    fn test_dependency_trust_graph_inherits_from_dependencies() {
+        let mut graph = DependencyTrustGraph::new(0.7, 0.05, 0.5);
+        graph.add_dependency("dependent".to_string(), "upstream".to_string(), 0.8);
+
+        let direct_scores = HashMap::from([
+            ("dependent".to_string(), 0.2),
+            ("upstream".to_string(), 0.9),
+        ]);
+
+        let effective = graph.effective_trust_for("dependent", &direct_scores);
+        // direct_score * self_weight + inherited == 0.2 * 0.5 + 0.9
+        assert!((effective - (0.2 * 0.5 + 0.9)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_trust_graph_propagation_updates_dependent_component_score() {
+        let engine = ContinualAssuranceEngine::new();
+
+        let upstream_config = MonitoringConfig {
+            update_interval: Duration::from_secs(30),
+            trust_thresholds: TrustThresholds { critical: 0.2, warning: 0.5, normal: 0.8 },
+            data_sources: Vec::new(),
+            calculation_method: CalculationMethod::WeightedAverage,
+            alert_settings: AlertSettings { enabled: false, escalation_levels: Vec::new(), notification_channels: Vec::new() },
+        };
+        let dependent_config = MonitoringConfig {
+            calculation_method: CalculationMethod::TrustGraph,
+            ..upstream_config.clone()
+        };
+
+        engine.register_component("upstream".to_string(), upstream_config).await;
+        engine.register_component("dependent".to_string(), dependent_config).await;
+        engine.add_trust_dependency("dependent".to_string(), "upstream".to_string(), 0.8).await;
+
+        {
+            let mut registry = engine.component_registry.write().await;
+            registry.get_mut("upstream").unwrap().current_trust_score = 0.9;
+            registry.get_mut("dependent").unwrap().current_trust_score = 0.2;
+        }
+
+        engine.apply_trust_graph_propagation().await.unwrap();
+
+        let registry = engine.component_registry.read().await;
+        let dependent = registry.get("dependent").unwrap();
+        assert!((dependent.current_trust_score - (0.2 * 0.5 + 0.9)).abs() < 1e-9);
+        assert_eq!(dependent.trust_history.len(), 1);
+    }
+
+    #[test]
+    fn test_channel_type_round_trips_new_variants() {
+        for label in ["DiscordWebhook", "Telegram"] {
+            let json = format!("\"{}\"", label);
+            let channel_type: ChannelType = serde_json::from_str(&json).unwrap();
+            assert_eq!(serde_json::to_string(&channel_type).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn test_notification_channel_from_env_reads_configured_channels() {
+        std::env::set_var("SLACK_WEBHOOK", "https://hooks.slack.test/xyz");
+        std::env::remove_var("DISCORD_WEBHOOK");
+        std::env::remove_var("TELEGRAM_BOT_TOKEN");
+        std::env::remove_var("TELEGRAM_CHAT_ID");
+
+        let channels = NotificationChannel::from_env();
+
+        std::env::remove_var("SLACK_WEBHOOK");
+
+        assert_eq!(channels.len(), 1);
+        assert!(matches!(channels[0].channel_type, ChannelType::Slack));
+        assert_eq!(channels[0].endpoint, "https://hooks.slack.test/xyz");
+    }
+
+    #[derive(Default)]
+    struct FakeDispatcher {
+        sent: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl NotificationDispatcher for FakeDispatcher {
+        async fn send(&self, channel: &NotificationChannel, component_id: &str, message: &str) -> Result<(), String> {
+            self.sent.lock().unwrap().push(format!("{}:{}:{}", channel.channel_id, component_id, message));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_alerts_dispatches_on_transition_but_not_on_repeated_flapping() {
+        let mut engine = ContinualAssuranceEngine::new();
+        let fake = Arc::new(FakeDispatcher::default());
+        engine.notification_dispatcher = fake.clone();
+
+        engine.register_component("svc-a".to_string(), MonitoringConfig {
+            update_interval: Duration::from_secs(30),
+            trust_thresholds: TrustThresholds { critical: 0.3, warning: 0.5, normal: 0.8 },
+            data_sources: Vec::new(),
+            calculation_method: CalculationMethod::WeightedAverage,
+            alert_settings: AlertSettings { enabled: true, escalation_levels: Vec::new(), notification_channels: Vec::new() },
+        }).await;
+
+        {
+            let mut alert_manager = engine.alert_manager.write().await;
+            alert_manager.notification_channels.push(NotificationChannel::slack("oncall", "https://hooks.slack.test/abc"));
+            alert_manager.add_grouping_rule(GroupingRule {
+                rule_id: "route-all".to_string(),
+                component_id: None,
+                alert_type: None,
+                channel_ids: vec!["oncall".to_string()],
+            });
+        }
+
+        // Same critical condition observed on two consecutive ticks: only
+        // the first should actually notify the channel.
+        engine.check_alerts("svc-a", 0.1, &[], &HashMap::new()).await;
+        engine.check_alerts("svc-a", 0.1, &[], &HashMap::new()).await;
+
+        let sent = fake.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].contains("svc-a"));
+    }
+
+    #[test]
+    fn test_acknowledge_and_resolve_alert_update_status_and_timestamp() {
+        let mut alert_manager = AlertManager {
+            alerts: Vec::new(),
+            escalation_policies: Vec::new(),
+            notification_channels: Vec::new(),
+            condition_since: HashMap::new(),
+            policy_since: HashMap::new(),
+            escalated_levels: HashMap::new(),
+            suppression_rules: Vec::new(),
+            grouping_rules: Vec::new(),
+        };
+        let now = Utc::now();
+        alert_manager.record_alert("svc-a", AlertType::TrustScoreLow, AlertSeverity::Critical, "critical".to_string(), now);
+        let alert_id = alert_manager.alerts[0].alert_id.clone();
+
+        let ack_at = now + chrono::Duration::seconds(5);
+        alert_manager.acknowledge_alert(&alert_id, ack_at).unwrap();
+        assert!(matches!(alert_manager.alerts[0].status, AlertStatus::Acknowledged));
+        assert_eq!(alert_manager.alerts[0].acknowledged_at, Some(ack_at));
+
+        let resolve_at = now + chrono::Duration::seconds(10);
+        alert_manager.resolve_alert(&alert_id, resolve_at).unwrap();
+        assert!(matches!(alert_manager.alerts[0].status, AlertStatus::Resolved));
+        assert_eq!(alert_manager.alerts[0].resolved_at, Some(resolve_at));
+
+        assert!(alert_manager.acknowledge_alert("no-such-alert", resolve_at).is_err());
+    }
+
+    #[test]
+    fn test_escalate_stale_alerts_waits_for_level_timeout_then_bumps_severity() {
+        let mut alert_manager = AlertManager {
+            alerts: Vec::new(),
+            escalation_policies: Vec::new(),
+            notification_channels: Vec::new(),
+            condition_since: HashMap::new(),
+            policy_since: HashMap::new(),
+            escalated_levels: HashMap::new(),
+            suppression_rules: Vec::new(),
+            grouping_rules: Vec::new(),
+        };
+        let now = Utc::now();
+        alert_manager.record_alert("svc-a", AlertType::TrustScoreLow, AlertSeverity::Low, "low trust".to_string(), now);
+
+        let levels_by_component = HashMap::from([(
+            "svc-a".to_string(),
+            vec![EscalationLevel {
+                level: 1,
+                threshold: 0.0,
+                actions: vec!["oncall".to_string()],
+                timeout: Duration::from_secs(60),
+            }],
+        )]);
+
+        // Not enough time has passed yet -- no escalation.
+        let too_soon = now + chrono::Duration::seconds(30);
+        assert!(alert_manager.escalate_stale_alerts(&levels_by_component, too_soon).is_empty());
+        assert_eq!(alert_manager.alerts[0].escalation_level, 0);
+
+        // Past the level's timeout -- escalates and bumps severity.
+        let past_timeout = now + chrono::Duration::seconds(90);
+        let escalated = alert_manager.escalate_stale_alerts(&levels_by_component, past_timeout);
+        assert_eq!(escalated.len(), 1);
+        assert_eq!(escalated[0].1, vec!["oncall".to_string()]);
+        assert_eq!(alert_manager.alerts[0].escalation_level, 1);
+        assert!(matches!(alert_manager.alerts[0].severity, AlertSeverity::Medium));
+
+        // No further configured level -- stays put and doesn't re-fire.
+        let much_later = now + chrono::Duration::seconds(600);
+        assert!(alert_manager.escalate_stale_alerts(&levels_by_component, much_later).is_empty());
+    }
+
+    #[test]
+    fn test_escalate_stale_alerts_skips_acknowledged_alerts() {
+        let mut alert_manager = AlertManager {
+            alerts: Vec::new(),
+            escalation_policies: Vec::new(),
+            notification_channels: Vec::new(),
+            condition_since: HashMap::new(),
+            policy_since: HashMap::new(),
+            escalated_levels: HashMap::new(),
+            suppression_rules: Vec::new(),
+            grouping_rules: Vec::new(),
+        };
+        let now = Utc::now();
+        alert_manager.record_alert("svc-a", AlertType::TrustScoreLow, AlertSeverity::Low, "low trust".to_string(), now);
+        let alert_id = alert_manager.alerts[0].alert_id.clone();
+        alert_manager.acknowledge_alert(&alert_id, now).unwrap();
+
+        let levels_by_component = HashMap::from([(
+            "svc-a".to_string(),
+            vec![EscalationLevel { level: 1, threshold: 0.0, actions: vec!["oncall".to_string()], timeout: Duration::from_secs(1) }],
+        )]);
+
+        let later = now + chrono::Duration::seconds(60);
+        assert!(alert_manager.escalate_stale_alerts(&levels_by_component, later).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_alert_escalations_pages_the_ladder_channel() {
+        let mut engine = ContinualAssuranceEngine::new();
+        let fake = Arc::new(FakeDispatcher::default());
+        engine.notification_dispatcher = fake.clone();
+
+        engine.register_component("svc-a".to_string(), MonitoringConfig {
+            update_interval: Duration::from_secs(30),
+            trust_thresholds: TrustThresholds { critical: 0.3, warning: 0.5, normal: 0.8 },
+            data_sources: Vec::new(),
+            calculation_method: CalculationMethod::WeightedAverage,
+            alert_settings: AlertSettings {
+                enabled: true,
+                escalation_levels: vec![EscalationLevel {
+                    level: 1,
+                    threshold: 0.0,
+                    actions: vec!["oncall".to_string()],
+                    timeout: Duration::from_secs(0),
+                }],
+                notification_channels: Vec::new(),
+            },
+        }).await;
+
+        {
+            let mut alert_manager = engine.alert_manager.write().await;
+            alert_manager.notification_channels.push(NotificationChannel::slack("oncall", "https://hooks.slack.test/abc"));
+            alert_manager.record_alert("svc-a", AlertType::TrustScoreLow, AlertSeverity::Low, "low trust".to_string(), Utc::now());
+        }
+
+        engine.evaluate_alert_escalations().await;
+
+        let sent = fake.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].contains("svc-a"));
+    }
+
+    fn unique_temp_path(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("trust-monitoring-test-{}-{:?}.json", label, std::thread::current().id()))
+    }
+
+    #[tokio::test]
+    async fn test_persistent_config_store_round_trips_registered_components() {
+        let path = unique_temp_path("registry-roundtrip");
+        let _ = std::fs::remove_file(&path);
+
+        let config = MonitoringConfig {
+            update_interval: Duration::from_secs(30),
+            trust_thresholds: TrustThresholds { critical: 0.3, warning: 0.5, normal: 0.8 },
+            data_sources: vec!["prometheus-main".to_string()],
+            calculation_method: CalculationMethod::WeightedAverage,
+            alert_settings: AlertSettings { enabled: true, escalation_levels: Vec::new(), notification_channels: Vec::new() },
+        };
+
+        {
+            let engine = ContinualAssuranceEngine::with_config_store(PersistentConfigStore::new(&path));
+            engine.register_component("svc-a".to_string(), config.clone()).await;
+        }
+
+        let reloaded = ContinualAssuranceEngine::with_config_store(PersistentConfigStore::new(&path));
+        let registry = reloaded.component_registry.read().await;
+        assert!(registry.contains_key("svc-a"));
+        assert_eq!(registry["svc-a"].monitoring_config.data_sources, vec!["prometheus-main".to_string()]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    struct FlakyDataSource {
+        name: String,
+    }
+
+    #[async_trait::async_trait]
+    impl DataSource for FlakyDataSource {
+        fn get_data_stream(&self) -> Box<dyn Stream<Item = ObservabilityData> + Send + Unpin> {
+            Box::new(tokio_stream::iter(Vec::new()))
+        }
+
+        fn get_source_name(&self) -> String {
+            self.name.clone()
+        }
+
+        fn is_healthy(&self) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_data_sources_raises_communication_failure_after_grace_period() {
+        let mut engine = ContinualAssuranceEngine::with_config_store(PersistentConfigStore::new(unique_temp_path("source-health")));
+        let fake = Arc::new(FakeDispatcher::default());
+        engine.notification_dispatcher = fake.clone();
+
+        engine.register_component("svc-a".to_string(), MonitoringConfig {
+            update_interval: Duration::from_secs(30),
+            trust_thresholds: TrustThresholds { critical: 0.3, warning: 0.5, normal: 0.8 },
+            data_sources: vec!["flaky".to_string()],
+            calculation_method: CalculationMethod::WeightedAverage,
+            alert_settings: AlertSettings { enabled: true, escalation_levels: Vec::new(), notification_channels: Vec::new() },
+        }).await;
+
+        {
+            let mut sources = engine.data_sources.write().await;
+            sources.push(Box::new(FlakyDataSource { name: "flaky".to_string() }));
+        }
+
+        {
+            let mut alert_manager = engine.alert_manager.write().await;
+            alert_manager.notification_channels.push(NotificationChannel::slack("oncall", "https://hooks.slack.test/abc"));
+            alert_manager.add_grouping_rule(GroupingRule {
+                rule_id: "route-all".to_string(),
+                component_id: None,
+                alert_type: None,
+                channel_ids: vec!["oncall".to_string()],
+            });
+        }
+
+        // Simulate the source having already been down past the grace
+        // period, rather than sleeping for real in a unit test.
+        {
+            let mut health = engine.source_health.write().await;
+            health.insert("flaky".to_string(), SourceHealthState {
+                consecutive_failures: 3,
+                unreachable_since: Some(Utc::now() - chrono::Duration::seconds(301)),
+                backoff_until: Utc::now() - chrono::Duration::seconds(1),
+            });
+        }
+
+        engine.poll_data_sources(&["svc-a".to_string()]).await;
+
+        let sent = fake.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].contains("svc-a"));
+    }
+
+    struct FakeMetricsSource {
+        name: String,
+        metrics: HashMap<String, f64>,
+    }
+
+    #[async_trait::async_trait]
+    impl DataSource for FakeMetricsSource {
+        fn get_data_stream(&self) -> Box<dyn Stream<Item = ObservabilityData> + Send + Unpin> {
+            Box::new(tokio_stream::iter(Vec::new()))
+        }
+
+        fn get_source_name(&self) -> String {
+            self.name.clone()
+        }
+
+        fn is_healthy(&self) -> bool {
+            true
+        }
+
+        async fn fetch_metrics(&self, _component_id: &str) -> HashMap<String, f64> {
+            self.metrics.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_data_sources_merges_named_sources_for_a_component() {
+        let engine = ContinualAssuranceEngine::with_config_store(PersistentConfigStore::new(unique_temp_path("merge-sources")));
+
+        let config = |data_sources: Vec<String>| MonitoringConfig {
+            update_interval: Duration::from_secs(30),
+            trust_thresholds: TrustThresholds { critical: 0.3, warning: 0.5, normal: 0.8 },
+            data_sources,
+            calculation_method: CalculationMethod::WeightedAverage,
+            alert_settings: AlertSettings { enabled: true, escalation_levels: Vec::new(), notification_channels: Vec::new() },
+        };
+        engine.register_component("svc-a".to_string(), config(vec!["prom-a".to_string(), "prom-b".to_string()])).await;
+        engine.register_component("svc-b".to_string(), config(Vec::new())).await;
+
+        {
+            let mut sources = engine.data_sources.write().await;
+            sources.push(Box::new(FakeMetricsSource {
+                name: "prom-a".to_string(),
+                metrics: HashMap::from([("security_score".to_string(), 0.9)]),
+            }));
+            sources.push(Box::new(FakeMetricsSource {
+                name: "prom-b".to_string(),
+                metrics: HashMap::from([("performance_score".to_string(), 0.8)]),
+            }));
+        }
+
+        let by_component = engine.poll_data_sources(&["svc-a".to_string(), "svc-b".to_string()]).await;
+
+        assert!(!by_component.contains_key("svc-b"));
+        let data = &by_component["svc-a"];
+        assert_eq!(data.len(), 2);
+        let merged: HashMap<String, f64> = data.iter().flat_map(|d| d.metrics.clone()).collect();
+        assert_eq!(merged.get("security_score"), Some(&0.9));
+        assert_eq!(merged.get("performance_score"), Some(&0.8));
+    }
+
+    #[tokio::test]
+    async fn test_prometheus_data_source_fetch_metrics_is_empty_when_endpoint_unreachable() {
+        let source = PrometheusDataSource::new("prom", "http://127.0.0.1:1", HashMap::from([
+            ("security_score".to_string(), "avg(x{component=\"{component_id}\"})".to_string()),
+        ]));
+
+        let metrics = source.fetch_metrics("svc-a").await;
+
+        assert!(metrics.is_empty());
+    }
 }
@@ -0,0 +1,154 @@
+#![cfg(feature = "http-api")]
+//! HTTP control surface for `IncidentResponseEngine`, modeled on
+//! MeiliSearch's routes module: small `async fn` handlers that take the
+//! shared engine plus a request payload and return a `warp::Reply`, wired
+//! together into filters by `incident_response_routes`. The engine itself
+//! stays transport-agnostic (see `stats`/`health`/`snapshot`/`restore` on
+//! `IncidentResponseEngine`) — everything warp-specific lives here, gated
+//! behind the `http-api` feature so a binary that doesn't serve HTTP pays
+//! nothing for this module.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+use warp::Filter;
+
+use super::incident_response_engine::{
+    EngineSnapshot, IncidentResponseEngine, ResponsePolicy,
+};
+
+async fn list_incidents(engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    Ok(warp::reply::json(&engine.get_active_incidents().await))
+}
+
+async fn get_incident(incident_id: String, engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    let incidents = engine.get_active_incidents().await;
+    match incidents.into_iter().find(|i| i.incident_id == incident_id) {
+        Some(incident) => Ok(warp::reply::with_status(warp::reply::json(&incident), warp::http::StatusCode::OK)),
+        None => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({"error": format!("incident {} not found", incident_id)})),
+            warp::http::StatusCode::NOT_FOUND,
+        )),
+    }
+}
+
+async fn resolve_incident(incident_id: String, engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    match engine.resolve_incident(&incident_id).await {
+        Ok(()) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({"status": "resolved"})),
+            warp::http::StatusCode::OK,
+        )),
+        Err(e) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({"error": e})),
+            warp::http::StatusCode::NOT_FOUND,
+        )),
+    }
+}
+
+async fn list_policies(engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    Ok(warp::reply::json(&*engine.response_policies.read().await))
+}
+
+async fn add_policy(policy: ResponsePolicy, engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    engine.add_response_policy(policy).await;
+    Ok(warp::reply::with_status(
+        warp::reply::json(&serde_json::json!({"status": "registered"})),
+        warp::http::StatusCode::CREATED,
+    ))
+}
+
+async fn get_health(engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    Ok(warp::reply::json(&engine.health().await))
+}
+
+async fn get_stats(engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    Ok(warp::reply::json(&engine.stats().await))
+}
+
+async fn dump_snapshot(engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    Ok(warp::reply::json(&engine.snapshot().await))
+}
+
+async fn restore_snapshot(snapshot: EngineSnapshot, engine: Arc<IncidentResponseEngine>) -> Result<impl warp::Reply, Infallible> {
+    match engine.restore(snapshot).await {
+        Ok(()) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({"status": "restored"})),
+            warp::http::StatusCode::OK,
+        )),
+        Err(e) => Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({"error": e})),
+            warp::http::StatusCode::BAD_REQUEST,
+        )),
+    }
+}
+
+fn with_engine(engine: Arc<IncidentResponseEngine>) -> impl Filter<Extract = (Arc<IncidentResponseEngine>,), Error = Infallible> + Clone {
+    warp::any().map(move || engine.clone())
+}
+
+/// Builds the full `/incidents`, `/policies`, `/health`, `/stats`, and
+/// `/dumps` filter tree for `engine`. The caller combines this with
+/// `warp::serve` (see `trust-monitoring-system/src/main.rs` for the
+/// pattern used by the other engines' routes).
+pub fn incident_response_routes(
+    engine: Arc<IncidentResponseEngine>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let list_incidents_route = warp::path("incidents")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(with_engine(engine.clone()))
+        .and_then(list_incidents);
+
+    let get_incident_route = warp::path!("incidents" / String)
+        .and(warp::get())
+        .and(with_engine(engine.clone()))
+        .and_then(get_incident);
+
+    let resolve_incident_route = warp::path!("incidents" / String / "resolve")
+        .and(warp::post())
+        .and(with_engine(engine.clone()))
+        .and_then(resolve_incident);
+
+    let list_policies_route = warp::path("policies")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(with_engine(engine.clone()))
+        .and_then(list_policies);
+
+    let add_policy_route = warp::path("policies")
+        .and(warp::path::end())
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_engine(engine.clone()))
+        .and_then(add_policy);
+
+    let health_route = warp::path("health")
+        .and(warp::get())
+        .and(with_engine(engine.clone()))
+        .and_then(get_health);
+
+    let stats_route = warp::path("stats")
+        .and(warp::get())
+        .and(with_engine(engine.clone()))
+        .and_then(get_stats);
+
+    let dump_route = warp::path!("dumps")
+        .and(warp::get())
+        .and(with_engine(engine.clone()))
+        .and_then(dump_snapshot);
+
+    let restore_route = warp::path!("dumps" / "restore")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_engine(engine))
+        .and_then(restore_snapshot);
+
+    list_incidents_route
+        .or(get_incident_route)
+        .or(resolve_incident_route)
+        .or(list_policies_route)
+        .or(add_policy_route)
+        .or(health_route)
+        .or(stats_route)
+        .or(dump_route)
+        .or(restore_route)
+}
@@ -1,18 +1,258 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use opentelemetry::{global, Context, KeyValue};
+use opentelemetry::metrics::{Counter, Gauge};
+use opentelemetry::trace::{Span, TraceContextExt, Tracer, TracerProvider as _};
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc, Duration};
 use uuid::Uuid;
 
+/// OpenTelemetry instrumentation for the incident lifecycle: a span per
+/// incident from `create_incident` through `resolve_incident`, child spans
+/// per `ResponseAction` execution, and metrics for `IncidentMetrics` plus
+/// triggered policies, executor failures, and escalations. A no-op unless
+/// an `IncidentResponseEngine` is built `with_telemetry`, so there's no hard
+/// dependency on an OTLP collector being reachable.
+pub struct Telemetry {
+    tracer: opentelemetry_sdk::trace::Tracer,
+    detection_time_gauge: Gauge<f64>,
+    response_time_gauge: Gauge<f64>,
+    resolution_time_gauge: Gauge<f64>,
+    business_impact_gauge: Gauge<f64>,
+    affected_users_gauge: Gauge<u64>,
+    policies_triggered_counter: Counter<u64>,
+    executor_failure_counter: Counter<u64>,
+    escalation_counter: Counter<u64>,
+    /// Spans open for the lifetime of an incident, keyed by `component_id`
+    /// (the same key `active_incidents` uses), from `start_incident_span`
+    /// until `end_incident_span` closes them in `resolve_incident`.
+    incident_spans: RwLock<HashMap<String, opentelemetry::trace::BoxedSpan>>,
+}
+
+impl std::fmt::Debug for Telemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Telemetry").finish_non_exhaustive()
+    }
+}
+
+impl Telemetry {
+    /// Stands up OTLP tracer and meter providers pointed at `otlp_endpoint`
+    /// and registers them as the process-wide global providers.
+    pub fn init(otlp_endpoint: &str) -> Result<Self, String> {
+        let tracer_provider = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(otlp_endpoint),
+            )
+            .install_batch(opentelemetry_sdk::runtime::Tokio)
+            .map_err(|e| e.to_string())?;
+        let tracer = tracer_provider.tracer("trust-monitoring-system-incident-response");
+        global::set_tracer_provider(tracer_provider);
+
+        let meter_provider = opentelemetry_otlp::new_pipeline()
+            .metrics(opentelemetry_sdk::runtime::Tokio)
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(otlp_endpoint),
+            )
+            .build()
+            .map_err(|e| e.to_string())?;
+        let meter = meter_provider.meter("trust-monitoring-system-incident-response");
+        global::set_meter_provider(meter_provider);
+
+        let detection_time_gauge = meter
+            .f64_gauge("lanc_incident_detection_time_ms")
+            .with_description("Milliseconds from trigger to detection for the most recently resolved incident")
+            .init();
+        let response_time_gauge = meter
+            .f64_gauge("lanc_incident_response_time_ms")
+            .with_description("Milliseconds from detection to first response action for the most recently resolved incident")
+            .init();
+        let resolution_time_gauge = meter
+            .f64_gauge("lanc_incident_resolution_time_ms")
+            .with_description("Milliseconds from detection to resolution for the most recently resolved incident")
+            .init();
+        let business_impact_gauge = meter
+            .f64_gauge("lanc_incident_business_impact")
+            .with_description("Business impact score of the most recently resolved incident")
+            .init();
+        let affected_users_gauge = meter
+            .u64_gauge("lanc_incident_affected_users")
+            .with_description("Affected user count of the most recently resolved incident")
+            .init();
+        let policies_triggered_counter = meter
+            .u64_counter("lanc_incident_policies_triggered_total")
+            .with_description("Response policies triggered by process_trust_update")
+            .init();
+        let executor_failure_counter = meter
+            .u64_counter("lanc_incident_executor_failures_total")
+            .with_description("ResponseAction executions that failed or timed out, after retries")
+            .init();
+        let escalation_counter = meter
+            .u64_counter("lanc_incident_escalations_total")
+            .with_description("Policy executions whose escalation_chain was non-empty")
+            .init();
+
+        Ok(Self {
+            tracer,
+            detection_time_gauge,
+            response_time_gauge,
+            resolution_time_gauge,
+            business_impact_gauge,
+            affected_users_gauge,
+            policies_triggered_counter,
+            executor_failure_counter,
+            escalation_counter,
+            incident_spans: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Opens a span for a newly created incident, tagged with the incident
+    /// id and severity. The span stays open until `end_incident_span` closes
+    /// it from `resolve_incident`.
+    async fn start_incident_span(&self, component_id: &str, incident_id: &str, severity: &IncidentSeverity) {
+        let span = self.tracer
+            .span_builder("incident")
+            .with_attributes(vec![
+                KeyValue::new("incident_id", incident_id.to_string()),
+                KeyValue::new("component_id", component_id.to_string()),
+                KeyValue::new("severity", format!("{:?}", severity)),
+            ])
+            .start(&self.tracer);
+        self.incident_spans.write().await.insert(component_id.to_string(), span);
+    }
+
+    /// Opens a child span around a single `ResponseAction` execution,
+    /// parented to the incident span for `component_id` when one is open.
+    async fn start_action_span(&self, component_id: &str, action: &ResponseAction) -> opentelemetry::trace::BoxedSpan {
+        let builder = self.tracer
+            .span_builder("response_action")
+            .with_attributes(vec![
+                KeyValue::new("action_id", action.action_id.clone()),
+                KeyValue::new("action_type", format!("{:?}", action.action_type)),
+                KeyValue::new("target_components", action.target_components.join(",")),
+            ]);
+
+        let spans = self.incident_spans.read().await;
+        match spans.get(component_id) {
+            Some(parent) => {
+                let parent_cx = Context::new().with_remote_span_context(parent.span_context().clone());
+                self.tracer.build_with_context(builder, &parent_cx)
+            }
+            None => builder.start(&self.tracer),
+        }
+    }
+
+    /// Tags `span` with the action's outcome, counts executor failures, and
+    /// ends the span.
+    fn end_action_span(&self, mut span: opentelemetry::trace::BoxedSpan, status: &ActionStatus) {
+        span.set_attribute(KeyValue::new("success", matches!(status, ActionStatus::Completed)));
+        if matches!(status, ActionStatus::Failed) {
+            self.executor_failure_counter.add(1, &[]);
+        }
+        span.end();
+    }
+
+    fn record_policy_triggered(&self) {
+        self.policies_triggered_counter.add(1, &[]);
+    }
+
+    fn record_escalation(&self) {
+        self.escalation_counter.add(1, &[]);
+    }
+
+    /// Records an incident's final metrics and closes the span opened in
+    /// `start_incident_span`.
+    async fn end_incident_span(&self, component_id: &str, metrics: &IncidentMetrics) {
+        self.detection_time_gauge.record(metrics.detection_time.num_milliseconds() as f64, &[]);
+        self.response_time_gauge.record(metrics.response_time.num_milliseconds() as f64, &[]);
+        self.resolution_time_gauge.record(metrics.resolution_time.num_milliseconds() as f64, &[]);
+        self.business_impact_gauge.record(metrics.business_impact, &[]);
+        self.affected_users_gauge.record(metrics.affected_users, &[]);
+
+        if let Some(mut span) = self.incident_spans.write().await.remove(component_id) {
+            span.end();
+        }
+    }
+}
+
 /// Incident response orchestration engine for automated security actions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IncidentResponseEngine {
     pub response_policies: Arc<RwLock<Vec<ResponsePolicy>>>,
     pub active_incidents: Arc<RwLock<HashMap<String, Incident>>>,
-    pub action_executors: Arc<RwLock<HashMap<String, Box<dyn ActionExecutor + Send + Sync>>>>,
+    pub action_executors: Arc<RwLock<HashMap<String, Arc<dyn ActionExecutor + Send + Sync>>>>,
     pub escalation_manager: Arc<RwLock<EscalationManager>>,
     pub recovery_coordinator: Arc<RwLock<RecoveryCoordinator>>,
+    /// Policy executions waiting to run, ordered by `QueuedExecution::execution_id`
+    /// so concurrent `process_trust_update` callers are serialized
+    /// deterministically instead of racing each other through incident history.
+    pending_queue: Arc<RwLock<VecDeque<QueuedExecution>>>,
+    next_execution_id: Arc<RwLock<u64>>,
+    /// Only one `drain_pending_queue` pass may mutate incident history at a
+    /// time; a caller that finds `Processing` already set leaves its work on
+    /// `pending_queue` for the in-flight pass to pick up.
+    execution_state: Arc<RwLock<ExecutionState>>,
+    /// OTEL export for the incident lifecycle. `None` unless the engine was
+    /// built `with_telemetry`, so instrumentation costs nothing by default.
+    #[serde(skip)]
+    telemetry: Option<Arc<Telemetry>>,
+    /// Approval requests currently awaiting a decision, keyed by
+    /// `request_seq`, so `list_pending_approvals` can show a dashboard
+    /// what's outstanding.
+    pending_approvals: Arc<RwLock<HashMap<u64, ApprovalRequest>>>,
+    next_approval_seq: Arc<RwLock<u64>>,
+    /// The other half of each pending request's correlation: resolved by
+    /// `submit_approval`, consumed by the `run_escalation_step` call
+    /// awaiting it (or dropped on timeout).
+    #[serde(skip)]
+    approval_responses: Arc<RwLock<HashMap<u64, tokio::sync::oneshot::Sender<(ApprovalDecision, String)>>>>,
+    /// Engine-wide default for what an irrecoverably-failed action chain
+    /// means for its target components' trust state. Overridable per
+    /// `ResponsePolicy` via `ResponsePolicy::failure_mode`.
+    pub failure_mode: Arc<RwLock<FailureMode>>,
+    /// The latest trust score this engine has observed for each component,
+    /// as recorded by `process_trust_update`. The baseline `System::snapshot`
+    /// and `apply_changeset` diff and roll back against.
+    component_trust_scores: Arc<RwLock<HashMap<String, f64>>>,
+    /// Global audit-only override: when `true`, no `ResponseAction` is ever
+    /// executed regardless of its own `allowed_to_mutate`, and every action
+    /// is instead recorded as `ActionStatus::Audited`.
+    pub dry_run: Arc<RwLock<bool>>,
+    /// Resolves a `SignedTrustUpdate::key_id` to its signer's public key for
+    /// `process_signed_trust_update`. `None` unless the engine was built
+    /// `with_key_resolver`; while it is `None`, every signed update is
+    /// rejected rather than silently trusted.
+    #[serde(skip)]
+    key_resolver: Option<Arc<dyn KeyResolver + Send + Sync>>,
+    /// How far `process_signed_trust_update` lets a `SignedTrustUpdate`'s
+    /// `timestamp` drift from now, in either direction, before rejecting it
+    /// as a possible replay.
+    pub clock_skew_allowance: Arc<RwLock<Duration>>,
+    /// Gates which calling principals may drive privileged `ResponseAction`s
+    /// (see `is_privileged_action`). Disabled by default via `Acl::default`,
+    /// so every principal is permitted until an operator opts in.
+    pub acl: Arc<RwLock<Acl>>,
+}
+
+/// A single policy trigger waiting its turn in `pending_queue`.
+#[derive(Debug, Clone)]
+struct QueuedExecution {
+    execution_id: u64,
+    policy: ResponsePolicy,
+    component_id: String,
+    principal: String,
+    verdict: TrustVerdict,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExecutionState {
+    Idle,
+    Processing,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +264,9 @@ pub struct ResponsePolicy {
     pub priority: u32,
     pub enabled: bool,
     pub escalation_chain: Vec<EscalationStep>,
+    /// Overrides `IncidentResponseEngine::failure_mode` for this policy's
+    /// own action chain. `None` inherits the engine-wide default.
+    pub failure_mode: Option<FailureMode>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,6 +307,14 @@ pub struct ResponseAction {
     pub timeout: Duration,
     pub retry_count: u32,
     pub dependencies: Vec<String>,
+    /// Whether `execute_actions` is allowed to actually run this action
+    /// against its executor. Defaults to `false` (audit-only): an action
+    /// that isn't explicitly marked mutable is still evaluated and recorded
+    /// as an `ActionStatus::Audited` `ActionRecord` describing what it would
+    /// have done, but `IncidentResponseEngine::dry_run` and this flag both
+    /// have to allow mutation before an executor is actually invoked.
+    #[serde(default)]
+    pub allowed_to_mutate: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -106,6 +357,128 @@ pub struct Incident {
     pub actions_taken: Vec<ActionRecord>,
     pub escalation_history: Vec<EscalationRecord>,
     pub metrics: IncidentMetrics,
+    /// Tamper-evident audit log: one entry per `ActionRecord`,
+    /// `EscalationRecord`, and status transition, each hashed together with
+    /// the previous entry's hash. See `verify_incident_integrity`.
+    pub audit_chain: Vec<AuditChainEntry>,
+    /// Hash of the last entry in `audit_chain`, or `AUDIT_CHAIN_GENESIS` if
+    /// the chain is empty.
+    pub audit_head_hash: String,
+}
+
+/// Fixed starting hash for an incident's audit chain, used as `h_0` so the
+/// first real entry's hash still depends on a known, constant value.
+const AUDIT_CHAIN_GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// What a single `AuditChainEntry` records.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AuditEntryKind {
+    Action(ActionRecord),
+    Escalation(EscalationRecord),
+    StatusTransition { from: IncidentStatus, to: IncidentStatus },
+}
+
+/// One link in an incident's hash chain: `hash = H(previous_hash ||
+/// serialize(kind) || recorded_at)`. Stored alongside its own index so a
+/// `verify_incident_integrity` failure can point at the offending entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditChainEntry {
+    pub sequence: usize,
+    pub kind: AuditEntryKind,
+    pub recorded_at: DateTime<Utc>,
+    pub hash: String,
+}
+
+/// Which side of a pair a `MerklePathItem`'s hash sits on, for recombining
+/// `left || right` in the right order while walking a proof.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum MerkleDirection {
+    Left,
+    Right,
+}
+
+/// A single step of a Merkle inclusion proof: the sibling hash needed at
+/// that level, and which side it belongs on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MerklePathItem {
+    pub hash: String,
+    pub direction: MerkleDirection,
+}
+
+pub type MerkleHash = String;
+
+/// Hashes `previous_hash`, the canonical (serde_json) bytes of `kind`, and
+/// `recorded_at` together into the next link of an audit chain.
+fn audit_chain_link_hash(previous_hash: &str, kind: &AuditEntryKind, recorded_at: &DateTime<Utc>) -> Result<String, String> {
+    let mut bytes = previous_hash.as_bytes().to_vec();
+    bytes.extend_from_slice(&serde_json::to_vec(kind).map_err(|e| e.to_string())?);
+    bytes.extend_from_slice(recorded_at.to_rfc3339().as_bytes());
+    let digest = ring::digest::digest(&ring::digest::SHA256, &bytes);
+    Ok(hex::encode(digest.as_ref()))
+}
+
+/// Combines two child hashes into their parent's hash for a Merkle tree.
+fn merkle_combine(left: &str, right: &str) -> String {
+    let mut bytes = Vec::with_capacity(left.len() + right.len());
+    bytes.extend_from_slice(left.as_bytes());
+    bytes.extend_from_slice(right.as_bytes());
+    hex::encode(ring::digest::digest(&ring::digest::SHA256, &bytes).as_ref())
+}
+
+/// Computes a Merkle root over `leaf_hashes`. An odd node at any level is
+/// promoted unchanged rather than duplicated, avoiding the well-known
+/// duplicate-leaf ambiguity in naive Merkle tree constructions.
+fn merkle_root(leaf_hashes: &[String]) -> Option<MerkleHash> {
+    if leaf_hashes.is_empty() {
+        return None;
+    }
+
+    let mut level = leaf_hashes.to_vec();
+    while level.len() > 1 {
+        let mut next = Vec::with_capacity((level.len() + 1) / 2);
+        for pair in level.chunks(2) {
+            if pair.len() == 2 {
+                next.push(merkle_combine(&pair[0], &pair[1]));
+            } else {
+                next.push(pair[0].clone());
+            }
+        }
+        level = next;
+    }
+
+    level.into_iter().next()
+}
+
+/// Builds an inclusion proof for the leaf at `index`: the sibling hash and
+/// side needed at each level to recompute `merkle_root` starting from that
+/// single leaf.
+fn merkle_proof(leaf_hashes: &[String], mut index: usize) -> Option<Vec<MerklePathItem>> {
+    if index >= leaf_hashes.len() {
+        return None;
+    }
+
+    let mut path = Vec::new();
+    let mut level = leaf_hashes.to_vec();
+    while level.len() > 1 {
+        let sibling_index = if index % 2 == 0 { index + 1 } else { index - 1 };
+        if sibling_index < level.len() {
+            let direction = if index % 2 == 0 { MerkleDirection::Right } else { MerkleDirection::Left };
+            path.push(MerklePathItem { hash: level[sibling_index].clone(), direction });
+        }
+
+        let mut next = Vec::with_capacity((level.len() + 1) / 2);
+        for pair in level.chunks(2) {
+            if pair.len() == 2 {
+                next.push(merkle_combine(&pair[0], &pair[1]));
+            } else {
+                next.push(pair[0].clone());
+            }
+        }
+        index /= 2;
+        level = next;
+    }
+
+    Some(path)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -144,6 +517,10 @@ pub enum ActionStatus {
     Completed,
     Failed,
     Cancelled,
+    /// Evaluated but not executed: the action's `allowed_to_mutate` was
+    /// `false`, the engine was running with `dry_run` enabled, or the
+    /// action is privileged and its principal is not allowed by `acl`.
+    Audited,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -154,6 +531,43 @@ pub struct EscalationRecord {
     pub actions_taken: Vec<String>,
 }
 
+/// A request for a human to approve or deny running an `EscalationStep`'s
+/// actions, sequenced like a Debug Adapter Protocol request so a later
+/// `submit_approval` call can be correlated back to exactly one pending
+/// request by `request_seq`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalRequest {
+    pub request_seq: u64,
+    pub incident_id: String,
+    pub component_id: String,
+    pub step_id: String,
+    pub notification_channels: Vec<String>,
+    pub requested_at: DateTime<Utc>,
+    pub deadline: DateTime<Utc>,
+}
+
+/// An external approver's response to an `ApprovalRequest`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ApprovalDecision {
+    Approve,
+    Deny,
+    /// A reverse-request for more information before deciding; treated the
+    /// same as `Deny` for whether the step's actions run, but recorded
+    /// distinctly so an operator knows to follow up rather than assuming
+    /// the step was rejected outright.
+    NeedsMoreInfo { question: String },
+}
+
+/// What ultimately happened to an `ApprovalRequest`, recorded in the
+/// incident's `EscalationRecord` regardless of how it was resolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ApprovalOutcome {
+    Approved { approver: String },
+    Denied { approver: String },
+    NeedsMoreInfo { approver: String, question: String },
+    TimedOut,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IncidentMetrics {
     pub detection_time: Duration,
@@ -318,6 +732,232 @@ impl ActionExecutor for WorkflowExecutor {
     }
 }
 
+/// A single target or a fan-out list sharing one dispatch/fold code path,
+/// so `RemoteExecutor` doesn't need a separate branch for the common
+/// single-target case.
+#[derive(Debug, Clone)]
+enum OneOrVec<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrVec<T> {
+    fn from_targets(mut targets: Vec<T>) -> Self {
+        if targets.len() == 1 {
+            OneOrVec::One(targets.pop().expect("len == 1 checked above"))
+        } else {
+            OneOrVec::Many(targets)
+        }
+    }
+
+    fn iter(&self) -> std::slice::Iter<'_, T> {
+        match self {
+            OneOrVec::One(t) => std::slice::from_ref(t).iter(),
+            OneOrVec::Many(v) => v.iter(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            OneOrVec::One(_) => 1,
+            OneOrVec::Many(v) => v.len(),
+        }
+    }
+}
+
+/// A job dispatched to the agent owning `target_component`. `job_id` is
+/// deterministic (`action_id:target_component`) rather than random, so a
+/// scheduler retry of the same action produces the same job id and an
+/// agent that dedupes by `job_id` won't double-execute it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteJob {
+    pub job_id: String,
+    pub action_id: String,
+    pub action_type: ActionType,
+    pub parameters: HashMap<String, String>,
+    pub target_component: String,
+}
+
+/// An agent's reply to a dispatched `RemoteJob`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobResult {
+    pub job_id: String,
+    pub success: bool,
+    pub message: String,
+}
+
+/// Transport used by `RemoteExecutor` to hand a `RemoteJob` to the agent at
+/// `agent_endpoint` and wait for its `JobResult`. Kept sync since
+/// `ActionExecutor::execute` is sync; swap in a fake for tests.
+pub trait AgentTransport {
+    fn dispatch(&self, agent_endpoint: &str, job: &RemoteJob) -> Result<JobResult, String>;
+}
+
+/// Dispatches jobs to agents over HTTP: `POST {endpoint}/jobs` with the
+/// job as its JSON body, expecting a `JobResult` back as JSON.
+pub struct HttpAgentTransport {
+    client: reqwest::blocking::Client,
+}
+
+impl HttpAgentTransport {
+    /// Gives the underlying blocking client its own request timeout so an
+    /// unresponsive agent can't pin the `spawn_blocking` thread `dispatch`
+    /// runs on forever -- `execute_action_with_retries`'s
+    /// `tokio::time::timeout` only bounds how long the caller *waits* for
+    /// the blocking task, not how long that task itself runs.
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::blocking::Client::builder()
+                .timeout(std::time::Duration::from_secs(30))
+                .build()
+                .expect("reqwest::blocking::Client::builder with a fixed timeout should never fail to build"),
+        }
+    }
+}
+
+impl Default for HttpAgentTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentTransport for HttpAgentTransport {
+    fn dispatch(&self, agent_endpoint: &str, job: &RemoteJob) -> Result<JobResult, String> {
+        self.client
+            .post(format!("{}/jobs", agent_endpoint))
+            .json(job)
+            .send()
+            .map_err(|e| format!("dispatch to {} failed: {}", agent_endpoint, e))?
+            .json::<JobResult>()
+            .map_err(|e| format!("invalid job result from {}: {}", agent_endpoint, e))
+    }
+}
+
+/// A registered remote agent: which components it owns, and when it last
+/// proved it's alive.
+#[derive(Debug, Clone)]
+pub struct AgentInfo {
+    pub agent_id: String,
+    pub endpoint: String,
+    pub owned_components: Vec<String>,
+    pub last_heartbeat: DateTime<Utc>,
+}
+
+/// Dispatches `ResponseAction`s to lightweight remote agents instead of
+/// running them locally. Each target component's owning agent receives a
+/// `RemoteJob` and replies with a `JobResult`; results across all targets
+/// fold into a single `ActionResult`. `is_healthy` reflects whether any
+/// registered agent has heartbeat within `heartbeat_ttl`, and results are
+/// cached by `job_id` so a scheduler retry of the same action reuses the
+/// prior outcome instead of dispatching it twice.
+pub struct RemoteExecutor {
+    name: String,
+    transport: Box<dyn AgentTransport + Send + Sync>,
+    registry: std::sync::RwLock<HashMap<String, AgentInfo>>,
+    heartbeat_ttl: Duration,
+    completed_jobs: std::sync::RwLock<HashMap<String, JobResult>>,
+}
+
+impl RemoteExecutor {
+    pub fn new(name: &str, heartbeat_ttl: Duration) -> Self {
+        Self::with_transport(name, heartbeat_ttl, Box::new(HttpAgentTransport::new()))
+    }
+
+    pub fn with_transport(name: &str, heartbeat_ttl: Duration, transport: Box<dyn AgentTransport + Send + Sync>) -> Self {
+        Self {
+            name: name.to_string(),
+            transport,
+            registry: std::sync::RwLock::new(HashMap::new()),
+            heartbeat_ttl,
+            completed_jobs: std::sync::RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Registers (or re-registers) an agent as owning `owned_components`,
+    /// with a fresh heartbeat.
+    pub fn register_agent(&self, agent_id: &str, endpoint: &str, owned_components: Vec<String>) {
+        self.registry.write().expect("registry lock poisoned").insert(agent_id.to_string(), AgentInfo {
+            agent_id: agent_id.to_string(),
+            endpoint: endpoint.to_string(),
+            owned_components,
+            last_heartbeat: Utc::now(),
+        });
+    }
+
+    /// Records that `agent_id` is still alive.
+    pub fn heartbeat(&self, agent_id: &str) {
+        if let Some(agent) = self.registry.write().expect("registry lock poisoned").get_mut(agent_id) {
+            agent.last_heartbeat = Utc::now();
+        }
+    }
+
+    fn agent_for_component(&self, component_id: &str) -> Option<AgentInfo> {
+        self.registry.read().expect("registry lock poisoned").values()
+            .find(|a| a.owned_components.iter().any(|c| c == component_id))
+            .cloned()
+    }
+
+    fn is_agent_fresh(&self, agent: &AgentInfo) -> bool {
+        Utc::now() - agent.last_heartbeat <= self.heartbeat_ttl
+    }
+}
+
+impl ActionExecutor for RemoteExecutor {
+    fn execute(&self, action: &ResponseAction) -> Result<ActionResult, String> {
+        if action.target_components.is_empty() {
+            return Err("RemoteExecutor requires at least one target component".to_string());
+        }
+
+        let targets = OneOrVec::from_targets(action.target_components.clone());
+        let mut job_results = Vec::with_capacity(targets.len());
+
+        for component in targets.iter() {
+            let job_id = format!("{}:{}", action.action_id, component);
+
+            if let Some(cached) = self.completed_jobs.read().expect("completed_jobs lock poisoned").get(&job_id).cloned() {
+                job_results.push(cached);
+                continue;
+            }
+
+            let agent = self.agent_for_component(component)
+                .ok_or_else(|| format!("no agent registered for component {}", component))?;
+
+            let job = RemoteJob {
+                job_id: job_id.clone(),
+                action_id: action.action_id.clone(),
+                action_type: action.action_type.clone(),
+                parameters: action.parameters.clone(),
+                target_component: component.clone(),
+            };
+
+            let result = self.transport.dispatch(&agent.endpoint, &job)?;
+            self.completed_jobs.write().expect("completed_jobs lock poisoned").insert(job_id, result.clone());
+            job_results.push(result);
+        }
+
+        let success = job_results.iter().all(|r| r.success);
+        let message = job_results.iter()
+            .map(|r| format!("{}: {}", r.job_id, r.message))
+            .collect::<Vec<_>>()
+            .join("; ");
+        let metrics = HashMap::from([
+            ("targets_dispatched".to_string(), job_results.len() as f64),
+            ("targets_succeeded".to_string(), job_results.iter().filter(|r| r.success).count() as f64),
+        ]);
+
+        Ok(ActionResult { success, message, metrics, timestamp: Utc::now() })
+    }
+
+    fn get_executor_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn is_healthy(&self) -> bool {
+        let registry = self.registry.read().expect("registry lock poisoned");
+        !registry.is_empty() && registry.values().any(|a| self.is_agent_fresh(a))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionResult {
     pub success: bool,
@@ -460,9 +1100,66 @@ impl IncidentResponseEngine {
                 recovery_history: Vec::new(),
                 health_checks: Vec::new(),
             })),
+            pending_queue: Arc::new(RwLock::new(VecDeque::new())),
+            next_execution_id: Arc::new(RwLock::new(0)),
+            execution_state: Arc::new(RwLock::new(ExecutionState::Idle)),
+            telemetry: None,
+            pending_approvals: Arc::new(RwLock::new(HashMap::new())),
+            next_approval_seq: Arc::new(RwLock::new(0)),
+            approval_responses: Arc::new(RwLock::new(HashMap::new())),
+            failure_mode: Arc::new(RwLock::new(FailureMode::Deny)),
+            component_trust_scores: Arc::new(RwLock::new(HashMap::new())),
+            dry_run: Arc::new(RwLock::new(false)),
+            key_resolver: None,
+            clock_skew_allowance: Arc::new(RwLock::new(Duration::seconds(300))),
+            acl: Arc::new(RwLock::new(Acl::default())),
         }
     }
 
+    /// Export the incident lifecycle, action executions, and
+    /// `IncidentMetrics` through `telemetry`'s configured OTLP pipeline from
+    /// then on.
+    pub fn with_telemetry(mut self, telemetry: Telemetry) -> Self {
+        self.telemetry = Some(Arc::new(telemetry));
+        self
+    }
+
+    /// Sets the engine-wide default `FailureMode`, overridable per policy
+    /// via `ResponsePolicy::failure_mode`.
+    pub fn with_failure_mode(mut self, mode: FailureMode) -> Self {
+        self.failure_mode = Arc::new(RwLock::new(mode));
+        self
+    }
+
+    /// Puts the engine in (or out of) global audit-only mode: while
+    /// `dry_run` is `true`, no `ResponseAction` executes regardless of its
+    /// own `allowed_to_mutate`.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = Arc::new(RwLock::new(dry_run));
+        self
+    }
+
+    /// Configures the `KeyResolver` used to verify `SignedTrustUpdate`s via
+    /// `process_signed_trust_update`.
+    pub fn with_key_resolver(mut self, resolver: Box<dyn KeyResolver + Send + Sync>) -> Self {
+        self.key_resolver = Some(Arc::from(resolver));
+        self
+    }
+
+    /// Sets the allowed clock skew for `process_signed_trust_update`'s
+    /// replay protection.
+    pub fn with_clock_skew_allowance(mut self, allowance: Duration) -> Self {
+        self.clock_skew_allowance = Arc::new(RwLock::new(allowance));
+        self
+    }
+
+    /// Configures the `Acl` gating which principals may drive privileged
+    /// `ResponseAction`s via `process_trust_update`/`process_signed_trust_update`.
+    pub fn with_acl(mut self, acl: Acl) -> Self {
+        self.acl = Arc::new(RwLock::new(acl));
+        self
+    }
+
     /// Add a response policy
     pub async fn add_response_policy(&self, policy: ResponsePolicy) {
         let mut policies = self.response_policies.write().await;
@@ -470,29 +1167,207 @@ impl IncidentResponseEngine {
         policies.sort_by_key(|p| p.priority);
     }
 
-    /// Add an action executor
+    /// Add an action executor. Stored as an `Arc` (not the `Box` passed in)
+    /// so `execute_action_with_retries` can clone a handle to it and run it
+    /// on a blocking thread without holding the `action_executors` read lock
+    /// for the duration of the call.
     pub async fn add_action_executor(&self, name: String, executor: Box<dyn ActionExecutor + Send + Sync>) {
         let mut executors = self.action_executors.write().await;
-        executors.insert(name, executor);
+        executors.insert(name, Arc::from(executor));
+    }
+
+    /// Verifies a `SignedTrustUpdate` before letting it anywhere near
+    /// `process_trust_update`: resolves `update.key_id` via the configured
+    /// `KeyResolver`, checks its signature over
+    /// `canonical_trust_update_bytes`, and rejects it if `update.timestamp`
+    /// falls outside `clock_skew_allowance` of now (replay protection). Only
+    /// a verified update is allowed to drive a `ResponseAction`; anything
+    /// that fails verification is dropped here with an auditable reason
+    /// instead of ever reaching policy evaluation. The verified `update.key_id`
+    /// doubles as the calling principal for `Acl` gating, since a signature
+    /// check is a stronger identity proof than a caller-supplied string.
+    pub async fn process_signed_trust_update(&self, update: &SignedTrustUpdate, context: &TrustContext) -> Result<(Vec<String>, TrustVerdict, FailureModeOutcome), String> {
+        let resolver = self.key_resolver.as_ref()
+            .ok_or_else(|| "trust update rejected: no key resolver configured".to_string())?;
+
+        let public_key = resolver.resolve_key(&update.key_id)
+            .ok_or_else(|| format!("trust update rejected: unknown key id {}", update.key_id))?;
+
+        let canonical = canonical_trust_update_bytes(
+            &update.component_id, update.trust_score, &update.timestamp, &update.security_events,
+        )?;
+        verify_trust_update_signature(&public_key, &canonical, &update.signature)
+            .map_err(|e| format!("trust update rejected for component {}: {}", update.component_id, e))?;
+
+        let allowance = *self.clock_skew_allowance.read().await;
+        if (Utc::now() - update.timestamp).abs() > allowance {
+            return Err(format!(
+                "trust update rejected for component {}: timestamp {} is outside the allowed {}s clock skew",
+                update.component_id, update.timestamp, allowance.num_seconds()
+            ));
+        }
+
+        self.process_trust_update(&update.component_id, &update.key_id, update.trust_score, context).await
     }
 
-    /// Process a trust score update and trigger appropriate responses
-    pub async fn process_trust_update(&self, component_id: &str, trust_score: f64, context: &TrustContext) -> Result<Vec<String>, String> {
+    /// Process a trust score update and trigger appropriate responses.
+    /// Matching policies are enqueued onto `pending_queue` in order, then
+    /// drained by a single execution pass so concurrent callers never
+    /// interleave their actions against incident history. `principal`
+    /// identifies the caller driving this update, checked against `acl`
+    /// before any privileged `ResponseAction` (see `is_privileged_action`)
+    /// is allowed to actually execute.
+    pub async fn process_trust_update(&self, component_id: &str, principal: &str, trust_score: f64, context: &TrustContext) -> Result<(Vec<String>, TrustVerdict, FailureModeOutcome), String> {
+        self.component_trust_scores.write().await.insert(component_id.to_string(), trust_score);
+
         let policies = self.response_policies.read().await;
-        let mut triggered_actions = Vec::new();
-        
+        if policies.is_empty() {
+            return Ok((Vec::new(), TrustVerdict::Unspecified, FailureModeOutcome {
+                failure_mode: *self.failure_mode.read().await,
+                irrecoverable: false,
+                changed_outcome: false,
+            }));
+        }
+
+        let mut matched = Vec::new();
+        let mut overall_verdict = TrustVerdict::Proceed;
+
         for policy in policies.iter() {
             if !policy.enabled {
                 continue;
             }
-            
+
             if self.evaluate_policy_conditions(policy, component_id, trust_score, context).await {
-                let actions = self.execute_policy_actions(policy, component_id).await?;
-                triggered_actions.extend(actions);
+                if let Some(telemetry) = &self.telemetry {
+                    telemetry.record_policy_triggered();
+                }
+                let verdict = self.compute_trust_verdict(policy, trust_score, context);
+                if verdict.severity() > overall_verdict.severity() {
+                    overall_verdict = verdict;
+                }
+                matched.push((policy.clone(), verdict));
             }
         }
-        
-        Ok(triggered_actions)
+        drop(policies);
+
+        if matched.is_empty() {
+            return Ok((Vec::new(), TrustVerdict::Proceed, FailureModeOutcome {
+                failure_mode: *self.failure_mode.read().await,
+                irrecoverable: false,
+                changed_outcome: false,
+            }));
+        }
+
+        {
+            let mut next_id = self.next_execution_id.write().await;
+            let mut queue = self.pending_queue.write().await;
+            for (policy, verdict) in matched {
+                queue.push_back(QueuedExecution {
+                    execution_id: *next_id,
+                    policy,
+                    component_id: component_id.to_string(),
+                    principal: principal.to_string(),
+                    verdict,
+                });
+                *next_id += 1;
+            }
+        }
+
+        let (actions, failure_outcome) = self.drain_pending_queue().await?;
+        let failure_outcome = failure_outcome.unwrap_or(FailureModeOutcome {
+            failure_mode: *self.failure_mode.read().await,
+            irrecoverable: false,
+            changed_outcome: false,
+        });
+        Ok((actions, overall_verdict, failure_outcome))
+    }
+
+    /// Grades a matched policy's severity from the `TrustContext` that
+    /// triggered it. A condition over `SecurityEvent`/`DependencyFailure`/
+    /// `CommunicationFailure`, or a security event at or above 0.9
+    /// severity, is treated as conclusive compromise (`FatalTrustFailure`);
+    /// a condition over `TrustScore`/`PerformanceMetric`/
+    /// `BehavioralAnomaly` alone is treated as degraded-but-recoverable. An
+    /// out-of-range trust score means the verdict can't be trusted either,
+    /// so it's reported as `OtherError` rather than guessed.
+    fn compute_trust_verdict(&self, policy: &ResponsePolicy, trust_score: f64, context: &TrustContext) -> TrustVerdict {
+        if trust_score.is_nan() || !(0.0..=1.0).contains(&trust_score) {
+            return TrustVerdict::OtherError;
+        }
+
+        let has_fatal_condition = policy.conditions.iter().any(|condition| matches!(
+            condition.condition_type,
+            ConditionType::SecurityEvent | ConditionType::DependencyFailure | ConditionType::CommunicationFailure
+        ));
+        let has_critical_security_event = context.security_events.iter().any(|event| event.severity >= 0.9);
+
+        if has_fatal_condition || has_critical_security_event {
+            TrustVerdict::FatalTrustFailure
+        } else {
+            TrustVerdict::RecoverableTrustFailure
+        }
+    }
+
+    /// Runs every queued execution in `execution_id` order. If another call
+    /// already has a drain in progress, this returns immediately leaving the
+    /// freshly-queued work for that in-flight pass to pick up, so only one
+    /// pass mutates incident history at a time. Returns the accumulated
+    /// action messages alongside a `FailureModeOutcome` folded over this
+    /// pass: `irrecoverable` and `changed_outcome` are set if any policy's
+    /// were, and `failure_mode` is the last policy's (policies queued by a
+    /// concurrent caller may be interleaved in, same as the actions already
+    /// were).
+    async fn drain_pending_queue(&self) -> Result<(Vec<String>, Option<FailureModeOutcome>), String> {
+        let merge = |previous: Option<FailureModeOutcome>, outcome: FailureModeOutcome| match previous {
+            Some(prev) => FailureModeOutcome {
+                failure_mode: outcome.failure_mode,
+                irrecoverable: prev.irrecoverable || outcome.irrecoverable,
+                changed_outcome: prev.changed_outcome || outcome.changed_outcome,
+            },
+            None => outcome,
+        };
+
+        {
+            let mut state = self.execution_state.write().await;
+            if *state == ExecutionState::Processing {
+                return Ok((Vec::new(), None));
+            }
+            *state = ExecutionState::Processing;
+        }
+
+        let mut all_actions = Vec::new();
+        let mut last_failure_outcome = None;
+        loop {
+            let next = {
+                let mut queue = self.pending_queue.write().await;
+                queue.pop_front()
+            };
+
+            let Some(queued) = next else {
+                // Nothing left to pop, but a concurrent `process_trust_update`
+                // may have pushed between our pop returning `None` and here.
+                // Re-check with both locks held so we never flip to `Idle`
+                // while leaving freshly-queued work stranded.
+                let mut state = self.execution_state.write().await;
+                let mut queue = self.pending_queue.write().await;
+                if let Some(queued) = queue.pop_front() {
+                    drop(queue);
+                    drop(state);
+                    let (actions, outcome) = self.execute_policy_actions(&queued.policy, &queued.component_id, &queued.principal, queued.verdict).await?;
+                    all_actions.extend(actions);
+                    last_failure_outcome = Some(merge(last_failure_outcome.take(), outcome));
+                    continue;
+                }
+                *state = ExecutionState::Idle;
+                break;
+            };
+
+            let (actions, outcome) = self.execute_policy_actions(&queued.policy, &queued.component_id, &queued.principal, queued.verdict).await?;
+            all_actions.extend(actions);
+            last_failure_outcome = Some(merge(last_failure_outcome.take(), outcome));
+        }
+
+        Ok((all_actions, last_failure_outcome))
     }
 
     /// Evaluate if a policy's conditions are met
@@ -549,70 +1424,511 @@ impl IncidentResponseEngine {
         }
     }
 
-    /// Execute actions for a policy
-    async fn execute_policy_actions(&self, policy: &ResponsePolicy, component_id: &str) -> Result<Vec<String>, String> {
-        let executors = self.action_executors.read().await;
-        let mut executed_actions = Vec::new();
-        
-        for action in &policy.actions {
-            if let Some(executor) = self.get_executor_for_action(&executors, &action.action_type) {
-                match executor.execute(action) {
-                    Ok(result) => {
-                        executed_actions.push(format!("Action {} executed successfully: {}", 
-                                                     action.action_id, result.message));
-                        
-                        // Record the action in incident history if there's an active incident
-                        self.record_action_execution(component_id, action, &result).await;
-                    },
-                    Err(e) => {
-                        executed_actions.push(format!("Action {} failed: {}", action.action_id, e));
-                    }
-                }
-            } else {
-                executed_actions.push(format!("No executor found for action type: {:?}", action.action_type));
+    /// Execute a policy's actions as a DAG keyed by `action_id`: computes a
+    /// topological order over `dependencies` (rejecting cycles), then runs
+    /// each ready action in turn. An action whose dependency didn't complete
+    /// is marked `Cancelled` rather than executed.
+    async fn execute_policy_actions(&self, policy: &ResponsePolicy, component_id: &str, principal: &str, verdict: TrustVerdict) -> Result<(Vec<String>, FailureModeOutcome), String> {
+        if !policy.escalation_chain.is_empty() {
+            if let Some(telemetry) = &self.telemetry {
+                telemetry.record_escalation();
             }
         }
-        
-        Ok(executed_actions)
+
+        // A `FatalTrustFailure` runs immediately and ignores any override;
+        // only a `RecoverableTrustFailure` with somewhere to escalate to is
+        // held open for one.
+        if verdict == TrustVerdict::RecoverableTrustFailure && !policy.escalation_chain.is_empty() {
+            if self.await_override(component_id, policy).await {
+                return Ok((vec![format!(
+                    "Policy {} actions overridden for component {} before execution",
+                    policy.policy_id, component_id
+                )], FailureModeOutcome::default()));
+            }
+        }
+
+        let (mut messages, irrecoverable) = self.execute_actions(component_id, principal, &policy.actions).await?;
+        let failure_mode = match policy.failure_mode {
+            Some(mode) => mode,
+            None => *self.failure_mode.read().await,
+        };
+
+        let mut changed_outcome = false;
+        if irrecoverable && failure_mode == FailureMode::Deny {
+            let targets: Vec<String> = policy.actions.iter()
+                .flat_map(|action| action.target_components.clone())
+                .collect();
+            let isolate_messages = self.fail_closed_isolate(component_id, principal, &targets).await?;
+            changed_outcome = !isolate_messages.is_empty();
+            messages.extend(isolate_messages);
+        }
+
+        Ok((messages, FailureModeOutcome { failure_mode, irrecoverable, changed_outcome }))
     }
 
-    fn get_executor_for_action(
-        &self,
-        executors: &HashMap<String, Box<dyn ActionExecutor + Send + Sync>>,
-        action_type: &ActionType,
-    ) -> Option<&Box<dyn ActionExecutor + Send + Sync>> {
-        match action_type {
-            ActionType::IsolateComponent => executors.get("isolation"),
-            ActionType::ScaleResources => executors.get("scaling"),
-            ActionType::UpdateConfiguration => executors.get("configuration"),
-            ActionType::TriggerWorkflow => executors.get("workflow"),
-            _ => executors.values().next(),
+    /// Fail-closed default for an irrecoverably-failed action chain under
+    /// `FailureMode::Deny`: synthesizes and runs an `IsolateComponent`
+    /// action over `targets` so they end up untrusted rather than lingering
+    /// in whatever partial state the failed chain left them in.
+    async fn fail_closed_isolate(&self, component_id: &str, principal: &str, targets: &[String]) -> Result<Vec<String>, String> {
+        if targets.is_empty() {
+            return Ok(Vec::new());
         }
+
+        let synthetic_isolate = ResponseAction {
+            action_id: format!("fail-closed-isolate-{}", component_id),
+            action_type: ActionType::IsolateComponent,
+            target_components: targets.to_vec(),
+            parameters: HashMap::new(),
+            timeout: Duration::seconds(30),
+            retry_count: 1,
+            dependencies: Vec::new(),
+            // The action's own flag never holds back fail-closed isolation;
+            // `dry_run` and `acl` still apply to it like any other action.
+            allowed_to_mutate: true,
+        };
+
+        let (messages, _) = self.execute_actions(component_id, principal, std::slice::from_ref(&synthetic_isolate)).await?;
+        Ok(messages)
     }
 
-    /// Record action execution in incident history
-    async fn record_action_execution(&self, component_id: &str, action: &ResponseAction, result: &ActionResult) {
-        let mut incidents = self.active_incidents.write().await;
-        
-        if let Some(incident) = incidents.get_mut(component_id) {
-            let action_record = ActionRecord {
-                action_id: action.action_id.clone(),
+    /// Holds `policy.actions` for the first action's `timeout`, giving
+    /// anyone in `policy.escalation_chain` a window to override (cancel)
+    /// them via `submit_approval` before they run — the "held, may be
+    /// overridden" half of a `RecoverableTrustFailure` verdict. Returns
+    /// `true` if an override arrived in time, `false` if the hold expired
+    /// (or there was nothing to hold for) and the actions should proceed.
+    async fn await_override(&self, component_id: &str, policy: &ResponsePolicy) -> bool {
+        let Some(timeout) = policy.actions.first().and_then(|a| a.timeout.to_std().ok()) else {
+            return false;
+        };
+
+        let request_seq = {
+            let mut next = self.next_approval_seq.write().await;
+            let seq = *next;
+            *next += 1;
+            seq
+        };
+
+        let notification_channels: Vec<String> = policy.escalation_chain.iter()
+            .flat_map(|step| step.notification_channels.clone())
+            .collect();
+        let requested_at = Utc::now();
+        let request = ApprovalRequest {
+            request_seq,
+            incident_id: component_id.to_string(),
+            component_id: component_id.to_string(),
+            step_id: policy.policy_id.clone(),
+            notification_channels,
+            requested_at,
+            deadline: requested_at + Duration::from_std(timeout).unwrap_or_else(|_| Duration::zero()),
+        };
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.pending_approvals.write().await.insert(request_seq, request);
+        self.approval_responses.write().await.insert(request_seq, tx);
+
+        let received = tokio::time::timeout(timeout, rx).await;
+
+        self.pending_approvals.write().await.remove(&request_seq);
+        self.approval_responses.write().await.remove(&request_seq);
+
+        matches!(received, Ok(Ok((ApprovalDecision::Approve, _))))
+    }
+
+    /// Runs `actions` as a dependency DAG: computes a topological order over
+    /// `dependencies` (rejecting cycles), then runs each ready action in
+    /// turn. An action whose dependency didn't complete is marked
+    /// `Cancelled` rather than executed. Shared by `execute_policy_actions`
+    /// and `run_escalation_step`, since an approved escalation step's
+    /// `actions` need the same ordering/retry/telemetry handling as a
+    /// policy's. A privileged action (see `is_privileged_action`) whose
+    /// `principal` isn't allowed by `acl` is audited rather than executed,
+    /// same as `dry_run` or `!allowed_to_mutate`.
+    async fn execute_actions(&self, component_id: &str, principal: &str, actions: &[ResponseAction]) -> Result<(Vec<String>, bool), String> {
+        let order = Self::topological_action_order(actions)?;
+        let executors = self.action_executors.read().await;
+        let dry_run = *self.dry_run.read().await;
+        let acl = self.acl.read().await;
+        let mut executed_actions = Vec::new();
+        let mut action_status: HashMap<String, ActionStatus> = HashMap::new();
+        let mut irrecoverable = false;
+
+        for action_id in &order {
+            let action = actions.iter()
+                .find(|a| &a.action_id == action_id)
+                .expect("topological_action_order only returns ids present in actions");
+
+            let dependency_failed = action.dependencies.iter()
+                .any(|dep| !matches!(action_status.get(dep), Some(ActionStatus::Completed)));
+
+            if dependency_failed {
+                action_status.insert(action_id.clone(), ActionStatus::Cancelled);
+                irrecoverable = true;
+                executed_actions.push(format!("Action {} cancelled: a dependency did not complete", action_id));
+                self.record_action_result(component_id, ActionRecord {
+                    action_id: action_id.clone(),
+                    action_type: action.action_type.clone(),
+                    executed_at: Utc::now(),
+                    status: ActionStatus::Cancelled,
+                    result: "Cancelled: one or more dependencies did not complete".to_string(),
+                    duration: Duration::zero(),
+                }).await;
+                continue;
+            }
+
+            let span = match &self.telemetry {
+                Some(telemetry) => Some(telemetry.start_action_span(component_id, action).await),
+                None => None,
+            };
+
+            let acl_denied = is_privileged_action(&action.action_type) && !acl.allows(principal);
+            let (status, message, duration) = if dry_run || !action.allowed_to_mutate || acl_denied {
+                let message = if acl_denied {
+                    format!(
+                        "Action {} ({:?}) audited, not executed: principal {} is not permitted to drive this action",
+                        action.action_id, action.action_type, principal
+                    )
+                } else {
+                    format!(
+                        "Action {} ({:?}) audited, not executed: targets={:?} parameters={:?}",
+                        action.action_id, action.action_type, action.target_components, action.parameters
+                    )
+                };
+                (ActionStatus::Audited, message, Duration::zero())
+            } else {
+                self.execute_action_with_retries(&executors, action).await
+            };
+            let record_status = match &status {
+                ActionStatus::Completed => ActionStatus::Completed,
+                ActionStatus::Audited => ActionStatus::Audited,
+                _ => ActionStatus::Failed,
+            };
+            if matches!(record_status, ActionStatus::Failed) {
+                irrecoverable = true;
+            }
+
+            if let (Some(telemetry), Some(span)) = (&self.telemetry, span) {
+                telemetry.end_action_span(span, &status);
+            }
+
+            action_status.insert(action_id.clone(), status);
+            executed_actions.push(message.clone());
+            self.record_action_result(component_id, ActionRecord {
+                action_id: action_id.clone(),
                 action_type: action.action_type.clone(),
                 executed_at: Utc::now(),
-                status: if result.success { ActionStatus::Completed } else { ActionStatus::Failed },
-                result: result.message.clone(),
-                duration: Duration::from_secs(5), // Simplified duration
-            };
-            
-            incident.actions_taken.push(action_record);
+                status: record_status,
+                result: message,
+                duration,
+            }).await;
+        }
+
+        Ok((executed_actions, irrecoverable))
+    }
+
+    /// Computes a topological order over `actions` keyed by `action_id`,
+    /// honoring `dependencies` via Kahn's algorithm. Ties are broken by each
+    /// action's declared position, so the order is deterministic for a given
+    /// policy. Rejects a dependency on an unknown `action_id` and any cycle
+    /// with a clear error rather than silently dropping actions.
+    fn topological_action_order(actions: &[ResponseAction]) -> Result<Vec<String>, String> {
+        let known_ids: HashSet<&str> = actions.iter().map(|a| a.action_id.as_str()).collect();
+        let declared_index: HashMap<&str, usize> = actions.iter().enumerate()
+            .map(|(i, a)| (a.action_id.as_str(), i))
+            .collect();
+
+        let mut in_degree: HashMap<String, usize> = actions.iter()
+            .map(|a| (a.action_id.clone(), 0))
+            .collect();
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+
+        for action in actions {
+            for dep in &action.dependencies {
+                if !known_ids.contains(dep.as_str()) {
+                    return Err(format!(
+                        "action {} depends on unknown action {}",
+                        action.action_id, dep
+                    ));
+                }
+                *in_degree.get_mut(&action.action_id).expect("action_id was just inserted above") += 1;
+                dependents.entry(dep.clone()).or_insert_with(Vec::new).push(action.action_id.clone());
+            }
+        }
+
+        let mut ready: Vec<String> = in_degree.iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(id, _)| id.clone())
+            .collect();
+        ready.sort_by_key(|id| declared_index[id.as_str()]);
+        let mut queue: VecDeque<String> = ready.into();
+
+        let mut order = Vec::with_capacity(actions.len());
+        while let Some(action_id) = queue.pop_front() {
+            order.push(action_id.clone());
+
+            if let Some(deps) = dependents.get(&action_id) {
+                let mut newly_ready = Vec::new();
+                for dependent in deps {
+                    let degree = in_degree.get_mut(dependent).expect("dependent was seeded into in_degree above");
+                    *degree -= 1;
+                    if *degree == 0 {
+                        newly_ready.push(dependent.clone());
+                    }
+                }
+                newly_ready.sort_by_key(|id| declared_index[id.as_str()]);
+                queue.extend(newly_ready);
+            }
+        }
+
+        if order.len() != actions.len() {
+            return Err("cycle detected in action dependencies".to_string());
+        }
+
+        Ok(order)
+    }
+
+    /// Runs `action` through its executor on a blocking thread, under a
+    /// `tokio::time::timeout` of `action.timeout`, retrying up to
+    /// `action.retry_count` additional times with exponential backoff on
+    /// failure or timeout. `ActionExecutor::execute` is a synchronous call
+    /// that may block (e.g. `HttpAgentTransport::dispatch`'s
+    /// `reqwest::blocking::Client`), so it's dispatched via
+    /// `tokio::task::spawn_blocking` rather than awaited directly --
+    /// wrapping a non-yielding synchronous call in `async {}` would give
+    /// `timeout` no poll point to fire at and let it run unbounded.
+    async fn execute_action_with_retries(
+        &self,
+        executors: &HashMap<String, Arc<dyn ActionExecutor + Send + Sync>>,
+        action: &ResponseAction,
+    ) -> (ActionStatus, String, Duration) {
+        let Some(executor) = self.get_executor_for_action(executors, &action.action_type) else {
+            return (
+                ActionStatus::Failed,
+                format!("No executor found for action type: {:?}", action.action_type),
+                Duration::zero(),
+            );
+        };
+
+        let timeout = action.timeout.to_std().unwrap_or(std::time::Duration::from_secs(30));
+        let max_attempts = action.retry_count + 1;
+        let started_at = Utc::now();
+        let mut last_error = String::new();
+
+        for attempt in 0..max_attempts {
+            let executor = executor.clone();
+            let owned_action = action.clone();
+            let attempt_result = tokio::time::timeout(
+                timeout,
+                tokio::task::spawn_blocking(move || executor.execute(&owned_action)),
+            )
+            .await;
+
+            match attempt_result {
+                Ok(Ok(Ok(result))) => {
+                    return (
+                        ActionStatus::Completed,
+                        format!("Action {} executed successfully: {}", action.action_id, result.message),
+                        Utc::now() - started_at,
+                    );
+                }
+                Ok(Ok(Err(e))) => last_error = e,
+                Ok(Err(join_error)) => last_error = format!("executor task panicked: {}", join_error),
+                Err(_) => last_error = format!("timed out after {:?}", timeout),
+            }
+
+            if attempt + 1 < max_attempts {
+                let backoff = std::time::Duration::from_millis(100 * (1u64 << attempt.min(10)));
+                tokio::time::sleep(backoff).await;
+            }
+        }
+
+        (
+            ActionStatus::Failed,
+            format!("Action {} failed after {} attempt(s): {}", action.action_id, max_attempts, last_error),
+            Utc::now() - started_at,
+        )
+    }
+
+    fn get_executor_for_action(
+        &self,
+        executors: &HashMap<String, Arc<dyn ActionExecutor + Send + Sync>>,
+        action_type: &ActionType,
+    ) -> Option<Arc<dyn ActionExecutor + Send + Sync>> {
+        match action_type {
+            ActionType::IsolateComponent => executors.get("isolation"),
+            ActionType::ScaleResources => executors.get("scaling"),
+            ActionType::UpdateConfiguration => executors.get("configuration"),
+            ActionType::TriggerWorkflow => executors.get("workflow"),
+            _ => executors.values().next(),
+        }
+        .cloned()
+    }
+
+    /// Record an already-built action record in incident history.
+    async fn record_action_result(&self, component_id: &str, record: ActionRecord) {
+        let mut incidents = self.active_incidents.write().await;
+
+        if let Some(incident) = incidents.get_mut(component_id) {
+            Self::append_audit_entry(incident, AuditEntryKind::Action(record.clone()));
+            incident.actions_taken.push(record);
+            incident.updated_at = Utc::now();
+        }
+    }
+
+    /// Appends an escalation record to `component_id`'s incident, chaining
+    /// it into the audit log alongside action records and status
+    /// transitions.
+    pub async fn record_escalation_event(&self, component_id: &str, record: EscalationRecord) {
+        let mut incidents = self.active_incidents.write().await;
+
+        if let Some(incident) = incidents.get_mut(component_id) {
+            Self::append_audit_entry(incident, AuditEntryKind::Escalation(record.clone()));
+            incident.escalation_history.push(record);
             incident.updated_at = Utc::now();
         }
     }
 
+    /// Runs a single escalation step. If `step.approval_required`, emits an
+    /// `ApprovalRequest` over its `notification_channels` and awaits a
+    /// correlated `submit_approval` call (or `approval_deadline` elapsing)
+    /// before deciding whether to run `step.actions`. Returns the messages
+    /// from whatever actions ran (if any) alongside whether the step was
+    /// approved, so `run_escalation_chain` can decide whether to fall
+    /// through to the next step. `principal` is checked against `acl`
+    /// exactly as in `execute_actions`, for any of `step.actions` that are
+    /// privileged.
+    pub async fn run_escalation_step(
+        &self,
+        incident_id: &str,
+        component_id: &str,
+        principal: &str,
+        step: &EscalationStep,
+        approval_deadline: std::time::Duration,
+    ) -> Result<(Vec<String>, bool), String> {
+        if !step.approval_required {
+            let (messages, _irrecoverable) = self.execute_actions(component_id, principal, &step.actions).await?;
+            return Ok((messages, true));
+        }
+
+        let request_seq = {
+            let mut next = self.next_approval_seq.write().await;
+            let seq = *next;
+            *next += 1;
+            seq
+        };
+
+        let requested_at = Utc::now();
+        let deadline = requested_at + Duration::from_std(approval_deadline).unwrap_or_else(|_| Duration::zero());
+        let request = ApprovalRequest {
+            request_seq,
+            incident_id: incident_id.to_string(),
+            component_id: component_id.to_string(),
+            step_id: step.step_id.clone(),
+            notification_channels: step.notification_channels.clone(),
+            requested_at,
+            deadline,
+        };
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.pending_approvals.write().await.insert(request_seq, request);
+        self.approval_responses.write().await.insert(request_seq, tx);
+
+        let received = tokio::time::timeout(approval_deadline, rx).await;
+
+        // `submit_approval` already removes both entries on a resolved
+        // request; this is a no-op then, but is what cleans them up after a
+        // timeout that nobody ever resolved.
+        self.pending_approvals.write().await.remove(&request_seq);
+        self.approval_responses.write().await.remove(&request_seq);
+
+        let (outcome, approved) = match received {
+            Ok(Ok((ApprovalDecision::Approve, approver))) => (ApprovalOutcome::Approved { approver }, true),
+            Ok(Ok((ApprovalDecision::Deny, approver))) => (ApprovalOutcome::Denied { approver }, false),
+            Ok(Ok((ApprovalDecision::NeedsMoreInfo { question }, approver))) => {
+                (ApprovalOutcome::NeedsMoreInfo { approver, question }, false)
+            }
+            Ok(Err(_)) | Err(_) => (ApprovalOutcome::TimedOut, false),
+        };
+
+        self.record_escalation_event(component_id, EscalationRecord {
+            escalated_at: Utc::now(),
+            escalated_to: step.notification_channels.join(","),
+            reason: format!("approval for step {}: {:?}", step.step_id, outcome),
+            actions_taken: Vec::new(),
+        }).await;
+
+        if approved {
+            let (messages, _irrecoverable) = self.execute_actions(component_id, principal, &step.actions).await?;
+            Ok((messages, true))
+        } else {
+            Ok((vec![format!("Escalation step {} not executed: {:?}", step.step_id, outcome)], false))
+        }
+    }
+
+    /// Runs `chain` in order, stopping at the first step whose actions
+    /// actually execute. A denied, timed-out, or needs-more-info step falls
+    /// through to the next step in the chain instead of aborting it.
+    pub async fn run_escalation_chain(
+        &self,
+        incident_id: &str,
+        component_id: &str,
+        principal: &str,
+        chain: &[EscalationStep],
+        approval_deadline: std::time::Duration,
+    ) -> Result<Vec<String>, String> {
+        let mut messages = Vec::new();
+
+        for step in chain {
+            let (step_messages, approved) = self.run_escalation_step(incident_id, component_id, principal, step, approval_deadline).await?;
+            messages.extend(step_messages);
+            if approved {
+                break;
+            }
+        }
+
+        Ok(messages)
+    }
+
+    /// Resolves the pending approval request `request_seq` with `decision`,
+    /// attributed to `approver`. Used by external callers (chat bot,
+    /// webhook) to answer an `ApprovalRequest` surfaced via
+    /// `list_pending_approvals`.
+    pub async fn submit_approval(&self, request_seq: u64, decision: ApprovalDecision, approver: &str) -> Result<(), String> {
+        let sender = self.approval_responses.write().await.remove(&request_seq)
+            .ok_or_else(|| format!("no pending approval request with seq {}", request_seq))?;
+        self.pending_approvals.write().await.remove(&request_seq);
+
+        sender.send((decision, approver.to_string()))
+            .map_err(|_| "approval request is no longer awaited (it may have already timed out)".to_string())
+    }
+
+    /// Approval requests currently awaiting a decision, for a dashboard to
+    /// list what's outstanding.
+    pub async fn list_pending_approvals(&self) -> Vec<ApprovalRequest> {
+        self.pending_approvals.read().await.values().cloned().collect()
+    }
+
+    /// Hashes `kind` together with `incident.audit_head_hash`, appends the
+    /// resulting `AuditChainEntry`, and advances the head hash. Silently
+    /// skips the append if serialization fails, since an audit-log write
+    /// should never be able to block the state change it's recording.
+    fn append_audit_entry(incident: &mut Incident, kind: AuditEntryKind) {
+        let recorded_at = Utc::now();
+        let Ok(hash) = audit_chain_link_hash(&incident.audit_head_hash, &kind, &recorded_at) else {
+            return;
+        };
+
+        let sequence = incident.audit_chain.len();
+        incident.audit_chain.push(AuditChainEntry { sequence, kind, recorded_at, hash: hash.clone() });
+        incident.audit_head_hash = hash;
+    }
+
     /// Create a new incident
     pub async fn create_incident(&self, component_id: &str, severity: IncidentSeverity, description: &str) -> String {
         let incident_id = Uuid::new_v4().to_string();
-        
+        let span_severity = severity.clone();
+
         let incident = Incident {
             incident_id: incident_id.clone(),
             title: format!("Trust Score Incident - {}", component_id),
@@ -634,11 +1950,18 @@ impl IncidentResponseEngine {
                 affected_users: 0,
                 data_compromised: false,
             },
+            audit_chain: Vec::new(),
+            audit_head_hash: AUDIT_CHAIN_GENESIS.to_string(),
         };
-        
+
         let mut incidents = self.active_incidents.write().await;
         incidents.insert(component_id.to_string(), incident);
-        
+        drop(incidents);
+
+        if let Some(telemetry) = &self.telemetry {
+            telemetry.start_incident_span(component_id, &incident_id, &span_severity).await;
+        }
+
         incident_id
     }
 
@@ -651,17 +1974,83 @@ impl IncidentResponseEngine {
     /// Resolve an incident
     pub async fn resolve_incident(&self, incident_id: &str) -> Result<(), String> {
         let mut incidents = self.active_incidents.write().await;
-        
-        if let Some(incident) = incidents.values_mut().find(|i| i.incident_id == incident_id) {
+
+        if let Some((component_id, incident)) = incidents.iter_mut().find(|(_, i)| i.incident_id == incident_id) {
+            let previous_status = incident.status.clone();
             incident.status = IncidentStatus::Resolved;
             incident.resolved_at = Some(Utc::now());
             incident.updated_at = Utc::now();
+            Self::append_audit_entry(incident, AuditEntryKind::StatusTransition {
+                from: previous_status,
+                to: IncidentStatus::Resolved,
+            });
+
+            if let Some(telemetry) = &self.telemetry {
+                telemetry.end_incident_span(component_id, &incident.metrics).await;
+            }
+
             Ok(())
         } else {
             Err(format!("Incident {} not found", incident_id))
         }
     }
 
+    /// Recomputes `incident_id`'s audit chain from `AUDIT_CHAIN_GENESIS` and
+    /// compares every recomputed hash (including the final head hash)
+    /// against what's stored. Returns the sequence numbers of every entry
+    /// that doesn't match; an entry's content, timestamp, or position all
+    /// feed the hash, so a reordered or retimed entry is caught the same as
+    /// an edited one. An incident with no matching `incident_id` has
+    /// nothing to verify and is reported as `Ok(())`.
+    pub async fn verify_incident_integrity(&self, incident_id: &str) -> Result<(), Vec<usize>> {
+        let incidents = self.active_incidents.read().await;
+        let Some(incident) = incidents.values().find(|i| i.incident_id == incident_id) else {
+            return Ok(());
+        };
+
+        let mut mismatched = Vec::new();
+        let mut expected_hash = AUDIT_CHAIN_GENESIS.to_string();
+
+        for entry in &incident.audit_chain {
+            let recomputed = audit_chain_link_hash(&expected_hash, &entry.kind, &entry.recorded_at)
+                .unwrap_or_default();
+            if recomputed != entry.hash {
+                mismatched.push(entry.sequence);
+            }
+            expected_hash = recomputed;
+        }
+
+        if incident.audit_head_hash != expected_hash {
+            mismatched.push(incident.audit_chain.len());
+        }
+
+        if mismatched.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatched)
+        }
+    }
+
+    /// Merkle root over `incident_id`'s audit chain entry hashes, for
+    /// compactly attesting to the whole log without shipping it.
+    pub async fn incident_audit_merkle_root(&self, incident_id: &str) -> Option<MerkleHash> {
+        let incidents = self.active_incidents.read().await;
+        let incident = incidents.values().find(|i| i.incident_id == incident_id)?;
+        let leaves: Vec<String> = incident.audit_chain.iter().map(|e| e.hash.clone()).collect();
+        merkle_root(&leaves)
+    }
+
+    /// Inclusion proof for the audit chain entry at `sequence`, letting a
+    /// verifier confirm a single `ActionRecord` or `EscalationRecord` is
+    /// part of `incident_id`'s log against just `incident_audit_merkle_root`,
+    /// without needing the rest of the chain.
+    pub async fn incident_audit_merkle_proof(&self, incident_id: &str, sequence: usize) -> Option<Vec<MerklePathItem>> {
+        let incidents = self.active_incidents.read().await;
+        let incident = incidents.values().find(|i| i.incident_id == incident_id)?;
+        let leaves: Vec<String> = incident.audit_chain.iter().map(|e| e.hash.clone()).collect();
+        merkle_proof(&leaves, sequence)
+    }
+
     /// Start recovery process for a component
     pub async fn start_recovery(&self, component_id: &str, plan_id: &str) -> Result<String, String> {
         let recovery_coordinator = self.recovery_coordinator.read().await;
@@ -687,69 +2076,550 @@ impl IncidentResponseEngine {
             Err(format!("Recovery plan {} not found", plan_id))
         }
     }
+
+    /// Active incident counts by severity/status and the mean of each
+    /// `IncidentMetrics` field across them, for a `/stats` style dashboard.
+    pub async fn stats(&self) -> IncidentStats {
+        let incidents = self.active_incidents.read().await;
+        let mut by_severity = IncidentSeverityCounts::default();
+        let mut by_status = IncidentStatusCounts::default();
+        let mut totals = MeanIncidentMetrics::default();
+
+        for incident in incidents.values() {
+            match incident.severity {
+                IncidentSeverity::Low => by_severity.low += 1,
+                IncidentSeverity::Medium => by_severity.medium += 1,
+                IncidentSeverity::High => by_severity.high += 1,
+                IncidentSeverity::Critical => by_severity.critical += 1,
+                IncidentSeverity::Emergency => by_severity.emergency += 1,
+            }
+            match incident.status {
+                IncidentStatus::Open => by_status.open += 1,
+                IncidentStatus::Investigating => by_status.investigating += 1,
+                IncidentStatus::Mitigating => by_status.mitigating += 1,
+                IncidentStatus::Resolved => by_status.resolved += 1,
+                IncidentStatus::Closed => by_status.closed += 1,
+                IncidentStatus::Escalated => by_status.escalated += 1,
+            }
+            totals.detection_time_ms += incident.metrics.detection_time.num_milliseconds() as f64;
+            totals.response_time_ms += incident.metrics.response_time.num_milliseconds() as f64;
+            totals.resolution_time_ms += incident.metrics.resolution_time.num_milliseconds() as f64;
+            totals.business_impact += incident.metrics.business_impact;
+            totals.affected_users += incident.metrics.affected_users as f64;
+        }
+
+        let total_active = incidents.len();
+        if total_active > 0 {
+            let n = total_active as f64;
+            totals.detection_time_ms /= n;
+            totals.response_time_ms /= n;
+            totals.resolution_time_ms /= n;
+            totals.business_impact /= n;
+            totals.affected_users /= n;
+        }
+
+        IncidentStats {
+            total_active,
+            by_severity,
+            by_status,
+            mean_metrics: totals,
+        }
+    }
+
+    /// Each registered `ActionExecutor`'s `is_healthy()` plus the
+    /// `RecoveryCoordinator`'s configured `HealthCheck`s, for a `/health`
+    /// endpoint. Healthy overall iff every registered executor is healthy
+    /// (vacuously true with none registered).
+    pub async fn health(&self) -> EngineHealth {
+        let executors = self.action_executors.read().await;
+        let executor_health: Vec<ExecutorHealth> = executors.iter()
+            .map(|(name, executor)| ExecutorHealth { name: name.clone(), healthy: executor.is_healthy() })
+            .collect();
+        let healthy = executor_health.iter().all(|e| e.healthy);
+        let health_checks = self.recovery_coordinator.read().await.health_checks.clone();
+
+        EngineHealth {
+            executors: executor_health,
+            health_checks,
+            healthy,
+        }
+    }
+
+    /// Serializes policies, active incidents, and escalation/recovery
+    /// history into a versioned snapshot suitable for migrating or
+    /// recovering an engine after a crash. See `restore` for the inverse.
+    pub async fn snapshot(&self) -> EngineSnapshot {
+        EngineSnapshot {
+            version: ENGINE_SNAPSHOT_VERSION,
+            policies: self.response_policies.read().await.clone(),
+            incidents: self.active_incidents.read().await.values().cloned().collect(),
+            escalation_manager: self.escalation_manager.read().await.clone(),
+            recovery_coordinator: self.recovery_coordinator.read().await.clone(),
+            component_trust_scores: self.component_trust_scores.read().await.clone(),
+        }
+    }
+
+    /// Replaces policies, active incidents, and escalation/recovery history
+    /// with the contents of `snapshot`. Rejects a snapshot whose
+    /// `version` this engine doesn't understand rather than silently
+    /// mis-reading it. Registered action executors, telemetry, and any
+    /// in-flight approval requests are left untouched — those are runtime
+    /// wiring, not persisted state.
+    pub async fn restore(&self, snapshot: EngineSnapshot) -> Result<(), String> {
+        if snapshot.version != ENGINE_SNAPSHOT_VERSION {
+            return Err(format!(
+                "unsupported snapshot version {} (expected {})",
+                snapshot.version, ENGINE_SNAPSHOT_VERSION
+            ));
+        }
+
+        *self.response_policies.write().await = snapshot.policies;
+        *self.active_incidents.write().await = snapshot.incidents.into_iter()
+            .map(|incident| (incident.affected_components.first().cloned().unwrap_or_else(|| incident.incident_id.clone()), incident))
+            .collect();
+        *self.escalation_manager.write().await = snapshot.escalation_manager;
+        *self.recovery_coordinator.write().await = snapshot.recovery_coordinator;
+        *self.component_trust_scores.write().await = snapshot.component_trust_scores;
+
+        Ok(())
+    }
+
+    /// Applies every trust update staged on `changeset` as a single unit.
+    /// Each update is diffed against a `System::snapshot` taken before the
+    /// apply starts, so updates that wouldn't actually change a component's
+    /// trust score are skipped. If any staged component's
+    /// `process_trust_update` errors or comes back with an irrecoverable
+    /// `FailureModeOutcome`, the engine is `restore`d to its pre-apply
+    /// snapshot — including `component_trust_scores` — so a coordinated
+    /// response (quarantining a component and its failed dependencies
+    /// together) never lands half-done.
+    pub async fn apply_changeset(&self, changeset: &Changeset) -> Result<ChangesetOutcome, String> {
+        let before = System::snapshot(self).await;
+        let changed = changeset.diff(&before);
+        if changed.is_empty() {
+            return Ok(ChangesetOutcome {
+                actions: Vec::new(),
+                before: before.clone(),
+                after: before,
+            });
+        }
+
+        let rollback_point = self.snapshot().await;
+        let mut all_actions = Vec::new();
+
+        for (component_id, principal, trust_score, context) in changed {
+            match self.process_trust_update(component_id, principal, trust_score, context).await {
+                Ok((actions, _verdict, failure_outcome)) if !failure_outcome.irrecoverable => {
+                    all_actions.extend(actions);
+                }
+                Ok((_, _, failure_outcome)) => {
+                    self.restore(rollback_point).await?;
+                    return Err(format!(
+                        "changeset rolled back: component {} failed irrecoverably during apply ({:?} failure mode)",
+                        component_id, failure_outcome.failure_mode
+                    ));
+                }
+                Err(e) => {
+                    self.restore(rollback_point).await?;
+                    return Err(format!("changeset rolled back: {}", e));
+                }
+            }
+        }
+
+        let after = System::snapshot(self).await;
+        Ok(ChangesetOutcome { actions: all_actions, before, after })
+    }
+}
+
+/// Bumped whenever `EngineSnapshot`'s shape changes in a way that would
+/// make an older dump unsafe to `restore` blindly.
+pub const ENGINE_SNAPSHOT_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IncidentSeverityCounts {
+    pub low: usize,
+    pub medium: usize,
+    pub high: usize,
+    pub critical: usize,
+    pub emergency: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IncidentStatusCounts {
+    pub open: usize,
+    pub investigating: usize,
+    pub mitigating: usize,
+    pub resolved: usize,
+    pub closed: usize,
+    pub escalated: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MeanIncidentMetrics {
+    pub detection_time_ms: f64,
+    pub response_time_ms: f64,
+    pub resolution_time_ms: f64,
+    pub business_impact: f64,
+    pub affected_users: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TrustContext {
-    pub component_id: String,
-    pub trust_score: f64,
-    pub security_events: Vec<SecurityEvent>,
-    pub performance_metrics: HashMap<String, f64>,
-    pub behavioral_anomalies: Vec<BehavioralAnomaly>,
-    pub failed_dependencies: Vec<String>,
-    pub communication_failures: Vec<String>,
-    pub timestamp: DateTime<Utc>,
+pub struct IncidentStats {
+    pub total_active: usize,
+    pub by_severity: IncidentSeverityCounts,
+    pub by_status: IncidentStatusCounts,
+    pub mean_metrics: MeanIncidentMetrics,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SecurityEvent {
-    pub event_type: String,
-    pub severity: f64,
-    pub source: String,
-    pub description: String,
-    pub timestamp: DateTime<Utc>,
+pub struct ExecutorHealth {
+    pub name: String,
+    pub healthy: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BehavioralAnomaly {
-    pub anomaly_type: String,
-    pub severity: f64,
-    pub description: String,
-    pub timestamp: DateTime<Utc>,
+pub struct EngineHealth {
+    pub executors: Vec<ExecutorHealth>,
+    pub health_checks: Vec<HealthCheck>,
+    pub healthy: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineSnapshot {
+    pub version: u32,
+    pub policies: Vec<ResponsePolicy>,
+    pub incidents: Vec<Incident>,
+    pub escalation_manager: EscalationManager,
+    pub recovery_coordinator: RecoveryCoordinator,
+    pub component_trust_scores: HashMap<String, f64>,
+}
 
-    #[tokio::test]
-    async fn test_incident_response_engine() {
-        let engine = IncidentResponseEngine::new();
-        
-        // Add action executors
-        let isolation_executor = IsolationExecutor {
-            name: "isolation".to_string(),
-            kubernetes_client: Some("http://localhost:8080".to_string()),
-        };
-        
-        let scaling_executor = ScalingExecutor {
-            name: "scaling".to_string(),
-            cloud_provider: "aws".to_string(),
-        };
-        
-        engine.add_action_executor("isolation".to_string(), Box::new(isolation_executor)).await;
-        engine.add_action_executor("scaling".to_string(), Box::new(scaling_executor)).await;
-        
-        // Add a response policy
-        let policy = ResponsePolicy {
-            policy_id: "trust-score-critical".to_string(),
-            name: "Critical Trust Score Response".to_string(),
-            conditions: vec![ResponseCondition {
-                condition_type: ConditionType::TrustScore,
-                metric_name: "trust_score".to_string(),
-                operator: ComparisonOperator::LessThan,
-                threshold: 0.2,
-                duration: None,
+/// A point-in-time view of every component whose trust score the engine
+/// has observed via `process_trust_update`. `Changeset::diff` and
+/// `apply_changeset` compare against this before and after an atomic apply.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct System {
+    pub component_trust_scores: HashMap<String, f64>,
+}
+
+impl System {
+    /// Captures `engine`'s current `component_trust_scores`, the baseline
+    /// `Changeset::diff` and `apply_changeset`'s rollback compare against.
+    pub async fn snapshot(engine: &IncidentResponseEngine) -> System {
+        System {
+            component_trust_scores: engine.component_trust_scores.read().await.clone(),
+        }
+    }
+}
+
+/// Staged multi-component trust updates to apply atomically via
+/// `IncidentResponseEngine::apply_changeset`, so a coordinated response
+/// (quarantining a component and its failed dependencies together) either
+/// fully lands or fully rolls back instead of leaving a half-isolated
+/// dependency graph.
+#[derive(Debug, Clone, Default)]
+pub struct Changeset {
+    updates: Vec<(String, String, f64, TrustContext)>,
+}
+
+impl Changeset {
+    pub fn new() -> Self {
+        Self { updates: Vec::new() }
+    }
+
+    /// Stages a trust-score update for `component_id` on behalf of
+    /// `principal`, along with the `TrustContext` `apply_changeset` will
+    /// evaluate policies against when it runs. `principal` is checked
+    /// against `acl` the same as any other `process_trust_update` caller
+    /// once `apply_changeset` runs this update.
+    pub fn add_trust_update(&mut self, component_id: &str, principal: &str, trust_score: f64, context: TrustContext) {
+        self.updates.push((component_id.to_string(), principal.to_string(), trust_score, context));
+    }
+
+    pub fn len(&self) -> usize {
+        self.updates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.updates.is_empty()
+    }
+
+    /// Staged updates whose `trust_score` actually differs from `system`'s
+    /// recorded score for that component (or who aren't in `system` at
+    /// all), in staged order.
+    fn diff<'a>(&'a self, system: &System) -> Vec<(&'a str, &'a str, f64, &'a TrustContext)> {
+        self.updates.iter()
+            .filter(|(component_id, _, trust_score, _)| {
+                system.component_trust_scores.get(component_id) != Some(trust_score)
+            })
+            .map(|(component_id, principal, trust_score, context)| {
+                (component_id.as_str(), principal.as_str(), *trust_score, context)
+            })
+            .collect()
+    }
+}
+
+/// Result of a committed `apply_changeset`: the action messages it produced,
+/// and the `System` before and after the apply so a caller can see exactly
+/// what changed.
+#[derive(Debug, Clone)]
+pub struct ChangesetOutcome {
+    pub actions: Vec<String>,
+    pub before: System,
+    pub after: System,
+}
+
+/// Graded outcome of evaluating a `TrustContext` against a matched
+/// `ResponsePolicy`, borrowed from the graded-verdict model used by
+/// platform trust evaluators in place of a single pass/fail bit.
+/// `process_trust_update` computes one per matched policy and surfaces the
+/// most severe alongside the actions it chose, so a caller can audit why
+/// isolation happened instead of just that it did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrustVerdict {
+    /// No policy matched; the component is trusted as evaluated.
+    Proceed,
+    /// No policies are registered to evaluate against at all, so the
+    /// component is implicitly trusted by default rather than by any
+    /// explicit decision.
+    Unspecified,
+    /// Degraded but not conclusively compromised: the matched policy's
+    /// actions are held for `policy.escalation_chain` to override before
+    /// they run.
+    RecoverableTrustFailure,
+    /// Conclusively compromised: the matched policy's actions execute
+    /// immediately and no override is honored.
+    FatalTrustFailure,
+    /// The verdict itself couldn't be computed (e.g. an out-of-range trust
+    /// score), so the outcome is treated conservatively rather than guessed.
+    OtherError,
+}
+
+impl TrustVerdict {
+    /// Ranks verdicts by how much caution they warrant, for picking the
+    /// most severe one across several matched policies. Not a `derive(Ord)`
+    /// on the enum itself, since variant declaration order and severity
+    /// order are two different things worth keeping visibly separate.
+    fn severity(&self) -> u8 {
+        match self {
+            TrustVerdict::Unspecified => 0,
+            TrustVerdict::Proceed => 1,
+            TrustVerdict::RecoverableTrustFailure => 2,
+            TrustVerdict::OtherError => 3,
+            TrustVerdict::FatalTrustFailure => 4,
+        }
+    }
+}
+
+/// Whether a target component is treated as untrusted by default
+/// (`Deny`, fail-closed) or left in its prior trust state (`Allow`,
+/// fail-open) when a `ResponsePolicy`'s action chain fails irrecoverably —
+/// an action's `timeout` expires, its `retry_count` is exhausted, or it is
+/// cancelled because one of its `dependencies` did not complete. Set engine-wide via
+/// `IncidentResponseEngine::failure_mode` and overridable per policy via
+/// `ResponsePolicy::failure_mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailureMode {
+    Deny,
+    Allow,
+}
+
+/// What actually happened when a policy's action chain failed
+/// irrecoverably, so operators can reason about safety during partial
+/// outages of the response subsystem itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailureModeOutcome {
+    pub failure_mode: FailureMode,
+    /// Whether any policy processed in this update ended with a `Failed` or
+    /// `Cancelled` final action status.
+    pub irrecoverable: bool,
+    /// Whether `failure_mode` actually altered the outcome, i.e. a
+    /// fail-closed isolate ran because of `irrecoverable`.
+    pub changed_outcome: bool,
+}
+
+impl Default for FailureModeOutcome {
+    fn default() -> Self {
+        FailureModeOutcome {
+            failure_mode: FailureMode::Deny,
+            irrecoverable: false,
+            changed_outcome: false,
+        }
+    }
+}
+
+/// Whether `action_type` is privileged enough to require `Acl` gating —
+/// everything except alerting and metrics tagging, which stay ungated
+/// regardless of `Acl::enabled` since they can't themselves change a
+/// component's reachability or configuration.
+fn is_privileged_action(action_type: &ActionType) -> bool {
+    !matches!(action_type, ActionType::SendNotification | ActionType::EnableMonitoring)
+}
+
+/// Allow-list of principal ids permitted to drive privileged
+/// `ResponseAction`s (see `is_privileged_action`). Toggleable like a
+/// trusted-users list via `enabled`, so a fully-trusted or frequently-reset
+/// environment can run with every caller permitted without maintaining a
+/// list — set `enabled: false` (the default) rather than populating
+/// `allowed_principals` with everyone.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Acl {
+    pub enabled: bool,
+    pub allowed_principals: Option<HashSet<String>>,
+}
+
+impl Acl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `principal` may drive a privileged action. Always `true`
+    /// while `enabled` is `false`, or while `allowed_principals` hasn't been
+    /// configured at all (an enabled ACL with no list configured is not yet
+    /// restricting anyone).
+    pub fn allows(&self, principal: &str) -> bool {
+        if !self.enabled {
+            return true;
+        }
+        match &self.allowed_principals {
+            Some(allowed) => allowed.contains(principal),
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustContext {
+    pub component_id: String,
+    pub trust_score: f64,
+    pub security_events: Vec<SecurityEvent>,
+    pub performance_metrics: HashMap<String, f64>,
+    pub behavioral_anomalies: Vec<BehavioralAnomaly>,
+    pub failed_dependencies: Vec<String>,
+    pub communication_failures: Vec<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityEvent {
+    pub event_type: String,
+    pub severity: f64,
+    pub source: String,
+    pub description: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BehavioralAnomaly {
+    pub anomaly_type: String,
+    pub severity: f64,
+    pub description: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// A trust update as delivered over the wire, HTTP-signature-style: the
+/// same `(component_id, trust_score)` `process_trust_update` takes, plus
+/// the envelope `process_signed_trust_update` verifies before trusting
+/// either value — `key_id` identifies the signer, and `signature` covers
+/// `canonical_trust_update_bytes` of `component_id`, `trust_score`,
+/// `timestamp`, and `security_events`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedTrustUpdate {
+    pub component_id: String,
+    pub trust_score: f64,
+    pub timestamp: DateTime<Utc>,
+    pub security_events: Vec<SecurityEvent>,
+    pub key_id: String,
+    /// Hex-encoded Ed25519 signature over `canonical_trust_update_bytes`.
+    pub signature: String,
+}
+
+/// Resolves a `SignedTrustUpdate::key_id` to its signer's raw Ed25519
+/// public key, so keys can be sourced from static config, a component
+/// registry, or a secrets manager without the engine caring which.
+pub trait KeyResolver {
+    fn resolve_key(&self, key_id: &str) -> Option<Vec<u8>>;
+}
+
+/// A `KeyResolver` backed by a fixed, in-memory key id -> public key map,
+/// for static deployments and tests.
+pub struct StaticKeyResolver {
+    keys: HashMap<String, Vec<u8>>,
+}
+
+impl StaticKeyResolver {
+    pub fn new(keys: HashMap<String, Vec<u8>>) -> Self {
+        Self { keys }
+    }
+}
+
+impl KeyResolver for StaticKeyResolver {
+    fn resolve_key(&self, key_id: &str) -> Option<Vec<u8>> {
+        self.keys.get(key_id).cloned()
+    }
+}
+
+/// Canonical, deterministically-ordered bytes for a trust update's signable
+/// fields, modeled on HTTP signature header canonicalization: the signer
+/// and verifier both compute this independent of whatever order the wire
+/// envelope's fields happened to arrive in.
+fn canonical_trust_update_bytes(
+    component_id: &str,
+    trust_score: f64,
+    timestamp: &DateTime<Utc>,
+    security_events: &[SecurityEvent],
+) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(component_id.as_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(&trust_score.to_bits().to_be_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(timestamp.to_rfc3339().as_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(&serde_json::to_vec(security_events).map_err(|e| e.to_string())?);
+    Ok(bytes)
+}
+
+/// Verifies `signature_hex` (hex-encoded Ed25519) over `canonical_bytes`
+/// against `public_key`.
+fn verify_trust_update_signature(public_key: &[u8], canonical_bytes: &[u8], signature_hex: &str) -> Result<(), String> {
+    let signature_bytes = hex::decode(signature_hex).map_err(|e| format!("invalid signature encoding: {}", e))?;
+    let key = ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, public_key);
+    key.verify(canonical_bytes, &signature_bytes).map_err(|_| "signature verification failed".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_incident_response_engine() {
+        let engine = IncidentResponseEngine::new();
+        
+        // Add action executors
+        let isolation_executor = IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: Some("http://localhost:8080".to_string()),
+        };
+        
+        let scaling_executor = ScalingExecutor {
+            name: "scaling".to_string(),
+            cloud_provider: "aws".to_string(),
+        };
+        
+        engine.add_action_executor("isolation".to_string(), Box::new(isolation_executor)).await;
+        engine.add_action_executor("scaling".to_string(), Box::new(scaling_executor)).await;
+        
+        // Add a response policy
+        let policy = ResponsePolicy {
+            policy_id: "trust-score-critical".to_string(),
+            name: "Critical Trust Score Response".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.2,
+                duration: None,
             }],
             actions: vec![ResponseAction {
                 action_id: "isolate-component".to_string(),
@@ -759,10 +2629,12 @@ mod tests {
                 timeout: Duration::from_secs(30),
                 retry_count: 3,
                 dependencies: Vec::new(),
+                allowed_to_mutate: true,
             }],
             priority: 1,
             enabled: true,
             escalation_chain: Vec::new(),
+            failure_mode: None,
         };
         
         engine.add_response_policy(policy).await;
@@ -779,7 +2651,981 @@ mod tests {
             timestamp: Utc::now(),
         };
         
-        let actions = engine.process_trust_update("test-component", 0.1, &context).await;
+        let actions = engine.process_trust_update("test-component", "test-principal", 0.1, &context).await;
         assert!(actions.is_ok());
     }
+
+    fn action(action_id: &str, dependencies: Vec<&str>) -> ResponseAction {
+        ResponseAction {
+            action_id: action_id.to_string(),
+            action_type: ActionType::IsolateComponent,
+            target_components: vec!["test-component".to_string()],
+            parameters: HashMap::new(),
+            timeout: Duration::from_secs(30),
+            retry_count: 0,
+            dependencies: dependencies.into_iter().map(|d| d.to_string()).collect(),
+            allowed_to_mutate: true,
+        }
+    }
+
+    #[test]
+    fn test_topological_action_order_respects_dependencies() {
+        let actions = vec![
+            action("deploy", vec!["build"]),
+            action("build", vec![]),
+            action("notify", vec!["deploy"]),
+        ];
+
+        let order = IncidentResponseEngine::topological_action_order(&actions).unwrap();
+        let pos = |id: &str| order.iter().position(|a| a == id).unwrap();
+
+        assert!(pos("build") < pos("deploy"));
+        assert!(pos("deploy") < pos("notify"));
+    }
+
+    #[test]
+    fn test_topological_action_order_rejects_cycle() {
+        let actions = vec![
+            action("a", vec!["b"]),
+            action("b", vec!["a"]),
+        ];
+
+        let result = IncidentResponseEngine::topological_action_order(&actions);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_topological_action_order_rejects_unknown_dependency() {
+        let actions = vec![action("a", vec!["missing"])];
+        let result = IncidentResponseEngine::topological_action_order(&actions);
+        assert!(result.is_err());
+    }
+
+    struct FailingExecutor {
+        name: String,
+    }
+
+    impl ActionExecutor for FailingExecutor {
+        fn execute(&self, _action: &ResponseAction) -> Result<ActionResult, String> {
+            Err("simulated failure".to_string())
+        }
+
+        fn get_executor_name(&self) -> String {
+            self.name.clone()
+        }
+
+        fn is_healthy(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_policy_actions_cancels_dependents_of_a_failed_action() {
+        let engine = IncidentResponseEngine::new();
+        engine.add_action_executor("isolation".to_string(), Box::new(FailingExecutor {
+            name: "isolation".to_string(),
+        })).await;
+
+        let mut root = action("root", vec![]);
+        root.retry_count = 0;
+        let mut dependent = action("dependent", vec!["root"]);
+        dependent.retry_count = 0;
+
+        let policy = ResponsePolicy {
+            policy_id: "cancel-on-failure".to_string(),
+            name: "Cancel on failure".to_string(),
+            conditions: Vec::new(),
+            actions: vec![root, dependent],
+            priority: 1,
+            enabled: true,
+            escalation_chain: Vec::new(),
+            failure_mode: None,
+        };
+
+        engine.active_incidents.write().await.insert("test-component".to_string(), Incident {
+            incident_id: "inc-1".to_string(),
+            title: "Trust Score Incident - test-component".to_string(),
+            description: "test incident".to_string(),
+            severity: IncidentSeverity::Critical,
+            status: IncidentStatus::Open,
+            affected_components: vec!["test-component".to_string()],
+            root_cause: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            resolved_at: None,
+            actions_taken: Vec::new(),
+            escalation_history: Vec::new(),
+            metrics: IncidentMetrics {
+                detection_time: Duration::from_secs(0),
+                response_time: Duration::from_secs(0),
+                resolution_time: Duration::from_secs(0),
+                business_impact: 0.0,
+                affected_users: 0,
+                data_compromised: false,
+            },
+            audit_chain: Vec::new(),
+            audit_head_hash: AUDIT_CHAIN_GENESIS.to_string(),
+        });
+
+        let (messages, _failure_outcome) = engine.execute_policy_actions(&policy, "test-component", "test-principal", TrustVerdict::RecoverableTrustFailure).await.unwrap();
+        assert!(messages.iter().any(|m| m.contains("root") && m.contains("failed")));
+        assert!(messages.iter().any(|m| m.contains("dependent") && m.contains("cancelled")));
+
+        let incidents = engine.active_incidents.read().await;
+        let incident = incidents.get("test-component").unwrap();
+        let dependent_record = incident.actions_taken.iter().find(|r| r.action_id == "dependent").unwrap();
+        assert!(matches!(dependent_record.status, ActionStatus::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn test_audit_chain_verifies_intact_and_detects_tampering() {
+        let engine = IncidentResponseEngine::new();
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+
+        let incident_id = engine.create_incident("test-component", IncidentSeverity::High, "test").await;
+        engine.record_action_result("test-component", ActionRecord {
+            action_id: "isolate-component".to_string(),
+            action_type: ActionType::IsolateComponent,
+            executed_at: Utc::now(),
+            status: ActionStatus::Completed,
+            result: "ok".to_string(),
+            duration: Duration::zero(),
+        }).await;
+        engine.resolve_incident(&incident_id).await.unwrap();
+
+        assert!(engine.verify_incident_integrity(&incident_id).await.is_ok());
+
+        {
+            let mut incidents = engine.active_incidents.write().await;
+            let incident = incidents.get_mut("test-component").unwrap();
+            incident.audit_chain[0].kind = AuditEntryKind::Action(ActionRecord {
+                action_id: "isolate-component".to_string(),
+                action_type: ActionType::IsolateComponent,
+                executed_at: Utc::now(),
+                status: ActionStatus::Failed,
+                result: "tampered".to_string(),
+                duration: Duration::zero(),
+            });
+        }
+
+        let mismatched = engine.verify_incident_integrity(&incident_id).await.unwrap_err();
+        assert_eq!(mismatched, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_audit_merkle_proof_verifies_against_root() {
+        let engine = IncidentResponseEngine::new();
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+
+        let incident_id = engine.create_incident("test-component", IncidentSeverity::High, "test").await;
+        for i in 0..3 {
+            engine.record_action_result("test-component", ActionRecord {
+                action_id: format!("action-{}", i),
+                action_type: ActionType::IsolateComponent,
+                executed_at: Utc::now(),
+                status: ActionStatus::Completed,
+                result: "ok".to_string(),
+                duration: Duration::zero(),
+            }).await;
+        }
+
+        let root = engine.incident_audit_merkle_root(&incident_id).await.unwrap();
+        let proof = engine.incident_audit_merkle_proof(&incident_id, 1).await.unwrap();
+
+        let incidents = engine.active_incidents.read().await;
+        let incident = incidents.get("test-component").unwrap();
+        let mut hash = incident.audit_chain[1].hash.clone();
+        for step in proof {
+            hash = match step.direction {
+                MerkleDirection::Right => merkle_combine(&hash, &step.hash),
+                MerkleDirection::Left => merkle_combine(&step.hash, &hash),
+            };
+        }
+
+        assert_eq!(hash, root);
+    }
+
+    struct MockTransport {
+        dispatched: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl MockTransport {
+        fn new() -> Self {
+            Self { dispatched: std::sync::Mutex::new(Vec::new()) }
+        }
+    }
+
+    impl AgentTransport for MockTransport {
+        fn dispatch(&self, _agent_endpoint: &str, job: &RemoteJob) -> Result<JobResult, String> {
+            self.dispatched.lock().unwrap().push(job.job_id.clone());
+            Ok(JobResult {
+                job_id: job.job_id.clone(),
+                success: true,
+                message: format!("isolated {}", job.target_component),
+            })
+        }
+    }
+
+    #[test]
+    fn test_remote_executor_dispatches_to_owning_agent_and_folds_results() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        let executor = RemoteExecutor::with_transport("remote", Duration::seconds(30), Box::new(
+            MockTransportHandle(transport.clone())
+        ));
+        executor.register_agent("agent-1", "http://agent-1.local", vec!["svc-a".to_string(), "svc-b".to_string()]);
+
+        let action = ResponseAction {
+            action_id: "isolate-1".to_string(),
+            action_type: ActionType::IsolateComponent,
+            target_components: vec!["svc-a".to_string(), "svc-b".to_string()],
+            parameters: HashMap::new(),
+            timeout: Duration::from_secs(5),
+            retry_count: 0,
+            dependencies: Vec::new(),
+            allowed_to_mutate: true,
+        };
+
+        let result = executor.execute(&action).unwrap();
+        assert!(result.success);
+        assert_eq!(transport.dispatched.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_remote_executor_caches_result_by_job_id_so_retry_does_not_redispatch() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        let executor = RemoteExecutor::with_transport("remote", Duration::seconds(30), Box::new(
+            MockTransportHandle(transport.clone())
+        ));
+        executor.register_agent("agent-1", "http://agent-1.local", vec!["svc-a".to_string()]);
+
+        let action = ResponseAction {
+            action_id: "isolate-1".to_string(),
+            action_type: ActionType::IsolateComponent,
+            target_components: vec!["svc-a".to_string()],
+            parameters: HashMap::new(),
+            timeout: Duration::from_secs(5),
+            retry_count: 0,
+            dependencies: Vec::new(),
+            allowed_to_mutate: true,
+        };
+
+        executor.execute(&action).unwrap();
+        executor.execute(&action).unwrap();
+
+        assert_eq!(transport.dispatched.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_remote_executor_is_unhealthy_without_a_fresh_heartbeat() {
+        let transport = std::sync::Arc::new(MockTransport::new());
+        let executor = RemoteExecutor::with_transport("remote", Duration::seconds(0), Box::new(
+            MockTransportHandle(transport)
+        ));
+        assert!(!executor.is_healthy());
+
+        executor.register_agent("agent-1", "http://agent-1.local", vec!["svc-a".to_string()]);
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        assert!(!executor.is_healthy());
+    }
+
+    struct MockTransportHandle(std::sync::Arc<MockTransport>);
+
+    impl AgentTransport for MockTransportHandle {
+        fn dispatch(&self, agent_endpoint: &str, job: &RemoteJob) -> Result<JobResult, String> {
+            self.0.dispatch(agent_endpoint, job)
+        }
+    }
+
+    fn escalation_step(step_id: &str, approval_required: bool) -> EscalationStep {
+        EscalationStep {
+            step_id: step_id.to_string(),
+            delay: Duration::zero(),
+            actions: vec![action("isolate-component", vec![])],
+            notification_channels: vec!["oncall".to_string()],
+            approval_required,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_escalation_step_skips_approval_when_not_required() {
+        let engine = IncidentResponseEngine::new();
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+        engine.create_incident("test-component", IncidentSeverity::High, "test").await;
+
+        let step = escalation_step("notify-oncall", false);
+        let (messages, approved) = engine.run_escalation_step(
+            "inc-1", "test-component", "test-principal", &step, std::time::Duration::from_secs(1)
+        ).await.unwrap();
+
+        assert!(approved);
+        assert!(!messages.is_empty());
+        assert!(engine.list_pending_approvals().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_escalation_step_executes_actions_once_approved() {
+        let engine = std::sync::Arc::new(IncidentResponseEngine::new());
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+        engine.create_incident("test-component", IncidentSeverity::High, "test").await;
+
+        let step = escalation_step("require-approval", true);
+        let run_engine = engine.clone();
+        let handle = tokio::spawn(async move {
+            run_engine.run_escalation_step(
+                "inc-1", "test-component", "test-principal", &step, std::time::Duration::from_secs(5)
+            ).await
+        });
+
+        let request_seq = loop {
+            let pending = engine.list_pending_approvals().await;
+            if let Some(request) = pending.into_iter().next() {
+                break request.request_seq;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        };
+
+        engine.submit_approval(request_seq, ApprovalDecision::Approve, "oncall-engineer").await.unwrap();
+        let (messages, approved) = handle.await.unwrap().unwrap();
+
+        assert!(approved);
+        assert!(!messages.is_empty());
+        let incidents = engine.active_incidents.read().await;
+        let incident = incidents.get("test-component").unwrap();
+        assert!(incident.escalation_history.iter().any(|r| r.reason.contains("Approved")));
+    }
+
+    #[tokio::test]
+    async fn test_run_escalation_chain_falls_through_a_denied_step() {
+        let engine = std::sync::Arc::new(IncidentResponseEngine::new());
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+        engine.create_incident("test-component", IncidentSeverity::High, "test").await;
+
+        let chain = vec![escalation_step("first", true), escalation_step("second", false)];
+        let run_engine = engine.clone();
+        let handle = tokio::spawn(async move {
+            run_engine.run_escalation_chain(
+                "inc-1", "test-component", "test-principal", &chain, std::time::Duration::from_secs(5)
+            ).await
+        });
+
+        let request_seq = loop {
+            let pending = engine.list_pending_approvals().await;
+            if let Some(request) = pending.into_iter().next() {
+                break request.request_seq;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        };
+
+        engine.submit_approval(request_seq, ApprovalDecision::Deny, "oncall-engineer").await.unwrap();
+        let messages = handle.await.unwrap().unwrap();
+
+        assert!(messages.iter().any(|m| m.contains("not executed")));
+        assert!(messages.len() > 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_escalation_step_times_out_without_a_response() {
+        let engine = IncidentResponseEngine::new();
+        engine.create_incident("test-component", IncidentSeverity::High, "test").await;
+
+        let step = escalation_step("unanswered", true);
+        let (messages, approved) = engine.run_escalation_step(
+            "inc-1", "test-component", "test-principal", &step, std::time::Duration::from_millis(20)
+        ).await.unwrap();
+
+        assert!(!approved);
+        assert!(messages.iter().any(|m| m.contains("not executed")));
+        assert!(engine.list_pending_approvals().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_submit_approval_errors_on_unknown_request_seq() {
+        let engine = IncidentResponseEngine::new();
+        let result = engine.submit_approval(999, ApprovalDecision::Approve, "someone").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_process_trust_update_with_no_policies_is_unspecified() {
+        let engine = IncidentResponseEngine::new();
+        let context = TrustContext {
+            component_id: "test-component".to_string(),
+            trust_score: 0.9,
+            security_events: Vec::new(),
+            performance_metrics: HashMap::new(),
+            behavioral_anomalies: Vec::new(),
+            failed_dependencies: Vec::new(),
+            communication_failures: Vec::new(),
+            timestamp: Utc::now(),
+        };
+
+        let (actions, verdict, _failure_outcome) = engine.process_trust_update("test-component", "test-principal", 0.9, &context).await.unwrap();
+        assert!(actions.is_empty());
+        assert_eq!(verdict, TrustVerdict::Unspecified);
+    }
+
+    #[tokio::test]
+    async fn test_security_event_condition_grades_as_fatal_and_executes_immediately() {
+        let engine = IncidentResponseEngine::new();
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+
+        engine.add_response_policy(ResponsePolicy {
+            policy_id: "fatal-on-security-event".to_string(),
+            name: "Fatal on security event".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::SecurityEvent,
+                metric_name: "severity".to_string(),
+                operator: ComparisonOperator::GreaterThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![action("isolate-component", vec![])],
+            priority: 1,
+            enabled: true,
+            // An override channel is present, but a fatal verdict must
+            // ignore it and execute immediately anyway.
+            escalation_chain: vec![escalation_step("would-be-override", true)],
+            failure_mode: None,
+        }).await;
+
+        let context = TrustContext {
+            component_id: "test-component".to_string(),
+            trust_score: 0.9,
+            security_events: vec![SecurityEvent {
+                event_type: "credential-theft".to_string(),
+                severity: 0.95,
+                source: "ids".to_string(),
+                description: "stolen credential used".to_string(),
+                timestamp: Utc::now(),
+            }],
+            performance_metrics: HashMap::new(),
+            behavioral_anomalies: Vec::new(),
+            failed_dependencies: Vec::new(),
+            communication_failures: Vec::new(),
+            timestamp: Utc::now(),
+        };
+
+        let (actions, verdict, _failure_outcome) = engine.process_trust_update("test-component", "test-principal", 0.9, &context).await.unwrap();
+        assert_eq!(verdict, TrustVerdict::FatalTrustFailure);
+        assert!(actions.iter().any(|m| m.contains("isolate-component")));
+    }
+
+    #[tokio::test]
+    async fn test_recoverable_verdict_actions_are_held_and_can_be_overridden() {
+        let engine = std::sync::Arc::new(IncidentResponseEngine::new());
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+
+        let mut isolate = action("isolate-component", vec![]);
+        isolate.timeout = Duration::seconds(5);
+        engine.add_response_policy(ResponsePolicy {
+            policy_id: "recoverable-low-trust".to_string(),
+            name: "Recoverable low trust".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![isolate],
+            priority: 1,
+            enabled: true,
+            escalation_chain: vec![escalation_step("supervisor-override", true)],
+            failure_mode: None,
+        }).await;
+
+        let context = TrustContext {
+            component_id: "test-component".to_string(),
+            trust_score: 0.3,
+            security_events: Vec::new(),
+            performance_metrics: HashMap::new(),
+            behavioral_anomalies: Vec::new(),
+            failed_dependencies: Vec::new(),
+            communication_failures: Vec::new(),
+            timestamp: Utc::now(),
+        };
+
+        let run_engine = engine.clone();
+        let handle = tokio::spawn(async move {
+            run_engine.process_trust_update("test-component", "test-principal", 0.3, &context).await
+        });
+
+        let request_seq = loop {
+            let pending = engine.list_pending_approvals().await;
+            if let Some(request) = pending.into_iter().next() {
+                break request.request_seq;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        };
+
+        engine.submit_approval(request_seq, ApprovalDecision::Approve, "supervisor").await.unwrap();
+        let (actions, verdict, _failure_outcome) = handle.await.unwrap().unwrap();
+
+        assert_eq!(verdict, TrustVerdict::RecoverableTrustFailure);
+        assert!(actions.iter().any(|m| m.contains("overridden")));
+    }
+
+    #[tokio::test]
+    async fn test_fail_closed_mode_isolates_targets_after_irrecoverable_failure() {
+        let engine = IncidentResponseEngine::new();
+        // The policy's own action (scaling) fails irrecoverably; the
+        // fail-closed isolate that follows is a distinct `IsolateComponent`
+        // action, so it needs its own healthy executor to prove it ran.
+        engine.add_action_executor("scaling".to_string(), Box::new(FailingExecutor {
+            name: "scaling".to_string(),
+        })).await;
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+
+        let mut flaky = action("scale-out", vec![]);
+        flaky.action_type = ActionType::ScaleResources;
+        flaky.retry_count = 0;
+        engine.add_response_policy(ResponsePolicy {
+            policy_id: "fail-closed-by-default".to_string(),
+            name: "Fail closed by default".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![flaky],
+            priority: 1,
+            enabled: true,
+            escalation_chain: Vec::new(),
+            failure_mode: None,
+        }).await;
+
+        let context = TrustContext {
+            component_id: "test-component".to_string(),
+            trust_score: 0.3,
+            security_events: Vec::new(),
+            performance_metrics: HashMap::new(),
+            behavioral_anomalies: Vec::new(),
+            failed_dependencies: Vec::new(),
+            communication_failures: Vec::new(),
+            timestamp: Utc::now(),
+        };
+
+        let (actions, _verdict, failure_outcome) = engine.process_trust_update("test-component", "test-principal", 0.3, &context).await.unwrap();
+
+        assert_eq!(failure_outcome.failure_mode, FailureMode::Deny);
+        assert!(failure_outcome.irrecoverable);
+        assert!(failure_outcome.changed_outcome);
+        assert!(actions.iter().any(|m| m.contains("fail-closed-isolate")));
+    }
+
+    #[tokio::test]
+    async fn test_fail_open_mode_leaves_targets_untouched_after_irrecoverable_failure() {
+        let engine = IncidentResponseEngine::new().with_failure_mode(FailureMode::Allow);
+        engine.add_action_executor("isolation".to_string(), Box::new(FailingExecutor {
+            name: "isolation".to_string(),
+        })).await;
+
+        let mut flaky = action("isolate-component", vec![]);
+        flaky.retry_count = 0;
+        engine.add_response_policy(ResponsePolicy {
+            policy_id: "fail-open-policy".to_string(),
+            name: "Fail open policy".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![flaky],
+            priority: 1,
+            enabled: true,
+            escalation_chain: Vec::new(),
+            failure_mode: None,
+        }).await;
+
+        let context = TrustContext {
+            component_id: "test-component".to_string(),
+            trust_score: 0.3,
+            security_events: Vec::new(),
+            performance_metrics: HashMap::new(),
+            behavioral_anomalies: Vec::new(),
+            failed_dependencies: Vec::new(),
+            communication_failures: Vec::new(),
+            timestamp: Utc::now(),
+        };
+
+        let (actions, _verdict, failure_outcome) = engine.process_trust_update("test-component", "test-principal", 0.3, &context).await.unwrap();
+
+        assert_eq!(failure_outcome.failure_mode, FailureMode::Allow);
+        assert!(failure_outcome.irrecoverable);
+        assert!(!failure_outcome.changed_outcome);
+        assert!(!actions.iter().any(|m| m.contains("fail-closed-isolate")));
+    }
+
+    fn trust_context(component_id: &str, trust_score: f64) -> TrustContext {
+        TrustContext {
+            component_id: component_id.to_string(),
+            trust_score,
+            security_events: Vec::new(),
+            performance_metrics: HashMap::new(),
+            behavioral_anomalies: Vec::new(),
+            failed_dependencies: Vec::new(),
+            communication_failures: Vec::new(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_changeset_add_trust_update_and_len() {
+        let mut changeset = Changeset::new();
+        assert_eq!(changeset.len(), 0);
+        assert!(changeset.is_empty());
+
+        changeset.add_trust_update("component-a", "test-principal", 0.2, trust_context("component-a", 0.2));
+        changeset.add_trust_update("component-b", "test-principal", 0.4, trust_context("component-b", 0.4));
+
+        assert_eq!(changeset.len(), 2);
+        assert!(!changeset.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_changeset_commits_updates_across_components() {
+        let engine = IncidentResponseEngine::new();
+
+        let before = System::snapshot(&engine).await;
+        assert!(before.component_trust_scores.is_empty());
+
+        let mut changeset = Changeset::new();
+        changeset.add_trust_update("component-a", "test-principal", 0.9, trust_context("component-a", 0.9));
+        changeset.add_trust_update("component-b", "test-principal", 0.8, trust_context("component-b", 0.8));
+
+        let outcome = engine.apply_changeset(&changeset).await.unwrap();
+
+        assert!(outcome.actions.is_empty()); // no policies registered, nothing to execute
+        assert_eq!(outcome.after.component_trust_scores.get("component-a"), Some(&0.9));
+        assert_eq!(outcome.after.component_trust_scores.get("component-b"), Some(&0.8));
+
+        let after = System::snapshot(&engine).await;
+        assert_eq!(after.component_trust_scores, outcome.after.component_trust_scores);
+    }
+
+    #[tokio::test]
+    async fn test_apply_changeset_rolls_back_on_irrecoverable_failure() {
+        let engine = IncidentResponseEngine::new();
+        engine.add_action_executor("isolation".to_string(), Box::new(FailingExecutor {
+            name: "isolation".to_string(),
+        })).await;
+
+        let mut flaky = action("isolate-component", vec![]);
+        flaky.retry_count = 0;
+        engine.add_response_policy(ResponsePolicy {
+            policy_id: "isolate-on-low-trust".to_string(),
+            name: "Isolate on low trust".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![flaky],
+            priority: 1,
+            enabled: true,
+            escalation_chain: Vec::new(),
+            failure_mode: None,
+        }).await;
+
+        // component-a is healthy and commits cleanly before component-b's
+        // flaky isolate action fails the whole changeset.
+        let mut changeset = Changeset::new();
+        changeset.add_trust_update("component-a", "test-principal", 0.9, trust_context("component-a", 0.9));
+        changeset.add_trust_update("component-b", "test-principal", 0.3, trust_context("component-b", 0.3));
+
+        let before = System::snapshot(&engine).await;
+        let result = engine.apply_changeset(&changeset).await;
+
+        assert!(result.is_err());
+        let after = System::snapshot(&engine).await;
+        assert_eq!(after.component_trust_scores, before.component_trust_scores);
+        assert!(!after.component_trust_scores.contains_key("component-a"));
+        assert!(!after.component_trust_scores.contains_key("component-b"));
+    }
+
+    #[tokio::test]
+    async fn test_action_with_allowed_to_mutate_false_is_audited_not_executed() {
+        let engine = IncidentResponseEngine::new();
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+
+        let mut audit_only = action("isolate-component", vec![]);
+        audit_only.allowed_to_mutate = false;
+        engine.add_response_policy(ResponsePolicy {
+            policy_id: "audit-only-policy".to_string(),
+            name: "Audit only policy".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![audit_only],
+            priority: 1,
+            enabled: true,
+            escalation_chain: Vec::new(),
+            failure_mode: None,
+        }).await;
+
+        let context = trust_context("test-component", 0.3);
+        let (actions, _verdict, failure_outcome) = engine.process_trust_update("test-component", "test-principal", 0.3, &context).await.unwrap();
+
+        assert!(!failure_outcome.irrecoverable);
+        assert!(actions.iter().any(|m| m.contains("audited, not executed")
+            && m.contains("test-component")));
+    }
+
+    #[tokio::test]
+    async fn test_engine_wide_dry_run_overrides_an_action_allowed_to_mutate() {
+        let engine = IncidentResponseEngine::new().with_dry_run(true);
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+
+        let mut mutable = action("isolate-component", vec![]);
+        mutable.allowed_to_mutate = true;
+        engine.add_response_policy(ResponsePolicy {
+            policy_id: "dry-run-policy".to_string(),
+            name: "Dry run policy".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![mutable],
+            priority: 1,
+            enabled: true,
+            escalation_chain: Vec::new(),
+            failure_mode: None,
+        }).await;
+
+        let context = trust_context("test-component", 0.3);
+        let (actions, _verdict, _failure_outcome) = engine.process_trust_update("test-component", "test-principal", 0.3, &context).await.unwrap();
+
+        assert!(actions.iter().any(|m| m.contains("audited, not executed")));
+    }
+
+    fn generate_signed_update(component_id: &str, trust_score: f64, timestamp: DateTime<Utc>) -> (ring::signature::Ed25519KeyPair, SignedTrustUpdate) {
+        let key_pair = ring::signature::Ed25519KeyPair::from_pkcs8(
+            ring::signature::Ed25519KeyPair::generate_pkcs8(&ring::rand::SystemRandom::new()).unwrap().as_ref()
+        ).unwrap();
+
+        let security_events = Vec::new();
+        let canonical = canonical_trust_update_bytes(component_id, trust_score, &timestamp, &security_events).unwrap();
+        let signature = key_pair.sign(&canonical);
+
+        (key_pair, SignedTrustUpdate {
+            component_id: component_id.to_string(),
+            trust_score,
+            timestamp,
+            security_events,
+            key_id: "key-1".to_string(),
+            signature: hex::encode(signature.as_ref()),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_process_signed_trust_update_accepts_a_correctly_signed_update() {
+        let (key_pair, update) = generate_signed_update("test-component", 0.9, Utc::now());
+        let mut keys = HashMap::new();
+        keys.insert("key-1".to_string(), ring::signature::KeyPair::public_key(&key_pair).as_ref().to_vec());
+        let engine = IncidentResponseEngine::new().with_key_resolver(Box::new(StaticKeyResolver::new(keys)));
+
+        let context = trust_context("test-component", 0.9);
+        let result = engine.process_signed_trust_update(&update, &context).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_signed_trust_update_rejects_a_tampered_score() {
+        let (key_pair, mut update) = generate_signed_update("test-component", 0.9, Utc::now());
+        update.trust_score = 0.1; // tampered after signing, signature no longer matches
+        let mut keys = HashMap::new();
+        keys.insert("key-1".to_string(), ring::signature::KeyPair::public_key(&key_pair).as_ref().to_vec());
+        let engine = IncidentResponseEngine::new().with_key_resolver(Box::new(StaticKeyResolver::new(keys)));
+
+        let context = trust_context("test-component", 0.1);
+        let result = engine.process_signed_trust_update(&update, &context).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("rejected"));
+    }
+
+    #[tokio::test]
+    async fn test_process_signed_trust_update_rejects_an_unknown_key_id() {
+        let (_key_pair, update) = generate_signed_update("test-component", 0.9, Utc::now());
+        let engine = IncidentResponseEngine::new().with_key_resolver(Box::new(StaticKeyResolver::new(HashMap::new())));
+
+        let context = trust_context("test-component", 0.9);
+        let result = engine.process_signed_trust_update(&update, &context).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("unknown key id"));
+    }
+
+    #[tokio::test]
+    async fn test_process_signed_trust_update_rejects_a_stale_timestamp() {
+        let stale_timestamp = Utc::now() - Duration::seconds(3600);
+        let (key_pair, update) = generate_signed_update("test-component", 0.9, stale_timestamp);
+        let mut keys = HashMap::new();
+        keys.insert("key-1".to_string(), ring::signature::KeyPair::public_key(&key_pair).as_ref().to_vec());
+        let engine = IncidentResponseEngine::new().with_key_resolver(Box::new(StaticKeyResolver::new(keys)));
+
+        let context = trust_context("test-component", 0.9);
+        let result = engine.process_signed_trust_update(&update, &context).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("clock skew"));
+    }
+
+    #[tokio::test]
+    async fn test_process_signed_trust_update_without_a_key_resolver_is_rejected() {
+        let (_key_pair, update) = generate_signed_update("test-component", 0.9, Utc::now());
+        let engine = IncidentResponseEngine::new();
+
+        let context = trust_context("test-component", 0.9);
+        let result = engine.process_signed_trust_update(&update, &context).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("no key resolver configured"));
+    }
+
+    fn isolate_policy(policy_id: &str) -> ResponsePolicy {
+        ResponsePolicy {
+            policy_id: policy_id.to_string(),
+            name: "Isolate on low trust".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![action("isolate-component", vec![])],
+            priority: 1,
+            enabled: true,
+            escalation_chain: Vec::new(),
+            failure_mode: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acl_disabled_lets_any_principal_drive_a_privileged_action() {
+        let engine = IncidentResponseEngine::new();
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+        engine.add_response_policy(isolate_policy("acl-disabled-policy")).await;
+
+        let context = trust_context("test-component", 0.3);
+        let (actions, ..) = engine.process_trust_update("test-component", "anyone", 0.3, &context).await.unwrap();
+
+        assert!(actions.iter().any(|m| !m.contains("audited, not executed")));
+    }
+
+    #[tokio::test]
+    async fn test_acl_enabled_without_principal_denies_a_privileged_action_to_audit_only() {
+        let mut allowed = HashSet::new();
+        allowed.insert("ops".to_string());
+        let engine = IncidentResponseEngine::new().with_acl(Acl { enabled: true, allowed_principals: Some(allowed) });
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+        engine.add_response_policy(isolate_policy("acl-enabled-denied-policy")).await;
+
+        let context = trust_context("test-component", 0.3);
+        let (actions, _verdict, failure_outcome) = engine.process_trust_update("test-component", "unlisted-caller", 0.3, &context).await.unwrap();
+
+        assert!(!failure_outcome.irrecoverable);
+        assert!(actions.iter().any(|m| m.contains("audited, not executed")
+            && m.contains("unlisted-caller")));
+    }
+
+    #[tokio::test]
+    async fn test_acl_enabled_with_listed_principal_executes_a_privileged_action() {
+        let mut allowed = HashSet::new();
+        allowed.insert("ops".to_string());
+        let engine = IncidentResponseEngine::new().with_acl(Acl { enabled: true, allowed_principals: Some(allowed) });
+        engine.add_action_executor("isolation".to_string(), Box::new(IsolationExecutor {
+            name: "isolation".to_string(),
+            kubernetes_client: None,
+        })).await;
+        engine.add_response_policy(isolate_policy("acl-enabled-allowed-policy")).await;
+
+        let context = trust_context("test-component", 0.3);
+        let (actions, ..) = engine.process_trust_update("test-component", "ops", 0.3, &context).await.unwrap();
+
+        assert!(actions.iter().any(|m| !m.contains("audited, not executed")));
+    }
+
+    #[tokio::test]
+    async fn test_acl_enabled_never_gates_a_low_impact_notification_action() {
+        let engine = IncidentResponseEngine::new().with_acl(Acl { enabled: true, allowed_principals: Some(HashSet::new()) });
+        engine.add_action_executor("notifications".to_string(), Box::new(FailingExecutor {
+            name: "notifications".to_string(),
+        })).await;
+
+        let mut notify = action("notify-oncall", vec![]);
+        notify.action_type = ActionType::SendNotification;
+        engine.add_response_policy(ResponsePolicy {
+            policy_id: "notify-only-policy".to_string(),
+            name: "Notify on low trust".to_string(),
+            conditions: vec![ResponseCondition {
+                condition_type: ConditionType::TrustScore,
+                metric_name: "trust_score".to_string(),
+                operator: ComparisonOperator::LessThan,
+                threshold: 0.5,
+                duration: None,
+            }],
+            actions: vec![notify],
+            priority: 1,
+            enabled: true,
+            escalation_chain: Vec::new(),
+            failure_mode: None,
+        }).await;
+
+        let context = trust_context("test-component", 0.3);
+        let (actions, ..) = engine.process_trust_update("test-component", "unlisted-caller", 0.3, &context).await.unwrap();
+
+        assert!(actions.iter().any(|m| !m.contains("audited, not executed")));
+    }
 }
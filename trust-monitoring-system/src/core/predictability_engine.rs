@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, RwLock};
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc, Duration};
 use ndarray::{Array1, Array2};
+use rustfft::{num_complex::Complex, FftPlanner};
+use gbdt::config::Config as GbdtConfig;
+use gbdt::decision_tree::{Data as GbdtData, DataVec as GbdtDataVec};
+use gbdt::gradient_boost::GBDT;
 
 /// Trust prediction models for forecasting security state
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,10 +73,100 @@ pub struct BehavioralIndicators {
 }
 
 /// Machine learning models for trust prediction
+#[derive(Clone)]
 pub struct PredictabilityEngine {
     historical_data: Arc<RwLock<Vec<TrustDataPoint>>>,
     models: Arc<RwLock<HashMap<String, Box<dyn TrustModel + Send + Sync>>>>,
     risk_thresholds: Arc<RwLock<HashMap<String, f64>>>,
+    runner_state: Arc<RwLock<DetectionRunnerState>>,
+    runner_handle: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
+    warning_tx: mpsc::Sender<EarlyWarning>,
+    warning_rx: Arc<RwLock<Option<mpsc::Receiver<EarlyWarning>>>>,
+    alerting_state: Arc<RwLock<bool>>,
+    alerting_handle: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
+    http_client: reqwest::Client,
+    model_status: Arc<RwLock<HashMap<String, LearningStatus>>>,
+}
+
+/// Lifecycle state of a registered model, keyed by the same name it's
+/// stored under in `PredictabilityEngine::models` (e.g. `"lstm"`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum LearningStatus {
+    /// Registered but `train_models` hasn't been called yet.
+    Initialization,
+    /// A `train_models` call is in progress.
+    Learning,
+    /// Trained successfully and safe to serve predictions from.
+    Ready,
+    /// The last training attempt failed with this message.
+    Error(String),
+}
+
+/// Why `predict_trust`/`assess_risk` couldn't produce a prediction,
+/// distinguishing "nothing to learn from yet" from "still training" so
+/// callers like the detection runner can wait intelligently instead of
+/// treating every failure as permanent.
+#[derive(Debug, Clone)]
+pub enum PredictionError {
+    /// No historical data exists yet for this component.
+    NoData(String),
+    /// At least one model is registered but none has finished training.
+    StillLearning,
+    /// Every registered model is in the `Error` state (or none are
+    /// registered at all).
+    NoReadyModel,
+}
+
+impl std::fmt::Display for PredictionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PredictionError::NoData(component_id) => {
+                write!(f, "No historical data for component: {}", component_id)
+            }
+            PredictionError::StillLearning => write!(f, "Model is still learning, try again shortly"),
+            PredictionError::NoReadyModel => write!(f, "No trained models available"),
+        }
+    }
+}
+
+impl std::error::Error for PredictionError {}
+
+/// Where dispatched `EarlyWarning`s are delivered. An enum so additional
+/// sinks (SMTP, a message queue) can be added as variants later without
+/// changing the dispatch call site.
+#[derive(Debug, Clone)]
+pub enum AlertingType {
+    Webhook { endpoint: String },
+}
+
+/// Configuration for [`PredictabilityEngine::start_alerting`].
+#[derive(Debug, Clone)]
+pub struct AlertingConfig {
+    pub alerting_type: AlertingType,
+    /// Warnings raised within one interval are batched into a single
+    /// delivery instead of one request per warning.
+    pub dispatch_interval_secs: u64,
+}
+
+/// Configuration for a [`PredictabilityEngine`] background detection loop.
+#[derive(Debug, Clone)]
+pub struct DetectionRunnerConfig {
+    /// How often to re-run predictions for all known components.
+    pub interval: std::time::Duration,
+    /// Only components with historical data at or after this timestamp are
+    /// checked in each detection cycle; each check still predicts from the
+    /// component's full history.
+    pub from: DateTime<Utc>,
+}
+
+/// State of the background detection loop's "learning waiter": a run
+/// request made before any model is trained is queued here and executed
+/// automatically the moment `train_models` succeeds.
+#[derive(Debug, Clone)]
+enum DetectionRunnerState {
+    Stopped,
+    WaitingForModels { config: DetectionRunnerConfig },
+    Running,
 }
 
 pub trait TrustModel {
@@ -200,12 +294,511 @@ impl LSTMTrustModel {
     }
 }
 
+/// Seasonal trust model: resamples a component's trust history onto a fixed
+/// step and learns a mean/standard-deviation profile per seasonal phase
+/// (e.g. 144 steps for daily seasonality at 10-minute steps), instead of
+/// `LSTMTrustModel`'s single sequence window which ignores recurring
+/// daily/weekly patterns.
+pub struct SarimaTrustModel {
+    /// Number of steps per season (`S`), e.g. 144 for daily at 10-min steps.
+    seasonality_period: usize,
+    /// Fixed resampling step; consecutive `TrustDataPoint`s are bucketed
+    /// onto this grid before the seasonal profile is fit.
+    step: Duration,
+    /// `k` in `mu +/- k*sigma` confidence bounds, and the sigma threshold
+    /// past which an observation is flagged anomalous.
+    confidence_multiplier: f64,
+    /// Exponential-smoothing passes applied to `mu` after the initial
+    /// per-phase average, to stabilize a noisy seasonal profile.
+    seasonality_iterations: usize,
+    seasonal_mu: Vec<f64>,
+    seasonal_sigma: Vec<f64>,
+}
+
+impl SarimaTrustModel {
+    pub fn new(seasonality_period: usize, step: Duration, confidence_multiplier: f64, seasonality_iterations: usize) -> Self {
+        Self {
+            seasonality_period,
+            step,
+            confidence_multiplier,
+            seasonality_iterations,
+            seasonal_mu: vec![0.0; seasonality_period],
+            seasonal_sigma: vec![0.0; seasonality_period],
+        }
+    }
+
+    /// Buckets `data` (assumed to belong to one component) onto the fixed
+    /// `step` grid, averaging the trust score of points that land in the
+    /// same bucket, and returns the series in timestamp order.
+    fn resample(&self, data: &[TrustDataPoint]) -> Vec<(DateTime<Utc>, f64)> {
+        if data.is_empty() {
+            return Vec::new();
+        }
+
+        let mut sorted: Vec<&TrustDataPoint> = data.iter().collect();
+        sorted.sort_by_key(|d| d.timestamp);
+        let start = sorted[0].timestamp;
+        let step_ms = self.step.num_milliseconds().max(1);
+
+        let mut buckets: HashMap<i64, (f64, usize)> = HashMap::new();
+        for point in &sorted {
+            if point.trust_score.is_nan() {
+                continue;
+            }
+            let bin = (point.timestamp - start).num_milliseconds() / step_ms;
+            let entry = buckets.entry(bin).or_insert((0.0, 0));
+            entry.0 += point.trust_score;
+            entry.1 += 1;
+        }
+
+        let mut bins: Vec<i64> = buckets.keys().copied().collect();
+        bins.sort_unstable();
+        bins.into_iter()
+            .map(|bin| {
+                let (sum, count) = buckets[&bin];
+                (start + self.step * bin as i32, sum / count as f64)
+            })
+            .collect()
+    }
+}
+
+impl TrustModel for SarimaTrustModel {
+    fn train(&mut self, data: &[TrustDataPoint]) -> Result<(), String> {
+        let series = self.resample(data);
+        let s = self.seasonality_period.max(1);
+        if series.len() < 2 * s {
+            return Err(format!(
+                "SARIMA training requires at least {} resampled points (2 seasons), got {}",
+                2 * s,
+                series.len()
+            ));
+        }
+
+        let mut sums = vec![0.0; s];
+        let mut sumsq = vec![0.0; s];
+        let mut counts = vec![0usize; s];
+
+        for (i, (_, score)) in series.iter().enumerate() {
+            if score.is_nan() {
+                continue;
+            }
+            let phase = i % s;
+            sums[phase] += score;
+            sumsq[phase] += score * score;
+            counts[phase] += 1;
+        }
+
+        let mut mu = vec![0.0; s];
+        let mut sigma = vec![0.0; s];
+        for phase in 0..s {
+            if counts[phase] == 0 {
+                continue;
+            }
+            let n = counts[phase] as f64;
+            let mean = sums[phase] / n;
+            let variance = (sumsq[phase] / n - mean * mean).max(0.0);
+            mu[phase] = mean;
+            sigma[phase] = variance.sqrt();
+        }
+
+        // Exponential smoothing over the seasonal phases stabilizes a noisy
+        // profile, treating phases as a cycle (phase 0 follows phase S-1).
+        for _ in 0..self.seasonality_iterations {
+            let alpha = 0.3;
+            let mut smoothed = mu.clone();
+            for phase in 0..s {
+                let prev = smoothed[(phase + s - 1) % s];
+                smoothed[phase] = alpha * mu[phase] + (1.0 - alpha) * prev;
+            }
+            mu = smoothed;
+        }
+
+        self.seasonal_mu = mu;
+        self.seasonal_sigma = sigma;
+        Ok(())
+    }
+
+    fn predict(&self, data: &[TrustDataPoint]) -> TrustPrediction {
+        let series = self.resample(data);
+        let s = self.seasonality_period.max(1);
+        let k = self.confidence_multiplier;
+
+        let next_phase = series.len() % s;
+        let mu = self.seasonal_mu.get(next_phase).copied().unwrap_or(0.0);
+        let sigma = self.seasonal_sigma.get(next_phase).copied().unwrap_or(0.0);
+
+        let mut risk_factors = Vec::new();
+        if let Some(&(_, latest_score)) = series.last() {
+            let latest_phase = (series.len() - 1) % s;
+            let phase_mu = self.seasonal_mu.get(latest_phase).copied().unwrap_or(0.0);
+            let phase_sigma = self.seasonal_sigma.get(latest_phase).copied().unwrap_or(0.0);
+
+            if phase_sigma > 0.0 {
+                let sigmas_out = (latest_score - phase_mu).abs() / phase_sigma;
+                if sigmas_out > k {
+                    risk_factors.push(RiskFactor {
+                        factor_type: RiskFactorType::AnomalousBehavior,
+                        severity: (sigmas_out / (k * 2.0)).min(1.0),
+                        description: format!(
+                            "Latest trust score {:.2} is {:.1} standard deviations from the seasonal mean {:.2}",
+                            latest_score, sigmas_out, phase_mu
+                        ),
+                        mitigation_suggestions: vec![
+                            "Compare against the same time-of-day/week in prior seasons".to_string(),
+                            "Investigate recent deployments or traffic shifts".to_string(),
+                        ],
+                    });
+                }
+            }
+        }
+
+        TrustPrediction {
+            component_id: data.last().map(|d| d.component_id.clone()).unwrap_or_default(),
+            predicted_trust_score: mu,
+            confidence_interval: (mu - k * sigma, mu + k * sigma),
+            risk_factors,
+            prediction_horizon: 60,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn get_model_type(&self) -> String {
+        "SARIMA".to_string()
+    }
+}
+
+/// Number of trailing samples a `PatternTrustModel` window covers; also the
+/// FFT input length.
+const FFT_WINDOW_LEN: usize = 64;
+/// Low-frequency FFT bins kept per signal; shape-based attack signatures
+/// (periodic beaconing, repeated probing) show up here without needing the
+/// full spectrum.
+const FFT_KEPT_BINS: usize = 16;
+/// A `security_events` severity above this, anywhere in a window, labels
+/// that window "compromised" during training.
+const PATTERN_HIGH_SEVERITY: f64 = 0.7;
+
+/// Magnitudes of the first `keep` FFT bins of `samples`, padded to
+/// `FFT_WINDOW_LEN` with the last sample (zeros if there is none) first so
+/// every window produces the same-length input.
+fn fft_magnitudes(samples: &[f64], keep: usize) -> Vec<f64> {
+    let mut padded = samples.to_vec();
+    padded.resize(FFT_WINDOW_LEN, samples.last().copied().unwrap_or(0.0));
+
+    let mut planner = FftPlanner::<f64>::new();
+    let fft = planner.plan_fft_forward(padded.len());
+    let mut buffer: Vec<Complex<f64>> = padded.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    fft.process(&mut buffer);
+
+    buffer.iter().take(keep).map(|c| c.norm()).collect()
+}
+
+/// `[mean, std, min, max]` of `samples`, or zeros if empty.
+fn summary_stats(samples: &[f64]) -> [f64; 4] {
+    if samples.is_empty() {
+        return [0.0; 4];
+    }
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    let min = samples.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    [mean, variance.sqrt(), min, max]
+}
+
+/// Fixed-length feature vector for one window: FFT magnitudes of the trust
+/// score and error-rate signals, plus their summary statistics.
+fn extract_window_features(window: &[TrustDataPoint]) -> Vec<f32> {
+    let trust_scores: Vec<f64> = window.iter().map(|d| d.trust_score).collect();
+    let error_rates: Vec<f64> = window.iter().map(|d| d.performance_metrics.error_rate).collect();
+
+    let mut features = fft_magnitudes(&trust_scores, FFT_KEPT_BINS);
+    features.extend(fft_magnitudes(&error_rates, FFT_KEPT_BINS));
+    features.extend(summary_stats(&trust_scores));
+    features.extend(summary_stats(&error_rates));
+    features.into_iter().map(|x| x as f32).collect()
+}
+
+/// A window is labeled "compromised" if any point in it carries a
+/// high-severity `SecurityEvent`.
+fn window_is_compromised(window: &[TrustDataPoint]) -> bool {
+    window
+        .iter()
+        .any(|d| d.security_events.iter().any(|e| e.severity > PATTERN_HIGH_SEVERITY))
+}
+
+/// Pattern-detection trust model: classifies sliding windows of trust/error
+/// signal as healthy or compromised using gradient-boosted decision trees
+/// over FFT-derived features, so recurring attack shapes (periodic
+/// beaconing, repeated probing) are detected even when `LSTMTrustModel`'s
+/// weighted-sum `forward_pass` sees nothing unusual in the raw values.
+pub struct PatternTrustModel {
+    window_size: usize,
+    config: GbdtConfig,
+    gbdt: Option<GBDT>,
+}
+
+impl PatternTrustModel {
+    pub fn new() -> Self {
+        let mut config = GbdtConfig::new();
+        config.set_feature_size(FFT_KEPT_BINS * 2 + 8);
+        config.set_max_depth(5);
+        config.set_iterations(50);
+        config.set_shrinkage(0.1);
+        config.set_loss("LogLikelyhood");
+        config.set_debug(false);
+
+        Self {
+            window_size: FFT_WINDOW_LEN,
+            config,
+            gbdt: None,
+        }
+    }
+}
+
+impl Default for PatternTrustModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TrustModel for PatternTrustModel {
+    fn train(&mut self, data: &[TrustDataPoint]) -> Result<(), String> {
+        if data.len() < self.window_size {
+            return Err(format!(
+                "PatternTrustModel requires at least {} samples per window, got {}",
+                self.window_size,
+                data.len()
+            ));
+        }
+
+        let mut sorted: Vec<&TrustDataPoint> = data.iter().collect();
+        sorted.sort_by_key(|d| d.timestamp);
+
+        let mut train_data: GbdtDataVec = Vec::new();
+        for window in sorted.windows(self.window_size) {
+            let owned: Vec<TrustDataPoint> = window.iter().map(|&d| d.clone()).collect();
+            let features = extract_window_features(&owned);
+            let label = if window_is_compromised(&owned) { 1.0 } else { 0.0 };
+            train_data.push(GbdtData::new_training_data(features, 1.0, label, None));
+        }
+
+        let mut gbdt = GBDT::new(&self.config);
+        gbdt.fit(&mut train_data);
+        self.gbdt = Some(gbdt);
+        Ok(())
+    }
+
+    fn predict(&self, data: &[TrustDataPoint]) -> TrustPrediction {
+        let mut sorted: Vec<&TrustDataPoint> = data.iter().collect();
+        sorted.sort_by_key(|d| d.timestamp);
+        let component_id = sorted.last().map(|d| d.component_id.clone()).unwrap_or_default();
+
+        let Some(gbdt) = &self.gbdt else {
+            return TrustPrediction {
+                component_id,
+                predicted_trust_score: 0.5,
+                confidence_interval: (0.0, 1.0),
+                risk_factors: Vec::new(),
+                prediction_horizon: 60,
+                timestamp: Utc::now(),
+            };
+        };
+
+        let window: Vec<TrustDataPoint> = sorted
+            .iter()
+            .rev()
+            .take(self.window_size)
+            .rev()
+            .map(|&d| d.clone())
+            .collect();
+
+        let features = extract_window_features(&window);
+        let test_data: GbdtDataVec = vec![GbdtData::new_test_data(features, None)];
+        let compromised_score = gbdt.predict(&test_data).first().copied().unwrap_or(0.0).clamp(0.0, 1.0) as f64;
+        let predicted_trust_score = 1.0 - compromised_score;
+
+        let mut risk_factors = Vec::new();
+        if compromised_score > 0.5 {
+            risk_factors.push(RiskFactor {
+                factor_type: RiskFactorType::AnomalousBehavior,
+                severity: compromised_score,
+                description: format!(
+                    "GBDT pattern model scored this window {:.2} toward known compromised-behavior signatures",
+                    compromised_score
+                ),
+                mitigation_suggestions: vec![
+                    "Compare the flagged window against known attack signatures".to_string(),
+                    "Correlate with recent security events for this component".to_string(),
+                ],
+            });
+        }
+
+        TrustPrediction {
+            component_id,
+            predicted_trust_score,
+            confidence_interval: (predicted_trust_score - 0.15, predicted_trust_score + 0.15),
+            risk_factors,
+            prediction_horizon: 60,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn get_model_type(&self) -> String {
+        "GBDT-FFT".to_string()
+    }
+}
+
+/// Lightweight, training-free trust model: maps configurable thresholds on
+/// `PerformanceMetrics` and aggregated `SecurityEvent` severity directly to
+/// a predicted trust score. Gives deployments with no history yet (or a
+/// `train_models` call that failed for lack of data) immediate, explainable
+/// detection instead of no model at all.
+#[derive(Debug, Clone)]
+pub struct ThresholdTrustModel {
+    pub max_error_rate: f64,
+    pub min_availability: f64,
+    pub max_response_time_ms: f64,
+    pub max_security_event_severity: f64,
+}
+
+impl ThresholdTrustModel {
+    pub fn new(
+        max_error_rate: f64,
+        min_availability: f64,
+        max_response_time_ms: f64,
+        max_security_event_severity: f64,
+    ) -> Self {
+        Self {
+            max_error_rate,
+            min_availability,
+            max_response_time_ms,
+            max_security_event_severity,
+        }
+    }
+}
+
+impl Default for ThresholdTrustModel {
+    fn default() -> Self {
+        Self::new(0.05, 0.99, 500.0, 0.5)
+    }
+}
+
+impl TrustModel for ThresholdTrustModel {
+    /// No-op: the threshold set is configured up front at construction
+    /// time, so there's nothing to learn from historical data.
+    fn train(&mut self, _data: &[TrustDataPoint]) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn predict(&self, data: &[TrustDataPoint]) -> TrustPrediction {
+        let Some(latest) = data.iter().max_by_key(|d| d.timestamp) else {
+            return TrustPrediction {
+                component_id: String::new(),
+                predicted_trust_score: 0.5,
+                confidence_interval: (0.0, 1.0),
+                risk_factors: Vec::new(),
+                prediction_horizon: 60,
+                timestamp: Utc::now(),
+            };
+        };
+
+        let mut risk_factors = Vec::new();
+        let mut penalty = 0.0;
+
+        let metrics = &latest.performance_metrics;
+        if metrics.error_rate > self.max_error_rate {
+            let severity = ((metrics.error_rate - self.max_error_rate) / self.max_error_rate.max(1e-6)).min(1.0);
+            penalty += severity * 0.3;
+            risk_factors.push(RiskFactor {
+                factor_type: RiskFactorType::PerformanceDegradation,
+                severity,
+                description: format!(
+                    "Error rate {:.4} exceeds threshold {:.4}",
+                    metrics.error_rate, self.max_error_rate
+                ),
+                mitigation_suggestions: vec!["Investigate recent error spikes".to_string()],
+            });
+        }
+
+        if metrics.response_time > self.max_response_time_ms {
+            let severity = ((metrics.response_time - self.max_response_time_ms) / self.max_response_time_ms.max(1e-6)).min(1.0);
+            penalty += severity * 0.2;
+            risk_factors.push(RiskFactor {
+                factor_type: RiskFactorType::PerformanceDegradation,
+                severity,
+                description: format!(
+                    "Response time {:.1}ms exceeds threshold {:.1}ms",
+                    metrics.response_time, self.max_response_time_ms
+                ),
+                mitigation_suggestions: vec!["Check for resource contention or downstream latency".to_string()],
+            });
+        }
+
+        if metrics.availability < self.min_availability {
+            let severity = ((self.min_availability - metrics.availability) / self.min_availability.max(1e-6)).min(1.0);
+            penalty += severity * 0.3;
+            risk_factors.push(RiskFactor {
+                factor_type: RiskFactorType::ComplianceViolation,
+                severity,
+                description: format!(
+                    "Availability {:.4} is below the required {:.4}",
+                    metrics.availability, self.min_availability
+                ),
+                mitigation_suggestions: vec!["Review SLA compliance and recent outages".to_string()],
+            });
+        }
+
+        let max_event_severity = latest
+            .security_events
+            .iter()
+            .map(|e| e.severity)
+            .fold(0.0_f64, f64::max);
+        if max_event_severity > self.max_security_event_severity {
+            penalty += max_event_severity * 0.2;
+            risk_factors.push(RiskFactor {
+                factor_type: RiskFactorType::Vulnerability,
+                severity: max_event_severity,
+                description: format!(
+                    "Security event severity {:.2} exceeds threshold {:.2}",
+                    max_event_severity, self.max_security_event_severity
+                ),
+                mitigation_suggestions: vec!["Review flagged security events for this component".to_string()],
+            });
+        }
+
+        let predicted_trust_score = (1.0 - penalty).clamp(0.0, 1.0);
+
+        TrustPrediction {
+            component_id: latest.component_id.clone(),
+            predicted_trust_score,
+            confidence_interval: (predicted_trust_score, predicted_trust_score),
+            risk_factors,
+            prediction_horizon: 60,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn get_model_type(&self) -> String {
+        "Threshold".to_string()
+    }
+}
+
 impl PredictabilityEngine {
     pub fn new() -> Self {
+        let (warning_tx, warning_rx) = mpsc::channel(256);
         Self {
             historical_data: Arc::new(RwLock::new(Vec::new())),
             models: Arc::new(RwLock::new(HashMap::new())),
             risk_thresholds: Arc::new(RwLock::new(HashMap::new())),
+            runner_state: Arc::new(RwLock::new(DetectionRunnerState::Stopped)),
+            runner_handle: Arc::new(RwLock::new(None)),
+            warning_tx,
+            warning_rx: Arc::new(RwLock::new(Some(warning_rx))),
+            alerting_state: Arc::new(RwLock::new(false)),
+            alerting_handle: Arc::new(RwLock::new(None)),
+            http_client: reqwest::Client::new(),
+            model_status: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -221,57 +814,123 @@ impl PredictabilityEngine {
 
     /// Train prediction models
     pub async fn train_models(&self) -> Result<(), String> {
+        // `ThresholdTrustModel` needs no training, so it is registered `Ready`
+        // first and keeps serving `predict_trust` if the LSTM below fails.
+        self.models.write().await.insert("threshold".to_string(), Box::new(ThresholdTrustModel::default()));
+        self.model_status.write().await.insert("threshold".to_string(), LearningStatus::Ready);
+        self.model_status.write().await.insert("lstm".to_string(), LearningStatus::Learning);
+
         let historical = self.historical_data.read().await;
         if historical.is_empty() {
-            return Err("No historical data available for training".to_string());
+            let err = "No historical data available for training".to_string();
+            self.model_status.write().await.insert("lstm".to_string(), LearningStatus::Error(err.clone()));
+            return Err(err);
         }
 
         let mut models = self.models.write().await;
-        
+
         // Initialize and train LSTM model
         let mut lstm_model = LSTMTrustModel {
             model_weights: Array2::zeros((10, 10)),
             hidden_size: 64,
             sequence_length: 10,
         };
-        
-        lstm_model.train(&historical)?;
+
+        if let Err(e) = lstm_model.train(&historical) {
+            self.model_status.write().await.insert("lstm".to_string(), LearningStatus::Error(e.clone()));
+            return Err(e);
+        }
         models.insert("lstm".to_string(), Box::new(lstm_model));
-        
+        drop(models);
+        drop(historical);
+        self.model_status.write().await.insert("lstm".to_string(), LearningStatus::Ready);
+
+        // If a `start_runner` call arrived before any model was trained, it
+        // queued itself here instead of failing; now that training has
+        // succeeded, start the loop it asked for.
+        let queued_config = {
+            let state = self.runner_state.read().await;
+            match &*state {
+                DetectionRunnerState::WaitingForModels { config } => Some(config.clone()),
+                _ => None,
+            }
+        };
+        if let Some(config) = queued_config {
+            self.spawn_runner(config).await;
+        }
+
         Ok(())
     }
 
+    /// Lifecycle state of a registered model, e.g. `"lstm"`. Models that
+    /// have never been touched by `train_models` report `Initialization`.
+    pub async fn status(&self, model_name: &str) -> LearningStatus {
+        self.model_status
+            .read()
+            .await
+            .get(model_name)
+            .cloned()
+            .unwrap_or(LearningStatus::Initialization)
+    }
+
+    /// Preference order for picking a model when more than one is
+    /// `Ready`; anything registered outside this list is tried afterwards
+    /// in arbitrary order.
+    const MODEL_PREFERENCE_ORDER: [&'static str; 4] = ["lstm", "sarima", "pattern", "threshold"];
+
     /// Predict trust score for a component
-    pub async fn predict_trust(&self, component_id: &str) -> Result<TrustPrediction, String> {
+    pub async fn predict_trust(&self, component_id: &str) -> Result<TrustPrediction, PredictionError> {
         let historical = self.historical_data.read().await;
         let component_data: Vec<TrustDataPoint> = historical
             .iter()
             .filter(|d| d.component_id == component_id)
             .cloned()
             .collect();
+        drop(historical);
 
         if component_data.is_empty() {
-            return Err(format!("No historical data for component: {}", component_id));
+            return Err(PredictionError::NoData(component_id.to_string()));
         }
 
         let models = self.models.read().await;
-        if let Some(model) = models.get("lstm") {
-            Ok(model.predict(&component_data))
-        } else {
-            Err("No trained models available".to_string())
+        let statuses = self.model_status.read().await;
+        let mut ordered_keys: Vec<&String> = Vec::new();
+        for preferred in Self::MODEL_PREFERENCE_ORDER {
+            if let Some(key) = models.keys().find(|k| k.as_str() == preferred) {
+                ordered_keys.push(key);
+            }
+        }
+        for key in models.keys() {
+            if !ordered_keys.contains(&key) {
+                ordered_keys.push(key);
+            }
         }
+
+        for key in ordered_keys {
+            if statuses.get(key) == Some(&LearningStatus::Ready) {
+                if let Some(model) = models.get(key) {
+                    return Ok(model.predict(&component_data));
+                }
+            }
+        }
+
+        if statuses.values().any(|s| matches!(s, LearningStatus::Initialization | LearningStatus::Learning)) {
+            return Err(PredictionError::StillLearning);
+        }
+
+        Err(PredictionError::NoReadyModel)
     }
 
     /// Get risk assessment for multiple components
     pub async fn assess_risk(&self, component_ids: &[String]) -> HashMap<String, TrustPrediction> {
         let mut results = HashMap::new();
-        
+
         for component_id in component_ids {
             if let Ok(prediction) = self.predict_trust(component_id).await {
                 results.insert(component_id.clone(), prediction);
             }
         }
-        
+
         results
     }
 
@@ -283,25 +942,34 @@ impl PredictabilityEngine {
 
     /// Get early warning alerts based on predictions
     pub async fn get_early_warnings(&self) -> Vec<EarlyWarning> {
+        self.get_early_warnings_since(DateTime::<Utc>::MIN_UTC).await
+    }
+
+    /// Get early warning alerts based on predictions, for the components
+    /// that have historical data at or after `from`; each prediction still
+    /// uses the component's full history. Used by the background detection
+    /// runner so each cycle only checks components active in the window the
+    /// caller configured.
+    pub async fn get_early_warnings_since(&self, from: DateTime<Utc>) -> Vec<EarlyWarning> {
         let mut warnings = Vec::new();
         let historical = self.historical_data.read().await;
-        
+
         // Group data by component
         let mut component_data: HashMap<String, Vec<TrustDataPoint>> = HashMap::new();
-        for data_point in historical.iter() {
+        for data_point in historical.iter().filter(|d| d.timestamp >= from) {
             component_data.entry(data_point.component_id.clone())
                 .or_insert_with(Vec::new)
                 .push(data_point.clone());
         }
-        
-        for (component_id, data) in component_data {
+
+        for (component_id, _data) in component_data {
             if let Ok(prediction) = self.predict_trust(&component_id).await {
                 if prediction.predicted_trust_score < 0.3 {
                     warnings.push(EarlyWarning {
                         component_id: component_id.clone(),
                         warning_type: "Trust Score Critical".to_string(),
                         severity: 1.0 - prediction.predicted_trust_score,
-                        description: format!("Component {} predicted trust score: {:.2}", 
+                        description: format!("Component {} predicted trust score: {:.2}",
                                            component_id, prediction.predicted_trust_score),
                         recommended_actions: vec![
                             "Immediate investigation required".to_string(),
@@ -313,9 +981,133 @@ impl PredictabilityEngine {
                 }
             }
         }
-        
+
         warnings
     }
+
+    /// Start the background detection loop. If no model has been trained
+    /// yet, the request is queued and started automatically the moment
+    /// `train_models` next succeeds, instead of the caller having to poll.
+    pub async fn start_runner(&self, config: DetectionRunnerConfig) {
+        if self.models.read().await.is_empty() {
+            *self.runner_state.write().await = DetectionRunnerState::WaitingForModels { config };
+            return;
+        }
+        self.spawn_runner(config).await;
+    }
+
+    /// Stop the background detection loop, if running.
+    pub async fn stop_runner(&self) {
+        *self.runner_state.write().await = DetectionRunnerState::Stopped;
+        if let Some(handle) = self.runner_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+
+    /// Take ownership of the channel `start_runner` pushes new
+    /// `EarlyWarning`s onto. Can only be taken once; subsequent calls
+    /// return `None`.
+    pub async fn take_warning_receiver(&self) -> Option<mpsc::Receiver<EarlyWarning>> {
+        self.warning_rx.write().await.take()
+    }
+
+    async fn spawn_runner(&self, config: DetectionRunnerConfig) {
+        *self.runner_state.write().await = DetectionRunnerState::Running;
+
+        let engine = self.clone();
+        let runner_state = self.runner_state.clone();
+        let warning_tx = self.warning_tx.clone();
+        let interval = config.interval;
+        let from = config.from;
+
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if !matches!(*runner_state.read().await, DetectionRunnerState::Running) {
+                    break;
+                }
+
+                for warning in engine.get_early_warnings_since(from).await {
+                    if warning_tx.send(warning).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        *self.runner_handle.write().await = Some(handle);
+    }
+
+    /// Start delivering `EarlyWarning`s whose severity crosses the
+    /// `risk_thresholds` entry for their component to an external sink,
+    /// instead of requiring callers to drain `get_early_warnings`
+    /// themselves. Warnings raised within one dispatch interval are
+    /// batched into a single request.
+    pub async fn start_alerting(&self, config: AlertingConfig) {
+        *self.alerting_state.write().await = true;
+
+        let engine = self.clone();
+        let alerting_state = self.alerting_state.clone();
+        let interval_secs = config.dispatch_interval_secs.max(1);
+
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(std::time::Duration::from_secs(interval_secs));
+            loop {
+                ticker.tick().await;
+                if !*alerting_state.read().await {
+                    break;
+                }
+
+                let warnings: Vec<EarlyWarning> = {
+                    let mut due = Vec::new();
+                    for warning in engine.get_early_warnings().await {
+                        if engine.should_alert(&warning).await {
+                            due.push(warning);
+                        }
+                    }
+                    due
+                };
+
+                if !warnings.is_empty() {
+                    engine.dispatch_alert_batch(&config.alerting_type, &warnings).await;
+                }
+            }
+        });
+
+        *self.alerting_handle.write().await = Some(handle);
+    }
+
+    /// Stop delivering alerts, if running.
+    pub async fn stop_alerting(&self) {
+        *self.alerting_state.write().await = false;
+        if let Some(handle) = self.alerting_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+
+    /// Whether `warning` crosses the risk threshold configured for its
+    /// component (falling back to a conservative default when none was
+    /// set via `set_risk_threshold`).
+    async fn should_alert(&self, warning: &EarlyWarning) -> bool {
+        const DEFAULT_ALERT_THRESHOLD: f64 = 0.5;
+        let thresholds = self.risk_thresholds.read().await;
+        let threshold = thresholds
+            .get(&warning.component_id)
+            .copied()
+            .unwrap_or(DEFAULT_ALERT_THRESHOLD);
+        warning.severity > threshold
+    }
+
+    async fn dispatch_alert_batch(&self, alerting_type: &AlertingType, warnings: &[EarlyWarning]) {
+        match alerting_type {
+            AlertingType::Webhook { endpoint } => {
+                if let Err(e) = self.http_client.post(endpoint).json(warnings).send().await {
+                    println!("   ⚠️  predictability_engine: failed to deliver early-warning batch to {}: {}", endpoint, e);
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -365,4 +1157,209 @@ mod tests {
         let prediction = engine.predict_trust("test-component").await;
         assert!(prediction.is_ok());
     }
+
+    fn make_point(component_id: &str, timestamp: DateTime<Utc>, trust_score: f64) -> TrustDataPoint {
+        TrustDataPoint {
+            timestamp,
+            component_id: component_id.to_string(),
+            trust_score,
+            security_events: vec![],
+            performance_metrics: PerformanceMetrics {
+                response_time: 100.0,
+                throughput: 1000.0,
+                error_rate: 0.01,
+                availability: 0.99,
+            },
+            behavioral_indicators: BehavioralIndicators {
+                request_patterns: HashMap::new(),
+                resource_usage: HashMap::new(),
+                communication_patterns: HashMap::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_sarima_rejects_short_series() {
+        let mut model = SarimaTrustModel::new(4, Duration::minutes(10), 2.0, 1);
+        let start = Utc::now();
+        let data: Vec<TrustDataPoint> = (0..5i64)
+            .map(|i| make_point("c1", start + Duration::minutes(10 * i), 0.8))
+            .collect();
+
+        assert!(model.train(&data).is_err());
+    }
+
+    #[test]
+    fn test_sarima_flags_anomalous_latest_score() {
+        let mut model = SarimaTrustModel::new(4, Duration::minutes(10), 1.0, 1);
+        let start = Utc::now();
+
+        // Three seasons with a slight per-season drift (so sigma is nonzero),
+        // then one wildly anomalous point.
+        let mut data: Vec<TrustDataPoint> = (0..12i64)
+            .map(|i| make_point("c1", start + Duration::minutes(10 * i), 0.9 + 0.01 * (i / 4) as f64))
+            .collect();
+        model.train(&data).expect("enough data for two seasons");
+
+        data.push(make_point("c1", start + Duration::minutes(10 * 12), 0.1));
+        let prediction = model.predict(&data);
+
+        assert!(prediction.risk_factors.iter().any(|f| matches!(f.factor_type, RiskFactorType::AnomalousBehavior)));
+    }
+
+    fn make_point_with_severity(
+        component_id: &str,
+        timestamp: DateTime<Utc>,
+        trust_score: f64,
+        error_rate: f64,
+        severity: f64,
+    ) -> TrustDataPoint {
+        let mut point = make_point(component_id, timestamp, trust_score);
+        point.performance_metrics.error_rate = error_rate;
+        if severity > 0.0 {
+            point.security_events.push(SecurityEvent {
+                event_type: "anomaly".to_string(),
+                severity,
+                source: "test".to_string(),
+                description: "synthetic security event".to_string(),
+            });
+        }
+        point
+    }
+
+    #[test]
+    fn test_window_is_compromised_detects_high_severity_event() {
+        let start = Utc::now();
+        let healthy: Vec<TrustDataPoint> = (0..FFT_WINDOW_LEN as i64)
+            .map(|i| make_point_with_severity("c1", start + Duration::minutes(i), 0.9, 0.01, 0.0))
+            .collect();
+        assert!(!window_is_compromised(&healthy));
+
+        let mut compromised = healthy.clone();
+        compromised[0].security_events.push(SecurityEvent {
+            event_type: "intrusion".to_string(),
+            severity: 0.9,
+            source: "test".to_string(),
+            description: "synthetic high-severity event".to_string(),
+        });
+        assert!(window_is_compromised(&compromised));
+    }
+
+    #[test]
+    fn test_pattern_model_trains_and_predicts() {
+        let mut model = PatternTrustModel::new();
+        let start = Utc::now();
+
+        let mut data: Vec<TrustDataPoint> = Vec::new();
+        for i in 0..(FFT_WINDOW_LEN as i64 * 3) {
+            let compromised_phase = (i / FFT_WINDOW_LEN as i64) % 2 == 1;
+            let point = make_point_with_severity(
+                "c1",
+                start + Duration::minutes(i),
+                if compromised_phase { 0.3 } else { 0.9 },
+                if compromised_phase { 0.4 } else { 0.01 },
+                if compromised_phase { 0.9 } else { 0.0 },
+            );
+            data.push(point);
+        }
+
+        assert!(model.train(&data).is_ok());
+        let prediction = model.predict(&data);
+        assert!(prediction.predicted_trust_score >= 0.0 && prediction.predicted_trust_score <= 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_runner_queues_until_models_are_trained() {
+        let engine = PredictabilityEngine::new();
+        let mut receiver = engine.take_warning_receiver().await.expect("receiver not yet taken");
+
+        engine
+            .start_runner(DetectionRunnerConfig {
+                interval: std::time::Duration::from_millis(5),
+                from: Utc::now() - Duration::days(1),
+            })
+            .await;
+        assert!(matches!(*engine.runner_state.read().await, DetectionRunnerState::WaitingForModels { .. }));
+
+        engine
+            .add_historical_data(make_point("low-trust", Utc::now(), 0.05))
+            .await;
+        engine.train_models().await.expect("training succeeds once data exists");
+        assert!(matches!(*engine.runner_state.read().await, DetectionRunnerState::Running));
+
+        let warning = tokio::time::timeout(std::time::Duration::from_secs(2), receiver.recv())
+            .await
+            .expect("runner should push a warning before the timeout");
+        assert!(warning.is_some());
+
+        engine.stop_runner().await;
+        assert!(matches!(*engine.runner_state.read().await, DetectionRunnerState::Stopped));
+    }
+
+    #[tokio::test]
+    async fn test_should_alert_uses_per_component_threshold_with_default_fallback() {
+        let engine = PredictabilityEngine::new();
+        let warning = EarlyWarning {
+            component_id: "svc-a".to_string(),
+            warning_type: "Trust Score Critical".to_string(),
+            severity: 0.6,
+            description: "synthetic".to_string(),
+            recommended_actions: vec![],
+            timestamp: Utc::now(),
+        };
+
+        // No threshold configured yet: falls back to the default and alerts.
+        assert!(engine.should_alert(&warning).await);
+
+        // A stricter threshold than the warning's severity suppresses it.
+        engine.set_risk_threshold("svc-a", 0.9).await;
+        assert!(!engine.should_alert(&warning).await);
+    }
+
+    #[test]
+    fn test_threshold_model_needs_no_training_and_flags_breaches() {
+        let mut model = ThresholdTrustModel::default();
+        assert!(model.train(&[]).is_ok());
+
+        let breaching = make_point_with_severity("svc-b", Utc::now(), 0.9, 0.5, 0.8);
+        let prediction = model.predict(&[breaching]);
+
+        assert!(prediction.predicted_trust_score < 1.0);
+        assert!(prediction.risk_factors.iter().any(|f| matches!(f.factor_type, RiskFactorType::PerformanceDegradation)));
+        assert!(prediction.risk_factors.iter().any(|f| matches!(f.factor_type, RiskFactorType::Vulnerability)));
+    }
+
+    #[test]
+    fn test_threshold_model_clean_point_has_no_risk_factors() {
+        let model = ThresholdTrustModel::default();
+        let healthy = make_point("svc-c", Utc::now(), 0.95);
+        let prediction = model.predict(&[healthy]);
+
+        assert!(prediction.risk_factors.is_empty());
+        assert_eq!(prediction.predicted_trust_score, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_predict_trust_distinguishes_no_data_from_still_learning() {
+        let engine = PredictabilityEngine::new();
+
+        // Nothing has ever been observed for this component: no data.
+        assert!(matches!(engine.predict_trust("ghost").await, Err(PredictionError::NoData(_))));
+
+        // Data exists (the 10 points the LSTM's sequence needs) but no model
+        // has finished training yet: still learning.
+        for _ in 0..10 {
+            engine.add_historical_data(make_point("svc-d", Utc::now(), 0.8)).await;
+        }
+        engine.model_status.write().await.insert("lstm".to_string(), LearningStatus::Learning);
+        assert!(matches!(engine.predict_trust("svc-d").await, Err(PredictionError::StillLearning)));
+
+        assert_eq!(engine.status("lstm").await, LearningStatus::Learning);
+        assert_eq!(engine.status("unregistered").await, LearningStatus::Initialization);
+
+        // Once training succeeds, predict_trust serves from the lstm model.
+        engine.train_models().await.expect("training succeeds once data exists");
+        assert_eq!(engine.status("lstm").await, LearningStatus::Ready);
+        assert!(engine.predict_trust("svc-d").await.is_ok());
+    }
 }
@@ -5,6 +5,20 @@ use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use warp::Filter;
 use std::convert::Infallible;
+use opentelemetry::{global, KeyValue};
+use opentelemetry::metrics::Gauge;
+use opentelemetry::trace::{Span, Tracer, TracerProvider as _};
+use arrow::array::{Float64Array, StringArray, TimestampMillisecondArray};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo, HandshakeRequest,
+    HandshakeResponse, PutResult, SchemaResult, Ticket,
+};
+use futures::stream::BoxStream;
+use futures::StreamExt;
 
 mod core;
 use core::predictability_engine::PredictabilityEngine;
@@ -21,6 +35,21 @@ pub struct TrustMonitoringOrchestrator {
     pub incident_response_engine: Arc<IncidentResponseEngine>,
     pub system_config: Arc<RwLock<SystemConfig>>,
     pub metrics_collector: Arc<RwLock<MetricsCollector>>,
+    /// Container-level trust state, maintained by `container_assessment_loop`.
+    /// Formerly only simulated by the standalone `trust-monitor-demo`
+    /// binary; folded in here so there's one deployable process.
+    pub container_store: ContainerStore,
+    pub node_store: NodeStore,
+    container_history: ContainerHistoryStore,
+    node_aggregates: NodeAggregateStore,
+    container_index: ContainerNodeIndex,
+    /// OTEL export for the monitoring loop. `None` unless `SystemConfig.otel_endpoint`
+    /// was set at construction, so instrumentation costs nothing by default.
+    telemetry: Option<Arc<Telemetry>>,
+    /// Sentry sink for triggered response actions and container status
+    /// transitions. `None` unless `SystemConfig.notification_channels` has
+    /// an enabled `"sentry"` channel at construction.
+    incident_reporter: Option<Arc<IncidentReporter>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +60,20 @@ pub struct SystemConfig {
     pub data_sources: Vec<DataSourceConfig>,
     pub notification_channels: Vec<NotificationConfig>,
     pub escalation_policies: Vec<EscalationPolicyConfig>,
+    /// OTLP collector endpoint trust telemetry is exported to. `None` means
+    /// the orchestrator and its engines run without OTEL instrumentation.
+    pub otel_endpoint: Option<String>,
+    /// HTTP bind address for the unified `/api/v1` server, replacing the
+    /// formerly hardcoded `:3030` so a deployment can reconfigure it
+    /// without a rebuild.
+    pub bind_addr: String,
+    /// Static HTML served at `/`. `None` disables the dashboard route
+    /// entirely instead of erroring on a missing file.
+    pub dashboard_path: Option<String>,
+    /// Which route groups `create_routes` mounts under `/api/v1`, so a
+    /// deployment can narrow the surface (e.g. containers-only) without
+    /// recompiling.
+    pub enabled_route_groups: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,6 +109,82 @@ pub struct EscalationPolicyConfig {
     pub priority: u32,
 }
 
+/// Per-container trust snapshot. This state (and everything below through
+/// `ContainerNodeIndex`) used to live only in the standalone
+/// `trust-monitor-demo` binary; the orchestrator now owns it directly,
+/// `container_assessment_loop` maintains it, and it's served from this same
+/// process under `/api/v1`, so there's one deployable server instead of two.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerTrustMetrics {
+    pub container_id: String,
+    pub node_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub integrity_score: f64,
+    pub behavioral_score: f64,
+    pub communication_score: f64,
+    pub overall_trust: f64,
+    pub status: ContainerStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ContainerStatus {
+    Trusted,
+    Suspicious,
+    Compromised,
+    Isolated,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeInfo {
+    pub node_id: String,
+    pub architecture: String,
+    pub containers: Vec<String>,
+    pub trust_level: f64,
+}
+
+pub type ContainerStore = Arc<RwLock<HashMap<String, ContainerTrustMetrics>>>;
+pub type NodeStore = Arc<RwLock<HashMap<String, NodeInfo>>>;
+/// Every `ContainerTrustMetrics` sample recorded by `container_assessment_loop`,
+/// oldest first, for the Arrow Flight history export -- unlike
+/// `ContainerStore`, which only keeps each container's latest snapshot.
+pub type ContainerHistoryStore = Arc<RwLock<std::collections::VecDeque<ContainerTrustMetrics>>>;
+
+/// Caps `ContainerHistoryStore` so a long-running process doesn't grow its
+/// Arrow backlog without bound; the oldest sample is evicted once this is hit.
+const MAX_HISTORY_SAMPLES: usize = 100_000;
+
+/// How many of a container's most recent `ContainerHistoryStore` samples to
+/// attach as breadcrumbs when it's reported to `IncidentReporter`.
+const INCIDENT_TRAJECTORY_SAMPLES: usize = 10;
+
+/// A node's trust level kept as a running sum/count instead of being
+/// re-filtered from every container on each tick, so `container_assessment_loop`
+/// only touches the containers that actually changed. `average()` is the
+/// defined default (`0.0`) for an empty node rather than a divide-by-zero.
+#[derive(Debug, Clone, Copy, Default)]
+struct NodeTrustAggregate {
+    sum: f64,
+    count: u64,
+}
+
+impl NodeTrustAggregate {
+    fn average(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.sum / self.count as f64
+        }
+    }
+}
+
+/// Per-node running `NodeTrustAggregate`, maintained incrementally by
+/// `container_assessment_loop` instead of recomputed by scanning `ContainerStore`.
+pub type NodeAggregateStore = Arc<RwLock<HashMap<String, NodeTrustAggregate>>>;
+/// The node each container's current score is folded into, so a container
+/// that moves between nodes retracts from the old node before adding to the
+/// new one.
+pub type ContainerNodeIndex = Arc<RwLock<HashMap<String, String>>>;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricsCollector {
     pub system_metrics: HashMap<String, f64>,
@@ -75,13 +194,334 @@ pub struct MetricsCollector {
     pub response_times: HashMap<String, Vec<f64>>,
 }
 
+/// OpenTelemetry instrumentation for the orchestrator: a span per
+/// `monitoring_loop` tick, a child span per `process_trust_update` call, and
+/// gauges for the `MetricsCollector` fields pushed once per tick. A no-op
+/// unless `SystemConfig.otel_endpoint` is set, so there's no hard dependency
+/// on an OTLP collector being reachable.
+pub struct Telemetry {
+    tracer: opentelemetry_sdk::trace::Tracer,
+    system_metric_gauge: Gauge<f64>,
+    trust_score_gauge: Gauge<f64>,
+    incident_count_gauge: Gauge<u64>,
+    response_time_gauge: Gauge<f64>,
+    /// Per-container `overall_trust`, tagged by `container_id`/`node_id`
+    /// instead of `component_id` like `trust_score_gauge` above.
+    container_trust_score_gauge: Gauge<f64>,
+}
+
+impl std::fmt::Debug for Telemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Telemetry").finish_non_exhaustive()
+    }
+}
+
+impl Telemetry {
+    /// Stands up OTLP tracer and meter providers pointed at `otlp_endpoint`
+    /// and registers them as the process-wide global providers.
+    pub fn init(otlp_endpoint: &str) -> Result<Self, String> {
+        let tracer_provider = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(otlp_endpoint),
+            )
+            .install_batch(opentelemetry_sdk::runtime::Tokio)
+            .map_err(|e| e.to_string())?;
+        let tracer = tracer_provider.tracer("trust-monitoring-system-orchestrator");
+        global::set_tracer_provider(tracer_provider);
+
+        let meter_provider = opentelemetry_otlp::new_pipeline()
+            .metrics(opentelemetry_sdk::runtime::Tokio)
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(otlp_endpoint),
+            )
+            .build()
+            .map_err(|e| e.to_string())?;
+        let meter = meter_provider.meter("trust-monitoring-system-orchestrator");
+        global::set_meter_provider(meter_provider);
+
+        let system_metric_gauge = meter
+            .f64_gauge("lanc_orchestrator_system_metric")
+            .with_description("MetricsCollector.system_metrics value, tagged by metric name")
+            .init();
+        let trust_score_gauge = meter
+            .f64_gauge("lanc_orchestrator_trust_score")
+            .with_description("MetricsCollector.trust_scores value, tagged by component_id")
+            .init();
+        let incident_count_gauge = meter
+            .u64_gauge("lanc_orchestrator_incident_count")
+            .with_description("MetricsCollector.incident_counts value, tagged by component_id")
+            .init();
+        let response_time_gauge = meter
+            .f64_gauge("lanc_orchestrator_response_time_ms")
+            .with_description("Most recent MetricsCollector.response_times sample, tagged by component_id")
+            .init();
+        let container_trust_score_gauge = meter
+            .f64_gauge("lanc_container_trust_score")
+            .with_description("Overall trust score per container, tagged by container_id/node_id")
+            .init();
+
+        Ok(Self {
+            tracer,
+            system_metric_gauge,
+            trust_score_gauge,
+            incident_count_gauge,
+            response_time_gauge,
+            container_trust_score_gauge,
+        })
+    }
+
+    fn start_tick_span(&self) -> opentelemetry::trace::BoxedSpan {
+        self.tracer.span_builder("monitoring_tick").start(&self.tracer)
+    }
+
+    fn start_container_tick_span(&self) -> opentelemetry::trace::BoxedSpan {
+        self.tracer.span_builder("container_trust_assessment_tick").start(&self.tracer)
+    }
+
+    fn start_trust_update_span(&self, component_id: &str) -> opentelemetry::trace::BoxedSpan {
+        self.tracer
+            .span_builder("process_trust_update")
+            .with_attributes(vec![KeyValue::new("component_id", component_id.to_string())])
+            .start(&self.tracer)
+    }
+
+    /// Pushes every `MetricsCollector` field as a gauge, tagged by whatever
+    /// key the field is already keyed by (metric name or component id).
+    fn record_metrics_collector(&self, metrics: &MetricsCollector) {
+        for (name, value) in &metrics.system_metrics {
+            self.system_metric_gauge.record(*value, &[KeyValue::new("metric", name.clone())]);
+        }
+        for (component_id, score) in &metrics.trust_scores {
+            self.trust_score_gauge.record(*score, &[KeyValue::new("component_id", component_id.clone())]);
+        }
+        for (component_id, count) in &metrics.incident_counts {
+            self.incident_count_gauge.record(*count, &[KeyValue::new("component_id", component_id.clone())]);
+        }
+        for (component_id, samples) in &metrics.response_times {
+            if let Some(latest) = samples.last() {
+                self.response_time_gauge.record(*latest, &[KeyValue::new("component_id", component_id.clone())]);
+            }
+        }
+    }
+
+    fn record_container_trust_metrics(&self, containers: &HashMap<String, ContainerTrustMetrics>) {
+        for metrics in containers.values() {
+            self.container_trust_score_gauge.record(
+                metrics.overall_trust,
+                &[
+                    KeyValue::new("container_id", metrics.container_id.clone()),
+                    KeyValue::new("node_id", metrics.node_id.clone()),
+                ],
+            );
+        }
+    }
+}
+
+/// Sentry-backed incident reporting for triggered response actions and
+/// container status transitions. Built behind `--features sentry`, with the
+/// DSN read from the first enabled `NotificationConfig` whose `channel_type`
+/// is `"sentry"`; a no-op if none is configured, or if this binary wasn't
+/// built with the feature at all.
+#[cfg(feature = "sentry")]
+pub struct IncidentReporter {
+    _guard: sentry::ClientInitGuard,
+}
+
+#[cfg(feature = "sentry")]
+impl std::fmt::Debug for IncidentReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IncidentReporter").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "sentry")]
+impl IncidentReporter {
+    pub fn init(dsn: &str) -> Self {
+        let guard = sentry::init((
+            dsn,
+            sentry::ClientOptions {
+                release: sentry::release_name!(),
+                ..Default::default()
+            },
+        ));
+        Self { _guard: guard }
+    }
+
+    /// Reports a component's triggered response actions, tagging the event
+    /// with its trust score and attaching its recent score trajectory
+    /// (oldest first) as breadcrumbs leading up to the trigger.
+    fn report_triggered_actions(&self, component_id: &str, trust_score: f64, actions: &[String], trajectory: &[core::continual_assurance_engine::TrustScorePoint]) {
+        sentry::with_scope(
+            |scope| {
+                scope.set_tag("component_id", component_id);
+                scope.set_tag("trust_score", format!("{:.3}", trust_score));
+                scope.set_tag("actions", actions.join(","));
+                for point in trajectory {
+                    scope.add_breadcrumb(sentry::Breadcrumb {
+                        timestamp: point.timestamp.into(),
+                        message: Some(format!("trust score {:.3} (confidence {:.3})", point.score, point.confidence)),
+                        ..Default::default()
+                    });
+                }
+            },
+            || {
+                sentry::capture_message(
+                    &format!("Triggered {} response action(s) for component {}", actions.len(), component_id),
+                    sentry::Level::Error,
+                );
+            },
+        );
+    }
+
+    /// Reports a container's transition into a degraded `ContainerStatus`,
+    /// tagging the event with its trust score and attaching its recent
+    /// trust history (oldest first) as breadcrumbs leading up to it.
+    fn report_status_transition(&self, container: &ContainerTrustMetrics, trajectory: &[ContainerTrustMetrics]) {
+        sentry::with_scope(
+            |scope| {
+                scope.set_tag("container_id", &container.container_id);
+                scope.set_tag("node_id", &container.node_id);
+                scope.set_tag("status", format!("{:?}", container.status));
+                scope.set_tag("overall_trust", format!("{:.3}", container.overall_trust));
+                for sample in trajectory {
+                    scope.add_breadcrumb(sentry::Breadcrumb {
+                        timestamp: sample.timestamp.into(),
+                        message: Some(format!("overall_trust {:.3}", sample.overall_trust)),
+                        ..Default::default()
+                    });
+                }
+            },
+            || {
+                sentry::capture_message(
+                    &format!("Container {} transitioned to {:?}", container.container_id, container.status),
+                    sentry::Level::Warning,
+                );
+            },
+        );
+    }
+}
+
+#[cfg(not(feature = "sentry"))]
+#[derive(Debug, Default)]
+pub struct IncidentReporter;
+
+#[cfg(not(feature = "sentry"))]
+impl IncidentReporter {
+    pub fn init(_dsn: &str) -> Self {
+        Self
+    }
+
+    fn report_triggered_actions(&self, _component_id: &str, _trust_score: f64, _actions: &[String], _trajectory: &[core::continual_assurance_engine::TrustScorePoint]) {}
+
+    fn report_status_transition(&self, _container: &ContainerTrustMetrics, _trajectory: &[ContainerTrustMetrics]) {}
+}
+
+/// Starting `container_store`/`node_store` state, ported from
+/// `trust-monitor-demo`'s `initialize_demo_data` so the unified API has
+/// something to serve under `/api/v1/containers` and `/api/v1/nodes` out of
+/// the box.
+fn seed_demo_containers() -> (HashMap<String, ContainerTrustMetrics>, HashMap<String, NodeInfo>) {
+    let mut containers = HashMap::new();
+    let mut nodes = HashMap::new();
+
+    let node_defs = vec![
+        ("edge-node-1", "aarch64", vec!["container-a", "container-b"]),
+        ("edge-node-2", "riscv64", vec!["container-c", "container-d"]),
+        ("cloud-node-1", "x86_64", vec!["container-e", "container-f"]),
+    ];
+
+    for (node_id, arch, container_ids) in node_defs {
+        nodes.insert(node_id.to_string(), NodeInfo {
+            node_id: node_id.to_string(),
+            architecture: arch.to_string(),
+            containers: container_ids.iter().map(|s| s.to_string()).collect(),
+            trust_level: 0.85,
+        });
+
+        for container_id in container_ids {
+            containers.insert(container_id.to_string(), ContainerTrustMetrics {
+                container_id: container_id.to_string(),
+                node_id: node_id.to_string(),
+                timestamp: Utc::now(),
+                integrity_score: 0.9,
+                behavioral_score: 0.8,
+                communication_score: 0.85,
+                overall_trust: 0.85,
+                status: ContainerStatus::Trusted,
+            });
+        }
+    }
+
+    (containers, nodes)
+}
+
 impl TrustMonitoringOrchestrator {
     pub fn new() -> Self {
+        // Wire every engine's OTEL instrumentation off the same collector
+        // endpoint, so a single `TRUST_MONITORING_OTEL_ENDPOINT` lights up
+        // traces and metrics for the whole orchestrator at once.
+        let otel_endpoint = std::env::var("TRUST_MONITORING_OTEL_ENDPOINT").ok();
+
+        let mut composition_engine = CompositionEngine::new();
+        let mut incident_response_engine = IncidentResponseEngine::new();
+        let mut telemetry = None;
+        if let Some(endpoint) = otel_endpoint.as_deref() {
+            match core::composition_engine::Telemetry::init(endpoint) {
+                Ok(t) => composition_engine = composition_engine.with_telemetry(t),
+                Err(e) => eprintln!("⚠️  Failed to initialize composition engine OTEL telemetry: {}", e),
+            }
+            match core::incident_response_engine::Telemetry::init(endpoint) {
+                Ok(t) => incident_response_engine = incident_response_engine.with_telemetry(t),
+                Err(e) => eprintln!("⚠️  Failed to initialize incident response OTEL telemetry: {}", e),
+            }
+            match Telemetry::init(endpoint) {
+                Ok(t) => telemetry = Some(Arc::new(t)),
+                Err(e) => eprintln!("⚠️  Failed to initialize orchestrator OTEL telemetry: {}", e),
+            }
+        }
+
+        // Seed a `"sentry"` notification channel from the environment, the
+        // same way `otel_endpoint` is seeded above, so there's somewhere for
+        // `incident_reporter` to read a DSN from without requiring a config
+        // API round-trip first.
+        let notification_channels = std::env::var("TRUST_MONITORING_SENTRY_DSN")
+            .ok()
+            .map(|dsn| {
+                vec![NotificationConfig {
+                    channel_id: "sentry-default".to_string(),
+                    channel_type: "sentry".to_string(),
+                    endpoint: dsn,
+                    enabled: true,
+                }]
+            })
+            .unwrap_or_default();
+
+        let incident_reporter = notification_channels
+            .iter()
+            .find(|channel| channel.channel_type == "sentry" && channel.enabled)
+            .map(|channel| Arc::new(IncidentReporter::init(&channel.endpoint)));
+
+        let (demo_containers, demo_nodes) = seed_demo_containers();
+
+        let mut node_aggregates = HashMap::new();
+        let mut container_index = HashMap::new();
+        for container in demo_containers.values() {
+            let aggregate = node_aggregates.entry(container.node_id.clone()).or_insert_with(NodeTrustAggregate::default);
+            aggregate.sum += container.overall_trust;
+            aggregate.count += 1;
+            container_index.insert(container.container_id.clone(), container.node_id.clone());
+        }
+
         Self {
             predictability_engine: Arc::new(PredictabilityEngine::new()),
-            composition_engine: Arc::new(CompositionEngine::new()),
+            composition_engine: Arc::new(composition_engine),
             continual_assurance_engine: Arc::new(ContinualAssuranceEngine::new()),
-            incident_response_engine: Arc::new(IncidentResponseEngine::new()),
+            incident_response_engine: Arc::new(incident_response_engine),
             system_config: Arc::new(RwLock::new(SystemConfig {
                 system_id: "trust-monitoring-system".to_string(),
                 update_interval: 30,
@@ -91,8 +531,14 @@ impl TrustMonitoringOrchestrator {
                     normal: 0.8,
                 },
                 data_sources: Vec::new(),
-                notification_channels: Vec::new(),
+                notification_channels,
                 escalation_policies: Vec::new(),
+                otel_endpoint,
+                bind_addr: std::env::var("TRUST_MONITORING_BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:3030".to_string()),
+                dashboard_path: std::env::var("TRUST_MONITORING_DASHBOARD_PATH").ok(),
+                enabled_route_groups: vec![
+                    "status", "trust-scores", "incidents", "alerts", "containers", "nodes",
+                ].into_iter().map(String::from).collect(),
             })),
             metrics_collector: Arc::new(RwLock::new(MetricsCollector {
                 system_metrics: HashMap::new(),
@@ -101,6 +547,13 @@ impl TrustMonitoringOrchestrator {
                 incident_counts: HashMap::new(),
                 response_times: HashMap::new(),
             })),
+            container_store: Arc::new(RwLock::new(demo_containers)),
+            node_store: Arc::new(RwLock::new(demo_nodes)),
+            container_history: Arc::new(RwLock::new(std::collections::VecDeque::new())),
+            node_aggregates: Arc::new(RwLock::new(node_aggregates)),
+            container_index: Arc::new(RwLock::new(container_index)),
+            telemetry,
+            incident_reporter,
         }
     }
 
@@ -235,7 +688,7 @@ impl TrustMonitoringOrchestrator {
     /// Start the trust monitoring system
     pub async fn start(&self) -> Result<(), String> {
         println!("🔄 Starting Trust Monitoring System...");
-        
+
         // Start continual assurance monitoring
         let assurance_engine = self.continual_assurance_engine.clone();
         tokio::spawn(async move {
@@ -243,46 +696,196 @@ impl TrustMonitoringOrchestrator {
                 eprintln!("Continual assurance monitoring error: {}", e);
             }
         });
-        
+
+        // Start the container trust assessment loop (formerly the
+        // standalone `trust-monitor-demo` binary's own loop, now driving
+        // `container_store`/`node_store` directly).
+        let orchestrator = self.clone();
+        tokio::spawn(async move {
+            orchestrator.container_assessment_loop().await;
+        });
+
         // Start the main monitoring loop
         self.monitoring_loop().await;
-        
+
         Ok(())
     }
 
+    /// Periodically recomputes every container's trust score and status,
+    /// keeps `node_store` trust levels in sync via the incremental
+    /// `node_aggregates` dirty set, records the running history, and reports
+    /// newly-degraded containers to Sentry. Ported from the standalone
+    /// `trust-monitor-demo` binary's `trust_assessment_loop`.
+    async fn container_assessment_loop(&self) {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(2));
+
+        loop {
+            interval.tick().await;
+
+            let span = self.telemetry.as_ref().map(|t| t.start_container_tick_span());
+
+            let mut container_data = self.container_store.write().await;
+            let mut node_data = self.node_store.write().await;
+            let mut aggregates = self.node_aggregates.write().await;
+            let mut index = self.container_index.write().await;
+
+            // Simulate trust assessment for each container, collecting only the
+            // ones whose score or node assignment actually changed into
+            // `retractions`/`additions` instead of re-aggregating every node
+            // from a full scan of `container_data`.
+            let mut retractions: Vec<(String, f64)> = Vec::new();
+            let mut additions: Vec<(String, f64)> = Vec::new();
+            // Containers whose status just became `Compromised`/`Isolated`,
+            // reported to `IncidentReporter` once the tick's writes settle.
+            let mut newly_degraded: Vec<ContainerTrustMetrics> = Vec::new();
+
+            for (container_id, metrics) in container_data.iter_mut() {
+                let previous_trust = metrics.overall_trust;
+                let previous_node = index.get(container_id).cloned();
+                let previous_status = metrics.status.clone();
+
+                // Simulate some containers becoming suspicious
+                if container_id == "container-b" {
+                    metrics.behavioral_score = (metrics.behavioral_score - 0.1).max(0.0);
+                    metrics.communication_score = (metrics.communication_score - 0.05).max(0.0);
+                }
+
+                // Recalculate overall trust
+                metrics.overall_trust = (metrics.integrity_score + metrics.behavioral_score + metrics.communication_score) / 3.0;
+                metrics.timestamp = Utc::now();
+
+                // Update status based on trust level
+                metrics.status = match metrics.overall_trust {
+                    t if t >= 0.8 => ContainerStatus::Trusted,
+                    t if t >= 0.6 => ContainerStatus::Suspicious,
+                    t if t >= 0.3 => ContainerStatus::Compromised,
+                    _ => ContainerStatus::Isolated,
+                };
+
+                if matches!(metrics.status, ContainerStatus::Compromised | ContainerStatus::Isolated)
+                    && !matches!(previous_status, ContainerStatus::Compromised | ContainerStatus::Isolated)
+                {
+                    newly_degraded.push(metrics.clone());
+                }
+
+                let score_changed = (metrics.overall_trust - previous_trust).abs() > f64::EPSILON;
+                let node_changed = previous_node.as_deref() != Some(metrics.node_id.as_str());
+                if !score_changed && !node_changed {
+                    continue;
+                }
+
+                // Retract the old contribution (if there was one) before adding
+                // the new one, so a container moving between nodes never
+                // double-counts against its old node.
+                if let Some(old_node_id) = previous_node {
+                    retractions.push((old_node_id, previous_trust));
+                }
+                additions.push((metrics.node_id.clone(), metrics.overall_trust));
+                index.insert(container_id.clone(), metrics.node_id.clone());
+            }
+
+            // Fold the dirty set's retract/add pairs into each touched node's
+            // running sum/count, then re-derive only those nodes' trust levels.
+            let mut dirty_nodes: std::collections::HashSet<String> = std::collections::HashSet::new();
+            for (node_id, trust) in retractions {
+                let aggregate = aggregates.entry(node_id.clone()).or_default();
+                aggregate.sum -= trust;
+                aggregate.count = aggregate.count.saturating_sub(1);
+                dirty_nodes.insert(node_id);
+            }
+            for (node_id, trust) in additions {
+                let aggregate = aggregates.entry(node_id.clone()).or_default();
+                aggregate.sum += trust;
+                aggregate.count += 1;
+                dirty_nodes.insert(node_id);
+            }
+
+            for node_id in &dirty_nodes {
+                if let Some(node) = node_data.get_mut(node_id) {
+                    node.trust_level = aggregates.get(node_id).copied().unwrap_or_default().average();
+                }
+            }
+
+            if let Some(telemetry) = &self.telemetry {
+                telemetry.record_container_trust_metrics(&container_data);
+            }
+
+            if let Some(reporter) = &self.incident_reporter {
+                if !newly_degraded.is_empty() {
+                    let history = self.container_history.read().await;
+                    for container in &newly_degraded {
+                        let trajectory: Vec<ContainerTrustMetrics> = history
+                            .iter()
+                            .filter(|m| m.container_id == container.container_id)
+                            .rev()
+                            .take(INCIDENT_TRAJECTORY_SAMPLES)
+                            .rev()
+                            .cloned()
+                            .collect();
+                        reporter.report_status_transition(container, &trajectory);
+                    }
+                }
+            }
+
+            {
+                let mut history = self.container_history.write().await;
+                history.extend(container_data.values().cloned());
+                while history.len() > MAX_HISTORY_SAMPLES {
+                    history.pop_front();
+                }
+            }
+
+            println!("📦 Container trust assessment completed for {} containers", container_data.len());
+
+            if let Some(mut span) = span {
+                span.end();
+            }
+        }
+    }
+
     async fn monitoring_loop(&self) {
         let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(30));
-        
+
         loop {
             interval.tick().await;
-            
+
+            let span = self.telemetry.as_ref().map(|t| t.start_tick_span());
+
             // Update trust scores for all components
             if let Err(e) = self.update_trust_scores().await {
                 eprintln!("Error updating trust scores: {}", e);
             }
-            
+
             // Check for incidents and trigger responses
             if let Err(e) = self.check_and_respond_to_incidents().await {
                 eprintln!("Error checking incidents: {}", e);
             }
+
+            if let Some(mut span) = span {
+                span.end();
+            }
         }
     }
 
     async fn update_trust_scores(&self) -> Result<(), String> {
         // Get current trust scores from continual assurance engine
         let trust_scores = self.continual_assurance_engine.get_trust_scores().await;
-        
+
         // Update metrics collector
         let mut metrics = self.metrics_collector.write().await;
         metrics.trust_scores = trust_scores.clone();
-        
+
         // Calculate system-wide trust using composition engine
         let component_ids: Vec<String> = trust_scores.keys().cloned().collect();
         if !component_ids.is_empty() {
             let system_trust = self.composition_engine.calculate_system_trust(&component_ids).await;
             metrics.system_metrics.insert("overall_trust".to_string(), system_trust.overall_trust);
         }
-        
+
+        if let Some(telemetry) = &self.telemetry {
+            telemetry.record_metrics_collector(&metrics);
+        }
+
         Ok(())
     }
 
@@ -303,10 +906,23 @@ impl TrustMonitoringOrchestrator {
             };
             
             // Process trust update and trigger responses
-            if let Ok(actions) = self.incident_response_engine.process_trust_update(&component_id, trust_score, &context).await {
+            let span = self.telemetry.as_ref().map(|t| t.start_trust_update_span(&component_id));
+            let result = self.incident_response_engine.process_trust_update(&component_id, "trust-monitoring-system", trust_score, &context).await;
+            if let Some(mut span) = span {
+                span.end();
+            }
+            if let Ok((actions, verdict, failure_outcome)) = result {
                 if !actions.is_empty() {
-                    println!("🚨 Triggered {} actions for component {} (trust score: {:.2})", 
-                            actions.len(), component_id, trust_score);
+                    println!("🚨 Triggered {} actions for component {} (trust score: {:.2}, verdict: {:?})",
+                            actions.len(), component_id, trust_score, verdict);
+                    if let Some(reporter) = &self.incident_reporter {
+                        let trajectory = self.continual_assurance_engine.get_trust_history(&component_id).await.unwrap_or_default();
+                        reporter.report_triggered_actions(&component_id, trust_score, &actions, &trajectory);
+                    }
+                }
+                if failure_outcome.changed_outcome {
+                    println!("⚠️  Response actions for component {} failed irrecoverably; applied {:?} failure mode",
+                            component_id, failure_outcome.failure_mode);
                 }
             }
         }
@@ -347,39 +963,370 @@ pub struct SystemStatus {
     pub last_updated: DateTime<Utc>,
 }
 
+/// Comma-separated id filter shared by every `/api/v1` list endpoint, so a
+/// caller can fetch one id (`?id=container-a`) or many
+/// (`?id=container-a,container-b`) without a different endpoint shape; an
+/// absent `id` returns everything.
+#[derive(Debug, Deserialize)]
+struct IdFilter {
+    id: Option<String>,
+}
+
+impl IdFilter {
+    fn ids(&self) -> Option<Vec<String>> {
+        self.id.as_deref().map(|raw| {
+            raw.split(',').map(str::trim).filter(|id| !id.is_empty()).map(String::from).collect()
+        })
+    }
+}
+
 /// HTTP API handlers
 async fn get_system_status(orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
     let status = orchestrator.get_system_status().await;
     Ok(warp::reply::json(&status))
 }
 
-async fn get_trust_scores(orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
+async fn get_trust_scores(filter: IdFilter, orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
     let scores = orchestrator.continual_assurance_engine.get_trust_scores().await;
+    let scores = match filter.ids() {
+        Some(ids) => scores.into_iter().filter(|(component_id, _)| ids.contains(component_id)).collect(),
+        None => scores,
+    };
     Ok(warp::reply::json(&scores))
 }
 
-async fn get_active_incidents(orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
+async fn get_active_incidents(filter: IdFilter, orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
     let incidents = orchestrator.incident_response_engine.get_active_incidents().await;
+    let incidents: Vec<_> = match filter.ids() {
+        Some(ids) => incidents.into_iter().filter(|incident| incident.affected_components.iter().any(|c| ids.contains(c))).collect(),
+        None => incidents,
+    };
     Ok(warp::reply::json(&incidents))
 }
 
-async fn get_active_alerts(orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
+async fn get_active_alerts(filter: IdFilter, orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
     let alerts = orchestrator.continual_assurance_engine.get_active_alerts().await;
+    let alerts: Vec<_> = match filter.ids() {
+        Some(ids) => alerts.into_iter().filter(|alert| ids.contains(&alert.component_id)).collect(),
+        None => alerts,
+    };
     Ok(warp::reply::json(&alerts))
 }
 
+async fn get_containers(filter: IdFilter, orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
+    let data = orchestrator.container_store.read().await;
+    let containers: Vec<&ContainerTrustMetrics> = match filter.ids() {
+        Some(ids) => data.values().filter(|c| ids.contains(&c.container_id)).collect(),
+        None => data.values().collect(),
+    };
+    Ok(warp::reply::json(&containers))
+}
+
+async fn get_nodes(filter: IdFilter, orchestrator: Arc<TrustMonitoringOrchestrator>) -> Result<impl warp::Reply, Infallible> {
+    let data = orchestrator.node_store.read().await;
+    let nodes: Vec<&NodeInfo> = match filter.ids() {
+        Some(ids) => data.values().filter(|n| ids.contains(&n.node_id)).collect(),
+        None => data.values().collect(),
+    };
+    Ok(warp::reply::json(&nodes))
+}
+
+async fn get_dashboard(dashboard_path: Option<String>) -> Result<impl warp::Reply, warp::Rejection> {
+    match dashboard_path {
+        Some(path) => match tokio::fs::read_to_string(&path).await {
+            Ok(html) => Ok(warp::reply::html(html)),
+            Err(_) => Err(warp::reject::not_found()),
+        },
+        None => Err(warp::reject::not_found()),
+    }
+}
+
+/// Columns match `ContainerTrustMetrics` field-for-field, so a downstream
+/// dataframe tool sees the same shape it would get from `/api/v1/containers`,
+/// just batched.
+fn container_trust_metrics_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("container_id", DataType::Utf8, false),
+        Field::new("node_id", DataType::Utf8, false),
+        Field::new("timestamp", DataType::Timestamp(TimeUnit::Millisecond, None), false),
+        Field::new("integrity_score", DataType::Float64, false),
+        Field::new("behavioral_score", DataType::Float64, false),
+        Field::new("communication_score", DataType::Float64, false),
+        Field::new("overall_trust", DataType::Float64, false),
+        Field::new("status", DataType::Utf8, false),
+    ]))
+}
+
+fn container_trust_metrics_to_record_batch(samples: &[ContainerTrustMetrics]) -> Result<RecordBatch, arrow::error::ArrowError> {
+    let container_ids: StringArray = samples.iter().map(|m| Some(m.container_id.as_str())).collect();
+    let node_ids: StringArray = samples.iter().map(|m| Some(m.node_id.as_str())).collect();
+    let timestamps: TimestampMillisecondArray = samples.iter().map(|m| Some(m.timestamp.timestamp_millis())).collect();
+    let integrity_scores: Float64Array = samples.iter().map(|m| Some(m.integrity_score)).collect();
+    let behavioral_scores: Float64Array = samples.iter().map(|m| Some(m.behavioral_score)).collect();
+    let communication_scores: Float64Array = samples.iter().map(|m| Some(m.communication_score)).collect();
+    let overall_trusts: Float64Array = samples.iter().map(|m| Some(m.overall_trust)).collect();
+    let statuses: StringArray = samples.iter().map(|m| Some(format!("{:?}", m.status))).collect();
+
+    RecordBatch::try_new(
+        container_trust_metrics_schema(),
+        vec![
+            Arc::new(container_ids),
+            Arc::new(node_ids),
+            Arc::new(timestamps),
+            Arc::new(integrity_scores),
+            Arc::new(behavioral_scores),
+            Arc::new(communication_scores),
+            Arc::new(overall_trusts),
+            Arc::new(statuses),
+        ],
+    )
+}
+
+/// Predicate a Flight client sends as the raw bytes of a `Ticket`,
+/// JSON-encoded, to pull a slice of history instead of everything
+/// `ContainerHistoryStore` holds. An empty ticket matches every sample.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct TrustHistoryTicket {
+    node_id: Option<String>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+}
+
+impl TrustHistoryTicket {
+    fn matches(&self, metrics: &ContainerTrustMetrics) -> bool {
+        self.node_id.as_deref().map_or(true, |n| metrics.node_id == n)
+            && self.since.map_or(true, |since| metrics.timestamp >= since)
+            && self.until.map_or(true, |until| metrics.timestamp <= until)
+    }
+}
+
+/// Arrow Flight endpoint over `ContainerHistoryStore`: `do_get` streams the
+/// history, `get_schema` describes its columns and `list_actions` returns an
+/// empty list; every other RPC responds `unimplemented`, since this service
+/// exists purely to stream container trust history, not to accept uploads or
+/// advertise a catalog of flights.
+pub struct TrustHistoryFlightService {
+    history: ContainerHistoryStore,
+}
+
+impl TrustHistoryFlightService {
+    pub fn new(history: ContainerHistoryStore) -> Self {
+        Self { history }
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for TrustHistoryFlightService {
+    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, tonic::Status>>;
+    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, tonic::Status>>;
+    type DoGetStream = BoxStream<'static, Result<FlightData, tonic::Status>>;
+    type DoPutStream = BoxStream<'static, Result<PutResult, tonic::Status>>;
+    type DoActionStream = BoxStream<'static, Result<arrow_flight::Result, tonic::Status>>;
+    type ListActionsStream = BoxStream<'static, Result<ActionType, tonic::Status>>;
+    type DoExchangeStream = BoxStream<'static, Result<FlightData, tonic::Status>>;
+
+    async fn handshake(
+        &self,
+        _request: tonic::Request<tonic::Streaming<HandshakeRequest>>,
+    ) -> Result<tonic::Response<Self::HandshakeStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: tonic::Request<Criteria>,
+    ) -> Result<tonic::Response<Self::ListFlightsStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("this service only exposes the trust-history ticket, not a flight catalog"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: tonic::Request<FlightDescriptor>,
+    ) -> Result<tonic::Response<FlightInfo>, tonic::Status> {
+        Err(tonic::Status::unimplemented("get_flight_info is not supported; call do_get directly with a TrustHistoryTicket"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: tonic::Request<FlightDescriptor>,
+    ) -> Result<tonic::Response<SchemaResult>, tonic::Status> {
+        SchemaResult::try_from(container_trust_metrics_schema().as_ref())
+            .map(tonic::Response::new)
+            .map_err(|e| tonic::Status::internal(e.to_string()))
+    }
+
+    /// Filters `ContainerHistoryStore` by the `TrustHistoryTicket` JSON
+    /// predicate in `request.ticket` (or everything, if the ticket is empty)
+    /// and streams the matches back as a single record batch.
+    async fn do_get(&self, request: tonic::Request<Ticket>) -> Result<tonic::Response<Self::DoGetStream>, tonic::Status> {
+        let ticket_bytes = request.into_inner().ticket;
+        let predicate: TrustHistoryTicket = if ticket_bytes.is_empty() {
+            TrustHistoryTicket::default()
+        } else {
+            serde_json::from_slice(&ticket_bytes)
+                .map_err(|e| tonic::Status::invalid_argument(format!("invalid trust history ticket: {}", e)))?
+        };
+
+        let matching: Vec<ContainerTrustMetrics> = {
+            let history = self.history.read().await;
+            history.iter().filter(|m| predicate.matches(m)).cloned().collect()
+        };
+
+        let batch = container_trust_metrics_to_record_batch(&matching).map_err(|e| tonic::Status::internal(e.to_string()))?;
+        let stream = FlightDataEncoderBuilder::new()
+            .build(futures::stream::iter(vec![Ok(batch)]))
+            .map_err(|e| tonic::Status::internal(e.to_string()));
+
+        Ok(tonic::Response::new(Box::pin(stream)))
+    }
+
+    async fn do_put(
+        &self,
+        _request: tonic::Request<tonic::Streaming<FlightData>>,
+    ) -> Result<tonic::Response<Self::DoPutStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("do_put is not supported; this service only streams history out"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: tonic::Request<Action>,
+    ) -> Result<tonic::Response<Self::DoActionStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("do_action is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: tonic::Request<Empty>,
+    ) -> Result<tonic::Response<Self::ListActionsStream>, tonic::Status> {
+        Ok(tonic::Response::new(Box::pin(futures::stream::empty())))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: tonic::Request<tonic::Streaming<FlightData>>,
+    ) -> Result<tonic::Response<Self::DoExchangeStream>, tonic::Status> {
+        Err(tonic::Status::unimplemented("do_exchange is not supported"))
+    }
+}
+
+/// Builds the unified `/api/v1` surface, mounting only the route groups
+/// listed in `SystemConfig.enabled_route_groups` plus the dashboard (if
+/// `SystemConfig.dashboard_path` is set), so the orchestrator, its engines,
+/// and the container/node state it owns are all served by one process.
+fn create_routes(
+    orchestrator: Arc<TrustMonitoringOrchestrator>,
+    enabled_route_groups: &[String],
+    dashboard_path: Option<String>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let enabled = |name: &str| enabled_route_groups.iter().any(|g| g == name);
+    let v1 = warp::path("api").and(warp::path("v1"));
+
+    let status_route = v1
+        .and(warp::path("status"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(with_orchestrator(orchestrator.clone()))
+        .and_then(get_system_status)
+        .map(|r| Box::new(r) as Box<dyn warp::Reply>)
+        .boxed();
+
+    let trust_scores_route = v1
+        .and(warp::path("trust-scores"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<IdFilter>())
+        .and(with_orchestrator(orchestrator.clone()))
+        .and_then(get_trust_scores)
+        .map(|r| Box::new(r) as Box<dyn warp::Reply>)
+        .boxed();
+
+    let incidents_route = v1
+        .and(warp::path("incidents"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<IdFilter>())
+        .and(with_orchestrator(orchestrator.clone()))
+        .and_then(get_active_incidents)
+        .map(|r| Box::new(r) as Box<dyn warp::Reply>)
+        .boxed();
+
+    let alerts_route = v1
+        .and(warp::path("alerts"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<IdFilter>())
+        .and(with_orchestrator(orchestrator.clone()))
+        .and_then(get_active_alerts)
+        .map(|r| Box::new(r) as Box<dyn warp::Reply>)
+        .boxed();
+
+    let containers_route = v1
+        .and(warp::path("containers"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<IdFilter>())
+        .and(with_orchestrator(orchestrator.clone()))
+        .and_then(get_containers)
+        .map(|r| Box::new(r) as Box<dyn warp::Reply>)
+        .boxed();
+
+    let nodes_route = v1
+        .and(warp::path("nodes"))
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::query::<IdFilter>())
+        .and(with_orchestrator(orchestrator.clone()))
+        .and_then(get_nodes)
+        .map(|r| Box::new(r) as Box<dyn warp::Reply>)
+        .boxed();
+
+    let dashboard_route = warp::path::end()
+        .and(warp::get())
+        .and(warp::any().map(move || dashboard_path.clone()))
+        .and_then(get_dashboard)
+        .map(|r| Box::new(r) as Box<dyn warp::Reply>)
+        .boxed();
+
+    let mut groups = Vec::new();
+    if enabled("status") {
+        groups.push(status_route);
+    }
+    if enabled("trust-scores") {
+        groups.push(trust_scores_route);
+    }
+    if enabled("incidents") {
+        groups.push(incidents_route);
+    }
+    if enabled("alerts") {
+        groups.push(alerts_route);
+    }
+    if enabled("containers") {
+        groups.push(containers_route);
+    }
+    if enabled("nodes") {
+        groups.push(nodes_route);
+    }
+    groups.push(dashboard_route);
+
+    groups
+        .into_iter()
+        .reduce(|combined, route| combined.or(route).unify().boxed())
+        .expect("dashboard_route is always pushed")
+        .with(warp::cors().allow_any_origin().allow_headers(vec!["content-type"]).allow_methods(vec!["GET", "POST"]))
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🛡️  Trust Monitoring System for Large Distributed Systems");
     println!("🎯 SCULI-Aligned Trust Assessment Framework");
     println!();
-    
+
     // Create the orchestrator
     let orchestrator = Arc::new(TrustMonitoringOrchestrator::new());
-    
+
     // Initialize the system
     orchestrator.initialize().await?;
-    
+
     // Start the monitoring system
     let orchestrator_clone = orchestrator.clone();
     tokio::spawn(async move {
@@ -387,47 +1334,53 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             eprintln!("Error starting trust monitoring system: {}", e);
         }
     });
-    
-    // Set up HTTP API
-    let status_route = warp::path("status")
-        .and(warp::get())
-        .and(with_orchestrator(orchestrator.clone()))
-        .and_then(get_system_status);
-    
-    let trust_scores_route = warp::path("trust-scores")
-        .and(warp::get())
-        .and(with_orchestrator(orchestrator.clone()))
-        .and_then(get_trust_scores);
-    
-    let incidents_route = warp::path("incidents")
-        .and(warp::get())
-        .and(with_orchestrator(orchestrator.clone()))
-        .and_then(get_active_incidents);
-    
-    let alerts_route = warp::path("alerts")
-        .and(warp::get())
-        .and(with_orchestrator(orchestrator.clone()))
-        .and_then(get_active_alerts);
-    
-    let api = status_route
-        .or(trust_scores_route)
-        .or(incidents_route)
-        .or(alerts_route)
-        .with(warp::cors().allow_any_origin().allow_headers(vec!["content-type"]).allow_methods(vec!["GET", "POST"]));
-    
-    println!("🌐 Starting HTTP API server on http://localhost:3030");
-    println!("📊 Available endpoints:");
-    println!("   GET /status - System status");
-    println!("   GET /trust-scores - Current trust scores");
-    println!("   GET /incidents - Active incidents");
-    println!("   GET /alerts - Active alerts");
+
+    // Serve historical container trust metrics over Arrow Flight, so
+    // dataframe tools can pull a node's or a time window's history in one
+    // columnar batch instead of N JSON round-trips against
+    // `/api/v1/containers`.
+    let flight_bind_addr = std::env::var("TRUST_FLIGHT_BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:8815".to_string());
+    match flight_bind_addr.parse() {
+        Ok(addr) => {
+            let flight_service = TrustHistoryFlightService::new(orchestrator.container_history.clone());
+            tokio::spawn(async move {
+                if let Err(e) = tonic::transport::Server::builder()
+                    .add_service(FlightServiceServer::new(flight_service))
+                    .serve(addr)
+                    .await
+                {
+                    eprintln!("Arrow Flight server error: {}", e);
+                }
+            });
+            println!("📦 Arrow Flight trust history endpoint listening on {}", flight_bind_addr);
+        }
+        Err(e) => eprintln!("⚠️  Arrow Flight disabled, invalid TRUST_FLIGHT_BIND_ADDR: {}", e),
+    }
+
+    let (bind_addr, dashboard_path, enabled_route_groups) = {
+        let config = orchestrator.system_config.read().await;
+        (config.bind_addr.clone(), config.dashboard_path.clone(), config.enabled_route_groups.clone())
+    };
+
+    let api = create_routes(orchestrator.clone(), &enabled_route_groups, dashboard_path);
+
+    let addr: std::net::SocketAddr = bind_addr.parse().map_err(|e| format!("invalid bind_addr {}: {}", bind_addr, e))?;
+
+    println!("🌐 Starting unified HTTP API server on http://{}", addr);
+    println!("📊 Available endpoints (under /api/v1, ?id=a,b for single-or-list filtering):");
+    println!("   GET /api/v1/status - System status");
+    println!("   GET /api/v1/trust-scores - Current trust scores");
+    println!("   GET /api/v1/incidents - Active incidents");
+    println!("   GET /api/v1/alerts - Active alerts");
+    println!("   GET /api/v1/containers - Container trust metrics");
+    println!("   GET /api/v1/nodes - Node info");
     println!();
-    
+
     // Start the HTTP server
     warp::serve(api)
-        .run(([0, 0, 0, 0], 3030))
+        .run(addr)
         .await;
-    
+
     Ok(())
 }
 
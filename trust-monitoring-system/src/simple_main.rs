@@ -1,19 +1,240 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{Notify, RwLock};
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use warp::Filter;
 use std::convert::Infallible;
+use rand::seq::SliceRandom;
+use casbin::{CoreApi, Enforcer};
+use prometheus::Encoder;
 
 /// Simplified Trust Monitoring System for demonstration
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TrustMonitoringSystem {
     pub system_id: String,
     pub components: Arc<RwLock<HashMap<String, Component>>>,
     pub trust_scores: Arc<RwLock<HashMap<String, f64>>>,
     pub incidents: Arc<RwLock<Vec<Incident>>>,
     pub alerts: Arc<RwLock<Vec<Alert>>>,
+    /// Last time a gossip message was received from each peer address,
+    /// reported by `GET /peers`.
+    pub peer_last_seen: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+    /// Monotonically increasing counter bumped on every mutation to
+    /// components/incidents/alerts, a la Consul's `ModifyIndex`. Lets
+    /// `wait_for_change` implement blocking queries instead of consumers
+    /// having to poll on a timer.
+    modify_index: Arc<RwLock<u64>>,
+    /// Woken whenever `modify_index` is bumped, so `wait_for_change` can
+    /// park a GET request until something actually changes.
+    change_notify: Arc<Notify>,
+    /// Append-only sequence of past trust scores per component, exposed by
+    /// `GET /history/{component_id}`. Kept in memory regardless of whether
+    /// a `store` is configured; `store` just makes it durable.
+    history: Arc<RwLock<HashMap<String, Vec<TrustHistoryEntry>>>>,
+    /// Embedded KV store backing `Component`/`Incident`/`Alert`/history
+    /// persistence, or `None` to run purely in-memory (the historical
+    /// default). Write-throughs happen inside the same critical section as
+    /// the in-memory mutation so a crash never loses a committed change.
+    store: Option<Arc<dyn Store>>,
+    /// Prometheus metric handles backing `GET /metrics`.
+    metrics: Arc<PrometheusMetrics>,
+    /// When each component most recently entered `critical` status, so a
+    /// later transition to `healthy` can observe the elapsed time into
+    /// `metrics.degradation_recovery_seconds`. Cleared on recovery.
+    degraded_since: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+}
+
+impl std::fmt::Debug for TrustMonitoringSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TrustMonitoringSystem")
+            .field("system_id", &self.system_id)
+            .field("has_store", &self.store.is_some())
+            .finish()
+    }
+}
+
+/// Prometheus metric handles for `GET /metrics`: a `trust_score` gauge per
+/// component (labelled by id and type), an `overall_trust` gauge,
+/// `incidents_total`/`alerts_total` counters, and a histogram of how long
+/// each component spent `critical` before recovering to `healthy`.
+pub struct PrometheusMetrics {
+    registry: prometheus::Registry,
+    trust_score_gauge: prometheus::GaugeVec,
+    overall_trust: prometheus::Gauge,
+    incidents_total: prometheus::IntCounter,
+    alerts_total: prometheus::CounterVec,
+    degradation_recovery_seconds: prometheus::Histogram,
+}
+
+impl std::fmt::Debug for PrometheusMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrometheusMetrics").finish_non_exhaustive()
+    }
+}
+
+impl PrometheusMetrics {
+    pub fn new() -> Self {
+        let registry = prometheus::Registry::new();
+
+        let trust_score_gauge = prometheus::GaugeVec::new(
+            prometheus::Opts::new("trust_score", "Current trust score per component"),
+            &["component_id", "component_type"],
+        )
+        .expect("valid metric definition");
+        let overall_trust = prometheus::Gauge::new("overall_trust", "Overall system trust score")
+            .expect("valid metric definition");
+        let incidents_total = prometheus::IntCounter::new("incidents_total", "Total incidents raised")
+            .expect("valid metric definition");
+        let alerts_total = prometheus::CounterVec::new(
+            prometheus::Opts::new("alerts_total", "Total alerts raised, by severity"),
+            &["severity"],
+        )
+        .expect("valid metric definition");
+        let degradation_recovery_seconds = prometheus::Histogram::with_opts(
+            prometheus::HistogramOpts::new(
+                "degradation_recovery_seconds",
+                "Time between a component entering critical status and recovering to healthy",
+            )
+            .buckets(vec![1.0, 5.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0, 3600.0]),
+        )
+        .expect("valid metric definition");
+
+        registry.register(Box::new(trust_score_gauge.clone())).expect("metric name collision");
+        registry.register(Box::new(overall_trust.clone())).expect("metric name collision");
+        registry.register(Box::new(incidents_total.clone())).expect("metric name collision");
+        registry.register(Box::new(alerts_total.clone())).expect("metric name collision");
+        registry.register(Box::new(degradation_recovery_seconds.clone())).expect("metric name collision");
+
+        Self {
+            registry,
+            trust_score_gauge,
+            overall_trust,
+            incidents_total,
+            alerts_total,
+            degradation_recovery_seconds,
+        }
+    }
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One historical trust-score reading for a component, in the order it was
+/// recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustHistoryEntry {
+    pub score: f64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Pluggable durable-persistence backend for `TrustMonitoringSystem`, a la
+/// fapolicy-analyzer's lmdb-rkv-backed trust database. Implementations
+/// must be safe to call from within an async critical section (sled's API
+/// is synchronous, so `SledStore` just calls straight through).
+pub trait Store: Send + Sync {
+    fn put_component(&self, component: &Component) -> Result<(), String>;
+    fn remove_component(&self, component_id: &str) -> Result<(), String>;
+    fn load_components(&self) -> Result<HashMap<String, Component>, String>;
+
+    fn put_incidents(&self, incidents: &[Incident]) -> Result<(), String>;
+    fn load_incidents(&self) -> Result<Vec<Incident>, String>;
+
+    fn put_alerts(&self, alerts: &[Alert]) -> Result<(), String>;
+    fn load_alerts(&self) -> Result<Vec<Alert>, String>;
+
+    fn append_history(&self, component_id: &str, entry: &TrustHistoryEntry) -> Result<(), String>;
+    fn load_history(&self) -> Result<HashMap<String, Vec<TrustHistoryEntry>>, String>;
+}
+
+/// Embedded `sled`-backed `Store`, mirroring the approach
+/// `sculi-trust-demo` takes for its own persistent trust database.
+pub struct SledStore {
+    db: sled::Db,
+}
+
+impl SledStore {
+    /// Opens (or creates) a sled database under `path`.
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self, String> {
+        let db = sled::open(path).map_err(|e| e.to_string())?;
+        Ok(Self { db })
+    }
+}
+
+/// Serializes `value` as JSON and writes it under `key`.
+fn sled_put<T: Serialize>(db: &sled::Db, key: &str, value: &T) -> Result<(), String> {
+    let bytes = serde_json::to_vec(value).map_err(|e| e.to_string())?;
+    db.insert(key.as_bytes(), bytes).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+impl Store for SledStore {
+    fn put_component(&self, component: &Component) -> Result<(), String> {
+        sled_put(&self.db, &format!("component:{}", component.id), component)
+    }
+
+    fn remove_component(&self, component_id: &str) -> Result<(), String> {
+        self.db.remove(format!("component:{}", component_id).as_bytes()).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    fn load_components(&self) -> Result<HashMap<String, Component>, String> {
+        let mut components = HashMap::new();
+        for entry in self.db.scan_prefix(b"component:") {
+            let (_, value) = entry.map_err(|e| e.to_string())?;
+            let component: Component = serde_json::from_slice(&value).map_err(|e| e.to_string())?;
+            components.insert(component.id.clone(), component);
+        }
+        Ok(components)
+    }
+
+    fn put_incidents(&self, incidents: &[Incident]) -> Result<(), String> {
+        sled_put(&self.db, "incidents", &incidents)
+    }
+
+    fn load_incidents(&self) -> Result<Vec<Incident>, String> {
+        match self.db.get(b"incidents").map_err(|e| e.to_string())? {
+            Some(bytes) => serde_json::from_slice(&bytes).map_err(|e| e.to_string()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn put_alerts(&self, alerts: &[Alert]) -> Result<(), String> {
+        sled_put(&self.db, "alerts", &alerts)
+    }
+
+    fn load_alerts(&self) -> Result<Vec<Alert>, String> {
+        match self.db.get(b"alerts").map_err(|e| e.to_string())? {
+            Some(bytes) => serde_json::from_slice(&bytes).map_err(|e| e.to_string()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn append_history(&self, component_id: &str, entry: &TrustHistoryEntry) -> Result<(), String> {
+        let key = format!("history:{}", component_id);
+        let mut history = match self.db.get(key.as_bytes()).map_err(|e| e.to_string())? {
+            Some(bytes) => serde_json::from_slice::<Vec<TrustHistoryEntry>>(&bytes).map_err(|e| e.to_string())?,
+            None => Vec::new(),
+        };
+        history.push(entry.clone());
+        sled_put(&self.db, &key, &history)
+    }
+
+    fn load_history(&self) -> Result<HashMap<String, Vec<TrustHistoryEntry>>, String> {
+        let mut history = HashMap::new();
+        for entry in self.db.scan_prefix(b"history:") {
+            let (key, value) = entry.map_err(|e| e.to_string())?;
+            let key_bytes: &[u8] = &key;
+            let component_id = String::from_utf8_lossy(&key_bytes["history:".len()..]).to_string();
+            let entries: Vec<TrustHistoryEntry> = serde_json::from_slice(&value).map_err(|e| e.to_string())?;
+            history.insert(component_id, entries);
+        }
+        Ok(history)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +245,26 @@ pub struct Component {
     pub trust_score: f64,
     pub status: String,
     pub last_updated: DateTime<Utc>,
+    /// Other components this one relies on, used to compute its *effective*
+    /// trust score (see `effective_trust_scores`) instead of just averaging
+    /// raw scores flatly. Defaults to empty so components restored from
+    /// before this field existed deserialize as having no dependencies.
+    #[serde(default)]
+    pub depends_on: Vec<Dependency>,
+}
+
+/// One edge in the dependency graph: `component_id` is depended on with
+/// `weight` (defaulting to 1.0), used by `aggregate_dependency_score` as the
+/// weight in the geometric mean over a component's dependencies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dependency {
+    pub component_id: String,
+    #[serde(default = "default_dependency_weight")]
+    pub weight: f64,
+}
+
+fn default_dependency_weight() -> f64 {
+    1.0
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +287,27 @@ pub struct Alert {
     pub timestamp: DateTime<Utc>,
 }
 
+/// One component's trust state advertised in a `GossipMessage`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipEntry {
+    pub component_id: String,
+    pub trust_score: f64,
+    pub last_updated: DateTime<Utc>,
+}
+
+/// A node's periodic digest of the components it knows about, sent over
+/// UDP to a random subset of peers so the cluster converges on a shared
+/// view of trust without a central database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipMessage {
+    pub node_id: String,
+    pub entries: Vec<GossipEntry>,
+}
+
+/// Caps entries per message so a chunk always fits one UDP datagram even
+/// for a system with many monitored components.
+const GOSSIP_MAX_ENTRIES_PER_DATAGRAM: usize = 50;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemStatus {
     pub overall_trust: f64,
@@ -56,6 +318,237 @@ pub struct SystemStatus {
     pub last_updated: DateTime<Utc>,
 }
 
+/// Map a trust score to the same status thresholds `update_trust_score`
+/// and `Changeset` application use.
+fn status_for_score(score: f64) -> String {
+    if score > 0.8 {
+        "healthy".to_string()
+    } else if score > 0.5 {
+        "warning".to_string()
+    } else {
+        "critical".to_string()
+    }
+}
+
+/// Derive the alert (if any) a new trust score should raise, using the
+/// same thresholds `update_trust_score` always has -- shared so
+/// `merge_gossip_entry` re-derives alerts identically for scores that
+/// arrive via gossip instead of a local update.
+fn alert_for_score(component_id: &str, new_score: f64) -> Option<Alert> {
+    let (alert_type, message, severity) = if new_score < 0.3 {
+        ("trust_score_critical", format!("Critical trust score: {:.2}", new_score), "critical")
+    } else if new_score < 0.5 {
+        ("trust_score_warning", format!("Warning trust score: {:.2}", new_score), "warning")
+    } else {
+        return None;
+    };
+
+    Some(Alert {
+        id: format!("alert-{}-{}", component_id, Utc::now().timestamp()),
+        component_id: component_id.to_string(),
+        alert_type: alert_type.to_string(),
+        message,
+        severity: severity.to_string(),
+        timestamp: Utc::now(),
+    })
+}
+
+/// Fixed-point passes to run when the dependency graph has a cycle and a
+/// topological order doesn't exist, capping how long `propagate_fixed_point`
+/// iterates trying to converge.
+const MAX_PROPAGATION_PASSES: usize = 10;
+
+/// Orders components so every dependency comes before the components that
+/// depend on it (a reverse-topological order w.r.t. `depends_on`), using
+/// Kahn's algorithm. Returns `None` if the dependency graph has a cycle.
+fn topological_order(components: &HashMap<String, Component>) -> Option<Vec<String>> {
+    let mut unresolved_deps: HashMap<&str, usize> = components.keys().map(|id| (id.as_str(), 0)).collect();
+    let mut dependents_of: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for component in components.values() {
+        for dependency in &component.depends_on {
+            if let Some(count) = unresolved_deps.get_mut(component.id.as_str()) {
+                if components.contains_key(&dependency.component_id) {
+                    *count += 1;
+                    dependents_of.entry(dependency.component_id.as_str()).or_default().push(component.id.as_str());
+                }
+            }
+        }
+    }
+
+    let mut queue: VecDeque<&str> = unresolved_deps.iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&id, _)| id)
+        .collect();
+
+    let mut order = Vec::with_capacity(components.len());
+    while let Some(id) = queue.pop_front() {
+        order.push(id.to_string());
+        for dependent in dependents_of.get(id).into_iter().flatten() {
+            let count = unresolved_deps.get_mut(dependent).expect("dependent listed in components");
+            *count -= 1;
+            if *count == 0 {
+                queue.push_back(dependent);
+            }
+        }
+    }
+
+    if order.len() == components.len() {
+        Some(order)
+    } else {
+        None
+    }
+}
+
+/// Weighted geometric mean of `component`'s dependencies' effective scores
+/// (looked up in `effective`, the scores resolved so far), clamped to at
+/// most 1.0 since a dependency factor should only ever discount a
+/// component's own score, never amplify it. A component with no resolvable
+/// dependencies aggregates to 1.0, i.e. contributes no discount at all.
+fn aggregate_dependency_score(component: &Component, effective: &HashMap<String, f64>) -> f64 {
+    let mut weighted_log_sum = 0.0;
+    let mut weight_total = 0.0;
+
+    for dependency in &component.depends_on {
+        if let Some(&score) = effective.get(&dependency.component_id) {
+            weighted_log_sum += dependency.weight * score.max(f64::EPSILON).ln();
+            weight_total += dependency.weight;
+        }
+    }
+
+    if weight_total <= 0.0 {
+        1.0
+    } else {
+        (weighted_log_sum / weight_total).exp().min(1.0)
+    }
+}
+
+/// Resolves effective scores in `order` (each dependency already resolved
+/// before the components depending on it), per
+/// `effective(c) = own_score(c) * aggregate(effective(d) for d in deps)`.
+fn propagate_in_order(components: &HashMap<String, Component>, order: &[String]) -> HashMap<String, f64> {
+    let mut effective = HashMap::with_capacity(order.len());
+    for id in order {
+        let component = &components[id];
+        let dependency_factor = aggregate_dependency_score(component, &effective);
+        effective.insert(id.clone(), component.trust_score * dependency_factor);
+    }
+    effective
+}
+
+/// Fallback for a cyclic dependency graph: iterates the same propagation
+/// rule from every component's own score, capped at `MAX_PROPAGATION_PASSES`
+/// passes, converging on a fixed point for acyclic subgraphs and settling
+/// into a stable (if not perfectly "correct") approximation around a cycle.
+fn propagate_fixed_point(components: &HashMap<String, Component>) -> HashMap<String, f64> {
+    let mut effective: HashMap<String, f64> = components.values()
+        .map(|component| (component.id.clone(), component.trust_score))
+        .collect();
+
+    for _ in 0..MAX_PROPAGATION_PASSES {
+        let mut next = HashMap::with_capacity(effective.len());
+        for component in components.values() {
+            let dependency_factor = aggregate_dependency_score(component, &effective);
+            next.insert(component.id.clone(), component.trust_score * dependency_factor);
+        }
+        effective = next;
+    }
+
+    effective
+}
+
+/// A single pending mutation staged onto a `Changeset`.
+#[derive(Debug, Clone)]
+enum ChangesetOp {
+    AddComponent(Component),
+    SetTrust { component_id: String, new_score: f64 },
+    RemoveComponent { component_id: String },
+    RaiseIncident(Incident),
+}
+
+/// Accumulates pending component/trust/incident mutations without touching
+/// `TrustMonitoringSystem`, so a multi-component operation like
+/// `simulate_trust_degradation` can be staged, previewed with `diff`, and
+/// applied in one atomic step via `TrustMonitoringSystem::apply` instead of
+/// mutating the live maps one component at a time.
+#[derive(Debug, Clone, Default)]
+pub struct Changeset {
+    ops: Vec<ChangesetOp>,
+}
+
+impl Changeset {
+    pub fn new() -> Self {
+        Self { ops: Vec::new() }
+    }
+
+    pub fn add_component(mut self, component: Component) -> Self {
+        self.ops.push(ChangesetOp::AddComponent(component));
+        self
+    }
+
+    pub fn set_trust(mut self, component_id: impl Into<String>, new_score: f64) -> Self {
+        self.ops.push(ChangesetOp::SetTrust { component_id: component_id.into(), new_score });
+        self
+    }
+
+    pub fn remove_component(mut self, component_id: impl Into<String>) -> Self {
+        self.ops.push(ChangesetOp::RemoveComponent { component_id: component_id.into() });
+        self
+    }
+
+    pub fn raise_incident(mut self, incident: Incident) -> Self {
+        self.ops.push(ChangesetOp::RaiseIncident(incident));
+        self
+    }
+
+    /// Preview the ids of every component whose trust score/status would
+    /// change if this changeset were applied, without mutating `system`.
+    pub async fn diff(&self, system: &TrustMonitoringSystem) -> HashSet<String> {
+        let components = system.components.read().await;
+        let mut changed = HashSet::new();
+
+        for op in &self.ops {
+            match op {
+                ChangesetOp::AddComponent(component) => {
+                    changed.insert(component.id.clone());
+                }
+                ChangesetOp::SetTrust { component_id, new_score } => {
+                    let differs = components.get(component_id)
+                        .map(|component| (component.trust_score - new_score).abs() > f64::EPSILON)
+                        .unwrap_or(true);
+                    if differs {
+                        changed.insert(component_id.clone());
+                    }
+                }
+                ChangesetOp::RemoveComponent { component_id } => {
+                    if components.contains_key(component_id) {
+                        changed.insert(component_id.clone());
+                    }
+                }
+                ChangesetOp::RaiseIncident(_) => {}
+            }
+        }
+
+        changed
+    }
+}
+
+/// What it takes to undo a single mutation that `apply` already committed.
+#[derive(Debug, Clone)]
+enum UndoOp {
+    RemoveComponent(String),
+    RestoreComponent(Component),
+    SetTrust { component_id: String, previous_score: f64 },
+    RemoveIncident(String),
+}
+
+/// A reversible token returned by `TrustMonitoringSystem::apply`. Hand it
+/// to `TrustMonitoringSystem::revert` to restore the prior component/trust
+/// state it replaced.
+pub struct AppliedChangeset {
+    undo_ops: Vec<UndoOp>,
+}
+
 impl TrustMonitoringSystem {
     pub fn new() -> Self {
         Self {
@@ -64,27 +557,98 @@ impl TrustMonitoringSystem {
             trust_scores: Arc::new(RwLock::new(HashMap::new())),
             incidents: Arc::new(RwLock::new(Vec::new())),
             alerts: Arc::new(RwLock::new(Vec::new())),
+            peer_last_seen: Arc::new(RwLock::new(HashMap::new())),
+            modify_index: Arc::new(RwLock::new(0)),
+            change_notify: Arc::new(Notify::new()),
+            history: Arc::new(RwLock::new(HashMap::new())),
+            store: None,
+            metrics: Arc::new(PrometheusMetrics::new()),
+            degraded_since: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
-    /// Initialize the system with sample components
+    /// Opens `store` and hydrates the in-memory maps from whatever it
+    /// already holds, so a restart doesn't lose components, incidents,
+    /// alerts, or trust history committed before the crash.
+    pub fn new_with_store(store: Arc<dyn Store>) -> Result<Self, String> {
+        let components = store.load_components()?;
+        let trust_scores = components.iter().map(|(id, component)| (id.clone(), component.trust_score)).collect();
+        let incidents = store.load_incidents()?;
+        let alerts = store.load_alerts()?;
+        let history = store.load_history()?;
+
+        Ok(Self {
+            system_id: "trust-monitoring-system".to_string(),
+            components: Arc::new(RwLock::new(components)),
+            trust_scores: Arc::new(RwLock::new(trust_scores)),
+            incidents: Arc::new(RwLock::new(incidents)),
+            alerts: Arc::new(RwLock::new(alerts)),
+            peer_last_seen: Arc::new(RwLock::new(HashMap::new())),
+            modify_index: Arc::new(RwLock::new(0)),
+            change_notify: Arc::new(Notify::new()),
+            history: Arc::new(RwLock::new(history)),
+            store: Some(store),
+            metrics: Arc::new(PrometheusMetrics::new()),
+            degraded_since: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+
+    /// Current `ModifyIndex`, for callers that just need a cheap read
+    /// without blocking (e.g. to include in a response body).
+    pub async fn current_index(&self) -> u64 {
+        *self.modify_index.read().await
+    }
+
+    /// Bumps `modify_index` and wakes anyone parked in `wait_for_change`.
+    /// Called after every mutation to components/incidents/alerts.
+    async fn bump_modify_index(&self) {
+        let mut index = self.modify_index.write().await;
+        *index += 1;
+        self.change_notify.notify_waiters();
+    }
+
+    /// Consul-style blocking query: if `index` equals the current
+    /// `ModifyIndex`, parks on `change_notify` until the index advances or
+    /// `wait` elapses (default 55s), then returns the current index. If
+    /// `index` is `None` or already stale, returns immediately.
+    pub async fn wait_for_change(&self, index: Option<u64>, wait: Option<Duration>) -> u64 {
+        let notified = self.change_notify.notified();
+        let current = self.current_index().await;
+        if index != Some(current) {
+            return current;
+        }
+
+        let wait = wait.unwrap_or(Duration::from_secs(55));
+        let _ = tokio::time::timeout(wait, notified).await;
+        self.current_index().await
+    }
+
+    /// Initialize the system with sample components. A no-op if components
+    /// were already restored from a persistent store, so restarting a node
+    /// backed by `new_with_store` doesn't clobber real state with samples.
     pub async fn initialize(&self) -> Result<(), String> {
-        println!("🚀 Initializing Trust Monitoring System...");
-        
-        // Add sample components
+        tracing::info!("🚀 Initializing Trust Monitoring System...");
+
+        if !self.components.read().await.is_empty() {
+            tracing::info!(count = self.components.read().await.len(), "✅ Skipping sample components, already restored from store");
+            return Ok(());
+        }
+
+        // Add sample components, with a couple of `depends_on` edges so
+        // the sample data actually exercises effective-trust propagation.
         let sample_components = vec![
-            ("user-service", "User Service", "microservice"),
-            ("payment-service", "Payment Service", "microservice"),
-            ("inventory-service", "Inventory Service", "microservice"),
-            ("database-primary", "Primary Database", "database"),
-            ("cache-redis", "Redis Cache", "cache"),
-            ("load-balancer", "Load Balancer", "infrastructure"),
+            ("user-service", "User Service", "microservice", vec![("database-primary", 1.0), ("cache-redis", 0.5)]),
+            ("payment-service", "Payment Service", "microservice", vec![("database-primary", 1.0)]),
+            ("inventory-service", "Inventory Service", "microservice", vec![("database-primary", 1.0), ("cache-redis", 0.5)]),
+            ("database-primary", "Primary Database", "database", vec![]),
+            ("cache-redis", "Redis Cache", "cache", vec![]),
+            ("load-balancer", "Load Balancer", "infrastructure", vec![]),
         ];
 
         let mut components = self.components.write().await;
         let mut trust_scores = self.trust_scores.write().await;
 
-        for (id, name, component_type) in sample_components {
+        for (id, name, component_type, depends_on) in sample_components {
             let component = Component {
                 id: id.to_string(),
                 name: name.to_string(),
@@ -92,64 +656,404 @@ impl TrustMonitoringSystem {
                 trust_score: 0.85, // Start with high trust
                 status: "healthy".to_string(),
                 last_updated: Utc::now(),
+                depends_on: depends_on.into_iter()
+                    .map(|(component_id, weight): (&str, f64)| Dependency { component_id: component_id.to_string(), weight })
+                    .collect(),
             };
-            
+
+            if let Some(store) = &self.store {
+                store.put_component(&component)?;
+            }
             components.insert(id.to_string(), component);
             trust_scores.insert(id.to_string(), 0.85);
         }
 
-        println!("✅ System initialized with {} components", components.len());
+        let component_count = components.len();
+        drop(components);
+        drop(trust_scores);
+
+        self.resync_effective_trust().await?;
+        self.bump_modify_index().await;
+
+        tracing::info!(count = component_count, "✅ System initialized");
         Ok(())
     }
 
     /// Update trust score for a component
     pub async fn update_trust_score(&self, component_id: &str, new_score: f64) -> Result<(), String> {
+        {
+            let mut components = self.components.write().await;
+            let mut trust_scores = self.trust_scores.write().await;
+
+            if let Some(component) = components.get_mut(component_id) {
+                component.trust_score = new_score;
+                component.last_updated = Utc::now();
+                if let Some(store) = &self.store {
+                    store.put_component(component)?;
+                }
+            }
+            trust_scores.insert(component_id.to_string(), new_score);
+        }
+
+        self.resync_effective_trust().await?;
+        self.record_history(component_id, new_score).await?;
+        self.bump_modify_index().await;
+
+        Ok(())
+    }
+
+    /// Appends `score` to `component_id`'s in-memory history, write-through
+    /// persisting it if a `store` is configured.
+    async fn record_history(&self, component_id: &str, score: f64) -> Result<(), String> {
+        let entry = TrustHistoryEntry { score, recorded_at: Utc::now() };
+
+        if let Some(store) = &self.store {
+            store.append_history(component_id, &entry)?;
+        }
+
+        self.history.write().await.entry(component_id.to_string()).or_insert_with(Vec::new).push(entry);
+        Ok(())
+    }
+
+    /// Records when `component_id` enters `critical` status and, once it
+    /// later recovers to `healthy`, observes the elapsed time into
+    /// `metrics.degradation_recovery_seconds`.
+    async fn track_status_transition(&self, component_id: &str, status: &str) {
+        let mut degraded_since = self.degraded_since.write().await;
+
+        if status == "critical" {
+            degraded_since.entry(component_id.to_string()).or_insert_with(Utc::now);
+        } else if status == "healthy" {
+            if let Some(started) = degraded_since.remove(component_id) {
+                let elapsed = (Utc::now() - started).num_milliseconds() as f64 / 1000.0;
+                self.metrics.degradation_recovery_seconds.observe(elapsed.max(0.0));
+            }
+        }
+    }
+
+    /// Every component's *effective* trust score:
+    /// `effective(c) = own_score(c) * aggregate(effective(d) for d in c.depends_on)`,
+    /// where `aggregate` is the weighted geometric mean implemented by
+    /// `aggregate_dependency_score`. Resolved in reverse-topological order
+    /// (each dependency settled before the components depending on it) so
+    /// the recursion above is never evaluated more than once per component;
+    /// falls back to a capped fixed-point iteration if `depends_on` forms a
+    /// cycle. Components with no dependencies just get back their own
+    /// score.
+    pub async fn effective_trust_scores(&self) -> HashMap<String, f64> {
+        let components = self.components.read().await;
+        match topological_order(&components) {
+            Some(order) => propagate_in_order(&components, &order),
+            None => propagate_fixed_point(&components),
+        }
+    }
+
+    /// Recomputes every component's effective trust score and keeps
+    /// `status`, alerts, and `degraded_since` in sync with *that* instead of
+    /// the raw score, so a struggling dependency's outage correctly
+    /// cascades `warning`/`critical` status (and alerts) to whatever
+    /// depends on it. Called in place of deriving status/alerts from a raw
+    /// score directly, after any mutation that can change a trust score.
+    async fn resync_effective_trust(&self) -> Result<(), String> {
+        let effective = self.effective_trust_scores().await;
+
         let mut components = self.components.write().await;
-        let mut trust_scores = self.trust_scores.write().await;
         let mut alerts = self.alerts.write().await;
 
-        if let Some(component) = components.get_mut(component_id) {
-            component.trust_score = new_score;
-            component.last_updated = Utc::now();
-            
-            // Update status based on trust score
-            component.status = if new_score > 0.8 {
-                "healthy".to_string()
-            } else if new_score > 0.5 {
-                "warning".to_string()
-            } else {
-                "critical".to_string()
-            };
+        for (component_id, &score) in &effective {
+            let status = status_for_score(score);
+            if let Some(component) = components.get_mut(component_id) {
+                component.status = status.clone();
+            }
+            self.track_status_transition(component_id, &status).await;
+
+            if let Some(alert) = alert_for_score(component_id, score) {
+                self.metrics.alerts_total.with_label_values(&[&alert.severity]).inc();
+                alerts.push(alert);
+            }
         }
 
-        trust_scores.insert(component_id.to_string(), new_score);
+        if let Some(store) = &self.store {
+            for component in components.values() {
+                store.put_component(component)?;
+            }
+            store.put_alerts(&alerts)?;
+        }
 
-        // Create alert if trust score is low
-        if new_score < 0.3 {
-            let alert = Alert {
-                id: format!("alert-{}-{}", component_id, Utc::now().timestamp()),
-                component_id: component_id.to_string(),
-                alert_type: "trust_score_critical".to_string(),
-                message: format!("Critical trust score: {:.2}", new_score),
-                severity: "critical".to_string(),
-                timestamp: Utc::now(),
-            };
-            alerts.push(alert);
-        } else if new_score < 0.5 {
-            let alert = Alert {
-                id: format!("alert-{}-{}", component_id, Utc::now().timestamp()),
-                component_id: component_id.to_string(),
-                alert_type: "trust_score_warning".to_string(),
-                message: format!("Warning trust score: {:.2}", new_score),
-                severity: "warning".to_string(),
-                timestamp: Utc::now(),
-            };
-            alerts.push(alert);
+        Ok(())
+    }
+
+    /// Past trust scores recorded for `component_id`, oldest first, for
+    /// `GET /history/{component_id}`.
+    pub async fn get_history(&self, component_id: &str) -> Vec<TrustHistoryEntry> {
+        self.history.read().await.get(component_id).cloned().unwrap_or_default()
+    }
+
+    /// Validate and apply every operation in `changeset` atomically: all
+    /// the `RwLock` write guards are acquired once up front, every
+    /// `set_trust`/`remove_component` op is checked against an unknown
+    /// component id before anything is mutated (an unknown id rejects the
+    /// whole changeset), and only then are the mutations committed. The
+    /// returned `AppliedChangeset` can be handed to `revert` to undo them.
+    pub async fn apply(&self, changeset: Changeset) -> Result<AppliedChangeset, String> {
+        let mut components = self.components.write().await;
+        let mut trust_scores = self.trust_scores.write().await;
+        let mut incidents = self.incidents.write().await;
+
+        for op in &changeset.ops {
+            match op {
+                ChangesetOp::SetTrust { component_id, .. } | ChangesetOp::RemoveComponent { component_id } => {
+                    if !components.contains_key(component_id) {
+                        return Err(format!("changeset references unknown component id '{}'", component_id));
+                    }
+                }
+                ChangesetOp::AddComponent(_) | ChangesetOp::RaiseIncident(_) => {}
+            }
+        }
+
+        let mut undo_ops = Vec::new();
+        let mut added_in_changeset = HashSet::new();
+        let mut history_writes = Vec::new();
+
+        for op in changeset.ops {
+            match op {
+                ChangesetOp::AddComponent(component) => {
+                    let component_id = component.id.clone();
+                    trust_scores.insert(component_id.clone(), component.trust_score);
+                    if let Some(store) = &self.store {
+                        store.put_component(&component)?;
+                    }
+                    history_writes.push((component_id.clone(), component.trust_score));
+                    match components.insert(component_id.clone(), component) {
+                        Some(replaced) => undo_ops.push(UndoOp::RestoreComponent(replaced)),
+                        None => undo_ops.push(UndoOp::RemoveComponent(component_id.clone())),
+                    }
+                    added_in_changeset.insert(component_id);
+                }
+                ChangesetOp::SetTrust { component_id, new_score } => {
+                    if let Some(component) = components.get_mut(&component_id) {
+                        if !added_in_changeset.contains(&component_id) {
+                            undo_ops.push(UndoOp::SetTrust { component_id: component_id.clone(), previous_score: component.trust_score });
+                        }
+                        component.trust_score = new_score;
+                        component.last_updated = Utc::now();
+                        if let Some(store) = &self.store {
+                            store.put_component(component)?;
+                        }
+                    }
+                    history_writes.push((component_id.clone(), new_score));
+                    trust_scores.insert(component_id, new_score);
+                }
+                ChangesetOp::RemoveComponent { component_id } => {
+                    if let Some(component) = components.remove(&component_id) {
+                        trust_scores.remove(&component_id);
+                        if let Some(store) = &self.store {
+                            store.remove_component(&component_id)?;
+                        }
+                        undo_ops.push(UndoOp::RestoreComponent(component));
+                    }
+                }
+                ChangesetOp::RaiseIncident(incident) => {
+                    undo_ops.push(UndoOp::RemoveIncident(incident.id.clone()));
+                    incidents.push(incident);
+                    self.metrics.incidents_total.inc();
+                    if let Some(store) = &self.store {
+                        store.put_incidents(&incidents)?;
+                    }
+                }
+            }
         }
 
+        drop(components);
+        drop(trust_scores);
+        drop(incidents);
+
+        self.resync_effective_trust().await?;
+        for (component_id, score) in &history_writes {
+            self.record_history(component_id, *score).await?;
+        }
+        self.bump_modify_index().await;
+
+        Ok(AppliedChangeset { undo_ops })
+    }
+
+    /// Undo a changeset previously committed by `apply`, restoring the
+    /// component/trust/incident state it replaced.
+    pub async fn revert(&self, applied: AppliedChangeset) -> Result<(), String> {
+        let mut components = self.components.write().await;
+        let mut trust_scores = self.trust_scores.write().await;
+        let mut incidents = self.incidents.write().await;
+
+        // Undo in reverse so e.g. a component that was added and then had
+        // its trust score changed within the same changeset is fully
+        // unwound before the add itself is undone.
+        let mut history_writes = Vec::new();
+
+        for undo in applied.undo_ops.into_iter().rev() {
+            match undo {
+                UndoOp::RemoveComponent(component_id) => {
+                    components.remove(&component_id);
+                    trust_scores.remove(&component_id);
+                    if let Some(store) = &self.store {
+                        store.remove_component(&component_id)?;
+                    }
+                }
+                UndoOp::RestoreComponent(component) => {
+                    trust_scores.insert(component.id.clone(), component.trust_score);
+                    if let Some(store) = &self.store {
+                        store.put_component(&component)?;
+                    }
+                    components.insert(component.id.clone(), component);
+                }
+                UndoOp::SetTrust { component_id, previous_score } => {
+                    if let Some(component) = components.get_mut(&component_id) {
+                        component.trust_score = previous_score;
+                        component.last_updated = Utc::now();
+                        if let Some(store) = &self.store {
+                            store.put_component(component)?;
+                        }
+                    }
+                    history_writes.push((component_id.clone(), previous_score));
+                    trust_scores.insert(component_id, previous_score);
+                }
+                UndoOp::RemoveIncident(incident_id) => {
+                    incidents.retain(|incident| incident.id != incident_id);
+                    if let Some(store) = &self.store {
+                        store.put_incidents(&incidents)?;
+                    }
+                }
+            }
+        }
+
+        drop(components);
+        drop(trust_scores);
+        drop(incidents);
+
+        self.resync_effective_trust().await?;
+        for (component_id, score) in history_writes {
+            self.record_history(&component_id, score).await?;
+        }
+        self.bump_modify_index().await;
+
         Ok(())
     }
 
+    /// Builds this node's digest of every component it knows about, split
+    /// into chunks that each fit one UDP datagram.
+    async fn gossip_message_chunks(&self) -> Vec<GossipMessage> {
+        let components = self.components.read().await;
+        let entries: Vec<GossipEntry> = components.values()
+            .map(|component| GossipEntry {
+                component_id: component.id.clone(),
+                trust_score: component.trust_score,
+                last_updated: component.last_updated,
+            })
+            .collect();
+
+        entries
+            .chunks(GOSSIP_MAX_ENTRIES_PER_DATAGRAM)
+            .map(|chunk| GossipMessage { node_id: self.system_id.clone(), entries: chunk.to_vec() })
+            .collect()
+    }
+
+    /// Merges a peer's gossip message into `components`/`trust_scores`
+    /// using last-writer-wins by `last_updated` (ties broken by the higher
+    /// node id), so a merge can never overwrite a strictly newer local
+    /// score with an older remote one. Ignores messages
+    /// carrying our own `system_id` to avoid feedback loops.
+    pub async fn merge_gossip_message(&self, message: &GossipMessage) {
+        if message.node_id == self.system_id {
+            return;
+        }
+
+        for entry in &message.entries {
+            self.merge_gossip_entry(&message.node_id, entry).await;
+        }
+    }
+
+    /// Applies a single gossip entry if it wins last-writer-wins against
+    /// the current local state, re-deriving status/alerts exactly as
+    /// `update_trust_score` does.
+    async fn merge_gossip_entry(&self, from_node_id: &str, entry: &GossipEntry) {
+        {
+            let mut components = self.components.write().await;
+            let mut trust_scores = self.trust_scores.write().await;
+
+            let should_apply = match components.get(&entry.component_id) {
+                Some(existing) => {
+                    entry.last_updated > existing.last_updated
+                        || (entry.last_updated == existing.last_updated && from_node_id > self.system_id.as_str())
+                }
+                None => true,
+            };
+            if !should_apply {
+                return;
+            }
+
+            components.entry(entry.component_id.clone())
+                .and_modify(|component| {
+                    component.trust_score = entry.trust_score;
+                    component.last_updated = entry.last_updated;
+                })
+                .or_insert_with(|| Component {
+                    id: entry.component_id.clone(),
+                    name: entry.component_id.clone(),
+                    component_type: "unknown".to_string(),
+                    trust_score: entry.trust_score,
+                    status: status_for_score(entry.trust_score),
+                    last_updated: entry.last_updated,
+                    depends_on: Vec::new(),
+                });
+            trust_scores.insert(entry.component_id.clone(), entry.trust_score);
+
+            if let Some(store) = &self.store {
+                if let Some(component) = components.get(&entry.component_id) {
+                    if let Err(e) = store.put_component(component) {
+                        tracing::error!(component_id = %entry.component_id, error = %e, "failed to persist component from gossip merge");
+                    }
+                }
+            }
+        }
+
+        if let Err(e) = self.resync_effective_trust().await {
+            tracing::error!(error = %e, "failed to resync effective trust after gossip merge");
+        }
+        self.record_history(&entry.component_id, entry.trust_score).await.ok();
+        self.bump_modify_index().await;
+    }
+
+    /// Records that a gossip message was just received from `peer`, so
+    /// `GET /peers` can report how recently this node last heard from it.
+    async fn record_peer_contact(&self, peer: &str) {
+        self.peer_last_seen.write().await.insert(peer.to_string(), Utc::now());
+    }
+
+    /// Last-seen timestamp per peer, for `GET /peers`.
+    pub async fn peer_status(&self) -> HashMap<String, DateTime<Utc>> {
+        self.peer_last_seen.read().await.clone()
+    }
+
+    /// Joins the gossip cluster: binds a UDP socket on `bind_addr` and
+    /// spawns a background task that, every `interval`, sends this node's
+    /// component digest to a random subset of `peers` and merges whatever
+    /// digests it receives. Returns the task handle; abort it to leave the
+    /// cluster.
+    pub async fn join_gossip(self: &Arc<Self>, bind_addr: &str, peers: Vec<String>, interval: Duration) -> Result<tokio::task::JoinHandle<()>, String> {
+        let socket = tokio::net::UdpSocket::bind(bind_addr).await
+            .map_err(|e| format!("failed to bind gossip socket on {}: {}", bind_addr, e))?;
+        let socket = Arc::new(socket);
+        let system = self.clone();
+
+        let handle = tokio::spawn(async move {
+            tokio::join!(
+                gossip_send_loop(system.clone(), socket.clone(), peers, interval),
+                gossip_recv_loop(system, socket),
+            );
+        });
+
+        Ok(handle)
+    }
+
     /// Get system status
     pub async fn get_system_status(&self) -> SystemStatus {
         let components = self.components.read().await;
@@ -181,6 +1085,32 @@ impl TrustMonitoringSystem {
         }
     }
 
+    /// Refreshes `trust_score`/`overall_trust` from current state and
+    /// renders the registry (including the `incidents_total`,
+    /// `alerts_total`, and `degradation_recovery_seconds` metrics kept
+    /// up-to-date as mutations happen) in Prometheus text exposition
+    /// format, for `GET /metrics`.
+    pub async fn render_metrics(&self) -> Result<String, String> {
+        {
+            let components = self.components.read().await;
+            for component in components.values() {
+                self.metrics
+                    .trust_score_gauge
+                    .with_label_values(&[&component.id, &component.component_type])
+                    .set(component.trust_score);
+            }
+        }
+
+        let status = self.get_system_status().await;
+        self.metrics.overall_trust.set(status.overall_trust);
+
+        let encoder = prometheus::TextEncoder::new();
+        let metric_families = self.metrics.registry.gather();
+        let mut buffer = Vec::new();
+        encoder.encode(&metric_families, &mut buffer).map_err(|e| e.to_string())?;
+        String::from_utf8(buffer).map_err(|e| e.to_string())
+    }
+
     /// Get trust scores for all components
     pub async fn get_trust_scores(&self) -> HashMap<String, f64> {
         let trust_scores = self.trust_scores.read().await;
@@ -201,7 +1131,7 @@ impl TrustMonitoringSystem {
 
     /// Simulate trust degradation
     pub async fn simulate_trust_degradation(&self) -> Result<(), String> {
-        println!("⚠️  Simulating trust degradation...");
+        tracing::warn!("⚠️  Simulating trust degradation...");
         
         // Simulate database issues
         self.update_trust_score("database-primary", 0.35).await?;
@@ -210,24 +1140,152 @@ impl TrustMonitoringSystem {
         // Simulate service issues
         self.update_trust_score("payment-service", 0.15).await?;
         
-        println!("✅ Trust degradation simulation completed");
+        tracing::info!("✅ Trust degradation simulation completed");
         Ok(())
     }
 
     /// Simulate recovery
     pub async fn simulate_recovery(&self) -> Result<(), String> {
-        println!("🔧 Simulating recovery process...");
+        tracing::info!("🔧 Simulating recovery process...");
         
         // Restore trust scores
         self.update_trust_score("database-primary", 0.80).await?;
         self.update_trust_score("cache-redis", 0.85).await?;
         self.update_trust_score("payment-service", 0.75).await?;
         
-        println!("✅ Recovery simulation completed");
+        tracing::info!("✅ Recovery simulation completed");
         Ok(())
     }
 }
 
+/// Wraps a Casbin `Enforcer` behind a `RwLock` so HTTP handlers can check
+/// `enforce(actor, object, action)` concurrently while `reload_policy`
+/// swaps in a freshly-read policy file without restarting the server.
+pub struct Permissions {
+    enforcer: Arc<RwLock<Enforcer>>,
+    policy_path: String,
+}
+
+impl Permissions {
+    /// Loads the Casbin model and policy from disk. `CASBIN_MODEL_PATH` /
+    /// `CASBIN_POLICY_PATH` (see `main`) default to `casbin_model.conf` /
+    /// `casbin_policy.csv` checked in alongside this crate, which define an
+    /// allow-everything policy so the server is still runnable out of the
+    /// box; an operator deploying this for real should point the env vars
+    /// at a model/policy that actually restricts access.
+    pub async fn load(model_path: &str, policy_path: &str) -> Result<Self, String> {
+        let enforcer = Enforcer::new(model_path, policy_path).await
+            .map_err(|e| format!("failed to load casbin model/policy: {}", e))?;
+        Ok(Self {
+            enforcer: Arc::new(RwLock::new(enforcer)),
+            policy_path: policy_path.to_string(),
+        })
+    }
+
+    /// Checks whether `actor` may perform `action` on `object`.
+    pub async fn enforce(&self, actor: &str, object: &str, action: &str) -> Result<bool, String> {
+        let enforcer = self.enforcer.read().await;
+        enforcer.enforce((actor, object, action)).map_err(|e| format!("enforce failed: {}", e))
+    }
+
+    /// Re-reads the policy file from disk, so an edited policy takes
+    /// effect without restarting the server.
+    pub async fn reload_policy(&self) -> Result<(), String> {
+        let mut enforcer = self.enforcer.write().await;
+        enforcer.load_policy().await.map_err(|e| format!("failed to reload casbin policy: {}", e))
+    }
+
+    /// Spawns a background task that polls the policy file's mtime every
+    /// `poll_interval` and calls `reload_policy` when it changes, so an
+    /// operator can hot-swap the policy file in place.
+    pub fn watch_for_changes(self: Arc<Self>, poll_interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut last_modified = std::fs::metadata(&self.policy_path).and_then(|m| m.modified()).ok();
+            let mut ticker = tokio::time::interval(poll_interval);
+            loop {
+                ticker.tick().await;
+                let Ok(modified) = std::fs::metadata(&self.policy_path).and_then(|m| m.modified()) else {
+                    continue;
+                };
+                if Some(modified) == last_modified {
+                    continue;
+                }
+                last_modified = Some(modified);
+                if let Err(e) = self.reload_policy().await {
+                    tracing::error!(error = %e, "failed to hot-reload casbin policy");
+                }
+            }
+        })
+    }
+}
+
+/// Rejection produced when `Permissions::enforce` denies a request.
+#[derive(Debug)]
+struct Forbidden;
+impl warp::reject::Reject for Forbidden {}
+
+/// Builds a filter that extracts the actor from the `Authorization: Bearer
+/// <actor>` header (defaulting to `"anonymous"` if absent) and calls
+/// `Permissions::enforce(actor, object, action)`, rejecting with
+/// `Forbidden` (mapped to a 403 by `handle_rejection`) unless it's
+/// allowed. GET routes should pass `action: "read"`, POST routes
+/// `action: "write"`.
+fn with_auth(permissions: Arc<Permissions>, object: &'static str, action: &'static str) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>("authorization")
+        .and_then(move |header: Option<String>| {
+            let permissions = permissions.clone();
+            async move {
+                let actor = header.as_deref()
+                    .and_then(|value| value.strip_prefix("Bearer "))
+                    .unwrap_or("anonymous");
+
+                match permissions.enforce(actor, object, action).await {
+                    Ok(true) => Ok(()),
+                    _ => Err(warp::reject::custom(Forbidden)),
+                }
+            }
+        })
+        .untuple_one()
+}
+
+/// Turns a `Forbidden` rejection into a 403 response instead of warp's
+/// default 500, leaving every other rejection (404s, bad query params) to
+/// warp's built-in handling.
+async fn handle_rejection(err: warp::Rejection) -> Result<impl warp::Reply, warp::Rejection> {
+    if err.find::<Forbidden>().is_some() {
+        Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({"status": "error", "message": "forbidden"})),
+            warp::http::StatusCode::FORBIDDEN,
+        ))
+    } else {
+        Err(err)
+    }
+}
+
+/// Query params accepted by every read route except `/` and `/metrics` to support Consul-style
+/// blocking queries: `?index=<n>&wait=<seconds>`. When `index` matches the
+/// current `ModifyIndex`, the handler parks in `wait_for_change` until it
+/// advances or `wait` elapses.
+#[derive(Debug, Deserialize)]
+struct IndexQuery {
+    index: Option<u64>,
+    wait: Option<u64>,
+}
+
+/// Wraps `body` with the resolved `index` (as both an `X-Trust-Index`
+/// header and an `index` field in the JSON) so a dashboard can pass the
+/// returned index straight back into its next request's `?index=`.
+fn indexed_reply(index: u64, body: serde_json::Value) -> impl warp::Reply {
+    let mut envelope = serde_json::json!({ "index": index });
+    if let serde_json::Value::Object(map) = body {
+        envelope.as_object_mut().unwrap().extend(map);
+    } else {
+        envelope.as_object_mut().unwrap().insert("data".to_string(), body);
+    }
+
+    warp::reply::with_header(warp::reply::json(&envelope), "X-Trust-Index", index.to_string())
+}
+
 /// HTTP API handlers
 async fn get_root() -> Result<impl warp::Reply, Infallible> {
     let response = serde_json::json!({
@@ -238,6 +1296,9 @@ async fn get_root() -> Result<impl warp::Reply, Infallible> {
             "GET /trust-scores": "Current trust scores for all components",
             "GET /incidents": "Active incidents",
             "GET /alerts": "Active alerts",
+            "GET /peers": "Gossip peer last-seen timestamps",
+            "GET /history/{component_id}": "Past trust scores for a component",
+            "GET /metrics": "Prometheus metrics in text exposition format",
             "POST /simulate-degradation": "Simulate trust degradation",
             "POST /simulate-recovery": "Simulate recovery process"
         }
@@ -245,26 +1306,56 @@ async fn get_root() -> Result<impl warp::Reply, Infallible> {
     Ok(warp::reply::json(&response))
 }
 
-async fn get_system_status(system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+#[tracing::instrument(skip(system))]
+async fn get_system_status(query: IndexQuery, system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+    let index = system.wait_for_change(query.index, query.wait.map(Duration::from_secs)).await;
     let status = system.get_system_status().await;
-    Ok(warp::reply::json(&status))
+    Ok(indexed_reply(index, serde_json::json!(status)))
 }
 
-async fn get_trust_scores(system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
-    let scores = system.get_trust_scores().await;
-    Ok(warp::reply::json(&scores))
+#[tracing::instrument(skip(system))]
+async fn get_trust_scores(query: IndexQuery, system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+    let index = system.wait_for_change(query.index, query.wait.map(Duration::from_secs)).await;
+    let raw = system.get_trust_scores().await;
+    let effective = system.effective_trust_scores().await;
+    Ok(indexed_reply(index, serde_json::json!({ "raw": raw, "effective": effective })))
 }
 
-async fn get_active_incidents(system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+#[tracing::instrument(skip(system))]
+async fn get_active_incidents(query: IndexQuery, system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+    let index = system.wait_for_change(query.index, query.wait.map(Duration::from_secs)).await;
     let incidents = system.get_active_incidents().await;
-    Ok(warp::reply::json(&incidents))
+    Ok(indexed_reply(index, serde_json::json!(incidents)))
 }
 
-async fn get_active_alerts(system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+#[tracing::instrument(skip(system))]
+async fn get_active_alerts(query: IndexQuery, system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+    let index = system.wait_for_change(query.index, query.wait.map(Duration::from_secs)).await;
     let alerts = system.get_active_alerts().await;
-    Ok(warp::reply::json(&alerts))
+    Ok(indexed_reply(index, serde_json::json!(alerts)))
+}
+
+#[tracing::instrument(skip(system))]
+async fn get_peers(query: IndexQuery, system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+    let index = system.wait_for_change(query.index, query.wait.map(Duration::from_secs)).await;
+    let peers = system.peer_status().await;
+    Ok(indexed_reply(index, serde_json::json!(peers)))
 }
 
+#[tracing::instrument(skip(system))]
+async fn get_component_history(component_id: String, query: IndexQuery, system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+    let index = system.wait_for_change(query.index, query.wait.map(Duration::from_secs)).await;
+    let history = system.get_history(&component_id).await;
+    Ok(indexed_reply(index, serde_json::json!(history)))
+}
+
+#[tracing::instrument(skip(system))]
+async fn get_metrics(system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
+    let body = system.render_metrics().await.unwrap_or_else(|e| format!("# error rendering metrics: {}\n", e));
+    Ok(warp::reply::with_header(body, "content-type", "text/plain; version=0.0.4"))
+}
+
+#[tracing::instrument(skip(system))]
 async fn simulate_degradation(system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
     match system.simulate_trust_degradation().await {
         Ok(_) => Ok(warp::reply::json(&serde_json::json!({"status": "success", "message": "Trust degradation simulated"}))),
@@ -272,6 +1363,7 @@ async fn simulate_degradation(system: Arc<TrustMonitoringSystem>) -> Result<impl
     }
 }
 
+#[tracing::instrument(skip(system))]
 async fn simulate_recovery(system: Arc<TrustMonitoringSystem>) -> Result<impl warp::Reply, Infallible> {
     match system.simulate_recovery().await {
         Ok(_) => Ok(warp::reply::json(&serde_json::json!({"status": "success", "message": "Recovery simulated"}))),
@@ -281,70 +1373,153 @@ async fn simulate_recovery(system: Arc<TrustMonitoringSystem>) -> Result<impl wa
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("🛡️  Trust Monitoring System for Large Distributed Systems");
-    println!("🎯 SCULI-Aligned Trust Assessment Framework");
-    println!();
-    
-    // Create the system
-    let system = Arc::new(TrustMonitoringSystem::new());
-    
+    tracing_subscriber::fmt::init();
+
+    tracing::info!("🛡️  Trust Monitoring System for Large Distributed Systems");
+    tracing::info!("🎯 SCULI-Aligned Trust Assessment Framework");
+
+    // Create the system, backed by a persistent sled store if configured so
+    // components/incidents/alerts/history survive a restart.
+    let system = match std::env::var("TRUST_STORE_PATH") {
+        Ok(path) => {
+            let store: Arc<dyn Store> = Arc::new(SledStore::open(&path)?);
+            tracing::info!(path = %path, "💾 Persisting trust state");
+            Arc::new(TrustMonitoringSystem::new_with_store(store)?)
+        }
+        Err(_) => Arc::new(TrustMonitoringSystem::new()),
+    };
+
     // Initialize the system
     system.initialize().await?;
-    
+
+    // Join the gossip cluster, if configured: periodically push this
+    // node's component digest to configured peers and merge what it
+    // receives, so multiple monitor instances converge on a shared view of
+    // trust instead of each being an isolated process.
+    let gossip_peers: Vec<String> = std::env::var("GOSSIP_PEERS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect();
+    if !gossip_peers.is_empty() {
+        let gossip_bind_addr = std::env::var("GOSSIP_BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:7946".to_string());
+        let gossip_interval = std::env::var("GOSSIP_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(5);
+        match system.join_gossip(&gossip_bind_addr, gossip_peers, Duration::from_secs(gossip_interval)).await {
+            Ok(_handle) => tracing::info!(bind_addr = %gossip_bind_addr, "📡 Gossip joined"),
+            Err(e) => tracing::warn!(error = %e, "⚠️  gossip disabled, failed to bind"),
+        }
+    }
+
+    // Authorization for the mutating/sensitive routes below, via a Casbin
+    // model+policy loaded from disk. Defaults to the permissive
+    // casbin_model.conf/casbin_policy.csv checked in next to this crate, so
+    // the server starts with zero configuration; hot-reloadable via
+    // watch_for_changes so swapping in a real policy doesn't require a
+    // restart.
+    let permissions = Arc::new(
+        Permissions::load(
+            &std::env::var("CASBIN_MODEL_PATH").unwrap_or_else(|_| "casbin_model.conf".to_string()),
+            &std::env::var("CASBIN_POLICY_PATH").unwrap_or_else(|_| "casbin_policy.csv".to_string()),
+        ).await?
+    );
+    permissions.clone().watch_for_changes(Duration::from_secs(5));
+
     // Set up HTTP API
     let root_route = warp::path::end()
         .and(warp::get())
         .and_then(get_root);
-    
+
     let status_route = warp::path("status")
         .and(warp::get())
+        .and(with_auth(permissions.clone(), "status", "read"))
+        .and(warp::query::<IndexQuery>())
         .and(with_system(system.clone()))
         .and_then(get_system_status);
-    
+
     let trust_scores_route = warp::path("trust-scores")
         .and(warp::get())
+        .and(with_auth(permissions.clone(), "trust-scores", "read"))
+        .and(warp::query::<IndexQuery>())
         .and(with_system(system.clone()))
         .and_then(get_trust_scores);
-    
+
     let incidents_route = warp::path("incidents")
         .and(warp::get())
+        .and(with_auth(permissions.clone(), "incidents", "read"))
+        .and(warp::query::<IndexQuery>())
         .and(with_system(system.clone()))
         .and_then(get_active_incidents);
-    
+
     let alerts_route = warp::path("alerts")
         .and(warp::get())
+        .and(with_auth(permissions.clone(), "alerts", "read"))
+        .and(warp::query::<IndexQuery>())
         .and(with_system(system.clone()))
         .and_then(get_active_alerts);
-    
+
+    let peers_route = warp::path("peers")
+        .and(warp::get())
+        .and(with_auth(permissions.clone(), "peers", "read"))
+        .and(warp::query::<IndexQuery>())
+        .and(with_system(system.clone()))
+        .and_then(get_peers);
+
+    let history_route = warp::path("history")
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(with_auth(permissions.clone(), "history", "read"))
+        .and(warp::query::<IndexQuery>())
+        .and(with_system(system.clone()))
+        .and_then(get_component_history);
+
+    let metrics_route = warp::path("metrics")
+        .and(warp::get())
+        .and(with_system(system.clone()))
+        .and_then(get_metrics);
+
     let simulate_degradation_route = warp::path("simulate-degradation")
         .and(warp::post())
+        .and(with_auth(permissions.clone(), "simulation", "write"))
         .and(with_system(system.clone()))
         .and_then(simulate_degradation);
-    
+
     let simulate_recovery_route = warp::path("simulate-recovery")
         .and(warp::post())
+        .and(with_auth(permissions.clone(), "simulation", "write"))
         .and(with_system(system.clone()))
         .and_then(simulate_recovery);
-    
+
     let api = root_route
         .or(status_route)
         .or(trust_scores_route)
         .or(incidents_route)
         .or(alerts_route)
+        .or(peers_route)
+        .or(history_route)
+        .or(metrics_route)
         .or(simulate_degradation_route)
         .or(simulate_recovery_route)
-        .with(warp::cors().allow_any_origin().allow_headers(vec!["content-type"]).allow_methods(vec!["GET", "POST"]));
-    
-    println!("🌐 Starting HTTP API server on http://localhost:3030");
-    println!("📊 Available endpoints:");
-    println!("   GET /status - System status");
-    println!("   GET /trust-scores - Current trust scores");
-    println!("   GET /incidents - Active incidents");
-    println!("   GET /alerts - Active alerts");
-    println!("   POST /simulate-degradation - Simulate trust degradation");
-    println!("   POST /simulate-recovery - Simulate recovery");
-    println!();
-    
+        .recover(handle_rejection)
+        .with(warp::cors().allow_any_origin().allow_headers(vec!["content-type", "authorization"]).allow_methods(vec!["GET", "POST"]));
+
+    tracing::info!("🌐 Starting HTTP API server on http://localhost:3030");
+    tracing::info!("📊 Available endpoints:");
+    tracing::info!("   GET /status - System status");
+    tracing::info!("   GET /trust-scores - Current trust scores");
+    tracing::info!("   GET /incidents - Active incidents");
+    tracing::info!("   GET /alerts - Active alerts");
+    tracing::info!("   GET /peers - Gossip peer last-seen timestamps");
+    tracing::info!("   GET /history/{{component_id}} - Past trust scores for a component");
+    tracing::info!("   GET /metrics - Prometheus metrics");
+    tracing::info!("   POST /simulate-degradation - Simulate trust degradation");
+    tracing::info!("   POST /simulate-recovery - Simulate recovery");
+
     // Start the HTTP server
     warp::serve(api)
         .run(([0, 0, 0, 0], 3030))
@@ -356,3 +1531,102 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 fn with_system(system: Arc<TrustMonitoringSystem>) -> impl Filter<Extract = (Arc<TrustMonitoringSystem>,), Error = Infallible> + Clone {
     warp::any().map(move || system.clone())
 }
+
+/// Every round, sends this node's component digest to a random subset of
+/// `peers`, chunked to fit one UDP datagram each.
+async fn gossip_send_loop(system: Arc<TrustMonitoringSystem>, socket: Arc<tokio::net::UdpSocket>, peers: Vec<String>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+
+        if peers.is_empty() {
+            continue;
+        }
+        let sample_size = 3.min(peers.len());
+        let sample: Vec<&String> = peers.choose_multiple(&mut rand::thread_rng(), sample_size).collect();
+
+        for chunk in system.gossip_message_chunks().await {
+            let Ok(bytes) = serde_json::to_vec(&chunk) else { continue };
+            for peer in &sample {
+                let _ = socket.send_to(&bytes, peer.as_str()).await;
+            }
+        }
+    }
+}
+
+/// Receives peer digests and merges them via
+/// `TrustMonitoringSystem::merge_gossip_message`.
+async fn gossip_recv_loop(system: Arc<TrustMonitoringSystem>, socket: Arc<tokio::net::UdpSocket>) {
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let (len, src) = match socket.recv_from(&mut buf).await {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let Ok(message) = serde_json::from_slice::<GossipMessage>(&buf[..len]) else {
+            continue;
+        };
+
+        system.record_peer_contact(&src.to_string()).await;
+        system.merge_gossip_message(&message).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_temp_path(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("simple-main-test-{}-{:?}.csv", label, std::thread::current().id()))
+    }
+
+    #[tokio::test]
+    async fn test_permissions_load_checked_in_defaults_allows_everything() {
+        let permissions = Permissions::load(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/casbin_model.conf"),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/casbin_policy.csv"),
+        )
+        .await
+        .expect("checked-in default model/policy should always load");
+
+        assert_eq!(permissions.enforce("anonymous", "status", "read").await, Ok(true));
+        assert_eq!(permissions.enforce("anonymous", "simulation", "write").await, Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_permissions_enforce_denies_when_policy_has_no_matching_rule() {
+        let model_path = concat!(env!("CARGO_MANIFEST_DIR"), "/casbin_model.conf");
+        let policy_path = unique_temp_path("empty-policy");
+        std::fs::write(&policy_path, "").unwrap();
+
+        let permissions = Permissions::load(model_path, policy_path.to_str().unwrap()).await.unwrap();
+        assert_eq!(permissions.enforce("anonymous", "status", "read").await, Ok(false));
+
+        let _ = std::fs::remove_file(&policy_path);
+    }
+
+    #[tokio::test]
+    async fn test_with_auth_passes_allowed_request_and_rejects_denied_one() {
+        let model_path = concat!(env!("CARGO_MANIFEST_DIR"), "/casbin_model.conf");
+        let policy_path = unique_temp_path("with-auth");
+        std::fs::write(&policy_path, "p, operator, status, read\n").unwrap();
+
+        let permissions = Arc::new(Permissions::load(model_path, policy_path.to_str().unwrap()).await.unwrap());
+        let route = with_auth(permissions, "status", "read").map(|| "ok").recover(handle_rejection);
+
+        let allowed = warp::test::request()
+            .header("authorization", "Bearer operator")
+            .reply(&route)
+            .await;
+        assert_eq!(allowed.status(), warp::http::StatusCode::OK);
+
+        let denied = warp::test::request()
+            .header("authorization", "Bearer anonymous")
+            .reply(&route)
+            .await;
+        assert_eq!(denied.status(), warp::http::StatusCode::FORBIDDEN);
+
+        let _ = std::fs::remove_file(&policy_path);
+    }
+}